@@ -1,6 +1,6 @@
 use std::hint::black_box;
 
-use arc_slice::ArcBytes;
+use arc_slice::{ArcBytes, ArcBytesMut};
 use bytes::Bytes;
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 
@@ -117,6 +117,187 @@ fn subslice_and_split_black_box(c: &mut Criterion) {
         });
     });
 }
+
+fn concat(c: &mut Criterion) {
+    let parts: [&[u8]; 4] = [
+        b"GET / HTTP/1.1\r\n",
+        b"Host: example.com\r\n",
+        b"",
+        b"\r\n",
+    ];
+    let mut group = c.benchmark_group("concat");
+    group.bench_function("concat", |b| {
+        b.iter(|| <ArcBytesMut>::concat(black_box(&parts)));
+    });
+    group.bench_function("extend_from_slice", |b| {
+        b.iter(|| {
+            let mut bytes = <ArcBytesMut>::new();
+            for part in black_box(&parts) {
+                bytes.extend_from_slice(part);
+            }
+            bytes
+        });
+    });
+}
+
+fn split_into(c: &mut Criterion) {
+    let frames = <ArcBytes>::from_slice(&[0; 4096]);
+    let mut group = c.benchmark_group("split_into");
+    group.bench_function("split_into", |b| {
+        b.iter(|| {
+            let mut rest = frames.clone();
+            while !rest.is_empty() {
+                let (frame, tail) = rest.split_into(16);
+                black_box(frame);
+                rest = tail;
+            }
+        });
+    });
+    group.bench_function("split_off", |b| {
+        b.iter(|| {
+            let mut rest = frames.clone();
+            while !rest.is_empty() {
+                let tail = rest.split_off(16);
+                black_box(std::mem::replace(&mut rest, tail));
+            }
+        });
+    });
+}
+
+fn filter_chunks(c: &mut Criterion) {
+    let frames = <ArcBytes>::from_slice(&[0; 4096]);
+    let keep = |chunk: &[u8]| black_box(chunk)[0] != 0;
+    let mut group = c.benchmark_group("filter_chunks");
+    group.bench_function("subslice", |b| {
+        b.iter(|| {
+            for i in (0..frames.len()).step_by(16) {
+                let chunk = frames.subslice(i..i + 16);
+                if keep(&chunk) {
+                    black_box(chunk);
+                }
+            }
+        });
+    });
+    group.bench_function("borrow_chunks", |b| {
+        b.iter(|| {
+            for chunk in frames.borrow_chunks(16) {
+                if keep(&chunk) {
+                    black_box(chunk.clone_arc());
+                }
+            }
+        });
+    });
+}
+
+fn push_64k(c: &mut Criterion) {
+    const SIZE: usize = 64 * 1024;
+    // buffer growths are counted through capacity changes
+    let push = |capacity_hint: Option<usize>, growths: &mut usize| {
+        let mut bytes = <ArcBytesMut>::new();
+        for i in 0..SIZE {
+            let capacity = bytes.capacity();
+            if let Some(hint) = capacity_hint {
+                bytes.reserve_with_hint(1, hint);
+            }
+            bytes.push(i as u8);
+            *growths += usize::from(bytes.capacity() != capacity);
+        }
+        bytes
+    };
+    let mut group = c.benchmark_group("push_64k");
+    for (name, capacity_hint) in [("push", None), ("push_with_hint", Some(SIZE))] {
+        let mut growths = 0;
+        black_box(push(capacity_hint, &mut growths));
+        println!("push_64k/{name}: {growths} growths");
+        group.bench_function(name, |b| b.iter(|| push(capacity_hint, &mut 0)));
+    }
+}
+
+enum Lz {
+    Literal(&'static [u8]),
+    BackRef { distance: usize, length: usize },
+}
+
+fn lz_decode(c: &mut Criterion) {
+    // synthetic stream mixing literals, RLE runs and overlapping back-references
+    let stream: Vec<Lz> = (0..1024)
+        .flat_map(|i| {
+            [
+                Lz::Literal(b"lorem ipsum"),
+                Lz::BackRef {
+                    distance: 1,
+                    length: 8 + i % 8,
+                },
+                Lz::BackRef {
+                    distance: 5,
+                    length: 32,
+                },
+            ]
+        })
+        .collect();
+    let mut group = c.benchmark_group("lz_decode");
+    group.bench_function("extend_from_within", |b| {
+        b.iter(|| {
+            let mut bytes = <ArcBytesMut>::new();
+            for op in &stream {
+                match *op {
+                    Lz::Literal(lit) => bytes.extend_from_slice(lit),
+                    Lz::BackRef { distance, length } => {
+                        let start = bytes.len() - distance;
+                        bytes.extend_from_within(start..start + length);
+                    }
+                }
+            }
+            bytes
+        });
+    });
+    group.bench_function("push", |b| {
+        b.iter(|| {
+            let mut bytes = <ArcBytesMut>::new();
+            for op in &stream {
+                match *op {
+                    Lz::Literal(lit) => bytes.extend_from_slice(lit),
+                    Lz::BackRef { distance, length } => {
+                        for _ in 0..length {
+                            bytes.push(bytes[bytes.len() - distance]);
+                        }
+                    }
+                }
+            }
+            bytes
+        });
+    });
+}
+
+fn insert_middle(c: &mut Criterion) {
+    let document = vec![b'a'; 16 * 1024];
+    let mut group = c.benchmark_group("insert_middle");
+    group.bench_function("insert_from_slice", |b| {
+        b.iter(|| {
+            let mut bytes = <ArcBytesMut>::from(&document[..]);
+            for i in 0..256 {
+                bytes.insert_from_slice(i * 61 % bytes.len(), b"inserted");
+            }
+            bytes
+        });
+    });
+    group.bench_function("split_rebuild", |b| {
+        b.iter(|| {
+            let mut bytes = <ArcBytesMut>::from(&document[..]);
+            for i in 0..256 {
+                let mut head = bytes.into_shared();
+                let tail = head.split_off(i * 61 % head.len());
+                let mut rebuilt = <ArcBytesMut>::with_capacity(head.len() + 8 + tail.len());
+                rebuilt.extend_from_slice(&head);
+                rebuilt.extend_from_slice(b"inserted");
+                rebuilt.extend_from_slice(&tail);
+                bytes = rebuilt;
+            }
+            bytes
+        });
+    });
+}
+
 criterion_group!(
     benches,
     empty,
@@ -125,5 +306,11 @@ criterion_group!(
     clone_shared,
     subslice_and_split,
     subslice_and_split_black_box,
+    concat,
+    split_into,
+    filter_chunks,
+    push_64k,
+    lz_decode,
+    insert_middle,
 );
 criterion_main!(benches);
@@ -196,7 +196,7 @@ unsafe impl BufMut for BytesMut {
 
     #[inline]
     fn chunk_mut(&mut self) -> &mut UninitSlice {
-        if self.capacity() == self.len() {
+        if self.capacity() == self.len() && self.0.try_reserve_at_most(64) == 0 {
             self.reserve(64);
         }
         self.spare_capacity_mut().into()
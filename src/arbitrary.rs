@@ -0,0 +1,103 @@
+use alloc::vec::Vec;
+use core::{convert::Infallible, ptr::NonNull};
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+#[cfg(feature = "inlined")]
+use crate::inlined::SmallArcSlice;
+use crate::{
+    layout::{Layout, LayoutMut},
+    utils::UnwrapInfallible,
+    ArcSlice, ArcSliceMut,
+};
+
+const MAX_SPARE_CAPACITY: usize = 64;
+
+// Static slices are subslices of this buffer.
+static STATIC_BYTES: [u8; 256] = {
+    let mut bytes = [0; 256];
+    let mut i = 0;
+    while i < bytes.len() {
+        bytes[i] = i as u8;
+        i += 1;
+    }
+    bytes
+};
+
+fn arbitrary_vec(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let bytes = <&[u8]>::arbitrary(u)?;
+    let spare_capacity = u.int_in_range(0..=MAX_SPARE_CAPACITY)?;
+    let mut vec = Vec::with_capacity(bytes.len() + spare_capacity);
+    vec.extend_from_slice(bytes);
+    Ok(vec)
+}
+
+/// Generates bytes with a random representation.
+///
+/// Bytes are copied into an Arc, stored in a vector with spare capacity if the layout supports
+/// arbitrary buffers, or subsliced from a static buffer if the layout supports static or
+/// arbitrary buffers. They are then randomly advanced, truncated or split.
+impl<'a, L: Layout> Arbitrary<'a> for ArcSlice<[u8], L> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut bytes = match u.int_in_range(0..=2)? {
+            0 => ArcSlice::new_bytes(<&[u8]>::arbitrary(u)?),
+            1 => ArcSlice::new_byte_vec(arbitrary_vec(u)?),
+            _ if L::STATIC_DATA.is_some() || L::ANY_BUFFER => {
+                let start = u.int_in_range(0..=STATIC_BYTES.len())?;
+                let end = u.int_in_range(start..=STATIC_BYTES.len())?;
+                let slice: &'static [u8] = &STATIC_BYTES[start..end];
+                let data = L::data_from_static::<_, Infallible>(slice).unwrap_infallible();
+                ArcSlice::init(NonNull::from(slice).cast(), slice.len(), data)
+            }
+            _ => ArcSlice::new_bytes(<&[u8]>::arbitrary(u)?),
+        };
+        let at = u.int_in_range(0..=bytes.len())?;
+        match u.int_in_range(0..=3)? {
+            0 => bytes.advance(at),
+            1 => bytes.truncate(at),
+            2 => drop(bytes.split_off(at)),
+            _ => {}
+        }
+        Ok(bytes)
+    }
+}
+
+/// Generates bytes with a random representation.
+///
+/// Bytes are copied into an Arc, or stored in a vector if the layout supports arbitrary
+/// buffers, both with random spare capacity. They are then randomly advanced or truncated.
+impl<'a, L: LayoutMut> Arbitrary<'a> for ArcSliceMut<[u8], L> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut bytes = if u.arbitrary()? {
+            ArcSliceMut::new_byte_vec(arbitrary_vec(u)?)
+        } else {
+            let slice = <&[u8]>::arbitrary(u)?;
+            let spare_capacity = u.int_in_range(0..=MAX_SPARE_CAPACITY)?;
+            let mut bytes = Self::with_capacity(slice.len() + spare_capacity);
+            bytes.extend_from_slice(slice);
+            bytes
+        };
+        let at = u.int_in_range(0..=bytes.len())?;
+        match u.int_in_range(0..=2)? {
+            0 => bytes.advance(at),
+            1 => bytes.truncate(at),
+            _ => {}
+        }
+        Ok(bytes)
+    }
+}
+
+/// Generates bytes with a random representation, either inlined or not.
+///
+/// Bytes are generated as [`ArcSlice`] ones, then randomly inlined if they fit.
+#[cfg(feature = "inlined")]
+impl<'a, L: Layout> Arbitrary<'a> for SmallArcSlice<[u8], L> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = ArcSlice::arbitrary(u)?;
+        Ok(if u.arbitrary()? {
+            SmallArcSlice::from_arc_inline(bytes)
+        } else {
+            bytes.into()
+        })
+    }
+}
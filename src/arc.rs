@@ -14,19 +14,21 @@ use crate::{
     atomic,
     atomic::AtomicUsize,
     buffer::{
-        Buffer, BufferExt, BufferMut, BufferMutExt, BufferWithMetadata, DynBuffer, Slice, SliceExt,
+        grow_layouts, Buffer, BufferExt, BufferMut, BufferMutExt, BufferSource, BufferWithMetadata,
+        DynBuffer, Slice, SliceExt, SliceMut, SliceMutExt,
     },
     error::{AllocErrorImpl, TryReserveError},
+    layout::Repr,
     macros::is,
     msrv::{ptr, NonZero},
     slice_mut::TryReserveResult,
-    utils::{assert_checked, unreachable_checked, NewChecked, UnwrapChecked},
-    vtable::{generic_take_buffer, VTable},
+    utils::{assert_checked, concat_len, unreachable_checked, NewChecked, UnwrapChecked},
+    vtable::{generic_take_buffer, generic_take_buffer_with_metadata, VTable},
 };
 
 const MAX_REFCOUNT: usize = isize::MAX as usize;
-#[cfg(not(feature = "abort-on-refcount-overflow"))]
 const SATURATED_REFCOUNT: usize = (isize::MIN / 2) as usize;
+const SATURATE_ON_OVERFLOW: bool = !cfg!(feature = "abort-on-refcount-overflow");
 
 const VTABLE_FLAG: usize = !(usize::MAX >> 1);
 const VTABLE_SHIFT: usize = 1;
@@ -36,25 +38,43 @@ const VTABLE_SHIFT: usize = 1;
 #[repr(C, align(2))]
 struct ArcInner<B> {
     refcount: AtomicUsize,
+    // As in `std::sync::Arc`, all strong references collectively hold one weak reference, so
+    // the allocation is released when both counts have dropped to zero.
+    weak: AtomicUsize,
     vtable_or_capacity: *const (),
     buffer: B,
 }
 
 impl<B> ArcInner<B> {
-    fn incr_refcount(&self) {
+    fn incr_refcount<const SATURATE: bool>(&self, count: usize) {
         // See `Arc` documentation
-        let old_size = self.refcount.fetch_add(1, Ordering::Relaxed);
-        if old_size > MAX_REFCOUNT {
-            // Saturate the refcount in no_std, as in Linux refcount
-            #[cfg(feature = "abort-on-refcount-overflow")]
-            crate::utils::abort();
-            #[cfg(not(feature = "abort-on-refcount-overflow"))]
-            self.refcount.store(SATURATED_REFCOUNT, Ordering::Relaxed);
+        let old_size = self.refcount.fetch_add(count, Ordering::Relaxed);
+        if old_size + (count - 1) > MAX_REFCOUNT {
+            if SATURATE {
+                // Saturate the refcount, as in Linux refcount
+                self.refcount.store(SATURATED_REFCOUNT, Ordering::Relaxed);
+            } else {
+                crate::utils::abort();
+            }
         }
     }
 
     fn is_unique(&self) -> bool {
-        self.refcount.load(Ordering::Acquire) == 1
+        if self.refcount.load(Ordering::Relaxed) != 1 {
+            return false;
+        }
+        // See `Arc::is_unique` documentation: the weak count is locked while the refcount is
+        // checked, so no weak reference can be upgraded in the meantime.
+        if self
+            .weak
+            .compare_exchange(1, usize::MAX, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return false;
+        }
+        let unique = self.refcount.load(Ordering::Acquire) == 1;
+        self.weak.store(1, Ordering::Release);
+        unique
     }
 
     fn decr_refcount(&self) -> bool {
@@ -64,13 +84,34 @@ impl<B> ArcInner<B> {
             atomic::fence(Ordering::Acquire);
             return true;
         }
-        // Saturate the refcount in no_std, as in Linux refcount
-        #[cfg(not(feature = "abort-on-refcount-overflow"))]
+        // Saturate the refcount, as in Linux refcount; the refcount can only overflow if a
+        // saturating layout has been used, as aborting layouts abort before.
         if prev_refcount > MAX_REFCOUNT {
             self.refcount.store(SATURATED_REFCOUNT, Ordering::Relaxed);
         }
         false
     }
+
+    fn incr_weak(&self) {
+        // The weak count cannot be locked, as there is at least the incremented weak reference.
+        if self.weak.fetch_add(1, Ordering::Relaxed) > MAX_REFCOUNT {
+            crate::utils::abort();
+        }
+    }
+
+    fn decr_weak(&self) -> bool {
+        if self.weak.fetch_sub(1, Ordering::Release) == 1 {
+            atomic::fence(Ordering::Acquire);
+            return true;
+        }
+        false
+    }
+
+    // Releases the weak reference held by the strong references, once the refcount has
+    // dropped to zero; no weak reference can be created anymore at this point.
+    fn release_weak(&self) -> bool {
+        self.weak.load(Ordering::Acquire) == 1 || self.decr_weak()
+    }
 }
 
 type ErasedArc = NonNull<ArcInner<()>>;
@@ -113,11 +154,15 @@ impl<S: Slice + ?Sized> CompactVec<S> {
 
     unsafe fn take_buffer(
         buffer: NonNull<()>,
+        metadata: Option<(NonNull<()>, TypeId)>,
         ptr: *const (),
         type_id: TypeId,
         start: NonNull<()>,
         length: usize,
     ) -> Option<NonNull<()>> {
+        if metadata.is_some() {
+            return None;
+        }
         let inner = unsafe { vtable::check_unique::<Self>(ptr)? };
         let vec = &unsafe { &*inner }.buffer;
         let capacity = vec.capacity.get();
@@ -137,11 +182,20 @@ impl<S: Slice + ?Sized> CompactVec<S> {
         Some(buffer)
     }
 
-    unsafe fn capacity(ptr: *const (), start: NonNull<()>) -> usize {
+    unsafe fn capacity(ptr: *const (), slice: Option<(NonNull<()>, usize)>) -> usize {
         // MSRV 1.65 let-else
-        let buffer = match unsafe { vtable::check_unique::<Self>(ptr) } {
-            Some(inner) => &unsafe { &*inner }.buffer,
-            None => return usize::MAX,
+        let (buffer, start) = match slice {
+            // items are not droppable, so there is no tail to drop
+            Some((start, _)) => match unsafe { vtable::check_unique::<Self>(ptr) } {
+                Some(inner) => (&unsafe { &*inner }.buffer, start),
+                None => return usize::MAX,
+            },
+            None => {
+                return unsafe { &*ptr.cast::<ArcInner<Self>>() }
+                    .buffer
+                    .capacity
+                    .get()
+            }
         };
         let offset = unsafe { start.cast().offset_from_unsigned(buffer.start) };
         buffer.capacity.get() - offset
@@ -167,7 +221,10 @@ impl<S: Slice + ?Sized> CompactVec<S> {
             }
         }
         unsafe impl<S: Slice + ?Sized> BufferMut<S> for ArcCompactVec<S> {
-            fn as_mut_slice(&mut self) -> &mut S {
+            fn as_mut_slice(&mut self) -> &mut S
+            where
+                S: SliceMut,
+            {
                 unsafe { S::from_raw_parts_mut(self.arc.buffer.start, self.length) }
             }
             fn capacity(&self) -> usize {
@@ -215,6 +272,117 @@ impl<S: Slice + ?Sized> Drop for CompactVec<S> {
 #[allow(type_alias_bounds)]
 type FullVec<S: Slice + ?Sized> = BufferWithMetadata<S::Vec, ()>;
 
+// A vector whose items are allocated with `A`, stored in an `ArcInner` allocated with the same
+// source; the source is thus stored inline, increasing the size of the inner allocation.
+struct VecIn<S: Slice + ?Sized, A: BufferSource> {
+    start: NonNull<S::Item>,
+    length: usize,
+    capacity: usize,
+    alloc: A,
+}
+
+unsafe impl<S: Slice + ?Sized, A: BufferSource> Send for VecIn<S, A> {}
+unsafe impl<S: Slice + ?Sized, A: BufferSource> Sync for VecIn<S, A> {}
+
+impl<S: Slice + ?Sized, A: BufferSource> VecIn<S, A> {
+    fn new<E: AllocErrorImpl>(capacity: usize, alloc: A) -> Result<Self, E> {
+        let layout = Layout::array::<S::Item>(capacity).map_err(|_| E::capacity_overflow())?;
+        let start = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            E::alloc_in(&alloc, layout)?
+        };
+        Ok(Self {
+            start,
+            length: 0,
+            capacity,
+            alloc,
+        })
+    }
+
+    unsafe fn deallocate(ptr: *mut ()) {
+        let inner = ptr.cast::<ArcInner<Self>>();
+        let vec = unsafe { ptr::read(addr_of_mut!((*inner).buffer)) };
+        let layout = Layout::new::<ArcInner<Self>>();
+        unsafe {
+            vec.alloc
+                .deallocate(NonNull::new_unchecked(ptr).cast(), layout);
+        };
+    }
+
+    unsafe fn get_metadata(_ptr: *const (), _type_id: TypeId) -> Option<NonNull<()>> {
+        None
+    }
+
+    unsafe fn take_buffer(
+        _buffer: NonNull<()>,
+        _metadata: Option<(NonNull<()>, TypeId)>,
+        _ptr: *const (),
+        _type_id: TypeId,
+        _start: NonNull<()>,
+        _length: usize,
+    ) -> Option<NonNull<()>> {
+        None
+    }
+}
+
+impl<S: Slice + ?Sized, A: BufferSource> Buffer<S> for VecIn<S, A> {
+    fn as_slice(&self) -> &S {
+        unsafe { S::from_raw_parts(self.start, self.length) }
+    }
+}
+
+unsafe impl<S: Slice + ?Sized, A: BufferSource> BufferMut<S> for VecIn<S, A> {
+    fn as_mut_slice(&mut self) -> &mut S
+    where
+        S: SliceMut,
+    {
+        unsafe { S::from_raw_parts_mut(self.start, self.length) }
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    unsafe fn set_len(&mut self, len: usize) -> bool {
+        self.length = len;
+        true
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let (cur_layout, new_layout, new_capacity) = unsafe {
+            grow_layouts(
+                self.length,
+                self.capacity,
+                additional,
+                Layout::array::<S::Item>,
+            )?
+        };
+        if new_layout.size() != 0 {
+            let start = self.start.cast();
+            self.start = if cur_layout.size() == 0 {
+                self.alloc.allocate(new_layout)?
+            } else {
+                unsafe { self.alloc.grow(start, cur_layout, new_layout)? }
+            }
+            .cast();
+        }
+        self.capacity = new_capacity;
+        Ok(())
+    }
+}
+
+impl<S: Slice + ?Sized, A: BufferSource> Drop for VecIn<S, A> {
+    fn drop(&mut self) {
+        let slice = ptr::slice_from_raw_parts_mut(self.start.as_ptr(), self.length);
+        unsafe { ptr::drop_in_place(slice) };
+        let layout = unsafe { Layout::array::<S::Item>(self.capacity).unwrap_unchecked() };
+        if layout.size() != 0 {
+            unsafe { self.alloc.deallocate(self.start.cast(), layout) };
+        }
+    }
+}
+
 pub(crate) mod vtable {
     use alloc::boxed::Box;
     use core::{
@@ -224,19 +392,32 @@ pub(crate) mod vtable {
         ptr::{addr_of_mut, NonNull},
     };
 
+    #[cfg(feature = "raw-buffer")]
+    use crate::layout::Repr;
     #[allow(unused_imports)]
     use crate::msrv::ConstPtrExt;
     use crate::{
-        arc::{ArcInner, CompactVec},
-        buffer::{Buffer, BufferExt, BufferMut, BufferMutExt, DynBuffer, Slice, SliceExt},
+        arc::{ArcInner, CompactVec, FullVec, VecIn},
+        buffer::{
+            Buffer, BufferExt, BufferMut, BufferMutExt, BufferSource, DynBuffer, Slice, SliceExt,
+            SliceMut, SliceMutExt,
+        },
         error::TryReserveError,
         macros::{is, is_not},
+        msrv::ptr,
         slice_mut::TryReserveResult,
         vtable::{no_capacity, VTable},
     };
 
     unsafe fn deallocate<B>(ptr: *mut ()) {
-        mem::drop(unsafe { Box::from_raw(ptr.cast::<ArcInner<B>>()) });
+        let inner = ptr.cast::<ArcInner<B>>();
+        unsafe { ptr::drop_in_place(addr_of_mut!((*inner).buffer)) };
+        if unsafe { &*inner }.release_weak() {
+            unsafe { free::<B>(ptr) };
+        }
+    }
+    unsafe fn free<B>(ptr: *mut ()) {
+        mem::drop(unsafe { Box::from_raw(ptr.cast::<ArcInner<MaybeUninit<B>>>()) });
     }
     unsafe fn is_buffer_unique<S: ?Sized, B: Buffer<S>>(ptr: *const ()) -> bool {
         let inner = unsafe { &*ptr.cast::<ArcInner<B>>() };
@@ -244,11 +425,11 @@ pub(crate) mod vtable {
     }
 
     unsafe fn get_metadata<B: DynBuffer>(ptr: *const (), type_id: TypeId) -> Option<NonNull<()>> {
-        if is!(B::Metadata, ()) || is_not!({ type_id }, B::Metadata) {
+        if is!(B::Metadata, ()) {
             return None;
         }
         let buffer = &unsafe { &*ptr.cast::<ArcInner<B>>() }.buffer;
-        Some(NonNull::from(buffer.get_metadata()).cast())
+        buffer.get_metadata_by_id(type_id)
     }
 
     pub(super) unsafe fn check_unique<B>(ptr: *const ()) -> Option<*mut ArcInner<B>> {
@@ -257,39 +438,115 @@ pub(crate) mod vtable {
             .then(|| ptr.cast_mut().cast())
     }
 
+    unsafe fn buffer_mut<B: DynBuffer>(ptr: NonNull<()>, type_id: TypeId) -> Option<NonNull<()>> {
+        let inner = unsafe { check_unique::<B>(ptr.as_ptr())? };
+        if is_not!({ type_id }, B::Buffer) {
+            return None;
+        }
+        Some(NonNull::from(unsafe { &mut *inner }.buffer.get_buffer_mut()).cast())
+    }
+
     unsafe fn take_buffer<S: Slice + ?Sized, B: DynBuffer + Buffer<S>>(
         buffer: NonNull<()>,
+        metadata: Option<(NonNull<()>, TypeId)>,
         ptr: *const (),
         type_id: TypeId,
         _start: NonNull<()>,
         length: usize,
     ) -> Option<NonNull<()>> {
+        let metadata = match metadata {
+            Some((metadata, metadata_type_id)) => {
+                if !B::OWNED_METADATA || is_not!({ metadata_type_id }, B::Metadata) {
+                    return None;
+                }
+                Some(metadata)
+            }
+            None => None,
+        };
         let inner = unsafe { check_unique::<B>(ptr)? };
         if is_not!({ type_id }, B::Buffer) || unsafe { &*inner }.buffer.len() != length {
             return None;
         }
-        unsafe { B::take_buffer(addr_of_mut!((*inner).buffer), buffer) };
+        unsafe { B::take_buffer(addr_of_mut!((*inner).buffer), buffer, metadata) };
         mem::drop(unsafe { Box::from_raw(inner.cast::<ArcInner<MaybeUninit<B>>>()) });
         Some(buffer)
     }
 
     unsafe fn capacity<S: Slice + ?Sized, B: BufferMut<S>>(
         ptr: *const (),
-        start: NonNull<()>,
+        slice: Option<(NonNull<()>, usize)>,
     ) -> usize {
-        let buffer = match unsafe { check_unique::<B>(ptr) } {
-            Some(inner) => &unsafe { &*inner }.buffer,
-            None => return usize::MAX,
+        let (buffer, start, length) = match slice {
+            Some((start, length)) => match unsafe { check_unique::<B>(ptr) } {
+                Some(inner) => (&mut unsafe { &mut *inner }.buffer, start.cast(), length),
+                None => return usize::MAX,
+            },
+            None => return unsafe { &*ptr.cast::<ArcInner<B>>() }.buffer.capacity(),
         };
-        buffer.capacity() - unsafe { buffer.offset(start.cast()) }
+        let offset = unsafe { buffer.offset(start) };
+        if S::needs_drop() && buffer.len() != offset + length {
+            let tail_len = buffer.len() - offset - length;
+            // the length is set first, so a panicking drop cannot cause a double drop
+            if !unsafe { buffer.set_len(offset + length) } {
+                return usize::MAX;
+            }
+            let tail = unsafe { start.as_ptr().add(length) };
+            unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, tail_len)) };
+        }
+        buffer.capacity() - offset
+    }
+
+    unsafe fn try_reserve<S: SliceMut + ?Sized, B: BufferMut<S>>(
+        ptr: NonNull<()>,
+        start: NonNull<()>,
+        length: usize,
+        additional: usize,
+        allocate: bool,
+    ) -> TryReserveResult<()> {
+        unsafe {
+            try_reserve_with::<S, B>(ptr, start, length, additional, allocate, |b| {
+                b.as_mut_slice().as_mut_ptr()
+            })
+        }
+    }
+
+    unsafe fn try_reserve_vec<S: Slice + ?Sized>(
+        ptr: NonNull<()>,
+        start: NonNull<()>,
+        length: usize,
+        additional: usize,
+        allocate: bool,
+    ) -> TryReserveResult<()> {
+        unsafe {
+            try_reserve_with::<S, FullVec<S>>(ptr, start, length, additional, allocate, |b| {
+                S::vec_start(b.buffer_mut())
+            })
+        }
+    }
+
+    unsafe fn try_reserve_in<S: Slice + ?Sized, A: BufferSource>(
+        ptr: NonNull<()>,
+        start: NonNull<()>,
+        length: usize,
+        additional: usize,
+        allocate: bool,
+    ) -> TryReserveResult<()> {
+        unsafe {
+            try_reserve_with::<S, VecIn<S, A>>(ptr, start, length, additional, allocate, |b| {
+                b.start
+            })
+        }
     }
 
-    unsafe fn try_reserve<S: Slice + ?Sized, B: BufferMut<S>>(
+    // the start pointer is not derived from the buffer slice for slices which cannot be
+    // mutably borrowed
+    unsafe fn try_reserve_with<S: Slice + ?Sized, B: BufferMut<S>>(
         ptr: NonNull<()>,
         start: NonNull<()>,
         length: usize,
         additional: usize,
         allocate: bool,
+        buffer_start: impl Fn(&mut B) -> NonNull<S::Item>,
     ) -> TryReserveResult<()> {
         let buffer = &mut unsafe { ptr.cast::<ArcInner<B>>().as_mut() }.buffer;
         let offset = unsafe { buffer.offset(start.cast()) };
@@ -297,18 +554,25 @@ pub(crate) mod vtable {
             return (Err(TryReserveError::Unsupported), start);
         }
         let (capacity, start) = unsafe {
-            buffer.try_reserve_impl(
-                offset,
-                length,
-                additional,
-                allocate,
-                |b| b.as_mut_slice().as_mut_ptr(),
-                || (),
-            )
+            buffer.try_reserve_impl(offset, length, additional, allocate, buffer_start, || ())
         };
         (capacity, start.cast())
     }
 
+    unsafe fn set_len<S: Slice + ?Sized, B: BufferMut<S>>(
+        ptr: NonNull<()>,
+        start: NonNull<()>,
+        length: usize,
+        shrink: bool,
+    ) -> bool {
+        let buffer = &mut unsafe { ptr.cast::<ArcInner<B>>().as_mut() }.buffer;
+        let offset = unsafe { buffer.offset(start.cast()) };
+        if !shrink && offset + length <= buffer.len() {
+            return true;
+        }
+        unsafe { buffer.set_len(offset + length) }
+    }
+
     #[cfg(feature = "raw-buffer")]
     unsafe fn drop<B>(ptr: *const ()) {
         let inner = unsafe { &*ptr.cast::<ArcInner<B>>() };
@@ -325,9 +589,25 @@ pub(crate) mod vtable {
         }
     }
 
+    #[cfg(feature = "raw-buffer")]
+    unsafe fn drop_in<S: Slice + ?Sized, A: BufferSource>(ptr: *const ()) {
+        let inner = unsafe { &*ptr.cast::<ArcInner<VecIn<S, A>>>() };
+        if inner.decr_refcount() {
+            unsafe { VecIn::<S, A>::deallocate(ptr.cast_mut()) }
+        }
+    }
+
+    #[cfg(feature = "raw-buffer")]
+    unsafe fn drop_with_unique_hint_in<S: Slice + ?Sized, A: BufferSource>(ptr: *const ()) {
+        let inner = unsafe { &*ptr.cast::<ArcInner<VecIn<S, A>>>() };
+        if inner.is_unique() || inner.decr_refcount() {
+            unsafe { VecIn::<S, A>::deallocate(ptr.cast_mut()) }
+        }
+    }
+
     #[cfg(feature = "raw-buffer")]
     unsafe fn clone(ptr: *const ()) {
-        unsafe { &*ptr.cast::<ArcInner<()>>() }.incr_refcount();
+        unsafe { &*ptr.cast::<ArcInner<()>>() }.incr_refcount::<{ super::SATURATE_ON_OVERFLOW }>(1);
     }
 
     #[cfg(feature = "raw-buffer")]
@@ -345,11 +625,14 @@ pub(crate) mod vtable {
     pub(crate) fn new<S: ?Sized + Slice, B: DynBuffer + Buffer<S>>() -> &'static VTable {
         &VTable {
             deallocate: deallocate::<B>,
+            free: Some(free::<B>),
             is_buffer_unique: is_buffer_unique::<S, B>,
             get_metadata: get_metadata::<B>,
             take_buffer: take_buffer::<S, B>,
             capacity: no_capacity,
             try_reserve: None,
+            buffer_mut: None,
+            set_len: None,
             #[cfg(feature = "raw-buffer")]
             drop: drop::<B>,
             #[cfg(feature = "raw-buffer")]
@@ -360,17 +643,22 @@ pub(crate) mod vtable {
             into_arc,
             #[cfg(feature = "raw-buffer")]
             into_arc_fallible,
+            #[cfg(feature = "raw-buffer")]
+            repr: Repr::ArcBuffer,
         }
     }
 
-    pub(crate) fn new_mut<S: ?Sized + Slice, B: DynBuffer + BufferMut<S>>() -> &'static VTable {
+    pub(crate) fn new_mut<S: ?Sized + SliceMut, B: DynBuffer + BufferMut<S>>() -> &'static VTable {
         &VTable {
             deallocate: deallocate::<B>,
+            free: Some(free::<B>),
             is_buffer_unique: is_buffer_unique::<S, B>,
             get_metadata: get_metadata::<B>,
             take_buffer: take_buffer::<S, B>,
             capacity: capacity::<S, B>,
             try_reserve: Some(try_reserve::<S, B>),
+            buffer_mut: Some(buffer_mut::<B>),
+            set_len: Some(set_len::<S, B>),
             #[cfg(feature = "raw-buffer")]
             drop: drop::<B>,
             #[cfg(feature = "raw-buffer")]
@@ -381,20 +669,74 @@ pub(crate) mod vtable {
             into_arc,
             #[cfg(feature = "raw-buffer")]
             into_arc_fallible,
+            #[cfg(feature = "raw-buffer")]
+            repr: Repr::ArcBuffer,
+        }
+    }
+
+    pub(crate) fn new_vec_in<S: Slice + ?Sized, A: BufferSource>() -> &'static VTable {
+        &VTable {
+            deallocate: VecIn::<S, A>::deallocate,
+            free: None,
+            is_buffer_unique: is_buffer_unique::<S, VecIn<S, A>>,
+            get_metadata: VecIn::<S, A>::get_metadata,
+            take_buffer: VecIn::<S, A>::take_buffer,
+            capacity: capacity::<S, VecIn<S, A>>,
+            try_reserve: Some(try_reserve_in::<S, A>),
+            buffer_mut: None,
+            set_len: Some(set_len::<S, VecIn<S, A>>),
+            #[cfg(feature = "raw-buffer")]
+            drop: drop_in::<S, A>,
+            #[cfg(feature = "raw-buffer")]
+            drop_with_unique_hint: drop_with_unique_hint_in::<S, A>,
+            #[cfg(feature = "raw-buffer")]
+            clone,
+            #[cfg(feature = "raw-buffer")]
+            into_arc,
+            #[cfg(feature = "raw-buffer")]
+            into_arc_fallible,
+            #[cfg(feature = "raw-buffer")]
+            repr: Repr::ArcBuffer,
         }
     }
 
     pub(crate) fn new_vec<S: Slice + ?Sized>() -> &'static VTable {
         if S::needs_drop() {
-            new::<S, super::FullVec<S>>()
+            &VTable {
+                deallocate: deallocate::<FullVec<S>>,
+                free: Some(free::<FullVec<S>>),
+                is_buffer_unique: is_buffer_unique::<S, FullVec<S>>,
+                get_metadata: get_metadata::<FullVec<S>>,
+                take_buffer: take_buffer::<S, FullVec<S>>,
+                capacity: capacity::<S, FullVec<S>>,
+                try_reserve: Some(try_reserve_vec::<S>),
+                buffer_mut: None,
+                set_len: Some(set_len::<S, FullVec<S>>),
+                #[cfg(feature = "raw-buffer")]
+                drop: drop::<FullVec<S>>,
+                #[cfg(feature = "raw-buffer")]
+                drop_with_unique_hint: drop_with_unique_hint::<FullVec<S>>,
+                #[cfg(feature = "raw-buffer")]
+                clone,
+                #[cfg(feature = "raw-buffer")]
+                into_arc,
+                #[cfg(feature = "raw-buffer")]
+                into_arc_fallible,
+                #[cfg(feature = "raw-buffer")]
+                repr: Repr::ArcBuffer,
+            }
         } else {
             &VTable {
                 deallocate: deallocate::<CompactVec<S>>,
+                free: Some(free::<CompactVec<S>>),
                 is_buffer_unique: CompactVec::<S>::is_buffer_unique,
                 get_metadata: CompactVec::<S>::get_metadata,
                 take_buffer: CompactVec::<S>::take_buffer,
                 capacity: CompactVec::<S>::capacity,
                 try_reserve: Some(CompactVec::<S>::try_reserve),
+                buffer_mut: None,
+                // items without drop glue don't need their length to be tracked
+                set_len: None,
                 #[cfg(feature = "raw-buffer")]
                 drop: drop::<CompactVec<S>>,
                 #[cfg(feature = "raw-buffer")]
@@ -405,11 +747,20 @@ pub(crate) mod vtable {
                 into_arc,
                 #[cfg(feature = "raw-buffer")]
                 into_arc_fallible,
+                #[cfg(feature = "raw-buffer")]
+                repr: Repr::ArcBuffer,
             }
         }
     }
 }
 
+fn tag_vtable(vtable: &'static VTable) -> *const () {
+    let vtable_ptr = ptr::from_ref(vtable);
+    vtable_ptr
+        .with_addr(VTABLE_FLAG | (vtable_ptr.addr() >> VTABLE_SHIFT))
+        .cast()
+}
+
 enum VTableOrCapacity {
     VTable(&'static VTable),
     Capacity(usize),
@@ -469,6 +820,7 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         let inner_ptr = E::alloc::<_, ZEROED>(layout)?;
         let inner = ArcInner {
             refcount: AtomicUsize::new(1),
+            weak: AtomicUsize::new(1),
             vtable_or_capacity: ptr::without_provenance(capacity),
             buffer: (),
         };
@@ -505,6 +857,32 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         unsafe { Self::new_unchecked(slice.to_slice()) }
     }
 
+    pub(crate) fn new_concat<E: AllocErrorImpl>(parts: &[&S]) -> Result<(Self, NonNull<S::Item>), E>
+    where
+        S::Item: Copy,
+    {
+        unsafe { Self::new_concat_unchecked(concat_len(parts), parts.iter().copied()) }
+    }
+
+    // `length` must be the sum of the parts lengths
+    pub(crate) unsafe fn new_concat_unchecked<'a, E: AllocErrorImpl>(
+        length: usize,
+        parts: impl Iterator<Item = &'a S>,
+    ) -> Result<(Self, NonNull<S::Item>), E>
+    where
+        S: 'a,
+        S::Item: Copy,
+    {
+        let (arc, start) = Self::allocate_slice::<E, false>(length, length)?;
+        let mut end = start.as_ptr();
+        for part in parts {
+            let part = part.to_slice();
+            unsafe { ptr::copy_nonoverlapping(part.as_ptr(), end, part.len()) };
+            end = unsafe { end.add(part.len()) };
+        }
+        Ok((arc, start))
+    }
+
     #[allow(clippy::type_complexity)]
     pub(crate) fn new_array<E: AllocErrorImpl, const N: usize>(
         array: [S::Item; N],
@@ -514,6 +892,32 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
             .map_err(|err| (err, ManuallyDrop::into_inner(array)))
     }
 
+    pub(crate) fn clone_with<const SATURATE: bool>(&self) -> Self {
+        unsafe { self.inner.as_ref() }.incr_refcount::<SATURATE>(1);
+        Self {
+            inner: self.inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    // adds `count` references at once, each of them owned by a copy of the raw pointer
+    pub(crate) fn forget_clones_with<const SATURATE: bool>(&self, count: usize) {
+        if count > 0 {
+            unsafe { self.inner.as_ref() }.incr_refcount::<SATURATE>(count);
+        }
+    }
+
+    pub(crate) fn forget_clones(&self, count: usize) {
+        self.forget_clones_with::<SATURATE_ON_OVERFLOW>(count);
+    }
+
+    pub(crate) fn repr(&self) -> Repr {
+        match self.vtable_or_capacity() {
+            VTableOrCapacity::VTable(_) => Repr::ArcBuffer,
+            VTableOrCapacity::Capacity(_) => Repr::ArcSlice,
+        }
+    }
+
     fn as_ptr(&self) -> *const () {
         self.inner.as_ptr().cast()
     }
@@ -533,6 +937,113 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         unsafe { self.inner.as_ref() }.is_unique()
     }
 
+    pub(crate) fn downgrade(&self) -> Option<NonNull<()>> {
+        if let VTableOrCapacity::VTable(vtable) = self.vtable_or_capacity() {
+            vtable.free?;
+        }
+        let inner = unsafe { self.inner.as_ref() };
+        let mut weak = inner.weak.load(Ordering::Relaxed);
+        loop {
+            // the weak count is locked by `is_unique`
+            if weak == usize::MAX {
+                core::hint::spin_loop();
+                weak = inner.weak.load(Ordering::Relaxed);
+                continue;
+            } else if weak > MAX_REFCOUNT {
+                crate::utils::abort();
+            }
+            match inner.weak.compare_exchange_weak(
+                weak,
+                weak + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Some(self.inner.cast()),
+                Err(prev) => weak = prev,
+            }
+        }
+    }
+
+    // `ptr` must have been obtained with `downgrade`
+    pub(crate) unsafe fn upgrade<const SATURATE: bool>(ptr: NonNull<()>) -> Option<Self> {
+        let inner = unsafe { ptr.cast::<ArcInner<()>>().as_ref() };
+        let mut refcount = inner.refcount.load(Ordering::Relaxed);
+        loop {
+            if refcount == 0 {
+                return None;
+            } else if refcount > MAX_REFCOUNT {
+                // a saturated refcount never drops to zero
+                if !SATURATE {
+                    crate::utils::abort();
+                }
+                return Some(unsafe { Self::from_raw(ptr) });
+            }
+            match inner.refcount.compare_exchange_weak(
+                refcount,
+                refcount + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Some(unsafe { Self::from_raw(ptr) }),
+                Err(prev) => refcount = prev,
+            }
+        }
+    }
+
+    // `ptr` must have been obtained with `downgrade`
+    pub(crate) unsafe fn clone_weak(ptr: NonNull<()>) {
+        unsafe { ptr.cast::<ArcInner<()>>().as_ref() }.incr_weak();
+    }
+
+    // `ptr` must have been obtained with `downgrade`
+    pub(crate) unsafe fn drop_weak(ptr: NonNull<()>) {
+        if !unsafe { ptr.cast::<ArcInner<()>>().as_ref() }.decr_weak() {
+            return;
+        }
+        // the buffer has already been dropped, only the allocation remains
+        let arc = ManuallyDrop::new(unsafe { Self::from_raw(ptr) });
+        match arc.vtable_or_capacity() {
+            VTableOrCapacity::VTable(vtable) => unsafe {
+                (vtable.free.unwrap_checked())(ptr.as_ptr());
+            },
+            VTableOrCapacity::Capacity(capacity) => {
+                let layout = unsafe { Self::slice_layout(capacity).unwrap_unchecked() };
+                unsafe { dealloc(ptr.as_ptr().cast(), layout) };
+            }
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    /// Checks the arc invariants for the items `[start, start + length)`, panicking if one of
+    /// them is broken; `unique` asserts the arc is not shared.
+    pub(crate) fn validate(&self, start: NonNull<S::Item>, length: usize, unique: bool) {
+        let inner = unsafe { self.inner.as_ref() };
+        let refcount = inner.refcount.load(Ordering::Relaxed);
+        assert_ne!(refcount, 0, "arc refcount is zero");
+        assert_ne!(
+            inner.weak.load(Ordering::Relaxed),
+            0,
+            "arc weak count is zero"
+        );
+        assert!(!unique || refcount == 1, "unique arc is shared");
+        assert!(
+            ANY_BUFFER || inner.vtable_or_capacity.addr() & VTABLE_FLAG == 0,
+            "arc slice has a vtable"
+        );
+        if let VTableOrCapacity::Capacity(capacity) = self.vtable_or_capacity() {
+            if core::mem::size_of::<S::Item>() == 0 {
+                return;
+            }
+            let slice_start = unsafe { self.slice_start() };
+            assert!(start >= slice_start, "slice starts before the arc buffer");
+            let offset = unsafe { start.offset_from_unsigned(slice_start) };
+            assert!(
+                offset + length <= capacity,
+                "slice ends after the arc buffer"
+            );
+        }
+    }
+
     fn vtable_or_capacity(&self) -> VTableOrCapacity {
         let ptr = unsafe { self.inner.as_ref().vtable_or_capacity };
         if ANY_BUFFER && ptr.addr() & VTABLE_FLAG != 0 {
@@ -574,6 +1085,16 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         }
     }
 
+    pub(crate) fn get_buffer_mut<B: Any>(&mut self) -> Option<&mut B> {
+        match self.vtable_or_capacity() {
+            VTableOrCapacity::VTable(vtable) => unsafe {
+                let buffer = (vtable.buffer_mut?)(self.inner.cast(), TypeId::of::<B>())?;
+                Some(buffer.cast().as_mut())
+            },
+            VTableOrCapacity::Capacity(_) => None,
+        }
+    }
+
     pub(crate) unsafe fn take_buffer<B: Buffer<S>, const UNIQUE: bool>(
         self,
         start: NonNull<S::Item>,
@@ -590,6 +1111,27 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         Err(ManuallyDrop::into_inner(this))
     }
 
+    pub(crate) unsafe fn take_buffer_with_metadata<B: Buffer<S>, M: Any>(
+        self,
+        start: NonNull<S::Item>,
+        length: usize,
+    ) -> Result<(B, M), Self> {
+        let this = ManuallyDrop::new(self);
+        if let VTableOrCapacity::VTable(vtable) = this.vtable_or_capacity() {
+            if let Some(buffer_and_metadata) = unsafe {
+                generic_take_buffer_with_metadata::<B, M>(
+                    this.as_ptr(),
+                    vtable,
+                    start.cast(),
+                    length,
+                )
+            } {
+                return Ok(buffer_and_metadata);
+            }
+        }
+        Err(ManuallyDrop::into_inner(this))
+    }
+
     pub(crate) unsafe fn take_array<const N: usize, const UNIQUE: bool>(
         self,
         start: NonNull<S::Item>,
@@ -612,15 +1154,44 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         }
     }
 
-    pub(crate) unsafe fn capacity(&mut self, start: NonNull<S::Item>) -> Option<usize> {
+    // Returns the capacity of a mutable slice `[start, start + length)` of the buffer, or
+    // `None` if it cannot be mutated. The items after the end of the slice are dropped, as they
+    // become spare capacity.
+    pub(crate) unsafe fn mut_capacity(
+        &mut self,
+        start: NonNull<S::Item>,
+        length: usize,
+    ) -> Option<usize> {
+        match self.vtable_or_capacity() {
+            VTableOrCapacity::VTable(vtable) => {
+                Some(unsafe { (vtable.capacity)(self.as_ptr(), Some((start.cast(), length))) })
+                    .filter(|&capacity| capacity != usize::MAX)
+            }
+            VTableOrCapacity::Capacity(capacity) => {
+                if !self.is_unique() {
+                    return None;
+                }
+                let offset = unsafe { start.offset_from_unsigned(self.slice_start()) };
+                if S::needs_drop() {
+                    let tail_len =
+                        unsafe { self.slice_length().unwrap_checked() } - offset - length;
+                    // the length is set first, so a panicking drop cannot cause a double drop
+                    unsafe { self.set_length_unchecked(offset + length) };
+                    let tail = unsafe { start.as_ptr().add(length) };
+                    unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, tail_len)) };
+                }
+                Some(capacity - offset)
+            }
+        }
+    }
+
+    pub(crate) fn buffer_capacity(&self) -> Option<usize> {
         match self.vtable_or_capacity() {
             VTableOrCapacity::VTable(vtable) => {
-                Some(unsafe { (vtable.capacity)(self.as_ptr(), start.cast()) })
+                Some(unsafe { (vtable.capacity)(self.as_ptr(), None) })
                     .filter(|&capacity| capacity != usize::MAX)
             }
-            VTableOrCapacity::Capacity(capacity) => self
-                .is_unique()
-                .then(|| capacity - unsafe { start.offset_from_unsigned(self.slice_start()) }),
+            VTableOrCapacity::Capacity(capacity) => Some(capacity),
         }
     }
 
@@ -634,6 +1205,15 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         if !UNIQUE && !self.is_unique() {
             return (Err(TryReserveError::NotUnique), start);
         }
+        // Split parts of a shared slice each hold a reference, and the reservation may give
+        // capacity beyond the part boundary, or shift items before its start, so it would
+        // overlap with the other parts if they were still alive.
+        debug_assert!(self.is_unique());
+        // items pushed since the last length update must be tracked by the buffer, as it may
+        // move them; items after the end of the slice are kept, making the reservation fail
+        if S::needs_drop() {
+            unsafe { self.set_buffer_length(start, length, false) };
+        }
         match self.vtable_or_capacity() {
             VTableOrCapacity::VTable(vtable) => {
                 let (capacity, start) = unsafe {
@@ -665,7 +1245,10 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
                     }
                 }
                 unsafe impl<S: Slice + ?Sized> BufferMut<S> for ArcSliceBuffer<S> {
-                    fn as_mut_slice(&mut self) -> &mut S {
+                    fn as_mut_slice(&mut self) -> &mut S
+                    where
+                        S: SliceMut,
+                    {
                         unsafe { S::from_raw_parts_mut(self.arc.slice_start(), self.length) }
                     }
                     fn capacity(&self) -> usize {
@@ -725,8 +1308,10 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
                         ));
                     };
                 }
-                let layout = unsafe { Self::slice_layout(capacity).unwrap_unchecked() };
-                unsafe { dealloc(self.inner.as_ptr().cast(), layout) };
+                if unsafe { self.inner.as_ref() }.release_weak() {
+                    let layout = unsafe { Self::slice_layout(capacity).unwrap_unchecked() };
+                    unsafe { dealloc(self.inner.as_ptr().cast(), layout) };
+                }
             }
         }
     }
@@ -736,9 +1321,44 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Arc<S, ANY_BUFFER> {
         start: NonNull<S::Item>,
         length: usize,
     ) {
+        // the items after the end of a shared slice, e.g. other parts of a split slice, are
+        // still owned by the buffer, so it can only grow
         if S::needs_drop() && (UNIQUE || self.is_unique()) {
-            let offset = unsafe { start.offset_from_unsigned(self.slice_start()) };
-            unsafe { self.set_length_unchecked(offset + length) };
+            unsafe { self.set_buffer_length(start, length, UNIQUE) };
+        }
+    }
+
+    // Sets the length of the unique buffer to the end of the slice `[start, start + length)`,
+    // returning `false` if the buffer doesn't support it.
+    pub(crate) unsafe fn set_unique_length(
+        &mut self,
+        start: NonNull<S::Item>,
+        length: usize,
+    ) -> bool {
+        unsafe { self.set_buffer_length(start, length, true) }
+    }
+
+    unsafe fn set_buffer_length(
+        &mut self,
+        start: NonNull<S::Item>,
+        length: usize,
+        shrink: bool,
+    ) -> bool {
+        match self.vtable_or_capacity() {
+            VTableOrCapacity::VTable(vtable) => match vtable.set_len {
+                Some(set_len) => unsafe {
+                    set_len(self.inner.cast(), start.cast(), length, shrink)
+                },
+                None => false,
+            },
+            VTableOrCapacity::Capacity(_) => {
+                let offset = unsafe { start.offset_from_unsigned(self.slice_start()) };
+                let slice_length = unsafe { self.slice_length().unwrap_checked() };
+                if shrink || offset + length > slice_length {
+                    unsafe { self.set_length_unchecked(offset + length) };
+                }
+                true
+            }
         }
     }
 
@@ -762,7 +1382,6 @@ impl<S: Slice + ?Sized> Arc<S> {
         vtable: &'static VTable,
         buffer: B,
     ) -> Result<Box<ArcInner<B>>, (E, B)> {
-        let vtable_ptr = ptr::from_ref(vtable);
         let layout = Layout::new::<ArcInner<B>>();
         // MSRV 1.65 let-else
         let ptr = match E::alloc::<_, true>(layout) {
@@ -771,9 +1390,8 @@ impl<S: Slice + ?Sized> Arc<S> {
         };
         let inner = ArcInner {
             refcount: AtomicUsize::new(refcount),
-            vtable_or_capacity: vtable_ptr
-                .with_addr(VTABLE_FLAG | (vtable_ptr.addr() >> VTABLE_SHIFT))
-                .cast(),
+            weak: AtomicUsize::new(1),
+            vtable_or_capacity: tag_vtable(vtable),
             buffer,
         };
         unsafe { ptr.write(inner) }
@@ -815,7 +1433,10 @@ impl<S: Slice + ?Sized> Arc<S> {
     #[allow(clippy::type_complexity)]
     pub(crate) fn new_buffer_mut<B: DynBuffer + BufferMut<S>, E: AllocErrorImpl>(
         buffer: B,
-    ) -> Result<(Self, NonNull<S::Item>, usize, usize), (E, B)> {
+    ) -> Result<(Self, NonNull<S::Item>, usize, usize), (E, B)>
+    where
+        S: SliceMut,
+    {
         let mut arc = Self::new_guard::<_, E>(vtable::new_mut::<S, B>(), buffer)?;
         let (start, length) = arc.buffer_mut().as_mut_slice().to_raw_parts_mut();
         let capacity = arc.buffer_mut().capacity();
@@ -844,6 +1465,42 @@ where {
     }
 }
 
+impl<S: Slice + ?Sized> Arc<S> {
+    pub(crate) fn with_capacity_in<A: BufferSource, E: AllocErrorImpl>(
+        capacity: usize,
+        alloc: A,
+    ) -> Result<(Self, NonNull<S::Item>), E> {
+        let vec = VecIn::<S, A>::new::<E>(capacity, alloc)?;
+        let ptr = E::alloc_in::<ArcInner<VecIn<S, A>>, _>(
+            &vec.alloc,
+            Layout::new::<ArcInner<VecIn<S, A>>>(),
+        )?;
+        let start = vec.start;
+        let inner = ArcInner {
+            refcount: AtomicUsize::new(1),
+            weak: AtomicUsize::new(1),
+            vtable_or_capacity: tag_vtable(vtable::new_vec_in::<S, A>()),
+            buffer: vec,
+        };
+        unsafe { ptr.write(inner) };
+        Ok((unsafe { Self::from_raw(ptr.cast()) }, start))
+    }
+
+    pub(crate) fn new_in<A: BufferSource, E: AllocErrorImpl>(
+        slice: &S,
+        alloc: A,
+    ) -> Result<(Self, NonNull<S::Item>), E>
+    where
+        S::Item: Copy,
+    {
+        let (arc, start) = Self::with_capacity_in::<A, E>(slice.len(), alloc)?;
+        let vec = &mut unsafe { arc.inner.cast::<ArcInner<VecIn<S, A>>>().as_mut() }.buffer;
+        unsafe { ptr::copy_nonoverlapping(slice.to_slice().as_ptr(), start.as_ptr(), slice.len()) };
+        vec.length = slice.len();
+        Ok((arc, start))
+    }
+}
+
 impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Drop for Arc<S, ANY_BUFFER> {
     fn drop(&mut self) {
         if unsafe { self.inner.as_ref() }.decr_refcount() {
@@ -854,11 +1511,7 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Drop for Arc<S, ANY_BUFFER> {
 
 impl<S: Slice + ?Sized, const ANY_BUFFER: bool> Clone for Arc<S, ANY_BUFFER> {
     fn clone(&self) -> Self {
-        unsafe { self.inner.as_ref() }.incr_refcount();
-        Self {
-            inner: self.inner,
-            _phantom: PhantomData,
-        }
+        self.clone_with::<SATURATE_ON_OVERFLOW>()
     }
 }
 
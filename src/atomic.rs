@@ -1,5 +1,85 @@
-#[cfg(not(feature = "portable-atomic"))]
-pub(crate) use core::sync::atomic::*;
+#[cfg(all(not(feature = "loom"), not(feature = "portable-atomic")))]
+use core::sync::atomic as native;
 
-#[cfg(feature = "portable-atomic")]
-pub(crate) use portable_atomic::*;
+#[cfg(feature = "loom")]
+pub(crate) use loom::sync::atomic::*;
+#[cfg(not(feature = "loom"))]
+pub(crate) use native::*;
+#[cfg(all(not(feature = "loom"), feature = "portable-atomic"))]
+use portable_atomic as native;
+
+/// An atomic pointer which can be created null in const context, e.g. for static data.
+///
+/// Loom atomics cannot be created in const context, so a null pointer is stored without atomic
+/// when the `loom` feature is enabled; it is never modified anyway.
+pub(crate) struct ConstAtomicPtr<T>(
+    #[cfg(not(feature = "loom"))] native::AtomicPtr<T>,
+    #[cfg(feature = "loom")] Option<AtomicPtr<T>>,
+);
+
+#[cfg(not(feature = "loom"))]
+impl<T> ConstAtomicPtr<T> {
+    pub(crate) const fn null() -> Self {
+        Self(native::AtomicPtr::new(core::ptr::null_mut()))
+    }
+
+    pub(crate) fn new(ptr: *mut T) -> Self {
+        Self(native::AtomicPtr::new(ptr))
+    }
+
+    #[inline(always)]
+    pub(crate) fn load(&self, order: Ordering) -> *mut T {
+        self.0.load(order)
+    }
+
+    #[inline(always)]
+    pub(crate) fn load_mut(&mut self) -> *mut T {
+        *self.0.get_mut()
+    }
+
+    pub(crate) fn compare_exchange(
+        &self,
+        current: *mut T,
+        new: *mut T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<*mut T, *mut T> {
+        self.0.compare_exchange(current, new, success, failure)
+    }
+}
+
+#[cfg(feature = "loom")]
+impl<T> ConstAtomicPtr<T> {
+    pub(crate) const fn null() -> Self {
+        Self(None)
+    }
+
+    pub(crate) fn new(ptr: *mut T) -> Self {
+        Self(Some(AtomicPtr::new(ptr)))
+    }
+
+    pub(crate) fn load(&self, order: Ordering) -> *mut T {
+        self.0
+            .as_ref()
+            .map_or(core::ptr::null_mut(), |ptr| ptr.load(order))
+    }
+
+    pub(crate) fn load_mut(&mut self) -> *mut T {
+        self.0
+            .as_mut()
+            .map_or(core::ptr::null_mut(), |ptr| ptr.with_mut(|ptr| *ptr))
+    }
+
+    pub(crate) fn compare_exchange(
+        &self,
+        current: *mut T,
+        new: *mut T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<*mut T, *mut T> {
+        match &self.0 {
+            Some(ptr) => ptr.compare_exchange(current, new, success, failure),
+            None => Err(core::ptr::null_mut()),
+        }
+    }
+}
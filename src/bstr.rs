@@ -9,7 +9,8 @@ use bstr::{BStr, BString, ByteSlice};
 use crate::buffer::Deserializable;
 use crate::{
     buffer::{
-        Buffer, BufferMut, Concatenable, Emptyable, Extendable, Slice, Subsliceable, Zeroable,
+        Buffer, BufferMut, Concatenable, Emptyable, Extendable, Slice, SliceMut, Subsliceable,
+        Zeroable,
     },
     error::TryReserveError,
 };
@@ -21,9 +22,6 @@ unsafe impl Slice for BStr {
     fn to_slice(&self) -> &[Self::Item] {
         self
     }
-    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item] {
-        self
-    }
     fn into_boxed_slice(self: Box<Self>) -> Box<[Self::Item]> {
         self.into()
     }
@@ -34,9 +32,6 @@ unsafe impl Slice for BStr {
     unsafe fn from_slice_unchecked(slice: &[Self::Item]) -> &Self {
         slice.as_bstr()
     }
-    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self {
-        slice.as_bstr_mut()
-    }
     unsafe fn from_boxed_slice_unchecked(boxed: Box<[Self::Item]>) -> Box<Self> {
         boxed.into()
     }
@@ -48,6 +43,15 @@ unsafe impl Slice for BStr {
     fn try_from_slice(slice: &[Self::Item]) -> Result<&Self, Self::TryFromSliceError> {
         Ok(slice.as_bstr())
     }
+}
+
+unsafe impl SliceMut for BStr {
+    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item] {
+        self
+    }
+    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self {
+        slice.as_bstr_mut()
+    }
     fn try_from_slice_mut(slice: &mut [Self::Item]) -> Result<&mut Self, Self::TryFromSliceError> {
         Ok(slice.as_bstr_mut())
     }
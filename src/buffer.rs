@@ -5,7 +5,7 @@
 use alloc::{alloc::realloc, boxed::Box, string::String, vec::Vec};
 use core::{
     alloc::{Layout, LayoutError},
-    any::Any,
+    any::{Any, TypeId},
     cmp::max,
     convert::Infallible,
     mem,
@@ -20,17 +20,30 @@ pub(crate) use crate::buffer::private::DynBuffer;
 #[allow(unused_imports)]
 use crate::msrv::{ConstPtrExt, NonNullExt, OffsetFromUnsignedExt, SlicePtrExt};
 use crate::{
-    error::TryReserveError, macros::assume, slice_mut::TryReserveResult, utils::NewChecked,
+    error::{AllocError, TryReserveError},
+    macros::assume,
+    slice_mut::TryReserveResult,
+    utils::NewChecked,
 };
 
 /// A slice, e.g. `[T]` or `str`.
 ///
+/// Slice items are required to be `Send + Sync`, as slices are shared between threads; that's
+/// why [`ArcSlice`](crate::ArcSlice) is always `Send + Sync`. Slices of `!Sync` items, like
+/// [`Cell`](core::cell::Cell), are not supported.
+///
+/// ```rust,compile_fail
+/// use std::cell::Cell;
+///
+/// use arc_slice::ArcSlice;
+///
+/// let s = ArcSlice::<[Cell<u8>]>::from_array([Cell::new(0)]);
+/// ```
+///
 /// # Safety
 ///
 /// - [`into_vec`](Self::into_vec) must be *pure*, i.e. `mem::forget(S::into_vec(ptr::read(vec_ptr)))`
 ///   should not invalidate memory behind `vec_ptr`.
-/// - If [`try_from_slice_mut`](Self::try_from_slice_mut) returns `Ok` for a slice, then
-///   [`try_from_slice`](Self::try_from_slice) must also return `Ok` for that slice.
 pub unsafe trait Slice: Send + Sync + 'static {
     /// The slice item, e.g. `T` for `[T]` or `u8` for `str`.
     type Item: Send + Sync + 'static;
@@ -39,12 +52,6 @@ pub unsafe trait Slice: Send + Sync + 'static {
 
     /// Converts a slice to its underlying item slice.
     fn to_slice(&self) -> &[Self::Item];
-    /// Converts a mutable slice to its underlying item slice.
-    ///
-    /// # Safety
-    ///
-    /// The item slice is never mutated, as it is only used for storage.
-    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item];
     /// Converts a boxed slice to its underlying boxed item slice.
     fn into_boxed_slice(self: Box<Self>) -> Box<[Self::Item]>;
     /// Converts a vector to its underlying item vector.
@@ -56,12 +63,6 @@ pub unsafe trait Slice: Send + Sync + 'static {
     ///
     /// The item slice must be valid as if it has been obtained from [`Self::to_slice`].
     unsafe fn from_slice_unchecked(slice: &[Self::Item]) -> &Self;
-    /// Converts back a mutable slice from its underlying item slice.
-    ///
-    /// # Safety
-    ///
-    /// The item slice must be valid as if it has been obtained from [`Self::to_slice_mut`].
-    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self;
     /// Converts back a boxed slice from its underlying boxed item slice.
     ///
     /// # Safety
@@ -80,6 +81,29 @@ pub unsafe trait Slice: Send + Sync + 'static {
     type TryFromSliceError;
     /// Try converting an item slice to the given slice type.
     fn try_from_slice(slice: &[Self::Item]) -> Result<&Self, Self::TryFromSliceError>;
+}
+
+/// A slice that can be mutably borrowed, as required by [`ArcSliceMut`](crate::ArcSliceMut).
+///
+/// # Safety
+///
+/// - [`to_slice_mut`](Self::to_slice_mut) must return the same item slice as
+///   [`to_slice`](Slice::to_slice).
+/// - If [`try_from_slice_mut`](Self::try_from_slice_mut) returns `Ok` for a slice, then
+///   [`try_from_slice`](Slice::try_from_slice) must also return `Ok` for that slice.
+pub unsafe trait SliceMut: Slice {
+    /// Converts a mutable slice to its underlying item slice.
+    ///
+    /// # Safety
+    ///
+    /// The item slice is never mutated, as it is only used for storage.
+    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item];
+    /// Converts back a mutable slice from its underlying item slice.
+    ///
+    /// # Safety
+    ///
+    /// The item slice must be valid as if it has been obtained from [`Self::to_slice_mut`].
+    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self;
     /// Tries converting a mutable item slice to the given slice type.
     fn try_from_slice_mut(slice: &mut [Self::Item]) -> Result<&mut Self, Self::TryFromSliceError>;
 }
@@ -88,9 +112,6 @@ pub(crate) trait SliceExt: Slice {
     fn as_ptr(&self) -> NonNull<Self::Item> {
         NonNull::new_checked(self.to_slice().as_ptr().cast_mut())
     }
-    fn as_mut_ptr(&mut self) -> NonNull<Self::Item> {
-        NonNull::new_checked(unsafe { self.to_slice_mut().as_mut_ptr() })
-    }
     fn len(&self) -> usize {
         self.to_slice().len()
     }
@@ -100,16 +121,10 @@ pub(crate) trait SliceExt: Slice {
     fn to_raw_parts(&self) -> (NonNull<Self::Item>, usize) {
         (self.as_ptr(), self.len())
     }
-    fn to_raw_parts_mut(&mut self) -> (NonNull<Self::Item>, usize) {
-        (self.as_mut_ptr(), self.len())
-    }
     unsafe fn from_raw_parts<'a>(start: NonNull<Self::Item>, length: usize) -> &'a Self {
         unsafe { Self::from_slice_unchecked(slice::from_raw_parts(start.as_ptr(), length)) }
     }
-    unsafe fn from_raw_parts_mut<'a>(start: NonNull<Self::Item>, length: usize) -> &'a mut Self {
-        unsafe { Self::from_slice_mut_unchecked(slice::from_raw_parts_mut(start.as_ptr(), length)) }
-    }
-    // use this instead of `BufferMutExt::as_mut_ptr` as the pointer
+    // use this instead of `SliceMutExt::as_mut_ptr` as the pointer
     // is not invalidated when the vector is moved
     fn vec_start(vec: &mut Self::Vec) -> NonNull<Self::Item> {
         let mut vec = ManuallyDrop::new(Self::into_vec(unsafe { ptr::read(vec) }));
@@ -122,11 +137,26 @@ pub(crate) trait SliceExt: Slice {
 
 impl<S: Slice + ?Sized> SliceExt for S {}
 
+pub(crate) trait SliceMutExt: SliceMut {
+    fn as_mut_ptr(&mut self) -> NonNull<Self::Item> {
+        NonNull::new_checked(unsafe { self.to_slice_mut().as_mut_ptr() })
+    }
+    fn to_raw_parts_mut(&mut self) -> (NonNull<Self::Item>, usize) {
+        (self.as_mut_ptr(), self.len())
+    }
+    unsafe fn from_raw_parts_mut<'a>(start: NonNull<Self::Item>, length: usize) -> &'a mut Self {
+        unsafe { Self::from_slice_mut_unchecked(slice::from_raw_parts_mut(start.as_ptr(), length)) }
+    }
+}
+
+impl<S: SliceMut + ?Sized> SliceMutExt for S {}
+
 /// A slice that can be empty.
 ///
 /// # Safety
 ///
-/// `Slice::try_from_slice(&[])`/`Slice::try_from_slice_mut(&mut [])` must be ok.
+/// `Slice::try_from_slice(&[])` must be ok, as well as `SliceMut::try_from_slice_mut(&mut [])`
+/// for mutable slices.
 pub unsafe trait Emptyable: Slice {}
 
 /// A slice that can be safely initialized from an all-zero byte-pattern.
@@ -155,6 +185,7 @@ pub unsafe trait Subsliceable: Slice {
     /// # Safety
     ///
     /// See [`Self::check_subslice`].
+    #[track_caller]
     unsafe fn check_advance(&self, offset: usize) {
         unsafe { self.check_subslice(offset, self.len()) }
     }
@@ -163,6 +194,7 @@ pub unsafe trait Subsliceable: Slice {
     /// # Safety
     ///
     /// See [`Self::check_subslice`].
+    #[track_caller]
     unsafe fn check_truncate(&self, len: usize) {
         unsafe { self.check_subslice(0, len) }
     }
@@ -171,6 +203,7 @@ pub unsafe trait Subsliceable: Slice {
     /// # Safety
     ///
     /// See [`Self::check_subslice`].
+    #[track_caller]
     unsafe fn check_split(&self, at: usize) {
         unsafe { self.check_subslice(0, at) };
         unsafe { self.check_subslice(at, self.len()) };
@@ -229,9 +262,6 @@ unsafe impl<T: Send + Sync + 'static> Slice for [T] {
     fn to_slice(&self) -> &[Self::Item] {
         self
     }
-    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item] {
-        self
-    }
     fn into_boxed_slice(self: Box<Self>) -> Box<[Self::Item]> {
         self
     }
@@ -242,9 +272,6 @@ unsafe impl<T: Send + Sync + 'static> Slice for [T] {
     unsafe fn from_slice_unchecked(slice: &[Self::Item]) -> &Self {
         slice
     }
-    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self {
-        slice
-    }
     unsafe fn from_boxed_slice_unchecked(boxed: Box<[Self::Item]>) -> Box<Self> {
         boxed
     }
@@ -256,6 +283,15 @@ unsafe impl<T: Send + Sync + 'static> Slice for [T] {
     fn try_from_slice(slice: &[Self::Item]) -> Result<&Self, Self::TryFromSliceError> {
         Ok(slice)
     }
+}
+
+unsafe impl<T: Send + Sync + 'static> SliceMut for [T] {
+    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item] {
+        self
+    }
+    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self {
+        slice
+    }
     fn try_from_slice_mut(slice: &mut [Self::Item]) -> Result<&mut Self, Self::TryFromSliceError> {
         Ok(slice)
     }
@@ -329,8 +365,9 @@ impl<T: for<'a> serde::Deserialize<'a> + Send + Sync + 'static> Deserializable f
     fn deserialize_from_string<E: serde::de::Error>(s: String) -> Result<Self::Vec, E> {
         Err(invalid_type::<T, E>(serde::de::Unexpected::Str(&s)))
     }
+    // byte sequences are accepted too, as human-readable formats serialize bytes this way
     fn try_deserialize_from_seq() -> bool {
-        crate::macros::is_not!(T, u8)
+        true
     }
 }
 
@@ -341,9 +378,6 @@ unsafe impl Slice for str {
     fn to_slice(&self) -> &[Self::Item] {
         self.as_bytes()
     }
-    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item] {
-        unsafe { self.as_bytes_mut() }
-    }
     fn into_boxed_slice(self: Box<Self>) -> Box<[Self::Item]> {
         self.into_boxed_bytes()
     }
@@ -354,9 +388,6 @@ unsafe impl Slice for str {
     unsafe fn from_slice_unchecked(slice: &[Self::Item]) -> &Self {
         unsafe { core::str::from_utf8_unchecked(slice) }
     }
-    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self {
-        unsafe { core::str::from_utf8_unchecked_mut(slice) }
-    }
     unsafe fn from_boxed_slice_unchecked(boxed: Box<[Self::Item]>) -> Box<Self> {
         unsafe { alloc::str::from_boxed_utf8_unchecked(boxed) }
     }
@@ -368,28 +399,41 @@ unsafe impl Slice for str {
     fn try_from_slice(slice: &[Self::Item]) -> Result<&Self, Self::TryFromSliceError> {
         core::str::from_utf8(slice)
     }
+}
+
+unsafe impl SliceMut for str {
+    unsafe fn to_slice_mut(&mut self) -> &mut [Self::Item] {
+        unsafe { self.as_bytes_mut() }
+    }
+    unsafe fn from_slice_mut_unchecked(slice: &mut [Self::Item]) -> &mut Self {
+        unsafe { core::str::from_utf8_unchecked_mut(slice) }
+    }
     fn try_from_slice_mut(slice: &mut [Self::Item]) -> Result<&mut Self, Self::TryFromSliceError> {
         core::str::from_utf8_mut(slice)
     }
 }
 
+#[track_caller]
 pub(crate) fn check_char_boundary(s: &str, offset: usize) {
     #[cold]
-    fn panic_not_a_char_boundary() -> ! {
-        panic!("not a char boundary")
+    #[track_caller]
+    fn panic_not_a_char_boundary(offset: usize) -> ! {
+        panic!("byte index {offset} is not a char boundary")
     }
     unsafe { assume!(offset <= s.len()) };
     if !s.is_char_boundary(offset) {
-        panic_not_a_char_boundary();
+        panic_not_a_char_boundary(offset);
     }
 }
 
 unsafe impl Subsliceable for str {
+    #[track_caller]
     unsafe fn check_subslice(&self, start: usize, end: usize) {
         check_char_boundary(self, start);
         check_char_boundary(self, end);
     }
 
+    #[track_caller]
     unsafe fn check_split(&self, at: usize) {
         check_char_boundary(self, at);
     }
@@ -435,6 +479,37 @@ pub trait Buffer<S: ?Sized>: Sized + Send + 'static {
     fn is_unique(&self) -> bool {
         true
     }
+    /// Returns the buffer slice with a `'static` lifetime, if the buffer is backed by static data.
+    ///
+    /// The returned slice must be the same as [`as_slice`](Self::as_slice). When it is provided,
+    /// [`ArcSlice::from_buffer`](crate::ArcSlice::from_buffer) drops the buffer and references the
+    /// static slice directly, avoiding an Arc allocation with static-compatible layouts;
+    /// the buffer can then no longer be extracted back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{buffer::Buffer, layout::ArcLayout, ArcBytes};
+    ///
+    /// struct Table(usize);
+    /// static TABLE: [&[u8]; 2] = [b"hello", b"world"];
+    ///
+    /// impl Buffer<[u8]> for Table {
+    ///     fn as_slice(&self) -> &[u8] {
+    ///         TABLE[self.0]
+    ///     }
+    ///     fn as_static(&self) -> Option<&'static [u8]> {
+    ///         Some(TABLE[self.0])
+    ///     }
+    /// }
+    ///
+    /// let bytes = ArcBytes::<ArcLayout<true, true>>::from_buffer(Table(1));
+    /// assert_eq!(bytes, b"world");
+    /// assert!(bytes.is_static());
+    /// ```
+    fn as_static(&self) -> Option<&'static S> {
+        None
+    }
 }
 
 impl<S: Slice + ?Sized> Buffer<S> for &'static S {
@@ -445,6 +520,10 @@ impl<S: Slice + ?Sized> Buffer<S> for &'static S {
     fn is_unique(&self) -> bool {
         false
     }
+
+    fn as_static(&self) -> Option<&'static S> {
+        Some(self)
+    }
 }
 
 impl<S: Slice + ?Sized> Buffer<S> for Box<S> {
@@ -465,6 +544,12 @@ impl Buffer<str> for String {
     }
 }
 
+impl<T: Send + 'static, const N: usize> Buffer<[T]> for [T; N] {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
 pub(crate) trait BufferExt<S: Slice + ?Sized>: Buffer<S> {
     unsafe fn offset(&self, start: NonNull<S::Item>) -> usize {
         unsafe { start.offset_from_unsigned(self.as_slice().as_ptr()) }
@@ -501,7 +586,9 @@ impl<S: Slice + ?Sized, B: Buffer<S>> BufferExt<S> for B {}
 /// [`borrow_metadata`]: BorrowMetadata::borrow_metadata
 pub unsafe trait BufferMut<S: ?Sized>: Buffer<S> {
     /// Returns the mutable buffer slice.
-    fn as_mut_slice(&mut self) -> &mut S;
+    fn as_mut_slice(&mut self) -> &mut S
+    where
+        S: SliceMut;
     /// Returns the buffer capacity.
     fn capacity(&self) -> usize;
     /// Set the length of the buffer slice.
@@ -562,6 +649,24 @@ unsafe impl BufferMut<str> for String {
     }
 }
 
+unsafe impl<T: Send + Sync + 'static, const N: usize> BufferMut<[T]> for [T; N] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    unsafe fn set_len(&mut self, _len: usize) -> bool {
+        false
+    }
+
+    fn try_reserve(&mut self, _additional: usize) -> Result<(), TryReserveError> {
+        Err(TryReserveError::Unsupported)
+    }
+}
+
 pub(crate) trait BufferMutExt<S: Slice + ?Sized>: BufferMut<S> {
     unsafe fn realloc<T>(
         &mut self,
@@ -569,13 +674,8 @@ pub(crate) trait BufferMutExt<S: Slice + ?Sized>: BufferMut<S> {
         ptr: NonNull<T>,
         layout: impl Fn(usize) -> Result<Layout, LayoutError>,
     ) -> Result<(NonNull<T>, usize), TryReserveError> {
-        let required = self
-            .len()
-            .checked_add(additional)
-            .ok_or(TryReserveError::CapacityOverflow)?;
-        let new_capacity = max(self.capacity() * 2, required);
-        let cur_layout = unsafe { layout(self.capacity()).unwrap_unchecked() };
-        let new_layout = layout(new_capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+        let (cur_layout, new_layout, new_capacity) =
+            unsafe { grow_layouts(self.len(), self.capacity(), additional, layout)? };
         let new_ptr =
             NonNull::new(unsafe { realloc(ptr.as_ptr().cast(), cur_layout, new_layout.size()) })
                 .ok_or(TryReserveError::AllocError)?;
@@ -644,11 +744,123 @@ pub(crate) trait BufferMutExt<S: Slice + ?Sized>: BufferMut<S> {
 
 impl<S: Slice + ?Sized, B: BufferMut<S>> BufferMutExt<S> for B {}
 
+// `layout(capacity)` must be valid
+pub(crate) unsafe fn grow_layouts(
+    length: usize,
+    capacity: usize,
+    additional: usize,
+    layout: impl Fn(usize) -> Result<Layout, LayoutError>,
+) -> Result<(Layout, Layout, usize), TryReserveError> {
+    let required = length
+        .checked_add(additional)
+        .ok_or(TryReserveError::CapacityOverflow)?;
+    let new_capacity = max(capacity * 2, required);
+    let cur_layout = unsafe { layout(capacity).unwrap_unchecked() };
+    let new_layout = layout(new_capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+    Ok((cur_layout, new_layout, new_capacity))
+}
+
+/// A source of memory for buffers, e.g. an arena or a pool.
+///
+/// It is a minimal stable counterpart of the unstable [`Allocator`] trait, used by
+/// [`ArcSliceMut::with_capacity_in`] to allocate both the buffer and the inner Arc. The source
+/// is stored next to the buffer, so memory is always released to the source it comes from.
+///
+/// With the `allocator-api` feature, every [`Allocator`] is a `BufferSource`.
+///
+/// # Safety
+///
+/// A memory block returned by [`allocate`](Self::allocate) or [`grow`](Self::grow) must fit
+/// the requested layout, and must stay valid until it is passed to
+/// [`deallocate`](Self::deallocate) or `grow`, even if the source is moved in between.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{alloc::Layout, ptr::NonNull};
+///
+/// use arc_slice::{buffer::BufferSource, error::AllocError, layout::ArcLayout, ArcSliceMut};
+///
+/// struct Heap;
+///
+/// unsafe impl BufferSource for Heap {
+///     fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+///         NonNull::new(unsafe { std::alloc::alloc(layout) }).ok_or(AllocError)
+///     }
+///
+///     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+///         unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) };
+///     }
+/// }
+///
+/// let mut s = ArcSliceMut::<[u8], ArcLayout<true>>::with_capacity_in(8, Heap);
+/// s.extend_from_slice(b"hello");
+/// assert_eq!(s, b"hello");
+/// ```
+///
+/// [`Allocator`]: https://doc.rust-lang.org/alloc/alloc/trait.Allocator.html
+/// [`ArcSliceMut::with_capacity_in`]: crate::ArcSliceMut::with_capacity_in
+pub unsafe trait BufferSource: Send + Sync + 'static {
+    /// Allocates a memory block; the layout size is never zero.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+    /// Deallocates a memory block.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this source with the given `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+    /// Grows a memory block, preserving its content.
+    ///
+    /// Sources that cannot grow blocks return [`TryReserveError::Unsupported`], which is the
+    /// default implementation.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this source with `old_layout`, and `new_layout` must
+    /// be greater than `old_layout`.
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, TryReserveError> {
+        let _ = (ptr, old_layout, new_layout);
+        Err(TryReserveError::Unsupported)
+    }
+}
+
+#[cfg(feature = "allocator-api")]
+unsafe impl<A: allocator_api2::alloc::Allocator + Send + Sync + 'static> BufferSource for A {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        Ok(self.allocate(layout).map_err(|_| AllocError)?.cast())
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { allocator_api2::alloc::Allocator::deallocate(self, ptr, layout) };
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, TryReserveError> {
+        let ptr =
+            unsafe { allocator_api2::alloc::Allocator::grow(self, ptr, old_layout, new_layout) };
+        Ok(ptr.map_err(|_| TryReserveError::AllocError)?.cast())
+    }
+}
+
 #[cfg(feature = "raw-buffer")]
 /// A buffer that can be stored into a raw pointer.
 ///
 /// The trait can be used when the actual buffer is already stored in an [`Arc`].
 ///
+/// `Arc<[T]>` and `Arc<str>` don't implement it, as the layout data has no room for the length
+/// of their fat pointer, which is needed to release them once the slice has been subsliced.
+/// They implement [`Buffer`] instead, so wrapping them allocates an Arc header, but doesn't
+/// copy the items.
+///
 /// # Safety
 ///
 /// - The slice returned by [`Buffer::as_slice`] must not be invalidated by
@@ -678,33 +890,102 @@ pub trait BorrowMetadata: Sync {
     fn borrow_metadata(&self) -> &Self::Metadata;
 }
 
+/// A chain of independently typed metadata, each of them being retrievable by its type.
+///
+/// It is implemented for tuples up to arity 8, but can also be implemented manually.
+/// Metadata chains are attached with
+/// [`ArcSlice::from_buffer_with_metadata_chain`](crate::ArcSlice::from_buffer_with_metadata_chain)
+/// or [`ArcSliceMut::from_buffer_with_metadata_chain`](crate::ArcSliceMut::from_buffer_with_metadata_chain).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::any::TypeId;
+///
+/// use arc_slice::{buffer::MetadataChain, layout::ArcLayout, ArcBytes};
+///
+/// struct Generation(u64);
+/// struct Checksum(u32);
+///
+/// let chain = ("file.bin".to_string(), Generation(1), Checksum(42));
+/// assert!(chain.get_metadata(TypeId::of::<Checksum>()).is_some());
+/// let bytes = ArcBytes::<ArcLayout<true>>::from_buffer_with_metadata_chain(vec![0, 1], chain);
+/// assert_eq!(bytes.metadata::<String>().unwrap(), "file.bin");
+/// assert_eq!(bytes.metadata::<Checksum>().unwrap().0, 42);
+/// assert!(bytes.metadata::<u64>().is_none());
+/// ```
+pub trait MetadataChain: Send + Sync + 'static {
+    /// Returns the first metadata of the chain having the given type, if any.
+    ///
+    /// The returned metadata must have the given type, otherwise it is ignored.
+    fn get_metadata(&self, type_id: TypeId) -> Option<&dyn Any>;
+}
+
+macro_rules! metadata_chain {
+    ($($ty:ident: $index:tt),*) => {
+        impl<$($ty: Send + Sync + 'static),*> MetadataChain for ($($ty,)*) {
+            fn get_metadata(&self, type_id: TypeId) -> Option<&dyn Any> {
+                $(if type_id == TypeId::of::<$ty>() {
+                    return Some(&self.$index);
+                })*
+                None
+            }
+        }
+    };
+}
+metadata_chain!(A: 0);
+metadata_chain!(A: 0, B: 1);
+metadata_chain!(A: 0, B: 1, C: 2);
+metadata_chain!(A: 0, B: 1, C: 2, D: 3);
+metadata_chain!(A: 0, B: 1, C: 2, D: 3, E: 4);
+metadata_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+metadata_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+metadata_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
 mod private {
-    use core::{any::Any, ptr::NonNull};
+    use core::{
+        any::{Any, TypeId},
+        ptr::NonNull,
+    };
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe trait DynBuffer {
         type Buffer: Any;
         type Metadata: Any;
+        // whether the metadata can be moved out by `take_buffer`
+        const OWNED_METADATA: bool;
         fn get_metadata(&self) -> &Self::Metadata;
-        unsafe fn take_buffer(this: *mut Self, buffer: NonNull<()>);
+        // returns the metadata itself, or one of its chained elements, matching the type id
+        fn get_metadata_by_id(&self, type_id: TypeId) -> Option<NonNull<()>> {
+            (type_id == TypeId::of::<Self::Metadata>())
+                .then(|| NonNull::from(self.get_metadata()).cast())
+        }
+        fn get_buffer_mut(&mut self) -> &mut Self::Buffer;
+        unsafe fn take_buffer(this: *mut Self, buffer: NonNull<()>, metadata: Option<NonNull<()>>);
     }
 }
 
 unsafe impl<B: BorrowMetadata + Any> DynBuffer for B {
     type Buffer = B;
     type Metadata = B::Metadata;
+    const OWNED_METADATA: bool = false;
 
     fn get_metadata(&self) -> &Self::Metadata {
         self.borrow_metadata()
     }
 
-    unsafe fn take_buffer(this: *mut Self, buffer: NonNull<()>) {
+    fn get_buffer_mut(&mut self) -> &mut Self::Buffer {
+        self
+    }
+
+    unsafe fn take_buffer(this: *mut Self, buffer: NonNull<()>, _metadata: Option<NonNull<()>>) {
         unsafe { ptr::copy_nonoverlapping(this, buffer.as_ptr().cast(), 1) }
     }
 }
 
+// `CHAIN` means the metadata is a `MetadataChain`, whose elements are also retrievable
 #[derive(Clone)]
-pub(crate) struct BufferWithMetadata<B, M> {
+pub(crate) struct BufferWithMetadata<B, M, const CHAIN: bool = false> {
     buffer: B,
     metadata: M,
 }
@@ -713,18 +994,30 @@ impl<B, M> BufferWithMetadata<B, M> {
     pub(crate) fn new(buffer: B, metadata: M) -> Self {
         Self { buffer, metadata }
     }
+}
+
+impl<B, M: MetadataChain> BufferWithMetadata<B, M, true> {
+    pub(crate) fn new_chain(buffer: B, metadata: M) -> Self {
+        Self { buffer, metadata }
+    }
+}
 
+impl<B, M, const CHAIN: bool> BufferWithMetadata<B, M, CHAIN> {
     pub(crate) fn buffer(self) -> B {
         self.buffer
     }
 
+    pub(crate) fn buffer_mut(&mut self) -> &mut B {
+        &mut self.buffer
+    }
+
     pub(crate) fn into_tuple(self) -> (B, M) {
         (self.buffer, self.metadata)
     }
 }
 
-impl<S: Slice + ?Sized, B: Buffer<S>, M: Send + Sync + 'static> Buffer<S>
-    for BufferWithMetadata<B, M>
+impl<S: Slice + ?Sized, B: Buffer<S>, M: Send + Sync + 'static, const CHAIN: bool> Buffer<S>
+    for BufferWithMetadata<B, M, CHAIN>
 {
     fn as_slice(&self) -> &S {
         self.buffer.as_slice()
@@ -735,10 +1028,13 @@ impl<S: Slice + ?Sized, B: Buffer<S>, M: Send + Sync + 'static> Buffer<S>
     }
 }
 
-unsafe impl<S: Slice + ?Sized, B: BufferMut<S>, M: Send + Sync + 'static> BufferMut<S>
-    for BufferWithMetadata<B, M>
+unsafe impl<S: Slice + ?Sized, B: BufferMut<S>, M: Send + Sync + 'static, const CHAIN: bool>
+    BufferMut<S> for BufferWithMetadata<B, M, CHAIN>
 {
-    fn as_mut_slice(&mut self) -> &mut S {
+    fn as_mut_slice(&mut self) -> &mut S
+    where
+        S: SliceMut,
+    {
         self.buffer.as_mut_slice()
     }
 
@@ -766,17 +1062,51 @@ unsafe impl<S: Slice + ?Sized, B: RawBuffer<S>> RawBuffer<S> for BufferWithMetad
     }
 }
 
-unsafe impl<B: Any, M: Any> DynBuffer for BufferWithMetadata<B, M> {
+trait MetadataLookup<const CHAIN: bool>: Any {
+    fn lookup(&self, type_id: TypeId) -> Option<NonNull<()>> {
+        (type_id == TypeId::of::<Self>()).then(|| NonNull::from(self).cast())
+    }
+}
+
+impl<M: Any> MetadataLookup<false> for M {}
+
+impl<M: MetadataChain> MetadataLookup<true> for M {
+    fn lookup(&self, type_id: TypeId) -> Option<NonNull<()>> {
+        if type_id == TypeId::of::<Self>() {
+            return Some(NonNull::from(self).cast());
+        }
+        let metadata = self.get_metadata(type_id)?;
+        (metadata.type_id() == type_id).then(|| NonNull::from(metadata).cast())
+    }
+}
+
+unsafe impl<B: Any, M: MetadataLookup<CHAIN>, const CHAIN: bool> DynBuffer
+    for BufferWithMetadata<B, M, CHAIN>
+{
     type Buffer = B;
     type Metadata = M;
+    const OWNED_METADATA: bool = true;
 
     fn get_metadata(&self) -> &Self::Metadata {
         &self.metadata
     }
 
-    unsafe fn take_buffer(this: *mut Self, buffer: NonNull<()>) {
+    fn get_metadata_by_id(&self, type_id: TypeId) -> Option<NonNull<()>> {
+        self.metadata.lookup(type_id)
+    }
+
+    fn get_buffer_mut(&mut self) -> &mut Self::Buffer {
+        &mut self.buffer
+    }
+
+    unsafe fn take_buffer(this: *mut Self, buffer: NonNull<()>, metadata: Option<NonNull<()>>) {
         unsafe { ptr::copy_nonoverlapping(addr_of!((*this).buffer), buffer.as_ptr().cast(), 1) }
-        unsafe { ptr::drop_in_place(addr_of_mut!((*this).metadata)) }
+        match metadata {
+            Some(metadata) => unsafe {
+                ptr::copy_nonoverlapping(addr_of!((*this).metadata), metadata.as_ptr().cast(), 1);
+            },
+            None => unsafe { ptr::drop_in_place(addr_of_mut!((*this).metadata)) },
+        }
     }
 }
 
@@ -870,7 +1200,10 @@ impl<S: ?Sized, B: AsRef<S> + Send + 'static> Buffer<S> for AsMutBuffer<B> {
 unsafe impl<S: Slice + ?Sized, B: AsRef<S> + AsMut<S> + Send + Sync + 'static> BufferMut<S>
     for AsMutBuffer<B>
 {
-    fn as_mut_slice(&mut self) -> &mut S {
+    fn as_mut_slice(&mut self) -> &mut S
+    where
+        S: SliceMut,
+    {
         self.0.as_mut()
     }
 
@@ -922,6 +1255,26 @@ const _: () = {
         }
     }
 
+    impl<T: Send + Sync + 'static> Buffer<[T]> for Arc<[T]> {
+        fn as_slice(&self) -> &[T] {
+            self
+        }
+
+        fn is_unique(&self) -> bool {
+            false
+        }
+    }
+
+    impl Buffer<str> for Arc<str> {
+        fn as_slice(&self) -> &str {
+            self
+        }
+
+        fn is_unique(&self) -> bool {
+            false
+        }
+    }
+
     #[cfg(feature = "raw-buffer")]
     unsafe impl<S: ?Sized, B: Buffer<S> + Sync> RawBuffer<S> for Arc<B> {
         fn into_raw(self) -> *const () {
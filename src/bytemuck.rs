@@ -0,0 +1,247 @@
+use core::{marker::PhantomData, mem::size_of};
+
+use bytemuck::{AnyBitPattern, NoUninit, Pod};
+
+use crate::{
+    buffer::{Buffer, BufferMut},
+    error::TryReserveError,
+    layout::{AnyBufferLayout, Layout, LayoutMut},
+    ArcSlice, ArcSliceMut,
+};
+
+fn check_cast<U: Pod>(bytes: &[u8]) -> bool {
+    bytemuck::try_cast_slice::<u8, U>(bytes).is_ok()
+}
+
+struct CastBuffer<U, L: Layout>(ArcSlice<[u8], L>, PhantomData<U>);
+
+impl<U: Pod + Send + Sync, L: Layout> Buffer<[U]> for CastBuffer<U, L> {
+    fn as_slice(&self) -> &[U] {
+        bytemuck::cast_slice(&self.0)
+    }
+
+    fn is_unique(&self) -> bool {
+        self.0.is_unique()
+    }
+}
+
+struct CastBufferMut<U, L: LayoutMut>(ArcSliceMut<[u8], L>, PhantomData<U>);
+
+impl<U: Pod + Send + Sync, L: LayoutMut> Buffer<[U]> for CastBufferMut<U, L> {
+    fn as_slice(&self) -> &[U] {
+        bytemuck::cast_slice(&self.0)
+    }
+}
+
+// SAFETY: the cast slice starts at the same address as the byte slice, and the byte slice
+// cannot be reallocated, as reservation is not supported.
+unsafe impl<U: Pod + Send + Sync, L: LayoutMut> BufferMut<[U]> for CastBufferMut<U, L> {
+    fn as_mut_slice(&mut self) -> &mut [U] {
+        bytemuck::cast_slice_mut(&mut self.0)
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.capacity() / size_of::<U>()
+    }
+
+    unsafe fn set_len(&mut self, len: usize) -> bool {
+        // SAFETY: same function contract
+        unsafe { self.0.set_len(len * size_of::<U>()) };
+        true
+    }
+
+    fn try_reserve(&mut self, _additional: usize) -> Result<(), TryReserveError> {
+        Err(TryReserveError::Unsupported)
+    }
+}
+
+impl<L: Layout> ArcSlice<[u8], L> {
+    /// Reads a `T` from the bytes at `offset`, or returns `None` if they don't fit in the slice.
+    ///
+    /// The bytes are copied, so they don't need to be aligned for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(&[0, 1, 0, 0, 0, 2]);
+    /// assert_eq!(s.pod_at::<u32>(1), Some(u32::from_ne_bytes([1, 0, 0, 0])));
+    /// assert_eq!(s.pod_at::<[u8; 2]>(4), Some([0, 2]));
+    /// assert_eq!(s.pod_at::<u32>(3), None);
+    /// ```
+    pub fn pod_at<T: AnyBitPattern>(&self, offset: usize) -> Option<T> {
+        let end = offset.checked_add(size_of::<T>())?;
+        Some(bytemuck::pod_read_unaligned(self.get(offset..end)?))
+    }
+}
+
+impl<L: AnyBufferLayout> ArcSlice<[u8], L> {
+    /// Returns a view of `count` items of type `T` starting at `offset`, keeping the underlying
+    /// buffer alive.
+    ///
+    /// The bytes are not copied, but the subslice is wrapped into a new buffer, as with
+    /// [`cast_slice`](Self::cast_slice), which allocates.
+    ///
+    /// Returns [`PodSliceError::OutOfRange`] if the view doesn't fit in the slice, and
+    /// [`PodSliceError::Misaligned`] if it is not correctly aligned for `T`.
+    ///
+    /// [`PodSliceError::OutOfRange`]: crate::error::PodSliceError::OutOfRange
+    /// [`PodSliceError::Misaligned`]: crate::error::PodSliceError::Misaligned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::PodSliceError, layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(vec![0u8; 16]);
+    /// let offset = s.as_ptr().align_offset(4);
+    /// let items = s.pod_slice_at::<u32>(offset, 2).unwrap();
+    /// assert_eq!(items, [0, 0]);
+    /// assert_eq!(
+    ///     s.pod_slice_at::<u32>(offset + 1, 2),
+    ///     Err(PodSliceError::Misaligned)
+    /// );
+    /// assert_eq!(s.pod_slice_at::<u32>(0, 5), Err(PodSliceError::OutOfRange));
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn pod_slice_at<T: Pod + Send + Sync>(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Result<ArcSlice<[T], L>, crate::error::PodSliceError> {
+        let end = count
+            .checked_mul(size_of::<T>())
+            .and_then(|len| offset.checked_add(len))
+            .filter(|&end| end <= self.len())
+            .ok_or(crate::error::PodSliceError::OutOfRange)?;
+        if !check_cast::<T>(&self[offset..end]) {
+            return Err(crate::error::PodSliceError::Misaligned);
+        }
+        Ok(self.subslice(offset..end).cast_slice())
+    }
+
+    /// Reinterprets the bytes as a slice of `U`, keeping the underlying buffer alive.
+    ///
+    /// The bytes are not copied, but the original `ArcSlice` is wrapped into a new buffer,
+    /// which allocates.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`bytemuck::cast_slice`], i.e. if the slice is not
+    /// correctly aligned for `U`, or if its length is not a multiple of `size_of::<U>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(vec![0u8; 8]);
+    /// let s2 = s.cast_slice::<u8>();
+    /// assert_eq!(s2, [0; 8]);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn cast_slice<U: Pod + Send + Sync>(self) -> ArcSlice<[U], L> {
+        bytemuck::cast_slice::<u8, U>(&self);
+        ArcSlice::from_buffer(CastBuffer(self, PhantomData))
+    }
+
+    /// Tries reinterpreting the bytes as a slice of `U`, keeping the underlying buffer alive,
+    /// and returning the original `ArcSlice` if the cast is not valid or if an allocation fails.
+    ///
+    /// The cast is valid in the same cases as for [`bytemuck::try_cast_slice`], i.e. if the
+    /// slice is correctly aligned for `U`, and if its length is a multiple of `size_of::<U>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(vec![0u8; 3]);
+    /// let s = s.try_cast_slice::<[u8; 2]>().unwrap_err();
+    /// let s2 = s.try_cast_slice::<[u8; 3]>().unwrap();
+    /// assert_eq!(s2, [[0; 3]]);
+    /// ```
+    pub fn try_cast_slice<U: Pod + Send + Sync>(self) -> Result<ArcSlice<[U], L>, Self> {
+        if !check_cast::<U>(&self) {
+            return Err(self);
+        }
+        ArcSlice::try_from_buffer(CastBuffer(self, PhantomData)).map_err(|buffer| buffer.0)
+    }
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> ArcSliceMut<[u8], L, UNIQUE> {
+    /// Writes the bytes of `value` into the slice at `offset`.
+    ///
+    /// The bytes don't need to be aligned for `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + size_of::<T>() > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(&[0; 6]);
+    /// s.write_pod_at(1, &0x0102_0304u32.to_be());
+    /// assert_eq!(s, [0, 1, 2, 3, 4, 0]);
+    /// ```
+    #[track_caller]
+    pub fn write_pod_at<T: NoUninit>(&mut self, offset: usize, value: &T) {
+        self.write_at(offset, bytemuck::bytes_of(value));
+    }
+}
+
+impl<L: AnyBufferLayout + LayoutMut> ArcSliceMut<[u8], L> {
+    /// Reinterprets the bytes as a mutable slice of `U`, keeping the underlying buffer alive.
+    ///
+    /// The bytes are not copied, but the original `ArcSliceMut` is wrapped into a new buffer,
+    /// which allocates. The capacity of the returned `ArcSliceMut` is the number of whole `U`
+    /// items fitting in the original capacity, and it cannot be increased.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`bytemuck::cast_slice_mut`], i.e. if the slice is not
+    /// correctly aligned for `U`, or if its length is not a multiple of `size_of::<U>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSliceMut};
+    ///
+    /// let s = ArcSliceMut::<[u8], ArcLayout<true>>::from(vec![0u8; 8]);
+    /// let mut s2 = s.cast_slice_mut::<[u8; 4]>();
+    /// s2[1] = [1; 4];
+    /// assert_eq!(s2, [[0; 4], [1; 4]]);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn cast_slice_mut<U: Pod + Send + Sync>(self) -> ArcSliceMut<[U], L> {
+        bytemuck::cast_slice::<u8, U>(&self);
+        ArcSliceMut::from_buffer(CastBufferMut(self, PhantomData))
+    }
+
+    /// Tries reinterpreting the bytes as a mutable slice of `U`, keeping the underlying buffer
+    /// alive, and returning the original `ArcSliceMut` if the cast is not valid or if an
+    /// allocation fails.
+    ///
+    /// See [`cast_slice_mut`](Self::cast_slice_mut).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSliceMut};
+    ///
+    /// let s = ArcSliceMut::<[u8], ArcLayout<true>>::from(vec![0u8; 3]);
+    /// let s = s.try_cast_slice_mut::<[u8; 2]>().unwrap_err();
+    /// let s2 = s.try_cast_slice_mut::<[u8; 3]>().unwrap();
+    /// assert_eq!(s2, [[0; 3]]);
+    /// ```
+    pub fn try_cast_slice_mut<U: Pod + Send + Sync>(self) -> Result<ArcSliceMut<[U], L>, Self> {
+        if !check_cast::<U>(&self) {
+            return Err(self);
+        }
+        ArcSliceMut::try_from_buffer(CastBufferMut(self, PhantomData)).map_err(|buffer| buffer.0)
+    }
+}
@@ -1,5 +1,7 @@
+#[cfg(feature = "oom-handling")]
+use crate::utils::panic_reserve;
 use crate::{
-    buffer::{Extendable, Slice, Subsliceable},
+    buffer::{Extendable, Slice, SliceMut, Subsliceable},
     layout::{Layout, LayoutMut},
     ArcSlice, ArcSliceMut,
 };
@@ -18,7 +20,7 @@ impl<S: Slice<Item = u8> + Subsliceable + ?Sized, L: Layout> bytes::Buf for ArcS
     }
 }
 
-impl<S: Slice<Item = u8> + Subsliceable + ?Sized, L: LayoutMut, const UNIQUE: bool> bytes::Buf
+impl<S: SliceMut<Item = u8> + Subsliceable + ?Sized, L: LayoutMut, const UNIQUE: bool> bytes::Buf
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn remaining(&self) -> usize {
@@ -34,11 +36,16 @@ impl<S: Slice<Item = u8> + Subsliceable + ?Sized, L: LayoutMut, const UNIQUE: bo
     }
 }
 
-unsafe impl<S: Slice<Item = u8> + Extendable + ?Sized, L: LayoutMut, const UNIQUE: bool>
+// Unique slices can grow like `BytesMut`, while shared ones are bounded by their capacity.
+unsafe impl<S: SliceMut<Item = u8> + Extendable + ?Sized, L: LayoutMut, const UNIQUE: bool>
     bytes::BufMut for ArcSliceMut<S, L, UNIQUE>
 {
     fn remaining_mut(&self) -> usize {
-        self.capacity() - self.len()
+        if UNIQUE && cfg!(feature = "oom-handling") {
+            usize::MAX - self.len()
+        } else {
+            self.capacity() - self.len()
+        }
     }
 
     unsafe fn advance_mut(&mut self, cnt: usize) {
@@ -47,6 +54,13 @@ unsafe impl<S: Slice<Item = u8> + Extendable + ?Sized, L: LayoutMut, const UNIQU
     }
 
     fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        #[cfg(feature = "oom-handling")]
+        if UNIQUE && self.capacity() == self.len() && self.try_reserve_at_most(64) == 0 {
+            // not even a single byte can be reserved, so reserving it returns the error
+            if let Err(err) = self.try_reserve(1) {
+                panic_reserve(err);
+            }
+        }
         // SAFETY: `UninitSlice` prevent writing uninitialized memory
         unsafe { self.spare_capacity_mut() }.into()
     }
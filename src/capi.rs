@@ -0,0 +1,321 @@
+//! C API for [`ArcBytes`].
+//!
+//! Functions of this module are exported with their unmangled names, allowing to share
+//! [`ArcBytes`] with C/C++ code without copying or serializing it. Slices are handled through
+//! an opaque [`arc_bytes_t`] pointer, which must be released with [`arc_bytes_drop`]. The C
+//! header can be generated with [cbindgen](https://github.com/mozilla/cbindgen).
+//!
+//! Null handles are accepted everywhere: they are returned by functions on invalid input, e.g.
+//! a null pointer with a non-zero length, or an out-of-range subslice, instead of panicking
+//! across the FFI boundary. Allocation failures abort the process.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use arc_slice::capi::*;
+//!
+//! unsafe {
+//!     let bytes = arc_bytes_from_copy(b"hello world".as_ptr(), 11);
+//!     let hello = arc_bytes_subslice(bytes, 0, 5);
+//!     arc_bytes_drop(bytes);
+//!     let slice = core::slice::from_raw_parts(arc_bytes_data(hello), arc_bytes_len(hello));
+//!     assert_eq!(slice, b"hello");
+//!     arc_bytes_drop(hello);
+//! }
+//! ```
+use alloc::boxed::Box;
+use core::{ffi::c_void, ptr, slice};
+
+use crate::{buffer::Buffer, layout::ArcLayout, ArcBytes};
+
+/// Opaque handle to an [`ArcBytes`], manipulated through pointers by the C API.
+#[allow(non_camel_case_types)]
+#[derive(Debug)]
+pub struct arc_bytes_t(ArcBytes<ArcLayout<true, true>>);
+
+/// Destructor callback of [`arc_bytes_from_foreign`].
+#[allow(non_camel_case_types)]
+pub type arc_bytes_drop_fn = unsafe extern "C" fn(ctx: *mut c_void);
+
+fn into_handle(bytes: ArcBytes<ArcLayout<true, true>>) -> *mut arc_bytes_t {
+    Box::into_raw(Box::new(arc_bytes_t(bytes)))
+}
+
+/// Returns the slice of the handle, or `None` if it is null.
+///
+/// # Safety
+///
+/// `bytes` must be null or a live handle.
+unsafe fn as_bytes<'a>(bytes: *const arc_bytes_t) -> Option<&'a ArcBytes<ArcLayout<true, true>>> {
+    // SAFETY: same function contract
+    unsafe { bytes.as_ref() }.map(|b| &b.0)
+}
+
+/// Creates a new slice by copying `len` bytes from `ptr`.
+///
+/// `ptr` can be null if `len` is zero, returning an empty slice; otherwise, a null `ptr`
+/// returns null.
+///
+/// # Safety
+///
+/// If non-null, `ptr` must be valid for reads of `len` bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::capi::*;
+///
+/// unsafe {
+///     let bytes = arc_bytes_from_copy(core::ptr::null(), 0);
+///     assert!(!bytes.is_null());
+///     assert_eq!(arc_bytes_len(bytes), 0);
+///     arc_bytes_drop(bytes);
+///     assert!(arc_bytes_from_copy(core::ptr::null(), 1).is_null());
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn arc_bytes_from_copy(ptr: *const u8, len: usize) -> *mut arc_bytes_t {
+    if ptr.is_null() {
+        return match len {
+            0 => into_handle(ArcBytes::new()),
+            _ => ptr::null_mut(),
+        };
+    }
+    // SAFETY: same function contract
+    into_handle(ArcBytes::from_slice(unsafe {
+        slice::from_raw_parts(ptr, len)
+    }))
+}
+
+struct ForeignBuffer {
+    ptr: *const u8,
+    len: usize,
+    ctx: *mut c_void,
+    drop_fn: Option<arc_bytes_drop_fn>,
+}
+
+// SAFETY: `arc_bytes_from_foreign` contract requires the memory and the destructor callback
+// to be usable from any thread
+unsafe impl Send for ForeignBuffer {}
+// SAFETY: same as above
+unsafe impl Sync for ForeignBuffer {}
+
+impl Buffer<[u8]> for ForeignBuffer {
+    fn as_slice(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: `arc_bytes_from_foreign` contract guarantees the memory is valid until
+        // the destructor is called
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl Drop for ForeignBuffer {
+    fn drop(&mut self) {
+        if let Some(drop_fn) = self.drop_fn {
+            // SAFETY: `arc_bytes_from_foreign` contract
+            unsafe { drop_fn(self.ctx) };
+        }
+    }
+}
+
+/// Creates a new slice wrapping `len` bytes of foreign memory at `ptr`, without copying them.
+///
+/// `drop_fn`, if not null, is called exactly once with `ctx` when the last slice referencing
+/// the memory is dropped, possibly immediately for an empty slice, and possibly from another
+/// thread.
+///
+/// `ptr` can be null if `len` is zero, returning an empty slice; otherwise, a null `ptr`
+/// returns null, and `drop_fn` is not called.
+///
+/// # Safety
+///
+/// If non-null, `ptr` must be valid for reads of `len` bytes, and the memory must not be
+/// mutated, until `drop_fn` is called, or forever if `drop_fn` is null. Both the memory and
+/// `drop_fn` must be usable from any thread.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::ffi::c_void;
+///
+/// use arc_slice::capi::*;
+///
+/// unsafe extern "C" fn free_vec(ctx: *mut c_void) {
+///     drop(unsafe { Box::from_raw(ctx.cast::<Vec<u8>>()) });
+/// }
+///
+/// let vec = Box::new(b"hello world".to_vec());
+/// let (ptr, len) = (vec.as_ptr(), vec.len());
+/// unsafe {
+///     let ctx = Box::into_raw(vec).cast();
+///     let bytes = arc_bytes_from_foreign(ptr, len, ctx, Some(free_vec));
+///     assert_eq!(arc_bytes_data(bytes), ptr);
+///     assert_eq!(arc_bytes_len(bytes), 11);
+///     arc_bytes_drop(bytes);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn arc_bytes_from_foreign(
+    ptr: *const u8,
+    len: usize,
+    ctx: *mut c_void,
+    drop_fn: Option<arc_bytes_drop_fn>,
+) -> *mut arc_bytes_t {
+    if ptr.is_null() && len != 0 {
+        return ptr::null_mut();
+    }
+    let buffer = ForeignBuffer {
+        ptr,
+        len,
+        ctx,
+        drop_fn,
+    };
+    into_handle(ArcBytes::from_buffer(buffer))
+}
+
+/// Returns a new handle to the same slice, or null if `bytes` is null.
+///
+/// The slice is not copied, only its reference count is incremented.
+///
+/// # Safety
+///
+/// `bytes` must be null or a live handle.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::capi::*;
+///
+/// unsafe {
+///     let bytes = arc_bytes_from_copy(b"hello".as_ptr(), 5);
+///     let clone = arc_bytes_clone(bytes);
+///     assert_eq!(arc_bytes_data(clone), arc_bytes_data(bytes));
+///     arc_bytes_drop(bytes);
+///     arc_bytes_drop(clone);
+///     assert!(arc_bytes_clone(core::ptr::null()).is_null());
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn arc_bytes_clone(bytes: *const arc_bytes_t) -> *mut arc_bytes_t {
+    // SAFETY: same function contract
+    match unsafe { as_bytes(bytes) } {
+        Some(bytes) => into_handle(bytes.clone()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Returns a new handle to the subslice of `len` bytes starting at `offset`.
+///
+/// The subslice shares the memory of the original slice. Returns null if `bytes` is null, or if
+/// the subslice is out of range.
+///
+/// # Safety
+///
+/// `bytes` must be null or a live handle.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::capi::*;
+///
+/// unsafe {
+///     let bytes = arc_bytes_from_copy(b"hello world".as_ptr(), 11);
+///     let world = arc_bytes_subslice(bytes, 6, 5);
+///     assert_eq!(arc_bytes_data(world), arc_bytes_data(bytes).add(6));
+///     assert_eq!(arc_bytes_len(world), 5);
+///     assert!(arc_bytes_subslice(bytes, 6, 6).is_null());
+///     arc_bytes_drop(bytes);
+///     arc_bytes_drop(world);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn arc_bytes_subslice(
+    bytes: *const arc_bytes_t,
+    offset: usize,
+    len: usize,
+) -> *mut arc_bytes_t {
+    // SAFETY: same function contract
+    match (unsafe { as_bytes(bytes) }, offset.checked_add(len)) {
+        (Some(bytes), Some(end)) if end <= bytes.len() => into_handle(bytes.subslice(offset..end)),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Returns a pointer to the first byte of the slice, or null if `bytes` is null.
+///
+/// The pointer is valid for reads of [`arc_bytes_len`] bytes as long as the handle is alive.
+/// It is never null for a non-null handle, but may be dangling for an empty slice.
+///
+/// # Safety
+///
+/// `bytes` must be null or a live handle.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::capi::*;
+///
+/// unsafe {
+///     let bytes = arc_bytes_from_copy(b"hello".as_ptr(), 5);
+///     assert_eq!(*arc_bytes_data(bytes), b'h');
+///     arc_bytes_drop(bytes);
+///     assert!(arc_bytes_data(core::ptr::null()).is_null());
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn arc_bytes_data(bytes: *const arc_bytes_t) -> *const u8 {
+    // SAFETY: same function contract
+    unsafe { as_bytes(bytes) }.map_or(ptr::null(), |bytes| bytes.as_ptr())
+}
+
+/// Returns the length of the slice, or zero if `bytes` is null.
+///
+/// # Safety
+///
+/// `bytes` must be null or a live handle.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::capi::*;
+///
+/// unsafe {
+///     let bytes = arc_bytes_from_copy(b"hello".as_ptr(), 5);
+///     assert_eq!(arc_bytes_len(bytes), 5);
+///     arc_bytes_drop(bytes);
+///     assert_eq!(arc_bytes_len(core::ptr::null()), 0);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn arc_bytes_len(bytes: *const arc_bytes_t) -> usize {
+    // SAFETY: same function contract
+    unsafe { as_bytes(bytes) }.map_or(0, |bytes| bytes.len())
+}
+
+/// Releases the handle; does nothing if `bytes` is null.
+///
+/// The memory is released when the last handle referencing it is dropped.
+///
+/// # Safety
+///
+/// `bytes` must be null or a live handle, which must not be used after.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::capi::*;
+///
+/// unsafe {
+///     arc_bytes_drop(arc_bytes_from_copy(b"hello".as_ptr(), 5));
+///     arc_bytes_drop(core::ptr::null_mut());
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn arc_bytes_drop(bytes: *mut arc_bytes_t) {
+    if !bytes.is_null() {
+        // SAFETY: same function contract
+        drop(unsafe { Box::from_raw(bytes) });
+    }
+}
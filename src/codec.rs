@@ -0,0 +1,261 @@
+//! Hexadecimal and base64 encoding.
+//!
+//! Strings are decoded into an [`ArcSliceMut`] with [`from_hex`](ArcSliceMut::from_hex) and
+//! [`from_base64`](ArcSliceMut::from_base64), and bytes are encoded into an [`ArcStr`] with
+//! [`encode_hex`](ArcSlice::encode_hex) and [`encode_base64`](ArcSlice::encode_base64). Both
+//! directions write directly into a buffer allocated with the exact capacity.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use arc_slice::{codec::Base64Alphabet, ArcBytes, ArcBytesMut};
+//!
+//! let bytes: ArcBytes = <ArcBytesMut>::from_hex("68656C6C6F")?.freeze();
+//! assert_eq!(bytes, b"hello");
+//! assert_eq!(bytes.encode_hex(), "68656c6c6f");
+//! assert_eq!(bytes.encode_base64(Base64Alphabet::Standard), "aGVsbG8=");
+//! # Ok::<_, arc_slice::error::HexError>(())
+//! ```
+//!
+//! [`ArcStr`]: crate::ArcStr
+use core::mem::MaybeUninit;
+
+use crate::{
+    error::{Base64Error, HexError},
+    layout::{ArcLayout, Layout, LayoutMut},
+    ArcSlice, ArcSliceMut,
+};
+
+/// A base64 alphabet, as defined in [RFC 4648].
+///
+/// [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard alphabet, using `+` and `/`; encoding appends `=` padding.
+    #[default]
+    Standard,
+    /// The URL and filename safe alphabet, using `-` and `_`; encoding doesn't append padding.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    fn symbols(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Self::UrlSafe => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        }
+    }
+
+    fn value(self, symbol: u8) -> Option<u8> {
+        Some(match symbol {
+            b'A'..=b'Z' => symbol - b'A',
+            b'a'..=b'z' => symbol - b'a' + 26,
+            b'0'..=b'9' => symbol - b'0' + 52,
+            b'+' if self == Self::Standard => 62,
+            b'/' if self == Self::Standard => 63,
+            b'-' if self == Self::UrlSafe => 62,
+            b'_' if self == Self::UrlSafe => 63,
+            _ => return None,
+        })
+    }
+
+    fn padding(self) -> bool {
+        self == Self::Standard
+    }
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|d| d as u8)
+}
+
+fn decode_hex(s: &[u8], out: &mut [MaybeUninit<u8>]) -> Result<(), HexError> {
+    let value = |index: usize| hex_value(s[index]).ok_or(HexError::InvalidDigit { index });
+    for (i, byte) in out.iter_mut().enumerate() {
+        byte.write(value(2 * i)? << 4 | value(2 * i + 1)?);
+    }
+    Ok(())
+}
+
+fn encode_hex(bytes: &[u8], out: &mut [MaybeUninit<u8>]) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    for (&b, digits) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+        digits[0].write(DIGITS[usize::from(b >> 4)]);
+        digits[1].write(DIGITS[usize::from(b & 0xf)]);
+    }
+}
+
+// Returns the unpadded symbols and the decoded length.
+fn base64_decoded_len(s: &[u8]) -> Result<(&[u8], usize), Base64Error> {
+    let unpadded = s
+        .strip_suffix(b"==")
+        .or_else(|| s.strip_suffix(b"="))
+        .unwrap_or(s);
+    if unpadded.len() != s.len() && s.len() % 4 != 0 {
+        return Err(Base64Error::InvalidPadding {
+            index: unpadded.len(),
+        });
+    }
+    match unpadded.len() % 4 {
+        1 => Err(Base64Error::InvalidLength {
+            index: unpadded.len() - 1,
+        }),
+        rem => Ok((unpadded, unpadded.len() / 4 * 3 + rem.saturating_sub(1))),
+    }
+}
+
+fn decode_base64(
+    s: &[u8],
+    alphabet: Base64Alphabet,
+    out: &mut [MaybeUninit<u8>],
+) -> Result<(), Base64Error> {
+    let value = |index: usize| {
+        let value = alphabet.value(s[index]);
+        value
+            .ok_or(Base64Error::InvalidCharacter { index })
+            .map(u32::from)
+    };
+    for (i, bytes) in out.chunks_mut(3).enumerate() {
+        let start = 4 * i;
+        let end = start + bytes.len() + 1;
+        let mut word = 0;
+        for index in start..end {
+            word |= value(index)? << (6 * (3 - (index - start)));
+        }
+        // the bits of the last symbol which are not decoded must be zero
+        if word.to_be_bytes()[bytes.len() + 1..]
+            .iter()
+            .any(|&b| b != 0)
+        {
+            return Err(Base64Error::InvalidCharacter { index: end - 1 });
+        }
+        for (byte, &b) in bytes.iter_mut().zip(&word.to_be_bytes()[1..]) {
+            byte.write(b);
+        }
+    }
+    Ok(())
+}
+
+fn base64_encoded_len(len: usize, alphabet: Base64Alphabet) -> usize {
+    match len % 3 {
+        0 => len / 3 * 4,
+        _ if alphabet.padding() => len / 3 * 4 + 4,
+        rem => len / 3 * 4 + rem + 1,
+    }
+}
+
+fn encode_base64(bytes: &[u8], alphabet: Base64Alphabet, out: &mut [MaybeUninit<u8>]) {
+    let symbols = alphabet.symbols();
+    for (chunk, symbols_out) in bytes.chunks(3).zip(out.chunks_mut(4)) {
+        let mut word = [0; 4];
+        word[1..=chunk.len()].copy_from_slice(chunk);
+        let word = u32::from_be_bytes(word);
+        for (i, symbol) in symbols_out.iter_mut().enumerate() {
+            symbol.write(if i <= chunk.len() {
+                symbols[(word >> (6 * (3 - i))) as usize & 0x3f]
+            } else {
+                b'='
+            });
+        }
+    }
+}
+
+impl<L: LayoutMut> ArcSliceMut<[u8], L> {
+    fn decode<E>(
+        len: usize,
+        decode: impl FnOnce(&mut [MaybeUninit<u8>]) -> Result<(), E>,
+    ) -> Result<Self, E> {
+        let mut bytes = Self::with_capacity(len);
+        // SAFETY: no uninit bytes are written
+        decode(&mut unsafe { bytes.spare_capacity_mut() }[..len])?;
+        // SAFETY: the first `len` bytes are initialized
+        unsafe { bytes.set_len(len) };
+        Ok(bytes)
+    }
+
+    /// Decodes a hexadecimal string, with two case-insensitive digits per byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::HexError, ArcBytesMut};
+    ///
+    /// assert_eq!(<ArcBytesMut>::from_hex("68656C6c6f").unwrap(), b"hello");
+    /// assert_eq!(
+    ///     <ArcBytesMut>::from_hex("68656c6"),
+    ///     Err(HexError::OddLength { index: 6 })
+    /// );
+    /// assert_eq!(
+    ///     <ArcBytesMut>::from_hex("68656g6c"),
+    ///     Err(HexError::InvalidDigit { index: 5 })
+    /// );
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self, HexError> {
+        if s.len() % 2 != 0 {
+            return Err(HexError::OddLength { index: s.len() - 1 });
+        }
+        Self::decode(s.len() / 2, |out| decode_hex(s.as_bytes(), out))
+    }
+
+    /// Decodes a base64 string with the given alphabet; padding is optional.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{codec::Base64Alphabet, error::Base64Error, ArcBytesMut};
+    ///
+    /// let bytes = <ArcBytesMut>::from_base64("aGVsbG8=", Base64Alphabet::Standard).unwrap();
+    /// assert_eq!(bytes, b"hello");
+    /// let bytes = <ArcBytesMut>::from_base64("-_8", Base64Alphabet::UrlSafe).unwrap();
+    /// assert_eq!(bytes, [0xfb, 0xff]);
+    /// assert_eq!(
+    ///     <ArcBytesMut>::from_base64("-_8", Base64Alphabet::Standard),
+    ///     Err(Base64Error::InvalidCharacter { index: 0 })
+    /// );
+    /// ```
+    pub fn from_base64(s: &str, alphabet: Base64Alphabet) -> Result<Self, Base64Error> {
+        let (symbols, len) = base64_decoded_len(s.as_bytes())?;
+        Self::decode(len, |out| decode_base64(symbols, alphabet, out))
+    }
+}
+
+impl<L: Layout> ArcSlice<[u8], L> {
+    fn encode(len: usize, encode: impl FnOnce(&mut [MaybeUninit<u8>])) -> ArcSlice<str, L> {
+        let mut s = ArcSliceMut::<[u8], ArcLayout<false>>::with_capacity(len);
+        // SAFETY: no uninit bytes are written
+        encode(&mut unsafe { s.spare_capacity_mut() }[..len]);
+        // SAFETY: the first `len` bytes are initialized
+        unsafe { s.set_len(len) };
+        // SAFETY: encoding only writes ASCII symbols
+        unsafe { ArcSlice::from_arc_slice_unchecked(s.freeze()) }
+    }
+
+    /// Encodes the bytes into a lowercase hexadecimal string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytes;
+    ///
+    /// let bytes = <ArcBytes>::from(b"hello");
+    /// assert_eq!(bytes.encode_hex(), "68656c6c6f");
+    /// ```
+    pub fn encode_hex(&self) -> ArcSlice<str, L> {
+        Self::encode(2 * self.len(), |out| encode_hex(self, out))
+    }
+
+    /// Encodes the bytes into a base64 string with the given alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{codec::Base64Alphabet, ArcBytes};
+    ///
+    /// let bytes = <ArcBytes>::from(b"hello");
+    /// assert_eq!(bytes.encode_base64(Base64Alphabet::Standard), "aGVsbG8=");
+    /// assert_eq!(bytes.encode_base64(Base64Alphabet::UrlSafe), "aGVsbG8");
+    /// ```
+    pub fn encode_base64(&self, alphabet: Base64Alphabet) -> ArcSlice<str, L> {
+        let len = base64_encoded_len(self.len(), alphabet);
+        Self::encode(len, |out| encode_base64(self, alphabet, out))
+    }
+}
@@ -0,0 +1,290 @@
+use core::{
+    any::Any,
+    borrow::Borrow,
+    ops::{Deref, RangeBounds},
+};
+
+#[cfg(feature = "oom-handling")]
+use crate::layout::FromLayout;
+use crate::{
+    layout::{ArcLayout, Layout},
+    ArcBytes,
+};
+
+type ErasedLayout = ArcLayout<true, true>;
+
+/// An [`ArcBytes`] whose layout is erased.
+///
+/// It allows storing bytes of different [layouts](crate::layout) in the same collection, or
+/// exposing them without generic parameter. All layouts are normalized to
+/// `ArcLayout<true, true>`, which supports arbitrary buffers and static slices, so the
+/// conversion cost is paid once, at construction.
+///
+/// The construction doesn't allocate for [`ArcLayout`] bytes, nor for bytes of other layouts
+/// backed by an Arc. It allocates an Arc for vectors and boxed slices stored inlined by
+/// [`VecLayout`](crate::layout::VecLayout)/[`BoxedSliceLayout`](crate::layout::BoxedSliceLayout),
+/// and for raw buffers of [`RawLayout`](crate::layout::RawLayout).
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::{
+///     layout::{ArcLayout, BoxedSliceLayout, VecLayout},
+///     ArcBytes, ErasedArcBytes,
+/// };
+///
+/// let bytes: Vec<ErasedArcBytes> = vec![
+///     ArcBytes::<ArcLayout<false>>::from(b"hello").into(),
+///     ArcBytes::<VecLayout>::from(b" ".to_vec()).into(),
+///     ArcBytes::<BoxedSliceLayout>::from(b"world".to_vec()).into(),
+/// ];
+/// assert_eq!(bytes.concat(), b"hello world");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErasedArcBytes(ArcBytes<ErasedLayout>);
+
+impl ErasedArcBytes {
+    /// Tries erasing the layout of the given bytes, returning them if an allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::VecLayout, ArcBytes, ErasedArcBytes};
+    ///
+    /// let bytes = ErasedArcBytes::try_new(ArcBytes::<VecLayout>::from(b"hello".to_vec())).unwrap();
+    /// assert_eq!(bytes, b"hello");
+    /// ```
+    pub fn try_new<L: Layout>(bytes: ArcBytes<L>) -> Result<Self, ArcBytes<L>> {
+        bytes.try_with_layout().map(Self)
+    }
+
+    /// Returns the number of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ErasedArcBytes};
+    ///
+    /// let bytes = ErasedArcBytes::from(<ArcBytes>::from(b"hello"));
+    /// assert_eq!(bytes.len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there is no byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ErasedArcBytes;
+    ///
+    /// assert!(ErasedArcBytes::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a reference to the underlying bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ErasedArcBytes};
+    ///
+    /// let bytes = ErasedArcBytes::from(<ArcBytes>::from(b"hello"));
+    /// assert_eq!(bytes.as_slice(), b"hello");
+    /// ```
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns a subslice of the bytes, sharing the same buffer.
+    ///
+    /// # Panics
+    ///
+    /// See [`ArcSlice::subslice`](crate::ArcSlice::subslice).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ErasedArcBytes};
+    ///
+    /// let bytes = ErasedArcBytes::from(<ArcBytes>::from(b"hello world"));
+    /// assert_eq!(bytes.subslice(..5), b"hello");
+    /// ```
+    #[track_caller]
+    pub fn subslice(&self, range: impl RangeBounds<usize>) -> Self {
+        Self(self.0.subslice(range))
+    }
+
+    /// Splits the bytes into two at the given index.
+    ///
+    /// Afterwards `self` contains bytes `[at, len)`, and the returned `ErasedArcBytes`
+    /// contains bytes `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ErasedArcBytes};
+    ///
+    /// let mut bytes = ErasedArcBytes::from(<ArcBytes>::from(b"hello world"));
+    /// assert_eq!(bytes.split_to(6), b"hello ");
+    /// assert_eq!(bytes, b"world");
+    /// ```
+    #[must_use = "consider `ErasedArcBytes::advance` if you don't need the other half"]
+    #[track_caller]
+    pub fn split_to(&mut self, at: usize) -> Self {
+        Self(self.0.split_to(at))
+    }
+
+    /// Splits the bytes into two at the given index.
+    ///
+    /// Afterwards `self` contains bytes `[0, at)`, and the returned `ErasedArcBytes`
+    /// contains bytes `[at, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ErasedArcBytes};
+    ///
+    /// let mut bytes = ErasedArcBytes::from(<ArcBytes>::from(b"hello world"));
+    /// assert_eq!(bytes.split_off(5), b" world");
+    /// assert_eq!(bytes, b"hello");
+    /// ```
+    #[must_use = "consider `ErasedArcBytes::truncate` if you don't need the other half"]
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        Self(self.0.split_off(at))
+    }
+
+    /// Advances the start of the bytes by `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ErasedArcBytes};
+    ///
+    /// let mut bytes = ErasedArcBytes::from(<ArcBytes>::from(b"hello world"));
+    /// bytes.advance(6);
+    /// assert_eq!(bytes, b"world");
+    /// ```
+    #[track_caller]
+    pub fn advance(&mut self, offset: usize) {
+        self.0.advance(offset);
+    }
+
+    /// Truncates the bytes, keeping the first `len` ones.
+    ///
+    /// If `len` is greater than the current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ErasedArcBytes};
+    ///
+    /// let mut bytes = ErasedArcBytes::from(<ArcBytes>::from(b"hello world"));
+    /// bytes.truncate(5);
+    /// assert_eq!(bytes, b"hello");
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    /// Accesses the metadata of the underlying buffer if it can be successfully downcast.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::VecLayout, ArcBytes, ErasedArcBytes};
+    ///
+    /// let bytes = ArcBytes::<VecLayout>::from_buffer_with_metadata(vec![0, 1, 2], "metadata");
+    /// let bytes = ErasedArcBytes::from(bytes);
+    /// assert_eq!(bytes.metadata::<&str>().unwrap(), &"metadata");
+    /// ```
+    pub fn metadata<M: Any>(&self) -> Option<&M> {
+        self.0.metadata()
+    }
+
+    /// Tries converting back into an [`ArcBytes`] with the given layout, returning the original
+    /// bytes if it fails.
+    ///
+    /// The [layouts](crate::layout) must be compatible for the conversion to succeed, see
+    /// [`ArcSlice::try_with_layout`](crate::ArcSlice::try_with_layout).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{
+    ///     layout::{ArcLayout, VecLayout},
+    ///     ArcBytes, ErasedArcBytes,
+    /// };
+    ///
+    /// let bytes = ErasedArcBytes::from(ArcBytes::<VecLayout>::from(b"hello".to_vec()));
+    /// let bytes = bytes.try_into_layout::<ArcLayout<false>>().unwrap_err();
+    /// assert_eq!(bytes.try_into_layout::<VecLayout>().unwrap(), b"hello");
+    /// ```
+    pub fn try_into_layout<L: Layout>(self) -> Result<ArcBytes<L>, Self> {
+        self.0.try_with_layout().map_err(Self)
+    }
+}
+
+impl Deref for ErasedArcBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for ErasedArcBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Borrow<[u8]> for ErasedArcBytes {
+    fn borrow(&self) -> &[u8] {
+        self
+    }
+}
+
+impl PartialEq<[u8]> for ErasedArcBytes {
+    fn eq(&self, other: &[u8]) -> bool {
+        **self == *other
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for ErasedArcBytes {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        **self == *other
+    }
+}
+
+impl<'a, const N: usize> PartialEq<&'a [u8; N]> for ErasedArcBytes {
+    fn eq(&self, other: &&'a [u8; N]) -> bool {
+        **self == **other
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<L: Layout> From<ArcBytes<L>> for ErasedArcBytes
+where
+    ErasedLayout: FromLayout<L>,
+{
+    fn from(value: ArcBytes<L>) -> Self {
+        Self(value.with_layout())
+    }
+}
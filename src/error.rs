@@ -1,4 +1,4 @@
-//! Error types used in fallible allocation and buffer resizing.
+//! Error types used in fallible allocation, buffer resizing, reading, framing and decoding.
 
 use core::fmt;
 
@@ -48,18 +48,188 @@ impl fmt::Display for TryReserveError {
     }
 }
 
+/// Error which can occur when trying to read a value from a slice without enough bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryGetError {
+    /// The number of bytes necessary to read the value.
+    pub requested: usize,
+    /// The number of bytes available in the slice.
+    pub available: usize,
+}
+
+impl fmt::Display for TryGetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "not enough bytes remaining in slice to read value (requested {} but only {} available)",
+            self.requested, self.available
+        )
+    }
+}
+
+/// Error which can occur when encoding or decoding a length-prefixed
+/// [frame](crate::frame).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameError {
+    /// The frame length doesn't fit in the prefix, or exceeds `isize::MAX` bytes.
+    Overflow,
+    /// The length prefix is malformed, e.g. a varint longer than 10 bytes.
+    Malformed,
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("frame length overflow"),
+            Self::Malformed => f.write_str("malformed frame length prefix"),
+        }
+    }
+}
+
+/// Error which can occur when splitting a string at an invalid index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CharBoundaryError {
+    /// The index is greater than the string length.
+    OutOfRange {
+        /// The requested index.
+        index: usize,
+        /// The string length.
+        len: usize,
+    },
+    /// The index is inside a multi-byte character.
+    NotCharBoundary {
+        /// The requested index.
+        index: usize,
+        /// The closest char boundary below the index.
+        floor: usize,
+        /// The closest char boundary above the index.
+        ceil: usize,
+    },
+}
+
+impl fmt::Display for CharBoundaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange { index, len } => {
+                write!(f, "byte index {index} is out of range of string of length {len}")
+            }
+            Self::NotCharBoundary { index, floor, ceil } => write!(
+                f,
+                "byte index {index} is not a char boundary (closest boundaries are {floor} and {ceil})"
+            ),
+        }
+    }
+}
+
+/// Error which can occur when reading a typed view of a byte slice.
+#[cfg(feature = "bytemuck")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PodSliceError {
+    /// The view doesn't fit in the slice.
+    OutOfRange,
+    /// The view is not correctly aligned for its item type.
+    Misaligned,
+}
+
+#[cfg(feature = "bytemuck")]
+impl fmt::Display for PodSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange => f.write_str("view out of range"),
+            Self::Misaligned => f.write_str("misaligned view"),
+        }
+    }
+}
+
+/// Error which can occur when decoding a hexadecimal string.
+#[cfg(feature = "codec")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexError {
+    /// The string has an odd number of digits; the index is the one of the unpaired digit.
+    OddLength {
+        /// The index of the unpaired digit.
+        index: usize,
+    },
+    /// The string contains a character which is not a hexadecimal digit.
+    InvalidDigit {
+        /// The index of the invalid character.
+        index: usize,
+    },
+}
+
+#[cfg(feature = "codec")]
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength { index } => write!(f, "unpaired hexadecimal digit at index {index}"),
+            Self::InvalidDigit { index } => write!(f, "invalid hexadecimal digit at index {index}"),
+        }
+    }
+}
+
+/// Error which can occur when decoding a base64 string.
+#[cfg(feature = "codec")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The string has a number of symbols which cannot be decoded, i.e. one more than a multiple
+    /// of four.
+    InvalidLength {
+        /// The index of the symbol which cannot be decoded.
+        index: usize,
+    },
+    /// The string contains a character which is not part of the alphabet, or a last symbol with
+    /// non-zero trailing bits.
+    InvalidCharacter {
+        /// The index of the invalid character.
+        index: usize,
+    },
+    /// The string is padded, but its length is not a multiple of four.
+    InvalidPadding {
+        /// The index of the padding.
+        index: usize,
+    },
+}
+
+#[cfg(feature = "codec")]
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { index } => write!(f, "dangling base64 symbol at index {index}"),
+            Self::InvalidCharacter { index } => {
+                write!(f, "invalid base64 character at index {index}")
+            }
+            Self::InvalidPadding { index } => write!(f, "invalid base64 padding at index {index}"),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 const _: () = {
     extern crate std;
     impl std::error::Error for AllocError {}
     impl std::error::Error for TryReserveError {}
+    impl std::error::Error for TryGetError {}
+    impl std::error::Error for FrameError {}
+    impl std::error::Error for CharBoundaryError {}
+    #[cfg(feature = "bytemuck")]
+    impl std::error::Error for PodSliceError {}
+    #[cfg(feature = "codec")]
+    impl std::error::Error for HexError {}
+    #[cfg(feature = "codec")]
+    impl std::error::Error for Base64Error {}
+
+    impl From<TryGetError> for std::io::Error {
+        fn from(error: TryGetError) -> Self {
+            std::io::Error::new(std::io::ErrorKind::UnexpectedEof, error)
+        }
+    }
 };
 
 mod private {
     use alloc::alloc::{alloc, alloc_zeroed, handle_alloc_error};
     use core::{alloc::Layout, convert::Infallible, mem, ptr::NonNull};
 
-    use crate::{error::AllocError, utils::assert_checked};
+    use crate::{buffer::BufferSource, error::AllocError, utils::assert_checked};
 
     pub trait AllocErrorImpl: Sized {
         const FALLIBLE: bool;
@@ -69,6 +239,7 @@ mod private {
         }
         fn capacity_overflow() -> Self;
         fn alloc<T, const ZEROED: bool>(layout: Layout) -> Result<NonNull<T>, Self>;
+        fn alloc_in<T, A: BufferSource>(alloc: &A, layout: Layout) -> Result<NonNull<T>, Self>;
     }
 
     impl AllocErrorImpl for AllocError {
@@ -81,6 +252,10 @@ mod private {
             let ptr = unsafe { (if ZEROED { alloc_zeroed } else { alloc })(layout) };
             Ok(NonNull::new(ptr).ok_or(AllocError)?.cast())
         }
+        fn alloc_in<T, A: BufferSource>(alloc: &A, layout: Layout) -> Result<NonNull<T>, Self> {
+            assert_checked(layout.size() > 0);
+            Ok(alloc.allocate(layout)?.cast())
+        }
     }
 
     impl AllocErrorImpl for Infallible {
@@ -93,5 +268,8 @@ mod private {
         fn alloc<T, const ZEROED: bool>(layout: Layout) -> Result<NonNull<T>, Self> {
             AllocError::alloc::<T, ZEROED>(layout).map_err(|_| handle_alloc_error(layout))
         }
+        fn alloc_in<T, A: BufferSource>(alloc: &A, layout: Layout) -> Result<NonNull<T>, Self> {
+            AllocError::alloc_in(alloc, layout).map_err(|_| handle_alloc_error(layout))
+        }
     }
 }
@@ -0,0 +1,608 @@
+//! Length-prefixed framing.
+//!
+//! Frames are written into an [`ArcSliceMut`] with [`begin_frame`](ArcSliceMut::begin_frame),
+//! which backfills the length prefix once the payload has been appended, and split out of an
+//! [`ArcSlice`] without copy with [`split_frame`](ArcSlice::split_frame).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use arc_slice::{frame::LengthPrefix, ArcBytes, ArcBytesMut};
+//!
+//! let mut buf = <ArcBytesMut>::new();
+//! buf.write_frame(LengthPrefix::default(), b"hello")?;
+//! let mut frame = buf.begin_frame(LengthPrefix::default());
+//! frame.extend_from_slice(b"world");
+//! frame.finish()?;
+//!
+//! let mut bytes: ArcBytes = buf.freeze();
+//! assert_eq!(
+//!     bytes.split_frame(LengthPrefix::default())?.unwrap(),
+//!     b"hello"
+//! );
+//! assert_eq!(
+//!     bytes.split_frame(LengthPrefix::default())?.unwrap(),
+//!     b"world"
+//! );
+//! assert_eq!(bytes.split_frame(LengthPrefix::default())?, None);
+//! # Ok::<_, arc_slice::error::FrameError>(())
+//! ```
+use core::{fmt, mem::ManuallyDrop, ops::Deref};
+
+#[cfg(not(feature = "oom-handling"))]
+use crate::layout::CloneNoAllocLayout;
+#[cfg(feature = "oom-handling")]
+use crate::layout::Layout;
+use crate::{
+    error::{FrameError, TryReserveError},
+    layout::{DefaultLayoutMut, LayoutMut},
+    ArcSlice, ArcSliceMut,
+};
+
+const MAX_VARINT_LEN: usize = 10;
+const MAX_PREFIX_LEN: usize = MAX_VARINT_LEN;
+
+/// The byte order of a fixed-width length prefix.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Big endian, i.e. network byte order.
+    #[default]
+    Big,
+    /// Little endian.
+    Little,
+}
+
+/// The encoding of a frame length prefix.
+///
+/// The default is a big endian `u32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthPrefix {
+    /// A `u8` prefix.
+    U8,
+    /// A `u16` prefix.
+    U16(Endian),
+    /// A `u32` prefix.
+    U32(Endian),
+    /// A `u64` prefix.
+    U64(Endian),
+    /// An unsigned LEB128 varint prefix, up to 10 bytes.
+    Varint,
+}
+
+impl Default for LengthPrefix {
+    fn default() -> Self {
+        Self::U32(Endian::Big)
+    }
+}
+
+macro_rules! encode_fixed {
+    ($int:ty, $endian:expr, $length:expr, $buf:expr) => {{
+        let length = <$int>::try_from($length).map_err(|_| FrameError::Overflow)?;
+        let bytes = match $endian {
+            Endian::Big => length.to_be_bytes(),
+            Endian::Little => length.to_le_bytes(),
+        };
+        $buf[..bytes.len()].copy_from_slice(&bytes);
+        Ok(bytes.len())
+    }};
+}
+
+macro_rules! decode_fixed {
+    ($int:ty, $endian:expr, $bytes:expr) => {{
+        const WIDTH: usize = core::mem::size_of::<$int>();
+        if $bytes.len() < WIDTH {
+            return Ok(None);
+        }
+        let mut buf = [0; WIDTH];
+        buf.copy_from_slice(&$bytes[..WIDTH]);
+        let length = match $endian {
+            Endian::Big => <$int>::from_be_bytes(buf),
+            Endian::Little => <$int>::from_le_bytes(buf),
+        };
+        (WIDTH, u64::from(length))
+    }};
+}
+
+impl LengthPrefix {
+    fn reserved_len(self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::U16(_) => 2,
+            Self::U32(_) => 4,
+            Self::U64(_) => 8,
+            Self::Varint => MAX_VARINT_LEN,
+        }
+    }
+
+    fn encode(self, length: usize, buf: &mut [u8; MAX_PREFIX_LEN]) -> Result<usize, FrameError> {
+        match self {
+            Self::U8 => encode_fixed!(u8, Endian::Big, length, buf),
+            Self::U16(endian) => encode_fixed!(u16, endian, length, buf),
+            Self::U32(endian) => encode_fixed!(u32, endian, length, buf),
+            Self::U64(endian) => encode_fixed!(u64, endian, length, buf),
+            Self::Varint => {
+                let mut length = length as u64;
+                let mut len = 0;
+                while length >= 0x80 {
+                    buf[len] = length as u8 | 0x80;
+                    length >>= 7;
+                    len += 1;
+                }
+                buf[len] = length as u8;
+                Ok(len + 1)
+            }
+        }
+    }
+
+    /// Returns the prefix length and the decoded payload length, or `None` if the prefix is
+    /// incomplete.
+    fn decode(self, bytes: &[u8]) -> Result<Option<(usize, usize)>, FrameError> {
+        let (prefix_len, length) = match self {
+            Self::U8 => decode_fixed!(u8, Endian::Big, bytes),
+            Self::U16(endian) => decode_fixed!(u16, endian, bytes),
+            Self::U32(endian) => decode_fixed!(u32, endian, bytes),
+            Self::U64(endian) => decode_fixed!(u64, endian, bytes),
+            Self::Varint => {
+                let mut length = 0u64;
+                let mut prefix_len = 0;
+                loop {
+                    // MSRV 1.65 let-else
+                    let byte = match bytes.get(prefix_len) {
+                        Some(&byte) => byte,
+                        None => return Ok(None),
+                    };
+                    // the 10th byte can only hold the last bit of a u64
+                    if prefix_len == MAX_VARINT_LEN - 1 && byte > 1 {
+                        return Err(FrameError::Malformed);
+                    }
+                    length |= u64::from(byte & 0x7F) << (7 * prefix_len);
+                    prefix_len += 1;
+                    if byte & 0x80 == 0 {
+                        break (prefix_len, length);
+                    }
+                }
+            }
+        };
+        match usize::try_from(length) {
+            Ok(length) if length <= isize::MAX as usize => Ok(Some((prefix_len, length))),
+            _ => Err(FrameError::Overflow),
+        }
+    }
+}
+
+/// A frame being written into an [`ArcSliceMut`].
+///
+/// The payload is appended through the guard, which only exposes append methods, so the
+/// reserved prefix cannot be moved or dropped; the guard dereferences to the slice for reading.
+/// The length prefix is backfilled by [`finish`](Self::finish); if the guard is dropped without
+/// being finished, the frame is discarded, i.e. the slice is truncated to its length before the
+/// frame.
+///
+/// It is returned by [`ArcSliceMut::begin_frame`] and [`ArcSliceMut::try_begin_frame`].
+pub struct FrameGuard<'a, L: LayoutMut = DefaultLayoutMut, const UNIQUE: bool = true> {
+    slice: &'a mut ArcSliceMut<[u8], L, UNIQUE>,
+    prefix: LengthPrefix,
+    start: usize,
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> FrameGuard<'_, L, UNIQUE> {
+    /// Returns the length of the payload appended so far.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// let mut buf = <ArcBytesMut>::from(b"previous");
+    /// let mut frame = buf.begin_frame(LengthPrefix::default());
+    /// frame.extend_from_slice(b"hello");
+    /// assert_eq!(frame.payload_len(), 5);
+    /// ```
+    pub fn payload_len(&self) -> usize {
+        self.slice.len() - self.payload_start()
+    }
+
+    /// Tries appending bytes to the payload, returning an error if the capacity reservation
+    /// fails.
+    ///
+    /// See [`ArcSliceMut::try_extend_from_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut buf = <ArcBytesMut>::new().into_shared();
+    /// let mut frame = buf.try_begin_frame(LengthPrefix::U8)?;
+    /// frame.try_extend_from_slice(b"hello")?;
+    /// frame.finish()?;
+    /// assert_eq!(buf, b"\x05hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_extend_from_slice(&mut self, bytes: &[u8]) -> Result<(), TryReserveError> {
+        self.slice.try_extend_from_slice(bytes)
+    }
+
+    /// Tries appending a byte to the payload, returning an error if the capacity reservation
+    /// fails.
+    ///
+    /// See [`ArcSliceMut::try_push`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut buf = <ArcBytesMut>::new().into_shared();
+    /// let mut frame = buf.try_begin_frame(LengthPrefix::U8)?;
+    /// frame.try_push(42)?;
+    /// frame.finish()?;
+    /// assert_eq!(buf, [1, 42]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_push(&mut self, byte: u8) -> Result<(), TryReserveError> {
+        self.slice.try_push(byte)
+    }
+
+    fn payload_start(&self) -> usize {
+        self.start + self.prefix.reserved_len()
+    }
+
+    /// Completes the frame by writing its length prefix.
+    ///
+    /// For [`LengthPrefix::Varint`], the maximal prefix length is reserved when the frame begins,
+    /// so the payload is moved back if the actual prefix is shorter.
+    ///
+    /// If the payload length doesn't fit in the prefix, an error is returned, and the frame is
+    /// discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{
+    ///     error::FrameError,
+    ///     frame::{Endian, LengthPrefix},
+    ///     ArcBytesMut,
+    /// };
+    ///
+    /// let mut buf = <ArcBytesMut>::new();
+    /// let mut frame = buf.begin_frame(LengthPrefix::U16(Endian::Little));
+    /// frame.extend_from_slice(b"hello");
+    /// frame.finish()?;
+    /// assert_eq!(buf, b"\x05\x00hello");
+    ///
+    /// let mut frame = buf.begin_frame(LengthPrefix::U16(Endian::Little));
+    /// frame.extend_from_slice(&[0; 1 << 16]);
+    /// assert_eq!(frame.finish(), Err(FrameError::Overflow));
+    /// assert_eq!(buf, b"\x05\x00hello");
+    /// # Ok::<_, FrameError>(())
+    /// ```
+    pub fn finish(self) -> Result<(), FrameError> {
+        let payload_start = self.payload_start();
+        let payload_len = self.slice.len() - payload_start;
+        let mut prefix = [0; MAX_PREFIX_LEN];
+        // on error, the guard is dropped, discarding the frame
+        let prefix_len = self.prefix.encode(payload_len, &mut prefix)?;
+        let mut this = ManuallyDrop::new(self);
+        let start = this.start;
+        let bytes = this.slice.as_mut_slice();
+        bytes[start..start + prefix_len].copy_from_slice(&prefix[..prefix_len]);
+        if start + prefix_len < payload_start {
+            bytes.copy_within(payload_start.., start + prefix_len);
+            this.slice.truncate(start + prefix_len + payload_len);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<L: LayoutMut> FrameGuard<'_, L> {
+    /// Appends bytes to the payload.
+    ///
+    /// See [`ArcSliceMut::extend_from_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// let mut buf = <ArcBytesMut>::new();
+    /// let mut frame = buf.begin_frame(LengthPrefix::U8);
+    /// frame.extend_from_slice(b"hello");
+    /// frame.finish()?;
+    /// assert_eq!(buf, b"\x05hello");
+    /// # Ok::<_, arc_slice::error::FrameError>(())
+    /// ```
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.slice.extend_from_slice(bytes);
+    }
+
+    /// Appends a byte to the payload.
+    ///
+    /// See [`ArcSliceMut::push`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// let mut buf = <ArcBytesMut>::new();
+    /// let mut frame = buf.begin_frame(LengthPrefix::U8);
+    /// frame.push(42);
+    /// frame.finish()?;
+    /// assert_eq!(buf, [1, 42]);
+    /// # Ok::<_, arc_slice::error::FrameError>(())
+    /// ```
+    pub fn push(&mut self, byte: u8) {
+        self.slice.push(byte);
+    }
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> Deref for FrameGuard<'_, L, UNIQUE> {
+    type Target = ArcSliceMut<[u8], L, UNIQUE>;
+
+    fn deref(&self) -> &Self::Target {
+        self.slice
+    }
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> AsMut<[u8]> for FrameGuard<'_, L, UNIQUE> {
+    /// Returns the payload appended so far, the prefix being excluded.
+    fn as_mut(&mut self) -> &mut [u8] {
+        let payload_start = self.payload_start();
+        &mut self.slice[payload_start..]
+    }
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> Drop for FrameGuard<'_, L, UNIQUE> {
+    fn drop(&mut self) {
+        self.slice.truncate(self.start);
+    }
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> fmt::Debug for FrameGuard<'_, L, UNIQUE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameGuard")
+            .field("prefix", &self.prefix)
+            .field("start", &self.start)
+            .field("len", &self.slice.len())
+            .finish()
+    }
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> ArcSliceMut<[u8], L, UNIQUE> {
+    /// Tries beginning a length-prefixed frame, returning an error if the prefix cannot be
+    /// reserved.
+    ///
+    /// See [`FrameGuard`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut buf = <ArcBytesMut>::new().into_shared();
+    /// let mut frame = buf.try_begin_frame(LengthPrefix::default())?;
+    /// frame.try_extend_from_slice(b"hello")?;
+    /// frame.finish()?;
+    /// assert_eq!(buf, b"\0\0\0\x05hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_begin_frame(
+        &mut self,
+        prefix: LengthPrefix,
+    ) -> Result<FrameGuard<'_, L, UNIQUE>, TryReserveError> {
+        let start = self.len();
+        self.try_extend_from_slice(&[0; MAX_PREFIX_LEN][..prefix.reserved_len()])?;
+        Ok(FrameGuard {
+            slice: self,
+            prefix,
+            start,
+        })
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<L: LayoutMut> ArcSliceMut<[u8], L> {
+    /// Begins a length-prefixed frame.
+    ///
+    /// The prefix is reserved, the payload can then be appended through the returned
+    /// [`FrameGuard`], and the prefix is backfilled by [`FrameGuard::finish`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// let mut buf = <ArcBytesMut>::new();
+    /// let mut frame = buf.begin_frame(LengthPrefix::Varint);
+    /// frame.extend_from_slice(b"hello");
+    /// frame.finish()?;
+    /// assert_eq!(buf, b"\x05hello");
+    /// # Ok::<_, arc_slice::error::FrameError>(())
+    /// ```
+    pub fn begin_frame(&mut self, prefix: LengthPrefix) -> FrameGuard<'_, L> {
+        let start = self.len();
+        self.extend_from_slice(&[0; MAX_PREFIX_LEN][..prefix.reserved_len()]);
+        FrameGuard {
+            slice: self,
+            prefix,
+            start,
+        }
+    }
+
+    /// Writes a length-prefixed frame with the given payload.
+    ///
+    /// If the payload length doesn't fit in the prefix, an error is returned, and nothing is
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{frame::LengthPrefix, ArcBytesMut};
+    ///
+    /// let mut buf = <ArcBytesMut>::new();
+    /// buf.write_frame(LengthPrefix::default(), b"hello")?;
+    /// assert_eq!(buf, b"\0\0\0\x05hello");
+    /// # Ok::<_, arc_slice::error::FrameError>(())
+    /// ```
+    pub fn write_frame(&mut self, prefix: LengthPrefix, payload: &[u8]) -> Result<(), FrameError> {
+        let mut buf = [0; MAX_PREFIX_LEN];
+        let prefix_len = prefix.encode(payload.len(), &mut buf)?;
+        self.reserve(prefix_len + payload.len());
+        self.extend_from_slice(&buf[..prefix_len]);
+        self.extend_from_slice(payload);
+        Ok(())
+    }
+}
+
+impl<
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ArcSlice<[u8], L>
+{
+    /// Splits a length-prefixed frame off the start of the slice, returning its payload.
+    ///
+    /// The payload shares the slice buffer, so no byte is copied. `None` is returned if the
+    /// frame is not complete yet, leaving the slice untouched; more bytes can then be
+    /// accumulated before trying again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FrameError::Overflow`] if the decoded length exceeds `isize::MAX`, and
+    /// [`FrameError::Malformed`] if the prefix is invalid; the slice is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{
+    ///     error::FrameError,
+    ///     frame::{Endian, LengthPrefix},
+    ///     ArcBytes,
+    /// };
+    ///
+    /// let prefix = LengthPrefix::U32(Endian::Little);
+    /// let mut bytes = <ArcBytes>::from(b"\x05\0\0\0hello\x05\0\0\0wor");
+    /// assert_eq!(bytes.split_frame(prefix)?.unwrap(), b"hello");
+    /// assert_eq!(bytes.split_frame(prefix)?, None);
+    /// assert_eq!(bytes, b"\x05\0\0\0wor");
+    ///
+    /// let mut bytes = <ArcBytes>::from(&[0xFF; 16]);
+    /// assert_eq!(
+    ///     bytes.split_frame(LengthPrefix::Varint),
+    ///     Err(FrameError::Malformed)
+    /// );
+    /// # Ok::<_, FrameError>(())
+    /// ```
+    pub fn split_frame(&mut self, prefix: LengthPrefix) -> Result<Option<Self>, FrameError> {
+        let (prefix_len, length) = match prefix.decode(self)? {
+            Some(decoded) => decoded,
+            None => return Ok(None),
+        };
+        if length > self.len() - prefix_len {
+            return Ok(None);
+        }
+        self.advance(prefix_len);
+        Ok(Some(self.split_to(length)))
+    }
+
+    /// Splits a frame prefixed by its `u8` length off the start of the slice, returning its
+    /// payload.
+    ///
+    /// This is [`split_frame`](Self::split_frame) with [`LengthPrefix::U8`]; `None` is returned
+    /// if the frame is not complete yet, leaving the slice untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytes;
+    ///
+    /// let mut bytes = <ArcBytes>::from(b"\x05hello\x05wor");
+    /// assert_eq!(bytes.split_prefixed_u8().unwrap(), b"hello");
+    /// assert_eq!(bytes.split_prefixed_u8(), None);
+    /// assert_eq!(bytes, b"\x05wor");
+    /// ```
+    pub fn split_prefixed_u8(&mut self) -> Option<Self> {
+        self.split_fixed_frame(LengthPrefix::U8)
+    }
+
+    /// Splits a frame prefixed by its big-endian `u16` length off the start of the slice,
+    /// returning its payload.
+    ///
+    /// This is [`split_frame`](Self::split_frame) with
+    /// [`LengthPrefix::U16`]`(`[`Endian::Big`]`)`; `None` is returned if the frame is not
+    /// complete yet, leaving the slice untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytes;
+    ///
+    /// let mut bytes = <ArcBytes>::from(b"\0\x05hello\0");
+    /// assert_eq!(bytes.split_prefixed_u16_be().unwrap(), b"hello");
+    /// assert_eq!(bytes.split_prefixed_u16_be(), None);
+    /// assert_eq!(bytes, b"\0");
+    /// ```
+    pub fn split_prefixed_u16_be(&mut self) -> Option<Self> {
+        self.split_fixed_frame(LengthPrefix::U16(Endian::Big))
+    }
+
+    /// Splits a frame prefixed by its big-endian `u32` length off the start of the slice,
+    /// returning its payload.
+    ///
+    /// This is [`split_frame`](Self::split_frame) with
+    /// [`LengthPrefix::U32`]`(`[`Endian::Big`]`)`; `None` is returned if the frame is not
+    /// complete yet, leaving the slice untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytes;
+    ///
+    /// let mut bytes = <ArcBytes>::from(b"\0\0\0\x05hello\0\0\0\x05wor");
+    /// assert_eq!(bytes.split_prefixed_u32_be().unwrap(), b"hello");
+    /// assert_eq!(bytes.split_prefixed_u32_be(), None);
+    /// assert_eq!(bytes, b"\0\0\0\x05wor");
+    /// ```
+    pub fn split_prefixed_u32_be(&mut self) -> Option<Self> {
+        self.split_fixed_frame(LengthPrefix::U32(Endian::Big))
+    }
+
+    /// Splits a frame prefixed by its unsigned LEB128 varint length off the start of the slice,
+    /// returning its payload.
+    ///
+    /// This is [`split_frame`](Self::split_frame) with [`LengthPrefix::Varint`]; `None` is
+    /// returned if the frame is not complete yet, leaving the slice untouched.
+    ///
+    /// # Errors
+    ///
+    /// Contrary to fixed-width prefixes, a varint prefix can be invalid, so errors are
+    /// returned like [`split_frame`](Self::split_frame).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::FrameError, ArcBytes};
+    ///
+    /// let mut bytes = <ArcBytes>::from(b"\x05hello\x80");
+    /// assert_eq!(bytes.split_prefixed_varint()?.unwrap(), b"hello");
+    /// assert_eq!(bytes.split_prefixed_varint()?, None);
+    /// assert_eq!(bytes, b"\x80");
+    /// # Ok::<_, FrameError>(())
+    /// ```
+    pub fn split_prefixed_varint(&mut self) -> Result<Option<Self>, FrameError> {
+        self.split_frame(LengthPrefix::Varint)
+    }
+
+    fn split_fixed_frame(&mut self, prefix: LengthPrefix) -> Option<Self> {
+        // a fixed-width length can only overflow `isize::MAX` on targets where such a payload
+        // could never be available, so the frame is just incomplete
+        self.split_frame(prefix).ok().flatten()
+    }
+}
@@ -0,0 +1,120 @@
+//! Filesystem helpers.
+//!
+//! The path of the read file is attached as [`PathBuf`] metadata to the returned slice.
+extern crate std;
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "memmap2")]
+use crate::{buffer::Buffer, layout::ArcLayout};
+use crate::{layout::AnyBufferLayout, ArcBytes};
+
+/// Reads the entire contents of a file into an `ArcBytes`.
+///
+/// The buffer is allocated once, sized from the file length, and is not copied afterward.
+/// If the file grows or shrinks between querying its length and reading it, the returned
+/// slice contains what has actually been read. An empty file doesn't allocate its bytes.
+///
+/// The path is attached as [`PathBuf`] metadata.
+///
+/// # Errors
+///
+/// Returns any I/O error encountered when opening or reading the file.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+///
+/// use arc_slice::{fs, layout::ArcLayout};
+///
+/// # fn main() -> std::io::Result<()> {
+/// # #[cfg(not(miri))]
+/// # {
+/// let bytes = fs::read::<ArcLayout<true>>("README.md")?;
+/// assert!(bytes.starts_with(b"# arc-slice"));
+/// assert_eq!(bytes.metadata::<PathBuf>().unwrap(), Path::new("README.md"));
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read<L: AnyBufferLayout>(path: impl AsRef<Path>) -> io::Result<ArcBytes<L>> {
+    let path = path.as_ref();
+    // `std::fs::read` allocates the vector with the file length as capacity, then reads until
+    // EOF, so the vector is moved into the buffer without copy.
+    let bytes = std::fs::read(path)?;
+    Ok(ArcBytes::from_buffer_with_metadata(
+        bytes,
+        PathBuf::from(path),
+    ))
+}
+
+#[cfg(feature = "memmap2")]
+struct MmapBuffer {
+    mmap: memmap2::Mmap,
+    _file: std::fs::File,
+}
+
+#[cfg(feature = "memmap2")]
+impl Buffer<[u8]> for MmapBuffer {
+    fn as_slice(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+/// Memory-maps a file into an `ArcBytes`.
+///
+/// The mapping and the file are kept alive by the buffer, and released when the last slice
+/// referencing them is dropped. An empty file is not mapped, as some platforms don't support
+/// zero-length mappings.
+///
+/// The path is attached as [`PathBuf`] metadata.
+///
+/// # Safety
+///
+/// See [`memmap2::Mmap::map`]: the file must not be modified, e.g. truncated, by this or
+/// another process while the returned slice (or any of its subslices) is alive, as it would
+/// be undefined behavior.
+///
+/// # Errors
+///
+/// Returns any I/O error encountered when opening or mapping the file.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+///
+/// use arc_slice::fs;
+///
+/// # fn main() -> std::io::Result<()> {
+/// # #[cfg(not(miri))]
+/// # {
+/// let bytes = unsafe { fs::read_with_mmap("README.md")? };
+/// assert!(bytes.starts_with(b"# arc-slice"));
+/// assert_eq!(bytes.metadata::<PathBuf>().unwrap(), Path::new("README.md"));
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "memmap2")]
+pub unsafe fn read_with_mmap(path: impl AsRef<Path>) -> io::Result<ArcBytes<ArcLayout<true>>> {
+    let path = path.as_ref();
+    let file = std::fs::File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        return Ok(ArcBytes::from_buffer_with_metadata(
+            &[] as &[u8],
+            PathBuf::from(path),
+        ));
+    }
+    // SAFETY: same function contract
+    let mmap = unsafe { memmap2::Mmap::map(&file)? };
+    let buffer = MmapBuffer { mmap, _file: file };
+    Ok(ArcBytes::from_buffer_with_metadata(
+        buffer,
+        PathBuf::from(path),
+    ))
+}
@@ -0,0 +1,31 @@
+extern crate std;
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::io;
+
+use futures_io::{AsyncBufRead, AsyncRead};
+
+use crate::{layout::Layout, ArcSlice};
+
+impl<L: Layout> AsyncRead for ArcSlice<[u8], L> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(io::Read::read(self.get_mut(), buf))
+    }
+}
+
+impl<L: Layout> AsyncBufRead for ArcSlice<[u8], L> {
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().advance(amt);
+    }
+}
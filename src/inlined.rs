@@ -1,7 +1,28 @@
 //! [Small String Optimization] support for [`ArcSlice`].
 //!
+//! Inline storage is provided by the [`SmallArcSlice`] wrapper rather than by a [`Layout`]:
+//! `ArcSlice` stores a pointer to its first item, which cannot point into inline storage, as
+//! the storage would move with the slice. The tradeoff is that `SmallArcSlice` stores up to
+//! `size_of::<ArcBytes<L>>() - 2` bytes without allocating, but loses the niche of `ArcSlice`,
+//! so an `Option<SmallArcSlice<S, L>>` is one word larger than an `Option<ArcSlice<S, L>>`.
+//!
+//! ```rust
+//! use core::mem::size_of;
+//!
+//! use arc_slice::{inlined::SmallArcBytes, ArcBytes};
+//!
+//! assert_eq!(size_of::<SmallArcBytes>(), size_of::<ArcBytes>());
+//! assert_eq!(size_of::<Option<ArcBytes>>(), size_of::<ArcBytes>());
+//! assert_eq!(
+//!     size_of::<Option<SmallArcBytes>>(),
+//!     size_of::<SmallArcBytes>() + size_of::<usize>()
+//! );
+//! ```
+//!
 //! [Small String Optimization]: https://cppdepend.com/blog/understanding-small-string-optimization-sso-in-stdstring/
 
+#[cfg(feature = "oom-handling")]
+use alloc::borrow::{Cow, ToOwned};
 use alloc::{string::String, vec::Vec};
 use core::{
     borrow::Borrow,
@@ -24,9 +45,9 @@ use crate::layout::CloneNoAllocLayout;
 use crate::{
     buffer::{Emptyable, Slice, SliceExt, Subsliceable},
     error::AllocError,
-    layout::{ArcLayout, BoxedSliceLayout, DefaultLayout, Layout, StaticLayout, VecLayout},
+    layout::{ArcLayout, BoxedSliceLayout, DefaultLayout, Layout, Repr, StaticLayout, VecLayout},
     msrv::ptr,
-    utils::{debug_slice, lower_hex, panic_out_of_range, range_offset_len, upper_hex},
+    utils::{debug_slice, lower_hex, panic_start_out_of_range, range_offset_len, upper_hex},
     ArcSlice,
 };
 
@@ -44,8 +65,8 @@ mod private {
 const _3_WORDS_LEN: usize = 3 * size_of::<usize>() - 2;
 const _4_WORDS_LEN: usize = 4 * size_of::<usize>() - 2;
 
-unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> InlinedLayout
-    for ArcLayout<ANY_BUFFER, STATIC>
+unsafe impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool> InlinedLayout
+    for ArcLayout<ANY_BUFFER, STATIC, SATURATE>
 {
     const LEN: usize = _3_WORDS_LEN;
     type Data = [MaybeUninit<u8>; _3_WORDS_LEN];
@@ -186,12 +207,13 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallSlice<S, L> {
     /// s.advance(6);
     /// assert_eq!(s, b"world");
     /// ```
+    #[track_caller]
     pub fn advance(&mut self, offset: usize)
     where
         S: Subsliceable,
     {
         if offset > self.len() {
-            panic_out_of_range()
+            panic_start_out_of_range(offset, self.len())
         }
         unsafe { self.check_advance(offset) };
         self.offset += offset as u8;
@@ -209,6 +231,7 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallSlice<S, L> {
     /// s.truncate(5);
     /// assert_eq!(s, b"hello");
     /// ```
+    #[track_caller]
     pub fn truncate(&mut self, len: usize)
     where
         S: Subsliceable,
@@ -230,6 +253,7 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallSlice<S, L> {
     /// let s2 = s.subslice(..5);
     /// assert_eq!(s2, b"hello");
     /// ```
+    #[track_caller]
     pub fn subslice(&self, range: impl RangeBounds<usize>) -> Self
     where
         S: Subsliceable,
@@ -403,6 +427,7 @@ impl<L: Layout> PartialEq<SmallSlice<str, L>> for String {
 /// It can store up to `size_of::<ArcBytes<L>>() - 2` bytes inline, without allocating.
 /// However, the niche optimization of `ArcSlice` is lost, which means that
 /// `size_of::<Option<SmallArcBytes<L>>>() == size_of::<SmallArcBytes<L>>() + size_of::<usize>()`.
+/// `ArcSlice` itself keeps its niche when the `inlined` feature is enabled.
 ///
 /// [small string optimization]: https://cppdepend.com/blog/understanding-small-string-optimization-sso-in-stdstring/
 pub struct SmallArcSlice<S: Slice<Item = u8> + ?Sized, L: Layout = DefaultLayout>(Inner<S, L>);
@@ -489,6 +514,8 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallArcSlice<S, L> {
     /// let s = SmallArcSlice::<[u8]>::from_array([0; 256]);
     /// assert!(matches!(s.as_either(), Either::Right(_)));
     /// ```
+    // `addr_of!` on union fields is only safe with recent compilers
+    #[allow(unused_unsafe)]
     #[inline(always)]
     pub fn as_either(&self) -> Either<&SmallSlice<S, L>, &ArcSlice<S, L>> {
         if unsafe { SmallSlice::is_inlined(addr_of!(self.0.small)) } {
@@ -513,6 +540,8 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallArcSlice<S, L> {
     /// let mut s = SmallArcSlice::<[u8]>::from_array([0; 256]);
     /// assert!(matches!(s.as_either_mut(), Either::Right(_)));
     /// ```
+    // `addr_of!` on union fields is only safe with recent compilers
+    #[allow(unused_unsafe)]
     #[inline(always)]
     pub fn as_either_mut(&mut self) -> Either<&mut SmallSlice<S, L>, &mut ArcSlice<S, L>> {
         if unsafe { SmallSlice::is_inlined(addr_of!(self.0.small)) } {
@@ -523,6 +552,8 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallArcSlice<S, L> {
     }
 
     /// Returns either the inlined [`SmallSlice`] storage, or the [`ArcSlice`] one.
+    // `addr_of!` on union fields is only safe with recent compilers
+    #[allow(unused_unsafe)]
     #[inline(always)]
     pub fn into_either(self) -> Either<SmallSlice<S, L>, ArcSlice<S, L>> {
         let mut this = ManuallyDrop::new(self);
@@ -533,6 +564,129 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallArcSlice<S, L> {
         }
     }
 
+    /// Creates a new `SmallArcSlice` from an [`ArcSlice`], storing it inlined if it can fit into a
+    /// `SmallSlice`.
+    ///
+    /// Contrary to the [`From`] implementation, which always keeps the `ArcSlice`, the bytes are
+    /// copied when they fit, releasing the reference to the backing buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{inlined::SmallArcSlice, ArcSlice};
+    ///
+    /// let bytes = ArcSlice::<[u8]>::from(b"hello world");
+    /// let s = SmallArcSlice::from_arc_inline(bytes.subslice(..5));
+    /// assert!(s.is_inlined());
+    /// assert_eq!(s, b"hello");
+    ///
+    /// let s = SmallArcSlice::from_arc_inline(ArcSlice::<[u8]>::from_array([0; 256]));
+    /// assert!(!s.is_inlined());
+    /// ```
+    pub fn from_arc_inline(slice: ArcSlice<S, L>) -> Self {
+        match SmallSlice::new(&*slice) {
+            Some(small) => small.into(),
+            None => slice.into(),
+        }
+    }
+
+    /// Returns `true` if the slice is stored inlined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::inlined::SmallArcSlice;
+    ///
+    /// assert!(SmallArcSlice::<[u8]>::from_slice(b"hello").is_inlined());
+    /// assert!(!SmallArcSlice::<[u8]>::from_array([0; 256]).is_inlined());
+    /// ```
+    // `addr_of!` on union fields is only safe with recent compilers
+    #[allow(unused_unsafe)]
+    pub fn is_inlined(&self) -> bool {
+        unsafe { SmallSlice::is_inlined(addr_of!(self.0.small)) }
+    }
+
+    /// Returns the runtime representation of the slice, [`Repr::Inlined`] if it is stored inlined.
+    ///
+    /// See [`ArcSlice::repr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{inlined::SmallArcSlice, layout::Repr};
+    ///
+    /// assert_eq!(
+    ///     SmallArcSlice::<[u8]>::from_slice(b"hello").repr(),
+    ///     Repr::Inlined
+    /// );
+    /// assert_eq!(
+    ///     SmallArcSlice::<[u8]>::from_array([0; 256]).repr(),
+    ///     Repr::ArcSlice
+    /// );
+    /// ```
+    pub fn repr(&self) -> Repr {
+        match self.as_either() {
+            Either::Left(_) => Repr::Inlined,
+            Either::Right(arc) => arc.repr(),
+        }
+    }
+
+    /// Checks the internal invariants of the slice, panicking if one of them is broken.
+    ///
+    /// An inlined slice must fit in its storage, see `ArcSlice::debug_validate` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::inlined::SmallArcSlice;
+    ///
+    /// SmallArcSlice::<[u8]>::from_slice(b"hello").debug_validate();
+    /// SmallArcSlice::<[u8]>::from_array([0; 256]).debug_validate();
+    /// ```
+    #[cfg(feature = "arbitrary")]
+    pub fn debug_validate(&self) {
+        match self.as_either() {
+            Either::Left(small) => {
+                let end = small.offset as usize + small.len();
+                assert!(
+                    end <= SmallSlice::<S, L>::MAX_LEN,
+                    "inlined slice out of storage"
+                );
+            }
+            Either::Right(arc) => arc.debug_validate(),
+        }
+    }
+
+    /// Converts the slice in place into its inlined representation if it fits, returning whether
+    /// it is now inlined.
+    ///
+    /// If the slice was stored in an [`ArcSlice`], its bytes are copied inlined, and the
+    /// `ArcSlice` is dropped, releasing the reference to the backing buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{inlined::SmallArcSlice, ArcSlice};
+    ///
+    /// let mut s = SmallArcSlice::from(ArcSlice::<[u8]>::from(b"hello"));
+    /// assert!(!s.is_inlined());
+    /// assert!(s.inline());
+    /// assert!(s.is_inlined());
+    /// assert_eq!(s, b"hello");
+    ///
+    /// let mut s = SmallArcSlice::<[u8]>::from_array([0; 256]);
+    /// assert!(!s.inline());
+    /// ```
+    pub fn inline(&mut self) -> bool {
+        if let Either::Right(slice) = self.as_either() {
+            match SmallSlice::new(&**slice) {
+                Some(small) => *self = small.into(),
+                None => return false,
+            }
+        }
+        true
+    }
+
     /// Returns the number of items in the slice.
     ///
     /// # Examples
@@ -621,6 +775,7 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallArcSlice<S, L> {
     /// # Ok(())
     /// # }
     /// ```
+    #[track_caller]
     pub fn try_subslice(&self, range: impl RangeBounds<usize>) -> Result<Self, AllocError>
     where
         S: Subsliceable,
@@ -632,6 +787,7 @@ impl<S: Slice<Item = u8> + ?Sized, L: Layout> SmallArcSlice<S, L> {
     }
 
     #[doc(hidden)]
+    #[track_caller]
     pub fn _advance(&mut self, cnt: usize)
     where
         S: Subsliceable,
@@ -691,6 +847,10 @@ impl<
 {
     /// Extracts a subslice of an `SmallArcSlice` with a given range.
     ///
+    /// If the slice is not inlined, the subslice starts at `self.as_ptr().add(offset)`, even if
+    /// it is empty, as with [`ArcSlice::subslice`]; an inlined slice is however copied, so the
+    /// subslice points to its own storage.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -700,6 +860,7 @@ impl<
     /// let s2 = s.subslice(..5);
     /// assert_eq!(s2, b"hello");
     /// ```
+    #[track_caller]
     pub fn subslice(&self, range: impl RangeBounds<usize>) -> Self
     where
         S: Subsliceable,
@@ -857,6 +1018,38 @@ impl<S: Ord + Slice<Item = u8> + ?Sized, L: Layout> Ord for SmallArcSlice<S, L>
     }
 }
 
+impl<S: PartialEq + Slice<Item = u8> + ?Sized, L: Layout, L2: Layout> PartialEq<ArcSlice<S, L2>>
+    for SmallArcSlice<S, L>
+{
+    fn eq(&self, other: &ArcSlice<S, L2>) -> bool {
+        **self == **other
+    }
+}
+
+impl<S: PartialEq + Slice<Item = u8> + ?Sized, L: Layout, L2: Layout>
+    PartialEq<SmallArcSlice<S, L2>> for ArcSlice<S, L>
+{
+    fn eq(&self, other: &SmallArcSlice<S, L2>) -> bool {
+        **self == **other
+    }
+}
+
+impl<S: PartialOrd + Slice<Item = u8> + ?Sized, L: Layout, L2: Layout> PartialOrd<ArcSlice<S, L2>>
+    for SmallArcSlice<S, L>
+{
+    fn partial_cmp(&self, other: &ArcSlice<S, L2>) -> Option<cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<S: PartialOrd + Slice<Item = u8> + ?Sized, L: Layout, L2: Layout>
+    PartialOrd<SmallArcSlice<S, L2>> for ArcSlice<S, L>
+{
+    fn partial_cmp(&self, other: &SmallArcSlice<S, L2>) -> Option<cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
 impl<S: PartialEq + Slice<Item = u8> + ?Sized, L: Layout> PartialEq<S> for SmallArcSlice<S, L> {
     fn eq(&self, other: &S) -> bool {
         self.deref() == other
@@ -969,6 +1162,18 @@ impl<L: AnyBufferLayout> From<String> for SmallArcSlice<str, L> {
     }
 }
 
+#[cfg(feature = "oom-handling")]
+impl<S: Slice<Item = u8> + ToOwned<Owned = S::Vec> + ?Sized, L: AnyBufferLayout>
+    From<Cow<'static, S>> for SmallArcSlice<S, L>
+{
+    fn from(value: Cow<'static, S>) -> Self {
+        match SmallSlice::new(&*value) {
+            Some(small) => small.into(),
+            None => ArcSlice::from(value).into(),
+        }
+    }
+}
+
 impl<S: Slice<Item = u8> + ?Sized, L: Layout> From<SmallSlice<S, L>> for SmallArcSlice<S, L> {
     fn from(value: SmallSlice<S, L>) -> Self {
         Self(Inner { small: value })
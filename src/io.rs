@@ -0,0 +1,138 @@
+//! Tokio I/O helpers.
+//!
+//! [`ArcSlice`](crate::ArcSlice) already implements [`AsyncRead`](tokio::io::AsyncRead) by
+//! consuming itself; these helpers cover reading without consuming, and writing slices without
+//! copying them.
+extern crate std;
+
+use core::{
+    future::poll_fn,
+    pin::Pin,
+    slice,
+    task::{ready, Poll},
+};
+use std::io::{self, IoSlice};
+
+use tokio::io::AsyncWrite;
+
+use crate::{
+    layout::{DefaultLayout, Layout},
+    ArcBytes, ArcSliceCursor,
+};
+
+/// Maximum number of slices passed to a single vectored write.
+const MAX_IO_SLICES: usize = 64;
+
+/// A reader over an [`ArcBytes`], which is left untouched.
+///
+/// The read position is tracked separately, so the bytes can be recovered, or re-read, once the
+/// reader is done. This is an alias of [`ArcSliceCursor`], which implements
+/// [`AsyncRead`](tokio::io::AsyncRead) and [`AsyncBufRead`](tokio::io::AsyncBufRead) for bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::{io::ArcBytesCursorRead, ArcBytes};
+/// use futures::executor::block_on;
+/// use tokio::io::AsyncReadExt;
+///
+/// let mut reader = ArcBytesCursorRead::new(<ArcBytes>::from(b"hello world"));
+/// let mut buf = [0; 5];
+/// block_on(reader.read_exact(&mut buf)).unwrap();
+/// assert_eq!(&buf, b"hello");
+/// assert_eq!(reader.position(), 5);
+/// assert_eq!(reader.into_inner(), b"hello world");
+/// ```
+pub type ArcBytesCursorRead<L = DefaultLayout> = ArcSliceCursor<[u8], L>;
+
+/// Writes an entire [`ArcBytes`] to a writer.
+///
+/// The bytes are only borrowed by the returned future, no reference is added to the underlying
+/// buffer.
+///
+/// # Errors
+///
+/// Returns any I/O error encountered when writing, or [`io::ErrorKind::WriteZero`] if the
+/// writer doesn't accept more bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::{io::write_all_arc, ArcBytes};
+/// use futures::executor::block_on;
+///
+/// let bytes = <ArcBytes>::from(b"hello world");
+/// let mut writer = Vec::new();
+/// block_on(write_all_arc(&mut writer, &bytes)).unwrap();
+/// assert_eq!(writer, b"hello world");
+/// ```
+pub async fn write_all_arc<W: AsyncWrite + Unpin + ?Sized, L: Layout>(
+    writer: &mut W,
+    bytes: &ArcBytes<L>,
+) -> io::Result<()> {
+    write_all_arc_vectored(writer, slice::from_ref(bytes)).await
+}
+
+/// Writes a list of [`ArcBytes`] segments to a writer, in order.
+///
+/// Segments are written with vectored writes if the writer
+/// [supports them](AsyncWrite::is_write_vectored). As with [`write_all_arc`], the segments are
+/// only borrowed by the returned future.
+///
+/// # Errors
+///
+/// Returns any I/O error encountered when writing, or [`io::ErrorKind::WriteZero`] if the
+/// writer doesn't accept more bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::{io::write_all_arc_vectored, ArcBytes};
+/// use futures::executor::block_on;
+///
+/// let mut bytes = <ArcBytes>::from(b"hello world");
+/// let hello = bytes.split_to(6);
+/// let mut writer = Vec::new();
+/// block_on(write_all_arc_vectored(&mut writer, &[hello, bytes])).unwrap();
+/// assert_eq!(writer, b"hello world");
+/// ```
+pub async fn write_all_arc_vectored<W: AsyncWrite + Unpin + ?Sized, L: Layout>(
+    writer: &mut W,
+    segments: &[ArcBytes<L>],
+) -> io::Result<()> {
+    let mut segments = segments;
+    let mut offset = 0;
+    poll_fn(|cx| loop {
+        // skip written (and empty) segments
+        while let Some(segment) = segments.first() {
+            if offset < segment.len() {
+                break;
+            }
+            offset -= segment.len();
+            segments = &segments[1..];
+        }
+        let first = match segments.first() {
+            Some(first) => &first[offset..],
+            None => return Poll::Ready(Ok(())),
+        };
+        let writer = Pin::new(&mut *writer);
+        let n = if writer.is_write_vectored() {
+            let mut io_slices = [IoSlice::new(&[]); MAX_IO_SLICES];
+            io_slices[0] = IoSlice::new(first);
+            let others = segments[1..].iter().filter(|segment| !segment.is_empty());
+            let mut count = 1;
+            for (io_slice, segment) in io_slices[1..].iter_mut().zip(others) {
+                *io_slice = IoSlice::new(segment);
+                count += 1;
+            }
+            ready!(writer.poll_write_vectored(cx, &io_slices[..count]))?
+        } else {
+            ready!(writer.poll_write(cx, first))?
+        };
+        if n == 0 {
+            return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+        }
+        offset += n;
+    })
+    .await
+}
@@ -38,6 +38,18 @@
 //! | `VecLayout`        | `4 * size_of::<usize>()` | yes                         | yes                       | yes                  | `Vec<T>`           |
 //! | `RawLayout`        | `4 * size_of::<usize>()` | yes                         | yes                       | no                   | `RawBuffer`        |
 //!
+//! ## Buffer metadata
+//!
+//! Metadata attached to a buffer, e.g. with [`ArcSliceMut::from_buffer_with_metadata`], is stored
+//! alongside the buffer, so it is preserved by every conversion that keeps the buffer, like
+//! [`ArcSliceMut::freeze`] or [`ArcSlice::with_layout`]. Layouts that don't support
+//! arbitrary buffers, i.e. `ArcLayout<false>`, cannot hold metadata; fallible conversions to
+//! them, like [`ArcSliceMut::try_freeze`], return an error instead of dropping it.
+//!
+//! | Source layout                     | `ArcLayout<true>` | `ArcLayout<false>` | `BoxedSliceLayout` | `VecLayout` | `RawLayout` |
+//! |-----------------------------------|-------------------|--------------------|--------------------|-------------|-------------|
+//! | `ArcLayout<true>`/`VecLayout` mut | preserved         | error              | preserved          | preserved   | preserved   |
+//!
 //! [crate feature]: crate#features
 //! [`Arc`]: alloc::sync::Arc
 
@@ -56,8 +68,8 @@ pub trait LayoutMut: Layout + private::LayoutMut {}
 pub trait AnyBufferLayout: Layout {}
 /// A layout that supports static slices without inner Arc allocation.
 ///
-/// It enables [`ArcSlice::new`] and [`ArcSlice::from_static`]. Additionally, empty subslices are
-/// stored as static slices to avoid Arc clone/drop overhead.
+/// It enables [`ArcSlice::from_static`]. Empty slices don't need it: they are never allocated
+/// nor refcounted, whatever the layout.
 pub trait StaticLayout: Layout {}
 /// A layout that supports [`clone`](ArcSlice::clone) without allocating.
 pub trait CloneNoAllocLayout: Layout {}
@@ -68,11 +80,17 @@ pub trait TruncateNoAllocLayout: Layout {}
 ///
 /// It aims to be more performant than other layouts for supported operations,
 /// though other layouts may support a broader range of use cases.
-/// It takes two generic boolean parameters, whose defaults can be overridden via
+/// It takes three generic boolean parameters, whose defaults can be overridden via
 /// [compilation features](crate#features):
 /// - `ANY_BUFFER`, default to false, if it supports arbitrary buffer;
 /// - `STATIC`, default to false, if it supports static slices without allocations; it
-///   enables [`Default`] implementation for [`ArcSlice`], as well as const constructors.
+///   enables [`Default`] implementation for [`ArcSlice`], as well as const constructors;
+/// - `SATURATE`, default to false unless `abort-on-refcount-overflow` feature is disabled, if
+///   the refcount saturates on overflow when cloning, leaking the buffer, instead of aborting.
+///   It allows libraries to choose the overflow behavior of their slices independently of the
+///   crate features. Other layouts don't have this parameter, and always follow the feature;
+///   converting a slice to them keeps sharing its buffer, whose clones then follow the target
+///   layout behavior.
 ///
 /// Other layouts support arbitrary buffers and static slices out of the box, but this flexibility
 /// comes at the cost of additional branching or virtual method call. `ArcLayout` focuses instead
@@ -88,19 +106,32 @@ pub trait TruncateNoAllocLayout: Layout {}
 pub struct ArcLayout<
     const ANY_BUFFER: bool = { cfg!(feature = "default-layout-any-buffer") },
     const STATIC: bool = { cfg!(feature = "default-layout-static") },
+    const SATURATE: bool = { !cfg!(feature = "abort-on-refcount-overflow") },
 >;
-impl<const ANY_BUFFER: bool, const STATIC: bool> Layout for ArcLayout<ANY_BUFFER, STATIC> {}
-impl<const STATIC: bool> AnyBufferLayout for ArcLayout<true, STATIC> {}
-impl<const ANY_BUFFER: bool> StaticLayout for ArcLayout<ANY_BUFFER, true> {}
-impl<const ANY_BUFFER: bool, const STATIC: bool> CloneNoAllocLayout
-    for ArcLayout<ANY_BUFFER, STATIC>
+impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool> Layout
+    for ArcLayout<ANY_BUFFER, STATIC, SATURATE>
+{
+}
+impl<const STATIC: bool, const SATURATE: bool> AnyBufferLayout
+    for ArcLayout<true, STATIC, SATURATE>
+{
+}
+impl<const ANY_BUFFER: bool, const SATURATE: bool> StaticLayout
+    for ArcLayout<ANY_BUFFER, true, SATURATE>
 {
 }
-impl<const ANY_BUFFER: bool, const STATIC: bool> TruncateNoAllocLayout
-    for ArcLayout<ANY_BUFFER, STATIC>
+impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool> CloneNoAllocLayout
+    for ArcLayout<ANY_BUFFER, STATIC, SATURATE>
+{
+}
+impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool> TruncateNoAllocLayout
+    for ArcLayout<ANY_BUFFER, STATIC, SATURATE>
+{
+}
+impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool> LayoutMut
+    for ArcLayout<ANY_BUFFER, STATIC, SATURATE>
 {
 }
-impl<const ANY_BUFFER: bool, const STATIC: bool> LayoutMut for ArcLayout<ANY_BUFFER, STATIC> {}
 
 /// Enables storing a boxed slice into an [`ArcSlice`] without requiring the allocation of an inner
 /// Arc, as long as there is a single instance.
@@ -183,7 +214,10 @@ impl TruncateNoAllocLayout for RawLayout {}
 /// edge case.
 pub trait FromLayout<L: Layout>: Layout {}
 
-impl<const STATIC: bool, L: Layout> FromLayout<ArcLayout<false, STATIC>> for L {}
+impl<const STATIC: bool, const SATURATE: bool, L: Layout>
+    FromLayout<ArcLayout<false, STATIC, SATURATE>> for L
+{
+}
 impl<L1: AnyBufferLayout, L2: AnyBufferLayout> FromLayout<L1> for L2 {}
 
 macro_rules! default_layout {
@@ -222,6 +256,51 @@ cfg_if::cfg_if! {
     }
 }
 
+/// The runtime representation of an [`ArcSlice`]/[`ArcSliceMut`] buffer.
+///
+/// Depending on its layout and on how it was built, a slice may be backed by different kinds
+/// of storage, each with its own cost model, e.g. cloning an inlined vector allocates an Arc
+/// while cloning an Arc only increments its refcount. It is returned by [`ArcSlice::repr`] and
+/// [`ArcSliceMut::repr`], and is mostly intended for debugging and performance analysis.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::{
+///     layout::{ArcLayout, Repr, VecLayout},
+///     ArcBytes,
+/// };
+///
+/// assert_eq!(<ArcBytes>::from(b"hello").repr(), Repr::ArcSlice);
+/// assert_eq!(
+///     ArcBytes::<ArcLayout<true, true>>::new().repr(),
+///     Repr::Static
+/// );
+/// assert_eq!(
+///     ArcBytes::<VecLayout>::from(b"hello".to_vec()).repr(),
+///     Repr::Vec
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Repr {
+    /// A static slice, including empty slices, which requires no allocation.
+    Static,
+    /// A slice allocated together with its Arc, e.g. when copied from another slice.
+    ArcSlice,
+    /// An arbitrary buffer, including vectors and boxed slices, stored in an Arc.
+    ArcBuffer,
+    /// A vector stored without Arc by [`VecLayout`]; it is promoted to an Arc when cloned.
+    Vec,
+    /// A boxed slice stored without Arc by [`BoxedSliceLayout`]; it is promoted to an Arc when
+    /// cloned.
+    BoxedSlice,
+    /// A [`RawBuffer`](crate::buffer::RawBuffer) stored without Arc by [`RawLayout`].
+    Raw,
+    /// A small slice stored inline, see [`SmallArcSlice`](crate::inlined::SmallArcSlice).
+    Inlined,
+}
+
 #[cfg(not(feature = "inlined"))]
 mod private {
     pub use crate::{slice::ArcSliceLayout as Layout, slice_mut::ArcSliceMutLayout as LayoutMut};
@@ -54,12 +54,33 @@
 //! The crate provides the following optional features:
 //! - `abort-on-refcount-overflow` (default): abort on refcount overflow; when disabled,
 //!   the refcount saturates on overflow, leaking allocated memory (as in Linux kernel refcounting).
+//!   [`ArcLayout`](layout::ArcLayout) allows choosing the behavior per slice type with its
+//!   `SATURATE` parameter, this feature only setting its default value; other layouts always
+//!   follow this feature.
+//! - `allocator-api`: implement [`BufferSource`](buffer::BufferSource) for every [`Allocator`],
+//!   allowing to use them with [`ArcSliceMut::with_capacity_in`]; the trait comes from
+//!   [`allocator_api2`], which re-exports the unstable `core::alloc::Allocator` when its `nightly`
+//!   feature is enabled.
+//! - `arbitrary`: implement [`Arbitrary`](::arbitrary::Arbitrary) for [`ArcBytes`] and
+//!   [`ArcBytesMut`], generating bytes with random representations, for fuzzing; also enable
+//!   [`ArcSlice::debug_validate`] and [`ArcSliceMut::debug_validate`] invariant checks.
 //! - `bstr`: implement slice traits for [`bstr`](::bstr) crate, allowing usage of `ArcSlice<BStr>`.
-//! - `bytemuck`: use [`bytemuck::Zeroable`] as a bound for zero-initialization with
-//!   [`ArcSliceMut::zeroed`].
+//! - `bytemuck`: use [`Zeroable`](::bytemuck::Zeroable) as a bound for zero-initialization with
+//!   [`ArcSliceMut::zeroed`], enable casting bytes with [`ArcSlice::cast_slice`] and
+//!   [`ArcSliceMut::cast_slice_mut`], and reading/writing plain data with [`ArcSlice::pod_at`]
+//!   and [`ArcSliceMut::write_pod_at`].
 //! - `bytes`: implement [`Buf`](::bytes::Buf) and [`BufMut`](::bytes::BufMut) traits for
 //!   [`ArcSlice`] and [`ArcSliceMut`].
+//! - `capi`: export a C API for [`ArcBytes`], see [`capi`].
+//! - `codec`: enable hexadecimal and base64 encoding/decoding, see [`codec`].
+//! - `futures`: implement [`AsyncRead`](::futures_io::AsyncRead) and
+//!   [`AsyncBufRead`](::futures_io::AsyncBufRead) traits for [`ArcSlice`].
 //! - `inlined`: enable [Small String Optimization] for [`ArcSlice`] via [`inlined::SmallArcSlice`].
+//! - `loom`: use [`loom`] atomics for refcounts, in order to model-check them with
+//!   `cargo test --features loom --test loom`; loom atomics can only be used inside loom models.
+//! - `memchr`: use [`memchr`] to search for subslices in byte slices.
+//! - `memmap2`: enable memory-mapping files with [`fs::read_with_mmap`] (the [`fs`] module also
+//!   requires `oom-handling`).
 //! - `oom-handling` (default): enable global [out-of-memory handling] with infallible allocation
 //!   methods.
 //! - `portable-atomic`: use [`portable_atomic`] instead of [`core::sync::atomic`].
@@ -67,8 +88,19 @@
 //!   [`alloc::sync::Arc`].
 //! - `raw-buffer`: enable [`RawBuffer`](buffer::RawBuffer) and [`RawLayout`](layout::RawLayout).
 //! - `serde`: implement [`Serialize`](::serde::Serialize) and [`Deserialize`](::serde::Deserialize)
-//!   for [`ArcSlice`] and [`ArcSliceMut`].
-//! - `std`: enable various `std` trait implementations and link to the standard library crate.
+//!   for [`ArcSlice`] and [`ArcSliceMut`], and enable [`serde`](mod@serde) helpers.
+//! - `std`: enable various `std` trait implementations, and link to the standard library crate;
+//!   together with `oom-handling`, it enables [`fs`] helpers. On Unix, it also allows usage of
+//!   `ArcSlice<OsStr>` and `ArcSlice<Path>`.
+//! - `tokio`: implement [`AsyncRead`](::tokio::io::AsyncRead) and
+//!   [`AsyncBufRead`](::tokio::io::AsyncBufRead) traits for [`ArcSlice`], and
+//!   [`AsyncWrite`](::tokio::io::AsyncWrite) for [`ArcSliceMut`], and enable [`io`] helpers.
+//! - `zeroize`: implement [`Zeroize`](::zeroize::Zeroize) for [`ArcSliceMut`], and enable
+//!   [`ArcSlice::try_zeroize`].
+//!
+//! On nightly Rust, building with `--cfg read_buf` rustc flag, together with `std` feature,
+//! enables `ArcSliceMut::read_buf_from`, reading into the uninitialized spare capacity with
+//! `std::io::Read::read_buf`.
 //!
 //! Additionally, the default [layout] can be overridden with these features:
 //! - `default-layout-any-buffer`: set [`ArcLayout`] `ANY_BUFFER` to `true`.
@@ -87,37 +119,69 @@
 //! [Small String Optimization]: https://cppdepend.com/blog/understanding-small-string-optimization-sso-in-stdstring/
 //! [out-of-memory handling]: alloc::alloc::handle_alloc_error
 //! [`ArcLayout`]: layout::ArcLayout
+//! [`Allocator`]: allocator_api2::alloc::Allocator
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(
+    all(read_buf, feature = "std"),
+    feature(read_buf, core_io_borrowed_buf)
+)]
 #![no_std]
 extern crate alloc;
 
 #[doc(hidden)]
 pub mod __private;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod arc;
 mod atomic;
 #[cfg(feature = "bstr")]
 mod bstr;
 pub mod buffer;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 #[cfg(feature = "bytes")]
 mod bytes;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "codec")]
+pub mod codec;
+mod erased;
 pub mod error;
+pub mod frame;
+#[cfg(all(feature = "std", feature = "oom-handling"))]
+pub mod fs;
+#[cfg(feature = "futures")]
+mod futures;
 #[cfg(feature = "inlined")]
 pub mod inlined;
+#[cfg(feature = "tokio")]
+pub mod io;
 pub mod layout;
+pub mod local;
 mod macros;
 mod msrv;
+#[cfg(all(feature = "std", unix))]
+mod path;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
 mod slice;
 mod slice_mut;
+#[cfg(feature = "tokio")]
+mod tokio;
 mod utils;
 mod vtable;
+#[cfg(feature = "zeroize")]
+mod zeroize;
 
 pub use crate::{
-    slice::{ArcSlice, ArcSliceBorrow},
-    slice_mut::ArcSliceMut,
+    erased::ErasedArcBytes,
+    slice::{
+        ArcSlice, ArcSliceBorrow, ArcSliceCursor, ArcSliceRawParts, ArcSliceWeak, BorrowChunks,
+        IntoIter, IntoIterArc, SplitBytes,
+    },
+    slice_mut::{ArcSliceMut, ArcSliceMutRawParts, SpareCapacityChunksMut},
 };
 
 /// An alias for `ArcSlice<[u8], L>`.
@@ -134,3 +198,30 @@ pub type ArcStrBorrow<'a, L = layout::DefaultLayout> = ArcSliceBorrow<'a, str, L
 /// An alias for `ArcSliceMut<str, L>`.
 pub type ArcStrMut<L = layout::DefaultLayoutMut, const UNIQUE: bool = true> =
     ArcSliceMut<str, L, UNIQUE>;
+
+/// Creates an [`ArcStrMut`] using interpolation of runtime expressions.
+///
+/// This is the `no_std` counterpart of [`format!`](alloc::format), see
+/// [`ArcSliceMut::from_fmt`]. The default layout is used, unless the expected type is
+/// specified with a layout, e.g. `arc_format!(ArcStrMut<VecLayout>; "{x}")`.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::{arc_format, layout::VecLayout, ArcStrMut};
+///
+/// let s = arc_format!("{}-{}", 4, 2);
+/// assert_eq!(s, "4-2");
+/// let s = arc_format!(ArcStrMut<VecLayout>; "{}-{}", 4, 2);
+/// assert_eq!(s, "4-2");
+/// ```
+#[cfg(feature = "oom-handling")]
+#[macro_export]
+macro_rules! arc_format {
+    ($ty:ty; $($arg:tt)*) => {
+        <$ty>::from_fmt(::core::format_args!($($arg)*))
+    };
+    ($($arg:tt)*) => {
+        $crate::ArcStrMut::<$crate::layout::DefaultLayoutMut>::from_fmt(::core::format_args!($($arg)*))
+    };
+}
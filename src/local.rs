@@ -0,0 +1,304 @@
+//! Thread-local shared slices.
+//!
+//! [`ArcSlice`] requires its items to be `Send + Sync`, as it can be shared between threads.
+//! [`LocalArcSlice`] lifts this requirement for slices which never leave the thread that created
+//! them, e.g. a map of [`Cell`](core::cell::Cell) in GUI or game code.
+use alloc::vec::Vec;
+#[cfg(feature = "oom-handling")]
+use core::convert::Infallible;
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, RangeBounds},
+    slice,
+};
+
+#[cfg(not(feature = "oom-handling"))]
+use crate::layout::CloneNoAllocLayout;
+#[cfg(feature = "oom-handling")]
+use crate::utils::UnwrapInfallible;
+use crate::{
+    error::AllocError,
+    layout::{AnyBufferLayout, ArcLayout, Layout},
+    ArcSlice,
+};
+
+/// An item wrapper asserting it is never accessed from another thread.
+///
+/// It is only used by [`LocalArcSlice`], which is `!Send` and `!Sync`; items can thus only be
+/// accessed, and dropped, on the thread that has created the slice.
+#[repr(transparent)]
+struct AssertThreadSafe<T>(T);
+
+unsafe impl<T> Send for AssertThreadSafe<T> {}
+unsafe impl<T> Sync for AssertThreadSafe<T> {}
+
+fn wrap_vec<T>(vec: Vec<T>) -> Vec<AssertThreadSafe<T>> {
+    let mut vec = ManuallyDrop::new(vec);
+    // SAFETY: `AssertThreadSafe<T>` is `repr(transparent)`, so it has the same layout as `T`
+    unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+}
+
+fn unwrap_vec<T>(vec: Vec<AssertThreadSafe<T>>) -> Vec<T> {
+    let mut vec = ManuallyDrop::new(vec);
+    // SAFETY: `AssertThreadSafe<T>` is `repr(transparent)`, so it has the same layout as `T`
+    unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+}
+
+/// A thread-local [`ArcSlice`], supporting items which are not `Send + Sync`.
+///
+/// Contrary to [`ArcSlice`], `LocalArcSlice` is neither `Send` nor `Sync`: all its clones stay
+/// on the thread that created it, so its items can safely be [`Cell`](core::cell::Cell) or
+/// [`Rc`](alloc::rc::Rc). The refcount is still atomic, as it is the one of the underlying
+/// [`ArcSlice`].
+///
+/// Items are moved into the slice from a vector, so the layout must support arbitrary buffers;
+/// the default one is thus `ArcLayout<true>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::cell::Cell;
+///
+/// use arc_slice::local::LocalArcSlice;
+///
+/// let tiles = LocalArcSlice::<_>::from(vec![Cell::new(0), Cell::new(1)]);
+/// let tiles2 = tiles.clone();
+/// tiles2[0].set(42);
+/// assert_eq!(tiles[0].get(), 42);
+/// ```
+///
+/// It cannot be sent to, or shared with, another thread:
+///
+/// ```rust,compile_fail
+/// use std::cell::Cell;
+///
+/// use arc_slice::local::LocalArcSlice;
+///
+/// let tiles = LocalArcSlice::<_>::from(vec![Cell::new(0)]);
+/// std::thread::spawn(move || tiles[0].set(42));
+/// ```
+///
+/// ```rust,compile_fail
+/// use arc_slice::local::LocalArcSlice;
+///
+/// fn assert_sync<T: Sync>(_: &T) {}
+/// assert_sync(&LocalArcSlice::<_>::from(vec![0u8]));
+/// ```
+pub struct LocalArcSlice<T: 'static, L: Layout = ArcLayout<true>> {
+    slice: ArcSlice<[AssertThreadSafe<T>], L>,
+    _not_send_sync: PhantomData<*const ()>,
+}
+
+impl<T: 'static, L: Layout> LocalArcSlice<T, L> {
+    fn new(slice: ArcSlice<[AssertThreadSafe<T>], L>) -> Self {
+        Self {
+            slice,
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Returns the underlying slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::local::LocalArcSlice;
+    ///
+    /// let s = LocalArcSlice::<u8>::from(vec![0, 1, 2]);
+    /// assert_eq!(s.as_slice(), [0, 1, 2]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        let slice = self.slice.as_slice();
+        // SAFETY: `AssertThreadSafe<T>` is `repr(transparent)`, so it has the same layout as `T`
+        unsafe { slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+
+    /// Tries cloning the `LocalArcSlice`, returning an error if an allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::local::LocalArcSlice;
+    ///
+    /// let s = LocalArcSlice::<u8>::from(vec![0, 1, 2]);
+    /// assert_eq!(s.try_clone().unwrap(), [0, 1, 2]);
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, AllocError> {
+        Ok(Self::new(self.slice.try_clone()?))
+    }
+
+    /// Tries extracting a subslice of a `LocalArcSlice` with a given range, returning an error if
+    /// an allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::local::LocalArcSlice;
+    ///
+    /// let s = LocalArcSlice::<u8>::from(vec![0, 1, 2]);
+    /// assert_eq!(s.try_subslice(1..).unwrap(), [1, 2]);
+    /// ```
+    #[track_caller]
+    pub fn try_subslice(&self, range: impl RangeBounds<usize>) -> Result<Self, AllocError> {
+        Ok(Self::new(self.slice.try_subslice(range)?))
+    }
+
+    /// Returns `true` if this is the only reference to the underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::local::LocalArcSlice;
+    ///
+    /// let s = LocalArcSlice::<u8>::from(vec![0, 1, 2]);
+    /// assert!(s.is_unique());
+    /// let s2 = s.clone();
+    /// assert!(!s.is_unique());
+    /// ```
+    pub fn is_unique(&self) -> bool {
+        self.slice.is_unique()
+    }
+
+    /// Tries converting the `LocalArcSlice` back into a vector, returning it if it is not unique,
+    /// or not backed by a complete vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cell::Cell;
+    ///
+    /// use arc_slice::{layout::VecLayout, local::LocalArcSlice};
+    ///
+    /// let s = LocalArcSlice::<_, VecLayout>::from(vec![Cell::new(0)]);
+    /// let s2 = s.clone();
+    /// let s = s.try_into_vec().unwrap_err();
+    /// drop(s2);
+    /// assert_eq!(s.try_into_vec().unwrap(), [Cell::new(0)]);
+    /// ```
+    pub fn try_into_vec(self) -> Result<Vec<T>, Self> {
+        self.slice
+            .try_into_buffer::<Vec<AssertThreadSafe<T>>>()
+            .map(unwrap_vec)
+            .map_err(Self::new)
+    }
+}
+
+impl<T: 'static, L: AnyBufferLayout> LocalArcSlice<T, L> {
+    /// Creates a new `LocalArcSlice` from a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use arc_slice::local::LocalArcSlice;
+    ///
+    /// let s = LocalArcSlice::<Rc<u8>>::from_vec(vec![Rc::new(0)]);
+    /// assert_eq!(*s[0], 0);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        Self::new(ArcSlice::from_vec_impl::<Infallible>(wrap_vec(vec)).unwrap_infallible())
+    }
+
+    /// Tries creating a new `LocalArcSlice` from a vector, returning it if an allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use arc_slice::local::LocalArcSlice;
+    ///
+    /// let s = LocalArcSlice::<Rc<u8>>::try_from_vec(vec![Rc::new(0)]).unwrap();
+    /// assert_eq!(*s[0], 0);
+    /// ```
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        match ArcSlice::from_vec_impl::<AllocError>(wrap_vec(vec)) {
+            Ok(slice) => Ok(Self::new(slice)),
+            Err((_, vec)) => Err(unwrap_vec(vec)),
+        }
+    }
+}
+
+impl<
+        T: 'static,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > LocalArcSlice<T, L>
+{
+    /// Extracts a subslice of a `LocalArcSlice` with a given range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::local::LocalArcSlice;
+    ///
+    /// let s = LocalArcSlice::<u8>::from(vec![0, 1, 2]);
+    /// assert_eq!(s.subslice(1..), [1, 2]);
+    /// ```
+    #[track_caller]
+    pub fn subslice(&self, range: impl RangeBounds<usize>) -> Self {
+        Self::new(self.slice.subslice(range))
+    }
+}
+
+impl<
+        T: 'static,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > Clone for LocalArcSlice<T, L>
+{
+    fn clone(&self) -> Self {
+        Self::new(self.slice.clone())
+    }
+}
+
+impl<T: 'static, L: Layout> Deref for LocalArcSlice<T, L> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: 'static, L: Layout> AsRef<[T]> for LocalArcSlice<T, L> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: fmt::Debug + 'static, L: Layout> fmt::Debug for LocalArcSlice<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: PartialEq + 'static, L: Layout> PartialEq for LocalArcSlice<T, L> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq + 'static, L: Layout> Eq for LocalArcSlice<T, L> {}
+
+impl<T: PartialEq + 'static, L: Layout> PartialEq<[T]> for LocalArcSlice<T, L> {
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq + 'static, L: Layout, const N: usize> PartialEq<[T; N]> for LocalArcSlice<T, L> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        **self == *other
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<T: 'static, L: AnyBufferLayout> From<Vec<T>> for LocalArcSlice<T, L> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_vec(value)
+    }
+}
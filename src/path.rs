@@ -0,0 +1,168 @@
+//! [`Slice`] implementations for [`OsStr`] and [`Path`].
+//!
+//! Only Unix is supported, as the item slice is the raw byte representation of the string
+//! returned by [`OsStrExt::as_bytes`]; on other platforms, the encoding is not exposed.
+//!
+//! Subslicing is not supported, as there is no meaningful boundary check for these slices.
+//! Mutable slices are not supported either, as std doesn't expose mutable access to the raw
+//! bytes: [`SliceMut`](crate::buffer::SliceMut) is not implemented, so
+//! [`ArcSliceMut`](crate::ArcSliceMut) cannot be used with these slices.
+//!
+//! ```rust,compile_fail
+//! use std::path::{Path, PathBuf};
+//!
+//! use arc_slice::{layout::ArcLayout, ArcSliceMut};
+//!
+//! let s = ArcSliceMut::<Path, ArcLayout<true>>::from_buffer(PathBuf::from("a/b"));
+//! ```
+extern crate std;
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{convert::Infallible, mem};
+use std::{
+    ffi::{OsStr, OsString},
+    os::unix::ffi::{OsStrExt, OsStringExt},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    buffer::{Buffer, BufferMut, Emptyable, Slice},
+    error::TryReserveError,
+};
+
+fn try_reserve(
+    len: usize,
+    res: Result<(), std::collections::TryReserveError>,
+    additional: usize,
+) -> Result<(), TryReserveError> {
+    let requested = |len| (len as isize).checked_add(additional.try_into().ok()?);
+    match res {
+        Ok(()) => Ok(()),
+        Err(_) if requested(len).is_none() => Err(TryReserveError::CapacityOverflow),
+        Err(_) => Err(TryReserveError::AllocError),
+    }
+}
+
+unsafe impl Slice for OsStr {
+    type Item = u8;
+    type Vec = OsString;
+
+    fn to_slice(&self) -> &[Self::Item] {
+        self.as_bytes()
+    }
+    fn into_boxed_slice(self: Box<Self>) -> Box<[Self::Item]> {
+        self.into_os_string().into_vec().into_boxed_slice()
+    }
+    fn into_vec(vec: Self::Vec) -> Vec<Self::Item> {
+        vec.into_vec()
+    }
+
+    unsafe fn from_slice_unchecked(slice: &[Self::Item]) -> &Self {
+        OsStr::from_bytes(slice)
+    }
+    unsafe fn from_boxed_slice_unchecked(boxed: Box<[Self::Item]>) -> Box<Self> {
+        OsString::from_vec(boxed.into_vec()).into_boxed_os_str()
+    }
+    unsafe fn from_vec_unchecked(vec: Vec<Self::Item>) -> Self::Vec {
+        OsString::from_vec(vec)
+    }
+
+    type TryFromSliceError = Infallible;
+    fn try_from_slice(slice: &[Self::Item]) -> Result<&Self, Self::TryFromSliceError> {
+        Ok(OsStr::from_bytes(slice))
+    }
+}
+
+unsafe impl Emptyable for OsStr {}
+
+impl Buffer<OsStr> for OsString {
+    fn as_slice(&self) -> &OsStr {
+        self
+    }
+}
+
+unsafe impl BufferMut<OsStr> for OsString {
+    fn as_mut_slice(&mut self) -> &mut OsStr {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    unsafe fn set_len(&mut self, len: usize) -> bool {
+        // the heap buffer is not moved by the round trip through `Vec`
+        let mut vec = mem::take(self).into_vec();
+        unsafe { vec.set_len(len) };
+        *self = OsString::from_vec(vec);
+        true
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        try_reserve(self.len(), self.try_reserve(additional), additional)
+    }
+}
+
+unsafe impl Slice for Path {
+    type Item = u8;
+    type Vec = PathBuf;
+
+    fn to_slice(&self) -> &[Self::Item] {
+        self.as_os_str().as_bytes()
+    }
+    fn into_boxed_slice(self: Box<Self>) -> Box<[Self::Item]> {
+        self.into_path_buf()
+            .into_os_string()
+            .into_vec()
+            .into_boxed_slice()
+    }
+    fn into_vec(vec: Self::Vec) -> Vec<Self::Item> {
+        vec.into_os_string().into_vec()
+    }
+
+    unsafe fn from_slice_unchecked(slice: &[Self::Item]) -> &Self {
+        Path::new(OsStr::from_bytes(slice))
+    }
+    unsafe fn from_boxed_slice_unchecked(boxed: Box<[Self::Item]>) -> Box<Self> {
+        PathBuf::from(OsString::from_vec(boxed.into_vec())).into_boxed_path()
+    }
+    unsafe fn from_vec_unchecked(vec: Vec<Self::Item>) -> Self::Vec {
+        OsString::from_vec(vec).into()
+    }
+
+    type TryFromSliceError = Infallible;
+    fn try_from_slice(slice: &[Self::Item]) -> Result<&Self, Self::TryFromSliceError> {
+        Ok(Path::new(OsStr::from_bytes(slice)))
+    }
+}
+
+unsafe impl Emptyable for Path {}
+
+impl Buffer<Path> for PathBuf {
+    fn as_slice(&self) -> &Path {
+        self
+    }
+}
+
+unsafe impl BufferMut<Path> for PathBuf {
+    fn as_mut_slice(&mut self) -> &mut Path {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    unsafe fn set_len(&mut self, len: usize) -> bool {
+        // the heap buffer is not moved by the round trip through `Vec`
+        let mut vec = mem::take(self).into_os_string().into_vec();
+        unsafe { vec.set_len(len) };
+        *self = OsString::from_vec(vec).into();
+        true
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let len = self.as_os_str().len();
+        try_reserve(len, self.try_reserve(additional), additional)
+    }
+}
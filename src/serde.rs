@@ -1,10 +1,14 @@
+//! Helpers for the [`serde`] crate.
+//!
+//! Byte slices are serialized with [`Serializer::serialize_bytes`], and string slices with
+//! [`Serializer::serialize_str`], borrowing the slice without copy.
 use alloc::{string::String, vec::Vec};
 use core::{cmp, fmt, marker::PhantomData, ops::Deref};
 
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    buffer::{Deserializable, Slice},
+    buffer::{Deserializable, Slice, SliceMut},
     layout::{ArcLayout, Layout, LayoutMut},
     utils::try_as_bytes,
     ArcSlice, ArcSliceMut,
@@ -31,7 +35,7 @@ impl<S: Serialize + Slice + ?Sized, L: Layout> Serialize for ArcSlice<S, L> {
     }
 }
 
-impl<S: Serialize + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Serialize
+impl<S: Serialize + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Serialize
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
@@ -42,13 +46,13 @@ impl<S: Serialize + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Serialize
     }
 }
 
-trait IntoArcSlice<S: Slice + ?Sized> {
+trait IntoArcSlice<S: SliceMut + ?Sized> {
     fn from_slice(slice: &S) -> Self;
     fn from_vec(vec: S::Vec) -> Self;
     fn from_arc_slice_mut(slice: ArcSliceMut<S, ArcLayout<false, false>>) -> Self;
 }
 
-impl<S: Slice + ?Sized, L: Layout> IntoArcSlice<S> for ArcSlice<S, L> {
+impl<S: SliceMut + ?Sized, L: Layout> IntoArcSlice<S> for ArcSlice<S, L> {
     fn from_slice(slice: &S) -> Self {
         ArcSlice::new_bytes(slice)
     }
@@ -60,7 +64,7 @@ impl<S: Slice + ?Sized, L: Layout> IntoArcSlice<S> for ArcSlice<S, L> {
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut> IntoArcSlice<S> for ArcSliceMut<S, L> {
+impl<S: SliceMut + ?Sized, L: LayoutMut> IntoArcSlice<S> for ArcSliceMut<S, L> {
     fn from_slice(slice: &S) -> Self {
         ArcSliceMut::new_bytes(slice)
     }
@@ -72,9 +76,9 @@ impl<S: Slice + ?Sized, L: LayoutMut> IntoArcSlice<S> for ArcSliceMut<S, L> {
     }
 }
 
-struct ArcSliceVisitor<S: Slice + ?Sized, T>(PhantomData<(S::Vec, T)>);
+struct ArcSliceVisitor<S: SliceMut + ?Sized, T>(PhantomData<(S::Vec, T)>);
 
-impl<'de, S: Slice + Deserializable + ?Sized, T: IntoArcSlice<S>> de::Visitor<'de>
+impl<'de, S: SliceMut + Deserializable + ?Sized, T: IntoArcSlice<S>> de::Visitor<'de>
     for ArcSliceVisitor<S, T>
 where
     S::Item: for<'a> Deserialize<'a>,
@@ -136,7 +140,10 @@ where
     }
 }
 
-impl<'de, S: Slice + Deserializable + ?Sized, L: Layout> Deserialize<'de> for ArcSlice<S, L>
+/// Byte slices are deserialized with [`Deserializer::deserialize_byte_buf`]. If the deserializer
+/// yields an owned buffer, it is reused without copy when the layout supports arbitrary buffers,
+/// e.g. [`VecLayout`](crate::layout::VecLayout).
+impl<'de, S: SliceMut + Deserializable + ?Sized, L: Layout> Deserialize<'de> for ArcSlice<S, L>
 where
     S::Item: for<'a> Deserialize<'a>,
     S::TryFromSliceError: fmt::Display,
@@ -149,7 +156,8 @@ where
     }
 }
 
-impl<'de, S: Slice + Deserializable + ?Sized, L: LayoutMut> Deserialize<'de> for ArcSliceMut<S, L>
+impl<'de, S: SliceMut + Deserializable + ?Sized, L: LayoutMut> Deserialize<'de>
+    for ArcSliceMut<S, L>
 where
     S::Item: for<'a> Deserialize<'a>,
     S::TryFromSliceError: fmt::Display,
@@ -175,7 +183,7 @@ const _: () = {
         }
     }
 
-    impl<S: Slice<Item = u8> + ?Sized, L: Layout> IntoArcSlice<S> for SmallArcSlice<S, L> {
+    impl<S: SliceMut<Item = u8> + ?Sized, L: Layout> IntoArcSlice<S> for SmallArcSlice<S, L> {
         fn from_slice(slice: &S) -> Self {
             SmallArcSlice::from_slice(slice)
         }
@@ -187,7 +195,7 @@ const _: () = {
         }
     }
 
-    impl<'de, S: Slice<Item = u8> + Deserializable + ?Sized, L: LayoutMut> Deserialize<'de>
+    impl<'de, S: SliceMut<Item = u8> + Deserializable + ?Sized, L: LayoutMut> Deserialize<'de>
         for SmallArcSlice<S, L>
     where
         S::TryFromSliceError: fmt::Display,
@@ -200,3 +208,100 @@ const _: () = {
         }
     }
 };
+
+/// Serialize bytes as a lowercase hexadecimal string for human-readable formats.
+///
+/// It is meant to be used with `#[serde(with = "arc_slice::serde::hex")]`. Non human-readable
+/// formats keep serializing raw bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::ArcBytes;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "arc_slice::serde::hex")]
+///     payload: ArcBytes,
+/// }
+///
+/// let message = Message {
+///     payload: ArcBytes::from(b"\x01\xab"),
+/// };
+/// let json = serde_json::to_string(&message).unwrap();
+/// assert_eq!(json, r#"{"payload":"01ab"}"#);
+/// let message: Message = serde_json::from_str(&json).unwrap();
+/// assert_eq!(message.payload, [0x01, 0xab]);
+/// ```
+pub mod hex {
+    use alloc::vec::Vec;
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{
+        de::{self, value::BytesDeserializer},
+        Deserialize, Deserializer, Serializer,
+    };
+
+    use crate::utils::lower_hex;
+
+    struct Hex<'a>(&'a [u8]);
+
+    impl fmt::Display for Hex<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            lower_hex(self.0, f)
+        }
+    }
+
+    /// Serializes bytes as a hexadecimal string if the format is human-readable, or as raw bytes
+    /// otherwise.
+    pub fn serialize<T: AsRef<[u8]> + ?Sized, S: Serializer>(
+        bytes: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Hex(bytes.as_ref()))
+        } else {
+            serializer.serialize_bytes(bytes.as_ref())
+        }
+    }
+
+    struct HexVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> de::Visitor<'de> for HexVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a hexadecimal string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            fn digit(c: u8) -> Option<u8> {
+                (c as char).to_digit(16).map(|d| d as u8)
+            }
+            if v.len() % 2 != 0 {
+                return Err(E::invalid_length(v.len(), &"an even number of digits"));
+            }
+            let bytes = (v.as_bytes().chunks_exact(2))
+                .map(|pair| Some(digit(pair[0])? << 4 | digit(pair[1])?))
+                .collect::<Option<Vec<u8>>>()
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))?;
+            T::deserialize(BytesDeserializer::new(&bytes))
+        }
+    }
+
+    /// Deserializes bytes from a hexadecimal string if the format is human-readable, or from raw
+    /// bytes otherwise.
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor(PhantomData))
+        } else {
+            T::deserialize(deserializer)
+        }
+    }
+}
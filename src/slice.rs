@@ -1,4 +1,9 @@
-use alloc::{boxed::Box, string::String, vec::Vec};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::String,
+    vec::Vec,
+};
 use core::{
     any::Any,
     borrow::Borrow,
@@ -6,6 +11,7 @@ use core::{
     convert::Infallible,
     fmt,
     hash::{Hash, Hasher},
+    iter::FusedIterator,
     marker::PhantomData,
     mem,
     mem::{ManuallyDrop, MaybeUninit},
@@ -16,24 +22,28 @@ use core::{
 #[cfg(feature = "raw-buffer")]
 use crate::buffer::RawBuffer;
 #[cfg(not(feature = "oom-handling"))]
-use crate::layout::{
-    ArcLayout, BoxedSliceLayout, CloneNoAllocLayout, TruncateNoAllocLayout, VecLayout,
-};
+use crate::layout::{BoxedSliceLayout, CloneNoAllocLayout, TruncateNoAllocLayout};
 #[allow(unused_imports)]
 use crate::msrv::{ptr, ConstPtrExt, NonNullExt, StrictProvenance};
 use crate::{
     arc::Arc,
     buffer::{
-        BorrowMetadata, Buffer, BufferExt, BufferMut, BufferWithMetadata, DynBuffer, Emptyable,
-        Slice, SliceExt, Subsliceable,
+        BorrowMetadata, Buffer, BufferExt, BufferMut, BufferSource, BufferWithMetadata,
+        Concatenable, DynBuffer, Emptyable, MetadataChain, Slice, SliceExt, SliceMut, Subsliceable,
+    },
+    error::{AllocError, AllocErrorImpl, CharBoundaryError, TryGetError, TryReserveError},
+    layout::{
+        AnyBufferLayout, ArcLayout, DefaultLayout, FromLayout, Layout, LayoutMut, Repr,
+        StaticLayout, VecLayout,
     },
-    error::{AllocError, AllocErrorImpl},
-    layout::{AnyBufferLayout, DefaultLayout, FromLayout, Layout, LayoutMut, StaticLayout},
     macros::is,
     slice_mut::{ArcSliceMutLayout, Data},
     utils::{
-        debug_slice, lower_hex, panic_out_of_range, range_offset_len, subslice_offset_len,
-        transmute_checked, try_transmute, upper_hex, UnwrapChecked, UnwrapInfallible,
+        ceil_char_boundary, concat_len, ct_eq, debug_slice, find, floor_char_boundary, lower_hex,
+        panic_advance_back_out_of_range, panic_end_out_of_range, panic_start_out_of_range,
+        range_offset_len, sign_extend, subslice_offset_len, transmute_checked, trim_ascii_end,
+        trim_ascii_start, try_char_boundary, try_transmute, upper_hex, UnwrapChecked,
+        UnwrapInfallible,
     },
     ArcSliceMut,
 };
@@ -48,6 +58,8 @@ pub unsafe trait ArcSliceLayout: 'static {
     type Data;
     const DATA_COPY: bool;
     const ANY_BUFFER: bool;
+    // data of empty slices, neither allocated nor refcounted
+    const EMPTY_DATA: Self::Data;
     const STATIC_DATA: Option<Self::Data>;
     // MSRV 1.83 const `Option::unwrap`
     const STATIC_DATA_UNCHECKED: MaybeUninit<Self::Data>;
@@ -86,6 +98,13 @@ pub unsafe trait ArcSliceLayout: 'static {
         length: usize,
         data: &Self::Data,
     ) -> Result<Self::Data, E>;
+    // clones the data `count > 0` times at once, all clones sharing the returned data
+    fn clone_many<S: Slice + ?Sized, E: AllocErrorImpl>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &Self::Data,
+        count: usize,
+    ) -> Result<Self::Data, E>;
     unsafe fn drop<S: Slice + ?Sized, const UNIQUE_HINT: bool>(
         start: NonNull<S::Item>,
         length: usize,
@@ -97,6 +116,12 @@ pub unsafe trait ArcSliceLayout: 'static {
     fn clone_borrowed_data<S: Slice + ?Sized>(_ptr: *const ()) -> Option<Self::Data> {
         None
     }
+    fn downgrade<S: Slice + ?Sized>(_data: &Self::Data) -> Option<NonNull<()>> {
+        None
+    }
+    unsafe fn upgrade<S: Slice + ?Sized>(_ptr: NonNull<()>) -> Option<Self::Data> {
+        None
+    }
     fn truncate<S: Slice + ?Sized, E: AllocErrorImpl>(
         _start: NonNull<S::Item>,
         _length: usize,
@@ -105,12 +130,21 @@ pub unsafe trait ArcSliceLayout: 'static {
         Ok(())
     }
     fn is_unique<S: Slice + ?Sized>(data: &Self::Data) -> bool;
+    fn buffer_capacity<S: Slice + ?Sized>(data: &Self::Data) -> Option<usize>;
     fn get_metadata<S: Slice + ?Sized, M: Any>(data: &Self::Data) -> Option<&M>;
+    fn repr<S: Slice + ?Sized>(data: &Self::Data) -> Repr;
+    #[cfg(feature = "arbitrary")]
+    fn validate<S: Slice + ?Sized>(_start: NonNull<S::Item>, _length: usize, _data: &Self::Data) {}
     unsafe fn take_buffer<S: Slice + ?Sized, B: Buffer<S>>(
         start: NonNull<S::Item>,
         length: usize,
         data: &mut ManuallyDrop<Self::Data>,
     ) -> Option<B>;
+    unsafe fn take_buffer_with_metadata<S: Slice + ?Sized, B: Buffer<S>, M: Any>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &mut ManuallyDrop<Self::Data>,
+    ) -> Option<(B, M)>;
     unsafe fn take_array<T: Send + Sync + 'static, const N: usize>(
         start: NonNull<T>,
         length: usize,
@@ -143,6 +177,13 @@ pub unsafe trait ArcSliceLayout: 'static {
 /// actually handle any type of slices, from strings with specific invariants to primitive slices
 /// with droppable items.
 ///
+/// [`ArcStr`] can be compared to byte slices, and [`ArcBytes`] to strings, comparing the string
+/// as its UTF-8 bytes.
+///
+/// `ArcSlice` is guaranteed to have a niche, whatever the layout and the enabled features, i.e.
+/// `size_of::<Option<ArcSlice<S, L>>>() == size_of::<ArcSlice<S, L>>()`. On the other hand,
+/// [`SmallArcSlice`](crate::inlined::SmallArcSlice) has no niche.
+///
 /// # Examples
 ///
 /// ```rust
@@ -207,8 +248,29 @@ pub struct ArcSlice<S: Slice + ?Sized, L: Layout = DefaultLayout> {
     pub(crate) length: usize,
 }
 
+// The slice start is a `NonNull` in every field ordering, which provides the documented niche.
+macro_rules! assert_niche {
+    ($($layout:ty),* $(,)?) => {$(
+        const _: () = assert!(
+            mem::size_of::<Option<ArcSlice<[u8], $layout>>>()
+                == mem::size_of::<ArcSlice<[u8], $layout>>()
+        );
+    )*};
+}
+assert_niche!(
+    crate::layout::ArcLayout<false, false>,
+    crate::layout::ArcLayout<false, true>,
+    crate::layout::ArcLayout<true, false>,
+    crate::layout::ArcLayout<true, true>,
+    crate::layout::BoxedSliceLayout,
+    crate::layout::VecLayout,
+);
+#[cfg(feature = "raw-buffer")]
+assert_niche!(crate::layout::RawLayout);
+
 unsafe impl<S: Slice + ?Sized, L: Layout> Send for ArcSlice<S, L> {}
 unsafe impl<S: Slice + ?Sized, L: Layout> Sync for ArcSlice<S, L> {}
+impl<S: Slice + ?Sized, L: Layout> Unpin for ArcSlice<S, L> {}
 
 impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     pub(crate) const fn init(
@@ -225,23 +287,23 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
 
     /// Creates a new empty `ArcSlice`.
     ///
-    /// This operation doesn't allocate; it is roughly equivalent to `ArcSlice::from_static(&[])`.
+    /// This operation doesn't allocate, and is available for every layout, even those without
+    /// static data; the returned slice is not refcounted, so cloning and dropping it is free.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use arc_slice::{layout::ArcLayout, ArcSlice};
     ///
-    /// let s = ArcSlice::<[u8], ArcLayout<true, true>>::new();
+    /// let s = ArcSlice::<[u8], ArcLayout<false, false>>::new();
     /// assert_eq!(s, []);
+    /// assert_eq!(s.clone(), []);
     /// ```
     pub const fn new() -> Self
     where
         S: Emptyable,
-        L: StaticLayout,
     {
-        let data = unsafe { L::STATIC_DATA_UNCHECKED.assume_init() };
-        Self::init(NonNull::dangling(), 0, data)
+        Self::init(NonNull::dangling(), 0, L::EMPTY_DATA)
     }
 
     fn from_slice_impl<E: AllocErrorImpl>(slice: &S) -> Result<Self, E>
@@ -299,6 +361,144 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         Self::from_slice_impl::<AllocError>(slice)
     }
 
+    fn from_slices_impl<E: AllocErrorImpl>(parts: &[&S]) -> Result<Self, E>
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        let length = concat_len(parts);
+        if let Some(empty) = ArcSlice::new_empty(NonNull::dangling(), length) {
+            return Ok(empty);
+        }
+        let (arc, start) = Arc::<S, false>::new_concat(parts)?;
+        Ok(Self::init(start, length, L::data_from_arc_slice(arc)))
+    }
+
+    /// Creates a new `ArcSlice` by concatenating the given slices.
+    ///
+    /// The total length is computed first, so there is a single allocation, and each part is
+    /// copied once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<str>::from_slices(&["hello", " ", "world"]);
+    /// assert_eq!(s, "hello world");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_slices(parts: &[&S]) -> Self
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        Self::from_slices_impl::<Infallible>(parts).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` by concatenating the given slices, returning an error if
+    /// the allocation fails.
+    ///
+    /// See [`from_slices`](Self::from_slices).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// let s = ArcSlice::<[u8]>::try_from_slices(&[b"hello", b" ", b"world"])?;
+    /// assert_eq!(s, b"hello world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_slices(parts: &[&S]) -> Result<Self, AllocError>
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        Self::from_slices_impl::<AllocError>(parts)
+    }
+
+    fn join_impl<E: AllocErrorImpl>(parts: &[impl AsRef<S>], sep: &S) -> Result<Self, E>
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        let seps_len = sep.len().saturating_mul(parts.len().saturating_sub(1));
+        let length = (parts.iter()).fold(seps_len, |len, part| {
+            len.saturating_add(part.as_ref().len())
+        });
+        if let Some(empty) = ArcSlice::new_empty(NonNull::dangling(), length) {
+            return Ok(empty);
+        }
+        let mut parts = parts.iter().map(AsRef::as_ref);
+        let first = parts.next().into_iter();
+        let joined = first.chain(parts.flat_map(|part| [sep, part]));
+        let (arc, start) = unsafe { Arc::<S, false>::new_concat_unchecked(length, joined) }?;
+        Ok(Self::init(start, length, L::data_from_arc_slice(arc)))
+    }
+
+    /// Creates a new `ArcSlice` by concatenating the given slices, placing the separator between
+    /// each of them.
+    ///
+    /// As with [`from_slices`](Self::from_slices), there is a single allocation. An empty input
+    /// gives an empty slice, and a single part is copied without separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let fields = [<ArcStr>::from("a"), "b".into(), "c".into()];
+    /// assert_eq!(<ArcStr>::join(&fields, ","), "a,b,c");
+    /// assert_eq!(
+    ///     <ArcStr>::join(&["usr", "local", "bin"], "/"),
+    ///     "usr/local/bin"
+    /// );
+    /// assert_eq!(<ArcStr>::join(&[] as &[&str], "/"), "");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn join(parts: &[impl AsRef<S>], sep: &S) -> Self
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        Self::join_impl::<Infallible>(parts, sep).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` by concatenating the given slices with a separator,
+    /// returning an error if the allocation fails.
+    ///
+    /// See [`join`](Self::join).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// let s = ArcSlice::<[u8]>::try_join(&[b"hello", b"world"], b", ")?;
+    /// assert_eq!(s, b"hello, world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_join(parts: &[impl AsRef<S>], sep: &S) -> Result<Self, AllocError>
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        Self::join_impl::<AllocError>(parts, sep)
+    }
+
     fn from_array_impl<E: AllocErrorImpl, const N: usize>(
         array: [S::Item; N],
     ) -> Result<Self, (E, [S::Item; N])> {
@@ -309,7 +509,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         Ok(Self::init(start, N, L::data_from_arc_slice(arc)))
     }
 
-    #[cfg(feature = "serde")]
+    #[cfg(any(feature = "arbitrary", feature = "serde"))]
     pub(crate) fn new_bytes(slice: &S) -> Self {
         let (start, length) = slice.to_raw_parts();
         if let Some(empty) = ArcSlice::new_empty(start, length) {
@@ -321,10 +521,10 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         Self::init(start, slice.len(), L::data_from_arc_slice(arc))
     }
 
-    #[cfg(feature = "serde")]
+    #[cfg(any(feature = "arbitrary", feature = "serde"))]
     pub(crate) fn new_byte_vec(vec: S::Vec) -> Self {
         if !L::ANY_BUFFER {
-            return Self::new_bytes(ManuallyDrop::new(vec).as_slice());
+            return Self::new_bytes(vec.as_slice());
         }
         Self::from_vec(vec)
     }
@@ -342,8 +542,10 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     }
 
     fn new_empty(start: NonNull<S::Item>, length: usize) -> Option<Self> {
-        let data = L::STATIC_DATA.filter(|_| length == 0)?;
-        Some(Self::init(start, length, data))
+        if length != 0 {
+            return None;
+        }
+        Some(Self::init(start, length, L::EMPTY_DATA))
     }
 
     /// Returns the number of items in the slice.
@@ -400,6 +602,24 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         unsafe { S::from_raw_parts(self.start, self.length) }
     }
 
+    /// Converts the `ArcSlice` into a cursor reading it without consuming it.
+    ///
+    /// See [`ArcSliceCursor`] documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let mut cursor = ArcSlice::<[u8]>::from(b"hello world").into_cursor();
+    /// cursor.set_position(6);
+    /// assert_eq!(cursor.remaining(), b"world");
+    /// assert_eq!(cursor.into_inner(), b"hello world");
+    /// ```
+    pub fn into_cursor(self) -> ArcSliceCursor<S, L> {
+        ArcSliceCursor::new(self)
+    }
+
     /// Returns a borrowed view of an `ArcSlice` subslice with a given range.
     ///
     /// See [`ArcSliceBorrow`] documentation.
@@ -414,6 +634,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// assert_eq!(&borrow[..], b"hello");
     /// let s2: ArcSlice<[u8]> = borrow.clone_arc();
     /// ```
+    #[track_caller]
     pub fn borrow(&self, range: impl RangeBounds<usize>) -> ArcSliceBorrow<'_, S, L>
     where
         S: Subsliceable,
@@ -436,6 +657,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// assert_eq!(&borrow[..], b"hello");
     /// let s2: ArcSlice<[u8]> = borrow.clone_arc();
     /// ```
+    #[track_caller]
     pub fn borrow_from_ref(&self, subset: &S) -> ArcSliceBorrow<'_, S, L>
     where
         S: Subsliceable,
@@ -443,6 +665,62 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         unsafe { self.borrow_impl(subslice_offset_len(self.as_slice(), subset)) }
     }
 
+    /// Returns the index of the first occurrence of `needle` in the slice items, or `None` if it
+    /// is not found.
+    ///
+    /// An empty `needle` is always found at index 0. Byte slices are searched with
+    /// `memchr::memmem` when the `memchr` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u16]>::from(&[0, 1, 2, 1, 2]);
+    /// assert_eq!(s.find_subslice(&[1, 2]), Some(1));
+    /// assert_eq!(s.find_subslice(&[2, 0]), None);
+    /// let s = ArcSlice::<str>::from("hello world");
+    /// assert_eq!(s.find_subslice(b"world"), Some(6));
+    /// ```
+    pub fn find_subslice(&self, needle: &[S::Item]) -> Option<usize>
+    where
+        S::Item: PartialEq,
+    {
+        find(self.to_slice(), needle)
+    }
+
+    /// Returns a borrowed view of the first occurrence of `needle` in the slice, or `None` if
+    /// it is not found.
+    ///
+    /// See [`find_subslice`](Self::find_subslice) and [`ArcSliceBorrow`] documentation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the occurrence is not a valid subslice, e.g. if `needle` starts or ends in the
+    /// middle of a UTF-8 character for a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"GET /index.html HTTP/1.1");
+    /// let borrow = s.find_and_borrow(b"/index.html").unwrap();
+    /// assert_eq!(&borrow[..], b"/index.html");
+    /// let path: ArcSlice<[u8]> = borrow.clone_arc();
+    /// assert_eq!(path.as_ptr(), s[4..].as_ptr());
+    /// assert!(s.find_and_borrow(b"POST").is_none());
+    /// ```
+    #[track_caller]
+    pub fn find_and_borrow(&self, needle: &[S::Item]) -> Option<ArcSliceBorrow<'_, S, L>>
+    where
+        S: Subsliceable,
+        S::Item: PartialEq,
+    {
+        let offset = self.find_subslice(needle)?;
+        Some(self.borrow(offset..offset + needle.len()))
+    }
+
     unsafe fn borrow_impl(&self, (offset, len): (usize, usize)) -> ArcSliceBorrow<'_, S, L>
     where
         S: Subsliceable,
@@ -505,9 +783,47 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         Ok(clone)
     }
 
+    // `boundaries` must be sorted valid split indexes; references are added all at once, and
+    // empty parts are not refcounted
+    pub(crate) unsafe fn split_parts(self, boundaries: &[usize], parts: &mut Vec<Self>) {
+        let this = ManuallyDrop::new(self);
+        let (slice_start, length) = (this.start, this.length);
+        let starts = core::iter::once(0).chain(boundaries.iter().copied());
+        let ends = boundaries.iter().copied().chain(core::iter::once(length));
+        let ranges = || starts.clone().zip(ends.clone());
+        let (mut data, mut clone_data) = (None, None);
+        match ranges().filter(|(start, end)| start < end).count() {
+            0 => drop(ManuallyDrop::into_inner(this)),
+            count => {
+                if count > 1 {
+                    let clone_many = L::clone_many::<S, Infallible>;
+                    let clone = clone_many(slice_start, length, &this.data, count - 1);
+                    clone_data = Some(clone.unwrap_infallible());
+                }
+                data = Some(unsafe { ptr::read(&*this.data) });
+            }
+        }
+        for (start, end) in ranges() {
+            let part_start = unsafe { slice_start.add(start) };
+            parts.push(match Self::new_empty(part_start, end - start) {
+                Some(empty) => empty,
+                None => {
+                    // the first part takes the original reference, the other ones the clones
+                    let data = data.take().unwrap_or_else(|| unsafe {
+                        ptr::read(clone_data.as_ref().unwrap_checked())
+                    });
+                    Self::init(part_start, end - start, data)
+                }
+            });
+        }
+    }
+
     /// Tries extracting a subslice of an `ArcSlice` with a given range, returning an error if an
     /// allocation fails.
     ///
+    /// The subslice always starts at `self.as_ptr().add(offset)`, see
+    /// [`subslice`](Self::subslice).
+    ///
     /// The operation may allocate. See [`CloneNoAllocLayout`](crate::layout::CloneNoAllocLayout)
     /// documentation for cases where it does not.
     ///
@@ -523,6 +839,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// # Ok(())
     /// # }
     /// ```
+    #[track_caller]
     pub fn try_subslice(&self, range: impl RangeBounds<usize>) -> Result<Self, AllocError>
     where
         S: Subsliceable,
@@ -549,6 +866,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// # Ok(())
     /// # }
     /// ```
+    #[track_caller]
     pub fn try_subslice_from_ref(&self, subset: &S) -> Result<Self, AllocError>
     where
         S: Subsliceable,
@@ -573,18 +891,20 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// s.advance(6);
     /// assert_eq!(s, b"world");
     /// ```
+    #[track_caller]
     pub fn advance(&mut self, offset: usize)
     where
         S: Subsliceable,
     {
         if offset > self.length {
-            panic_out_of_range();
+            panic_start_out_of_range(offset, self.length);
         }
         unsafe { self.check_advance(offset) };
         self.start = unsafe { self.start.add(offset) };
         self.length -= offset;
     }
 
+    #[track_caller]
     fn truncate_impl<E: AllocErrorImpl>(&mut self, len: usize) -> Result<(), E>
     where
         S: Subsliceable,
@@ -615,6 +935,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// # Ok(())
     /// }
     /// ```
+    #[track_caller]
     pub fn try_truncate(&mut self, len: usize) -> Result<(), AllocError>
     where
         S: Subsliceable,
@@ -622,6 +943,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         self.truncate_impl::<AllocError>(len)
     }
 
+    #[track_caller]
     fn split_off_impl<E: AllocErrorImpl>(&mut self, at: usize) -> Result<Self, E>
     where
         S: Subsliceable,
@@ -631,7 +953,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         } else if at == self.length {
             return unsafe { self.subslice_impl((at, 0)) };
         } else if at > self.length {
-            panic_out_of_range();
+            panic_start_out_of_range(at, self.length);
         }
         let mut clone = self.clone_impl()?;
         clone.start = unsafe { clone.start.add(at) };
@@ -667,6 +989,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// # Ok(())
     /// # }
     /// ```
+    #[track_caller]
     pub fn try_split_off(&mut self, at: usize) -> Result<Self, AllocError>
     where
         S: Subsliceable,
@@ -674,6 +997,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         self.split_off_impl::<AllocError>(at)
     }
 
+    #[track_caller]
     fn split_to_impl<E: AllocErrorImpl>(&mut self, at: usize) -> Result<Self, E>
     where
         S: Subsliceable,
@@ -685,7 +1009,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
                 self.subslice_impl((self.len(), 0))?
             }));
         } else if at > self.length {
-            panic_out_of_range();
+            panic_end_out_of_range(at, self.length);
         }
         let mut clone = self.clone_impl()?;
         clone.length = at;
@@ -721,6 +1045,7 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// # Ok(())
     /// # }
     /// ```
+    #[track_caller]
     pub fn try_split_to(&mut self, at: usize) -> Result<Self, AllocError>
     where
         S: Subsliceable,
@@ -728,12 +1053,61 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         self.split_to_impl::<AllocError>(at)
     }
 
+    #[allow(clippy::type_complexity)]
+    fn split_into_impl<E: AllocErrorImpl>(mut self, at: usize) -> Result<(Self, Self), (E, Self)>
+    where
+        S: Subsliceable,
+    {
+        if at > self.length {
+            panic_start_out_of_range(at, self.length);
+        }
+        unsafe { self.as_slice().check_split(at) };
+        match self.split_off_impl(at) {
+            Ok(tail) => Ok((self, tail)),
+            Err(err) => Err((err, self)),
+        }
+    }
+
+    /// Tries splitting the slice into two at the given index, consuming it, returning an error
+    /// together with the slice if an allocation fails.
+    ///
+    /// The first returned `ArcSlice` contains elements `[0, at)`, and the second one contains
+    /// elements `[at, len)`. This operation does not touch the underlying buffer, and the
+    /// reference of the consumed slice is reused for the first half, so the refcount is
+    /// incremented at most once. If `at == 0` or `at == len`, it is not incremented at all
+    /// when the layout supports static empty slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let (a, b) = s.try_split_into(5).unwrap();
+    ///
+    /// assert_eq!(a, b"hello");
+    /// assert_eq!(b, b" world");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn try_split_into(self, at: usize) -> Result<(Self, Self), (AllocError, Self)>
+    where
+        S: Subsliceable,
+    {
+        self.split_into_impl::<AllocError>(at)
+    }
+
     /// Tries to acquire the slice as mutable, returning an [`ArcSliceMut`] on success.
     ///
     /// There must be no other reference to the underlying buffer, and this one must be mutable
     /// for the conversion to succeed. Otherwise, the original slice is returned. An `ArcSlice`
     /// created from an array/slice or a vector is guaranteed to have a mutable buffer, as well
-    /// as one returned [`ArcSliceMut::freeze`].
+    /// as one returned [`ArcSliceMut::freeze`]. The spare capacity of the buffer, after the end
+    /// of the slice, is recovered as well.
     ///
     /// The conversion may allocate depending on the given [layouts](crate::layout), but allocation
     /// errors are caught and the original slice is also returned in this case.
@@ -750,7 +1124,10 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
     /// // b has been dropped
     /// let a_mut: ArcSliceMut<[u8]> = a.try_into_mut().unwrap();
     /// ```
-    pub fn try_into_mut<L2: LayoutMut>(self) -> Result<ArcSliceMut<S, L2>, Self> {
+    pub fn try_into_mut<L2: LayoutMut>(self) -> Result<ArcSliceMut<S, L2>, Self>
+    where
+        S: SliceMut,
+    {
         let mut this = ManuallyDrop::new(self);
         match unsafe { L::mut_data::<S, L2>(this.start, this.length, &mut this.data) } {
             Some((capacity, data)) => {
@@ -760,72 +1137,452 @@ impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
         }
     }
 
-    /// Returns `true` if this is the only reference to the underlying buffer, and if this one
-    /// is unique (see [`Buffer::is_unique`]).
+    fn into_mut_or_clone_impl<L2: LayoutMut, E: AllocErrorImpl>(
+        self,
+    ) -> Result<ArcSliceMut<S, L2>, (E, Self)>
+    where
+        S: SliceMut,
+        S::Item: Copy,
+    {
+        self.try_into_mut().or_else(|this| {
+            ArcSliceMut::from_slice_impl::<E>(this.as_slice()).map_err(|err| (err, this))
+        })
+    }
+
+    /// Acquires the slice as mutable, copying it if needed.
+    ///
+    /// If [`try_into_mut`](Self::try_into_mut) succeeds, the buffer is reused without copy;
+    /// otherwise, the slice is copied into a new [`ArcSliceMut`], and the original one is
+    /// dropped.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::ArcSlice;
+    /// use arc_slice::{ArcSlice, ArcSliceMut};
     ///
-    /// let s = ArcSlice::<[u8]>::from(b"hello world");
-    /// assert!(s.is_unique());
-    /// let s2 = s.clone();
-    /// assert!(!s.is_unique());
-    /// drop(s2);
-    /// assert!(s.is_unique());
+    /// let a = ArcSlice::<[u8]>::from(b"hello world");
+    /// let b = a.clone();
+    ///
+    /// let mut b_mut: ArcSliceMut<[u8]> = b.into_mut_or_clone();
+    /// b_mut[0] = b'j';
+    /// assert_eq!(b_mut, b"jello world");
+    /// // a is now unique, so its buffer is reused
+    /// let ptr = a.as_ptr();
+    /// let a_mut: ArcSliceMut<[u8]> = a.into_mut_or_clone();
+    /// assert_eq!(a_mut.as_ptr(), ptr);
     /// ```
-    pub fn is_unique(&self) -> bool {
-        L::is_unique::<S>(&self.data)
+    #[cfg(feature = "oom-handling")]
+    pub fn into_mut_or_clone<L2: LayoutMut>(self) -> ArcSliceMut<S, L2>
+    where
+        S: SliceMut,
+        S::Item: Copy,
+    {
+        self.into_mut_or_clone_impl::<L2, Infallible>()
+            .unwrap_infallible()
     }
 
-    /// Accesses the metadata of the underlying buffer if it can be successfully downcast.
+    /// Tries acquiring the slice as mutable, copying it if needed, returning the original slice
+    /// if an allocation fails.
+    ///
+    /// See [`into_mut_or_clone`](Self::into_mut_or_clone).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    /// use arc_slice::{ArcSlice, ArcSliceMut};
     ///
-    /// let metadata = "metadata".to_string();
-    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], metadata);
-    /// assert_eq!(s.metadata::<String>().unwrap(), "metadata");
+    /// let a = ArcSlice::<[u8]>::from(b"hello world");
+    /// let b = a.clone();
+    ///
+    /// let b_mut: ArcSliceMut<[u8]> = b.try_into_mut_or_clone().unwrap();
+    /// assert_eq!(b_mut, b"hello world");
+    /// assert_ne!(b_mut.as_ptr(), a.as_ptr());
     /// ```
-    pub fn metadata<M: Any>(&self) -> Option<&M> {
-        L::get_metadata::<S, M>(&self.data)
+    pub fn try_into_mut_or_clone<L2: LayoutMut>(self) -> Result<ArcSliceMut<S, L2>, Self>
+    where
+        S: SliceMut,
+        S::Item: Copy,
+    {
+        self.into_mut_or_clone_impl::<L2, AllocError>()
+            .map_err(|(_, this)| this)
     }
 
-    /// Tries downcasting the `ArcSlice` to its underlying buffer.
+    /// Copies the slice into `target`, reusing its capacity.
+    ///
+    /// `target` is cleared, then capacity is reserved for the slice, which is copied into it.
+    /// If `target` is large enough, there is no allocation.
+    ///
+    /// # Panics
+    ///
+    /// See [`ArcSliceMut::reserve`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
-    ///
-    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(vec![0, 1, 2]);
-    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), [0, 1, 2]);
+    /// use arc_slice::{ArcSlice, ArcSliceMut};
+    ///
+    /// let mut scratch = ArcSliceMut::<[u8]>::with_capacity(64);
+    /// let ptr = scratch.as_ptr();
+    /// ArcSlice::<[u8]>::from(b"hello").copy_into_mut(&mut scratch);
+    /// ArcSlice::<[u8]>::from(b"world").copy_into_mut(&mut scratch);
+    /// assert_eq!(scratch, b"world");
+    /// assert_eq!(scratch.as_ptr(), ptr);
     /// ```
-    pub fn try_into_buffer<B: Buffer<S>>(self) -> Result<B, Self> {
-        let mut this = ManuallyDrop::new(self);
-        unsafe { L::take_buffer::<S, B>(this.start, this.length, &mut this.data) }
-            .ok_or_else(|| ManuallyDrop::into_inner(this))
-    }
-
-    fn with_layout_impl<L2: Layout, E: AllocErrorImpl>(self) -> Result<ArcSlice<S, L2>, Self> {
-        let mut this = ManuallyDrop::new(self);
-        let data = unsafe { ManuallyDrop::take(&mut this.data) };
-        match L::update_layout::<S, L2, E>(this.start, this.length, data) {
-            Some(data) => Ok(ArcSlice::init(this.start, this.len(), data)),
-            None => Err(ManuallyDrop::into_inner(this)),
-        }
+    #[cfg(feature = "oom-handling")]
+    pub fn copy_into_mut<L2: LayoutMut>(&self, target: &mut ArcSliceMut<S, L2>)
+    where
+        S: Concatenable + SliceMut,
+        S::Item: Copy,
+    {
+        unsafe { target.truncate_unchecked(0) };
+        target.extend_from_slice(self);
     }
 
-    /// Tries to replace the layout of the `ArcSlice`, returning the original slice if it fails.
-    ///
-    /// The [layouts](crate::layout) must be compatible for the conversion to succeed, see
-    /// [`FromLayout`].
+    /// Tries copying the slice into `target`, reusing its capacity, returning an error if the
+    /// capacity reservation fails.
     ///
-    /// The conversion may allocate depending on the given [layouts](crate::layout), but allocation
-    /// errors are caught and the original slice is also returned in this case.
+    /// `target` is cleared, then capacity is reserved for the slice, which is copied into it.
+    /// If `target` is large enough, there is no allocation. If the reservation fails,
+    /// `target` is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcSlice, ArcSliceMut};
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut scratch = ArcSliceMut::<[u8]>::with_capacity(64).into_shared();
+    /// ArcSlice::<[u8]>::from(b"hello").try_copy_into_mut(&mut scratch)?;
+    /// assert_eq!(scratch, b"hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_copy_into_mut<L2: LayoutMut, const UNIQUE: bool>(
+        &self,
+        target: &mut ArcSliceMut<S, L2, UNIQUE>,
+    ) -> Result<(), TryReserveError>
+    where
+        S: Concatenable + SliceMut,
+        S::Item: Copy,
+    {
+        unsafe { target.truncate_unchecked(0) };
+        target.try_extend_from_slice(self)
+    }
+
+    /// Returns `true` if this is the only reference to the underlying buffer, and if this one
+    /// is unique (see [`Buffer::is_unique`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// assert!(s.is_unique());
+    /// let s2 = s.clone();
+    /// assert!(!s.is_unique());
+    /// drop(s2);
+    /// assert!(s.is_unique());
+    /// ```
+    pub fn is_unique(&self) -> bool {
+        L::is_unique::<S>(&self.data)
+    }
+
+    /// Returns the capacity of the underlying buffer, in items, if it can be known.
+    ///
+    /// This is the capacity of the whole buffer, not only of the part referenced by the slice.
+    /// It is always known for buffers allocated by the crate, i.e. arc slices and vectors, and
+    /// for buffers implementing [`BufferMut`](crate::buffer::BufferMut); it is `None` for
+    /// static slices and other buffers.
+    ///
+    /// The returned value is a snapshot: once the slice is unique, it can be converted back to
+    /// an [`ArcSliceMut`] whose reservations change the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let mut vec = Vec::with_capacity(64);
+    /// vec.extend_from_slice(b"hello world");
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(vec);
+    /// let hello = s.subslice(..5);
+    /// assert_eq!(hello.buffer_capacity(), Some(64));
+    /// let s = ArcSlice::<[u8], ArcLayout<true, true>>::from_static(b"hello");
+    /// assert_eq!(s.buffer_capacity(), None);
+    /// ```
+    pub fn buffer_capacity(&self) -> Option<usize> {
+        L::buffer_capacity::<S>(&self.data)
+    }
+
+    /// Returns the number of bytes of the underlying buffer kept alive by the slice, if its
+    /// capacity can be known.
+    ///
+    /// It is computed as the [buffer capacity](Self::buffer_capacity) multiplied by the size of
+    /// an item, so it doesn't account for the allocation overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::VecLayout, ArcSlice};
+    ///
+    /// let mut vec = Vec::<u32>::with_capacity(16);
+    /// vec.push(42);
+    /// let s = ArcSlice::<[u32], VecLayout>::from(vec);
+    /// assert_eq!(s.pinned_bytes(), Some(64));
+    /// ```
+    pub fn pinned_bytes(&self) -> Option<usize> {
+        Some(self.buffer_capacity()? * mem::size_of::<S::Item>())
+    }
+
+    /// Creates an [`ArcSliceWeak`] pointer to this slice.
+    ///
+    /// The weak pointer doesn't keep the underlying buffer alive, but the slice can be
+    /// recovered with [`ArcSliceWeak::upgrade`] as long as there is a strong reference left.
+    /// While it is alive, the buffer is not [unique](Self::is_unique).
+    ///
+    /// Only buffers allocated in an Arc with [`ArcLayout`](crate::layout::ArcLayout) support
+    /// weak references; other ones, including static slices, give a dangling weak pointer,
+    /// whose upgrade always returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(b"hello world".to_vec());
+    /// let weak = s.subslice(..5).downgrade();
+    /// assert!(!s.is_unique());
+    /// assert_eq!(weak.upgrade().unwrap(), b"hello");
+    /// drop(s);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> ArcSliceWeak<S, L> {
+        ArcSliceWeak {
+            start: self.start,
+            length: self.length,
+            ptr: L::downgrade::<S>(&self.data),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Decomposes the `ArcSlice` into its raw parts.
+    ///
+    /// The returned [`ArcSliceRawParts`] takes over the ownership of the slice, which can be
+    /// recovered with [`from_raw_parts`](Self::from_raw_parts); dropping the parts leaks the
+    /// underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let parts = s.into_raw_parts();
+    /// assert_eq!(parts.length, 11);
+    /// let s = unsafe { ArcSlice::from_raw_parts(parts) };
+    /// assert_eq!(s, b"hello world");
+    /// ```
+    pub fn into_raw_parts(self) -> ArcSliceRawParts<S, L> {
+        let mut this = ManuallyDrop::new(self);
+        ArcSliceRawParts {
+            start: this.start,
+            length: this.length,
+            data: ManuallyDrop::new(unsafe { ManuallyDrop::take(&mut this.data) }),
+        }
+    }
+
+    /// Reconstructs an `ArcSlice` from its raw parts.
+    ///
+    /// # Safety
+    ///
+    /// The parts must have been returned by [`into_raw_parts`](Self::into_raw_parts), with
+    /// their `start` and `length` fields unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let parts = s.into_raw_parts();
+    /// let s = unsafe { ArcSlice::from_raw_parts(parts) };
+    /// assert_eq!(s, b"hello world");
+    /// ```
+    pub unsafe fn from_raw_parts(parts: ArcSliceRawParts<S, L>) -> Self {
+        let mut parts = ManuallyDrop::new(parts);
+        Self::init(parts.start, parts.length, unsafe {
+            ManuallyDrop::take(&mut parts.data)
+        })
+    }
+
+    /// Accesses the metadata of the underlying buffer if it can be successfully downcast.
+    ///
+    /// If the metadata is a [`MetadataChain`], each of its elements can also be accessed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let metadata = "metadata".to_string();
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], metadata);
+    /// assert_eq!(s.metadata::<String>().unwrap(), "metadata");
+    /// ```
+    pub fn metadata<M: Any>(&self) -> Option<&M> {
+        L::get_metadata::<S, M>(&self.data)
+    }
+
+    /// Returns the runtime representation of the underlying buffer.
+    ///
+    /// See [`Repr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{
+    ///     layout::{ArcLayout, Repr},
+    ///     ArcSlice,
+    /// };
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// assert_eq!(s.repr(), Repr::ArcSlice);
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(vec![0, 1, 2]);
+    /// assert_eq!(s.repr(), Repr::ArcBuffer);
+    /// ```
+    pub fn repr(&self) -> Repr {
+        L::repr::<S>(&self.data)
+    }
+
+    /// Returns `true` if the slice is static, i.e. not backed by an allocated buffer.
+    ///
+    /// It is equivalent to `self.repr() == Repr::Static`; empty slices may also be static.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<false, true>>::from_static(b"hello world");
+    /// assert!(s.is_static());
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// assert!(!s.is_static());
+    /// ```
+    pub fn is_static(&self) -> bool {
+        self.repr() == Repr::Static
+    }
+
+    /// Checks the internal invariants of the slice, panicking if one of them is broken.
+    ///
+    /// The slice must lie within its underlying buffer, and the buffer refcount must not be
+    /// zero. It is intended for tests and fuzzing, e.g. after each operation of a random
+    /// sequence; a broken invariant means a bug in this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let mut s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let s2 = s.split_off(5);
+    /// s.debug_validate();
+    /// s2.debug_validate();
+    /// ```
+    #[cfg(feature = "arbitrary")]
+    pub fn debug_validate(&self) {
+        L::validate::<S>(self.start, self.length, &self.data);
+    }
+
+    /// Converts the slice into a [`Cow`], borrowing it if it [is static](Self::is_static).
+    ///
+    /// Otherwise, the underlying vector is reused if the slice can be downcast to it, see
+    /// [`try_into_buffer`](Self::try_into_buffer), or the slice is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// use arc_slice::{layout::ArcLayout, ArcStr};
+    ///
+    /// let s = ArcStr::<ArcLayout<true, true>>::from_static("hello");
+    /// assert!(matches!(s.into_cow(), Cow::Borrowed("hello")));
+    /// let s = ArcStr::<ArcLayout<true, true>>::from(String::from("hello"));
+    /// assert!(matches!(s.into_cow(), Cow::Owned(s) if s == "hello"));
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn into_cow(self) -> Cow<'static, S>
+    where
+        S: ToOwned<Owned = S::Vec>,
+    {
+        if self.is_static() {
+            return Cow::Borrowed(unsafe { S::from_raw_parts(self.start, self.length) });
+        }
+        match self.try_into_buffer::<S::Vec>() {
+            Ok(vec) => Cow::Owned(vec),
+            Err(this) => Cow::Owned(this.as_slice().to_owned()),
+        }
+    }
+
+    /// Tries downcasting the `ArcSlice` to its underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(vec![0, 1, 2]);
+    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), [0, 1, 2]);
+    /// ```
+    pub fn try_into_buffer<B: Buffer<S>>(self) -> Result<B, Self> {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { L::take_buffer::<S, B>(this.start, this.length, &mut this.data) }
+            .ok_or_else(|| ManuallyDrop::into_inner(this))
+    }
+
+    /// Tries downcasting the `ArcSlice` to its underlying buffer and metadata.
+    ///
+    /// It only succeeds if the `ArcSlice` is unique, and was created with
+    /// [`from_buffer_with_metadata`](Self::from_buffer_with_metadata) using the given buffer and
+    /// metadata types; the metadata is then moved out instead of being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let metadata = "metadata".to_string();
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], metadata);
+    /// let (buffer, metadata) = s
+    ///     .try_into_buffer_with_metadata::<Vec<u8>, String>()
+    ///     .unwrap();
+    /// assert_eq!(buffer, [0, 1, 2]);
+    /// assert_eq!(metadata, "metadata");
+    /// ```
+    pub fn try_into_buffer_with_metadata<B: Buffer<S>, M: Send + Sync + 'static>(
+        self,
+    ) -> Result<(B, M), Self> {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { L::take_buffer_with_metadata::<S, B, M>(this.start, this.length, &mut this.data) }
+            .ok_or_else(|| ManuallyDrop::into_inner(this))
+    }
+
+    fn with_layout_impl<L2: Layout, E: AllocErrorImpl>(self) -> Result<ArcSlice<S, L2>, Self> {
+        let mut this = ManuallyDrop::new(self);
+        let data = unsafe { ManuallyDrop::take(&mut this.data) };
+        match L::update_layout::<S, L2, E>(this.start, this.length, data) {
+            Some(data) => Ok(ArcSlice::init(this.start, this.len(), data)),
+            None => Err(ManuallyDrop::into_inner(this)),
+        }
+    }
+
+    /// Tries to replace the layout of the `ArcSlice`, returning the original slice if it fails.
+    ///
+    /// The [layouts](crate::layout) must be compatible for the conversion to succeed, see
+    /// [`FromLayout`].
+    ///
+    /// The conversion may allocate depending on the given [layouts](crate::layout), but allocation
+    /// errors are caught and the original slice is also returned in this case.
     ///
     /// # Examples
     /// ```rust
@@ -960,70 +1717,273 @@ impl<T: Send + Sync + 'static, L: Layout> ArcSlice<[T], L> {
     pub fn try_from_array<const N: usize>(array: [T; N]) -> Result<Self, [T; N]> {
         Self::from_array_impl::<AllocError, N>(array).map_err(|(_, array)| array)
     }
-}
 
-impl<
-        S: Slice + ?Sized,
-        #[cfg(feature = "oom-handling")] L: Layout,
-        #[cfg(not(feature = "oom-handling"))] L: TruncateNoAllocLayout,
-    > ArcSlice<S, L>
-{
-    /// Truncate the slice to the first `len` items.
+    /// Returns an array reference to the first `N` items, and the rest of the slice, or `None`
+    /// if the slice is shorter than `N`.
     ///
-    /// If `len` is greater than the slice length, this has no effect.
+    /// # Examples
     ///
     /// ```rust
     /// use arc_slice::ArcSlice;
     ///
-    /// let mut s = ArcSlice::<[u8]>::from(b"hello world");
-    /// s.truncate(5);
-    /// assert_eq!(s, b"hello");
+    /// let s = ArcSlice::<[u8]>::from(b"\x00\x05hello");
+    /// let (header, body) = s.split_first_chunk::<2>().unwrap();
+    /// assert_eq!(u16::from_be_bytes(*header), 5);
+    /// assert_eq!(body, b"hello");
+    /// assert!(s.split_first_chunk::<8>().is_none());
     /// ```
-    pub fn truncate(&mut self, len: usize)
-    where
-        S: Subsliceable,
-    {
-        self.truncate_impl::<Infallible>(len).unwrap_infallible();
+    pub fn split_first_chunk<const N: usize>(&self) -> Option<(&[T; N], &[T])> {
+        if self.length < N {
+            return None;
+        }
+        let (first, rest) = self.as_slice().split_at(N);
+        Some((first.try_into().unwrap(), rest))
     }
-}
 
-impl<
-        S: Slice + ?Sized,
-        #[cfg(feature = "oom-handling")] L: Layout,
-        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
-    > ArcSlice<S, L>
-{
-    /// Extracts a subslice of an `ArcSlice` with a given range.
+    /// Converts the slice into an iterator over subslices of length 1.
+    ///
+    /// Each subslice shares the underlying buffer, so no item is copied or cloned.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use arc_slice::ArcSlice;
     ///
-    /// let s = ArcSlice::<[u8]>::from(b"hello world");
-    /// let s2 = s.subslice(..5);
-    /// assert_eq!(s2, b"hello");
+    /// let s = ArcSlice::<[u8]>::from(b"abc");
+    /// let items: Vec<_> = s.into_iter_arc().collect();
+    /// assert_eq!(items, [b"a", b"b", b"c"]);
     /// ```
-    pub fn subslice(&self, range: impl RangeBounds<usize>) -> Self
-    where
-        S: Subsliceable,
-    {
-        unsafe { self.subslice_impl::<Infallible>(range_offset_len(self.as_slice(), range)) }
-            .unwrap_infallible()
+    pub fn into_iter_arc(self) -> IntoIterArc<T, L> {
+        IntoIterArc {
+            start: 0,
+            end: self.length,
+            slice: self,
+        }
     }
 
-    /// Extracts a subslice of an `ArcSlice` from a slice reference.
+    /// Returns an iterator over borrowed chunks of `chunk_size` items, starting at the beginning
+    /// of the slice.
     ///
-    /// # Examples
+    /// The chunks are [`ArcSliceBorrow`], so iterating doesn't touch the refcount of the
+    /// underlying buffer, contrary to [`subslice`](Self::subslice); only the chunks kept with
+    /// [`ArcSliceBorrow::clone_arc`] increment it. It makes filtering a lot of chunks, while
+    /// keeping only a few of them, cheaper.
     ///
-    /// ```rust
-    /// use arc_slice::ArcSlice;
+    /// The last chunk will be shorter if `chunk_size` doesn't divide the slice length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"GET /a\nPUT /b\nGET /c\n");
+    /// let gets: Vec<ArcSlice<[u8]>> = s
+    ///     .borrow_chunks(7)
+    ///     .filter(|chunk| chunk.starts_with(b"GET"))
+    ///     .map(|chunk| chunk.clone_arc())
+    ///     .collect();
+    /// assert_eq!(gets, [b"GET /a\n", b"GET /c\n"]);
+    /// ```
+    #[track_caller]
+    pub fn borrow_chunks(&self, chunk_size: usize) -> BorrowChunks<'_, T, L> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        BorrowChunks {
+            slice: self,
+            start: 0,
+            end: self.length,
+            chunk_size,
+        }
+    }
+
+    /// Tries converting the `ArcSlice` into a standard [`Arc`](alloc::sync::Arc), returning the
+    /// original slice if it fails.
+    ///
+    /// The memory layout of the crate's Arc differs from the standard one, so the conversion
+    /// only succeeds without copying if the `ArcSlice` is unique, spans its whole buffer, and the
+    /// buffer is itself an `Arc<[T]>`, e.g. with [`from_buffer`](Self::from_buffer). See
+    /// [`into_std_arc`](Self::into_std_arc) for a copying fallback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    ///
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let arc: Arc<[u8]> = Arc::new([0, 1, 2]);
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer(arc.clone());
+    /// assert!(Arc::ptr_eq(&s.try_into_std_arc().unwrap(), &arc));
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello");
+    /// assert_eq!(s.try_into_std_arc().unwrap_err(), b"hello");
+    /// ```
+    #[cfg(not(feature = "portable-atomic"))]
+    pub fn try_into_std_arc(self) -> Result<alloc::sync::Arc<[T]>, Self> {
+        self.try_into_buffer()
+    }
+
+    /// Converts the `ArcSlice` into a standard [`Arc`](alloc::sync::Arc), copying the items if the
+    /// conversion cannot be done in place.
+    ///
+    /// See [`try_into_std_arc`](Self::try_into_std_arc) for the zero-copy case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    ///
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello");
+    /// let arc: Arc<[u8]> = s.into_std_arc();
+    /// assert_eq!(*arc, *b"hello");
+    /// ```
+    #[cfg(all(feature = "oom-handling", not(feature = "portable-atomic")))]
+    pub fn into_std_arc(self) -> alloc::sync::Arc<[T]>
+    where
+        T: Clone,
+    {
+        self.try_into_std_arc()
+            .unwrap_or_else(|this| this.as_slice().into())
+    }
+}
+
+impl<
+        T: Send + Sync + 'static,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ArcSlice<[T], L>
+{
+    /// Returns an iterator over cloned items of the slice.
+    ///
+    /// The iterator holds a clone of the `ArcSlice`, so it is not bound to the lifetime of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"abc");
+    /// let iter = s.iter_cloned();
+    /// drop(s);
+    /// assert_eq!(iter.rev().collect::<Vec<_>>(), b"cba");
+    /// ```
+    pub fn iter_cloned(&self) -> IntoIter<T, L>
+    where
+        T: Clone,
+    {
+        self.clone().into_iter()
+    }
+}
+
+impl<
+        S: Slice + ?Sized,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: TruncateNoAllocLayout,
+    > ArcSlice<S, L>
+{
+    /// Truncate the slice to the first `len` items.
+    ///
+    /// If `len` is greater than the slice length, this has no effect.
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let mut s = ArcSlice::<[u8]>::from(b"hello world");
+    /// s.truncate(5);
+    /// assert_eq!(s, b"hello");
+    /// ```
+    #[track_caller]
+    pub fn truncate(&mut self, len: usize)
+    where
+        S: Subsliceable,
+    {
+        self.truncate_impl::<Infallible>(len).unwrap_infallible();
+    }
+
+    /// Removes the last `count` items of the slice.
+    ///
+    /// This is equivalent to `self.truncate(self.len() - count)`, but panics if the count is
+    /// out of range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count > self.len()`, or if the new end is not a valid subslice end, e.g. not
+    /// a char boundary for `str`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let mut s = ArcSlice::<[u8]>::from(b"hello world");
+    /// s.advance_back(6);
+    /// assert_eq!(s, b"hello");
+    /// ```
+    #[track_caller]
+    pub fn advance_back(&mut self, count: usize)
+    where
+        S: Subsliceable,
+    {
+        if count > self.length {
+            panic_advance_back_out_of_range(count, self.length);
+        }
+        self.truncate_impl::<Infallible>(self.length - count)
+            .unwrap_infallible();
+    }
+}
+
+impl<
+        S: Slice + ?Sized,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ArcSlice<S, L>
+{
+    /// Extracts a subslice of an `ArcSlice` with a given range.
+    ///
+    /// The subslice always starts at `self.as_ptr().add(offset)`, where `offset` is the start of
+    /// the range, even if it is empty; offsets into the parent slice can thus be recovered with
+    /// pointer arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let s2 = s.subslice(..5);
+    /// assert_eq!(s2, b"hello");
+    /// let empty = s.subslice(6..6);
+    /// assert_eq!(empty.as_ptr(), s[6..].as_ptr());
+    /// ```
+    #[track_caller]
+    pub fn subslice(&self, range: impl RangeBounds<usize>) -> Self
+    where
+        S: Subsliceable,
+    {
+        unsafe { self.subslice_impl::<Infallible>(range_offset_len(self.as_slice(), range)) }
+            .unwrap_infallible()
+    }
+
+    /// Extracts a subslice of an `ArcSlice` from a slice reference.
+    ///
+    /// The subslice always starts at `subset.as_ptr()`, even if `subset` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
     ///
     /// let s = ArcSlice::<[u8]>::from(b"hello world");
     /// let hello = &s[..5];
     /// let s2 = s.subslice_from_ref(hello);
     /// assert_eq!(s2, b"hello");
     /// ```
+    #[track_caller]
     pub fn subslice_from_ref(&self, subset: &S) -> Self
     where
         S: Subsliceable,
@@ -1032,6 +1992,66 @@ impl<
             .unwrap_infallible()
     }
 
+    /// Extracts a subslice of `len` items starting at `offset`, without checking the range.
+    ///
+    /// This is the unchecked counterpart of [`subslice`](Self::subslice), for ranges which
+    /// have already been validated.
+    ///
+    /// # Safety
+    ///
+    /// - `offset + len` must not overflow and must be less than or equal to `self.len()`;
+    /// - for `str`, both `offset` and `offset + len` must lie on UTF-8 char boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<str>::from("hello world");
+    /// let s2 = unsafe { s.subslice_unchecked(6, 5) };
+    /// assert_eq!(s2, "world");
+    /// ```
+    pub unsafe fn subslice_unchecked(&self, offset: usize, len: usize) -> Self
+    where
+        S: Subsliceable,
+    {
+        unsafe { self.subslice_impl::<Infallible>((offset, len)) }.unwrap_infallible()
+    }
+
+    /// Extracts a subslice of an `ArcSlice` from a pair of raw pointers, e.g. returned by a C
+    /// parser.
+    ///
+    /// This is the raw pointer counterpart of [`subslice_from_ref`](Self::subslice_from_ref).
+    ///
+    /// # Safety
+    ///
+    /// - `start` and `end` must both point inside the slice, or one item past its end;
+    /// - `start` must be less than or equal to `end`;
+    /// - for `str`, both `start` and `end` must lie on UTF-8 char boundaries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S::Item` is a zero-sized type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let range = s[6..].as_ptr_range();
+    /// let s2 = unsafe { s.subslice_from_raw(range.start, range.end) };
+    /// assert_eq!(s2, b"world");
+    /// ```
+    pub unsafe fn subslice_from_raw(&self, start: *const S::Item, end: *const S::Item) -> Self
+    where
+        S: Subsliceable,
+    {
+        let offset = unsafe { start.offset_from_unsigned(self.start.as_ptr()) };
+        let len = unsafe { end.offset_from_unsigned(start) };
+        unsafe { self.subslice_unchecked(offset, len) }
+    }
+
     /// Splits the slice into two at the given index.
     ///
     /// Afterwards `self` contains elements `[0, at)`, and the returned `ArcSlice`
@@ -1053,6 +2073,7 @@ impl<
     /// assert_eq!(b, b" world");
     /// ```
     #[must_use = "consider `ArcSlice::truncate` if you don't need the other half"]
+    #[track_caller]
     pub fn split_off(&mut self, at: usize) -> Self
     where
         S: Subsliceable,
@@ -1081,1004 +2102,3239 @@ impl<
     /// assert_eq!(b, b"hello");
     /// ```
     #[must_use = "consider `ArcSlice::advance` if you don't need the other half"]
+    #[track_caller]
     pub fn split_to(&mut self, at: usize) -> Self
     where
         S: Subsliceable,
     {
         self.split_to_impl::<Infallible>(at).unwrap_infallible()
     }
-}
 
-#[cfg(feature = "oom-handling")]
-impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
-    /// Replace the layout of the `ArcSlice`.
+    /// Splits the slice into two at the given index, consuming it.
     ///
-    /// The [layouts](crate::layout) must be compatible, see [`FromLayout`].
+    /// The first returned `ArcSlice` contains elements `[0, at)`, and the second one contains
+    /// elements `[at, len)`. This operation does not touch the underlying buffer, and the
+    /// reference of the consumed slice is reused for the first half, so the refcount is
+    /// incremented at most once. If `at == 0` or `at == len`, it is not incremented at all
+    /// when the layout supports static empty slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
     ///
     /// # Examples
+    ///
     /// ```rust
-    /// use arc_slice::{
-    ///     layout::{ArcLayout, BoxedSliceLayout, VecLayout},
-    ///     ArcSlice,
-    /// };
+    /// use arc_slice::ArcSlice;
     ///
-    /// let a = ArcSlice::<[u8]>::from(b"hello world");
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let (a, b) = s.split_into(5);
     ///
-    /// let b = a.with_layout::<VecLayout>();
+    /// assert_eq!(a, b"hello");
+    /// assert_eq!(b, b" world");
     /// ```
-    pub fn with_layout<L2: FromLayout<L>>(self) -> ArcSlice<S, L2> {
-        self.with_layout_impl::<L2, Infallible>().unwrap_checked()
+    #[track_caller]
+    pub fn split_into(self, at: usize) -> (Self, Self)
+    where
+        S: Subsliceable,
+    {
+        self.split_into_impl::<Infallible>(at)
+            .map_err(|(err, _)| err)
+            .unwrap_infallible()
     }
 }
 
-#[cfg(not(feature = "oom-handling"))]
-impl<S: Slice + ?Sized, const ANY_BUFFER: bool, const STATIC: bool>
-    ArcSlice<S, ArcLayout<ANY_BUFFER, STATIC>>
-{
-    /// Replace the layout of the `ArcSlice`.
+macro_rules! get_int {
+    ($($ty:ident: $get_be:ident, $get_le:ident, $get_ne:ident, $try_get_be:ident, $try_get_le:ident, $try_get_ne:ident;)*) => {$(
+        get_int!(@method $ty, $get_be, $try_get_be, from_be_bytes, to_be_bytes, "big-endian");
+        get_int!(@method $ty, $get_le, $try_get_le, from_le_bytes, to_le_bytes, "little-endian");
+        get_int!(@method $ty, $get_ne, $try_get_ne, from_ne_bytes, to_ne_bytes, "native-endian");
+    )*};
+    (@method $ty:ident, $get:ident, $try_get:ident, $from_bytes:ident, $to_bytes:ident, $order:literal) => {
+        #[doc = concat!("Reads a `", stringify!($ty), "` from the front of the slice, in ", $order, " byte order, and advances past it.")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if there are fewer than `size_of::<", stringify!($ty), ">()` bytes in the slice.")]
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use arc_slice::ArcBytes;
+        ///
+        #[doc = concat!("let mut s = <ArcBytes>::from((42 as ", stringify!($ty), ").", stringify!($to_bytes), "());")]
+        #[doc = concat!("assert_eq!(s.", stringify!($get), "(), 42 as ", stringify!($ty), ");")]
+        /// assert!(s.is_empty());
+        /// ```
+        #[track_caller]
+        pub fn $get(&mut self) -> $ty {
+            $ty::$from_bytes(self.get_array())
+        }
+
+        #[doc = concat!("Tries reading a `", stringify!($ty), "` from the front of the slice, in ", $order, " byte order, and advances past it.")]
+        ///
+        /// Returns an error if there are not enough bytes in the slice, which is then left
+        /// untouched.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use arc_slice::ArcBytes;
+        ///
+        #[doc = concat!("let mut s = <ArcBytes>::from((42 as ", stringify!($ty), ").", stringify!($to_bytes), "());")]
+        #[doc = concat!("assert_eq!(s.", stringify!($try_get), "(), Ok(42 as ", stringify!($ty), "));")]
+        #[doc = concat!("assert!(s.", stringify!($try_get), "().is_err());")]
+        /// ```
+        pub fn $try_get(&mut self) -> Result<$ty, TryGetError> {
+            self.try_get_array().map($ty::$from_bytes)
+        }
+    };
+}
+
+impl<L: Layout> ArcSlice<[u8], L> {
+    /// Returns the index of the first occurrence of `needle` in the slice, or `None` if it is
+    /// not found.
     ///
-    /// The [layouts](crate::layout) must be compatible, see [`FromLayout`].
+    /// An empty `needle` is always found at index 0.
     ///
     /// # Examples
+    ///
     /// ```rust
-    /// use arc_slice::{
-    ///     layout::{ArcLayout, BoxedSliceLayout, VecLayout},
-    ///     ArcSlice,
-    /// };
+    /// use arc_slice::ArcSlice;
     ///
-    /// let a = ArcSlice::<[u8]>::from(b"hello world");
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// assert_eq!(s.find(b"o w"), Some(4));
+    /// assert_eq!(s.find(b"xyz"), None);
+    /// ```
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        find(self.as_slice(), needle)
+    }
+
+    /// Compares the slice with `other` in constant time, e.g. for authentication tokens.
     ///
-    /// let b = a.with_layout::<VecLayout>();
+    /// The comparison time only depends on the length of the slices, not on their content; it
+    /// returns early if the lengths differ, so the length of secrets is not protected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let token = ArcSlice::<[u8]>::from(b"secret");
+    /// assert!(token.ct_eq(b"secret"));
+    /// assert!(!token.ct_eq(b"secreT"));
+    /// assert!(!token.ct_eq(b"secrets"));
     /// ```
-    pub fn with_layout<L2: FromLayout<ArcLayout<ANY_BUFFER, STATIC>>>(self) -> ArcSlice<S, L2> {
-        self.with_layout_impl::<L2, Infallible>().unwrap_checked()
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        ct_eq(self.as_slice(), other)
     }
-}
 
-impl<S: Slice + ?Sized, L: AnyBufferLayout> ArcSlice<S, L> {
-    pub(crate) fn from_dyn_buffer_impl<B: DynBuffer + Buffer<S>, E: AllocErrorImpl>(
-        buffer: B,
-    ) -> Result<Self, (E, B)> {
-        let (arc, start, length) = Arc::new_buffer::<_, E>(buffer)?;
-        let data = L::data_from_arc_buffer::<S, true, B>(arc);
-        Ok(Self::init(start, length, data))
+    /// Returns the length of the longest valid UTF-8 prefix of the slice.
+    ///
+    /// If `delim` is given and found in the slice, the prefix stops before its first occurrence.
+    /// A multibyte sequence truncated or invalid is never included in the prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"h\xc3\xa9llo\n\xff");
+    /// assert_eq!(s.utf8_prefix_len(None), 7);
+    /// assert_eq!(s.utf8_prefix_len(Some(b'\n')), 6);
+    /// let s = ArcSlice::<[u8]>::from(b"h\xc3");
+    /// assert_eq!(s.utf8_prefix_len(None), 1);
+    /// ```
+    pub fn utf8_prefix_len(&self, delim: Option<u8>) -> usize {
+        let bytes = self.as_slice();
+        let bytes = match delim.and_then(|delim| bytes.iter().position(|&b| b == delim)) {
+            Some(pos) => &bytes[..pos],
+            None => bytes,
+        };
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s.len(),
+            Err(err) => err.valid_up_to(),
+        }
     }
 
-    pub(crate) fn from_static_impl<E: AllocErrorImpl>(
-        slice: &'static S,
-    ) -> Result<Self, (E, &'static S)> {
-        let (start, length) = slice.to_raw_parts();
-        Ok(Self::init(
-            start,
-            length,
-            L::data_from_static::<_, E>(slice)?,
-        ))
+    fn try_get_array<const N: usize>(&mut self) -> Result<[u8; N], TryGetError> {
+        let array = match self.split_first_chunk::<N>() {
+            Some((array, _)) => *array,
+            None => {
+                return Err(TryGetError {
+                    requested: N,
+                    available: self.length,
+                })
+            }
+        };
+        self.advance(N);
+        Ok(array)
     }
 
-    fn from_buffer_impl<B: Buffer<S>, E: AllocErrorImpl>(mut buffer: B) -> Result<Self, (E, B)> {
-        match try_transmute::<B, &'static S>(buffer) {
-            Ok(slice) => {
-                return Self::from_static_impl::<E>(slice)
-                    .map_err(|(err, s)| (err, transmute_checked(s)))
-            }
-            Err(b) => buffer = b,
-        }
-        match try_transmute::<B, Box<S>>(buffer) {
-            Ok(boxed) => {
-                let vec = unsafe { S::from_vec_unchecked(boxed.into_boxed_slice().into_vec()) };
-                return match Self::from_vec_impl::<E>(vec) {
-                    Ok(this) => Ok(this),
-                    Err((err, vec)) => Err((
-                        err,
-                        transmute_checked(unsafe {
-                            S::from_boxed_slice_unchecked(S::into_vec(vec).into_boxed_slice())
-                        }),
-                    )),
-                };
-            }
-            Err(b) => buffer = b,
-        }
-        match try_transmute::<B, S::Vec>(buffer) {
-            Ok(vec) => {
-                return Self::from_vec_impl::<E>(vec)
-                    .map_err(|(err, v)| (err, transmute_checked(v)))
-            }
-            Err(b) => buffer = b,
+    #[track_caller]
+    fn get_array<const N: usize>(&mut self) -> [u8; N] {
+        match self.try_get_array() {
+            Ok(array) => array,
+            Err(err) => panic_start_out_of_range(err.requested, err.available),
         }
-        Self::from_dyn_buffer_impl::<_, E>(BufferWithMetadata::new(buffer, ()))
-            .map_err(|(err, b)| (err, b.buffer()))
     }
 
-    /// Creates a new `ArcSlice` with the given underlying buffer.
+    /// Reads a `u8` from the front of the slice, and advances past it.
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer).
+    /// # Panics
+    ///
+    /// Panics if the slice is empty.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer(vec![0, 1, 2]);
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// let mut s = <ArcBytes>::from(b"\x2a");
+    /// assert_eq!(s.get_u8(), 42);
+    /// assert!(s.is_empty());
     /// ```
-    #[cfg(feature = "oom-handling")]
-    pub fn from_buffer<B: Buffer<S>>(buffer: B) -> Self {
-        Self::from_buffer_impl::<_, Infallible>(buffer).unwrap_infallible()
+    #[track_caller]
+    pub fn get_u8(&mut self) -> u8 {
+        u8::from_be_bytes(self.get_array())
     }
 
-    /// Tries creating a new `ArcSlice` with the given underlying buffer, returning it if an
-    /// allocation fails.
+    /// Tries reading a `u8` from the front of the slice, and advances past it.
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer).
-    ///
-    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
-    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    /// Returns an error if the slice is empty.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// let s = ArcSlice::<[u8], ArcLayout<true>>::try_from_buffer(vec![0, 1, 2]).unwrap();
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// let mut s = <ArcBytes>::from(b"\x2a");
+    /// assert_eq!(s.try_get_u8(), Ok(42));
+    /// assert!(s.try_get_u8().is_err());
     /// ```
-    pub fn try_from_buffer<B: Buffer<S>>(buffer: B) -> Result<Self, B> {
-        Self::from_buffer_impl::<_, AllocError>(buffer).map_err(|(_, buffer)| buffer)
-    }
-
-    fn from_buffer_with_metadata_impl<B: Buffer<S>, M: Send + Sync + 'static, E: AllocErrorImpl>(
-        buffer: B,
-        metadata: M,
-    ) -> Result<Self, (E, (B, M))> {
-        if is!(M, ()) {
-            return Self::from_buffer_impl::<_, E>(buffer).map_err(|(err, b)| (err, (b, metadata)));
-        }
-        Self::from_dyn_buffer_impl::<_, E>(BufferWithMetadata::new(buffer, metadata))
-            .map_err(|(err, b)| (err, b.into_tuple()))
+    pub fn try_get_u8(&mut self) -> Result<u8, TryGetError> {
+        self.try_get_array().map(u8::from_be_bytes)
     }
 
-    /// Creates a new `ArcSlice` with the given underlying buffer and its associated metadata.
+    /// Reads an `i8` from the front of the slice, and advances past it.
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
-    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    /// # Panics
+    ///
+    /// Panics if the slice is empty.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// let metadata = "metadata".to_string();
-    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], metadata);
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.metadata::<String>().unwrap(), "metadata");
-    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// let mut s = <ArcBytes>::from(b"\xff");
+    /// assert_eq!(s.get_i8(), -1);
     /// ```
-    #[cfg(feature = "oom-handling")]
-    pub fn from_buffer_with_metadata<B: Buffer<S>, M: Send + Sync + 'static>(
-        buffer: B,
-        metadata: M,
-    ) -> Self {
-        Self::from_buffer_with_metadata_impl::<_, _, Infallible>(buffer, metadata)
-            .unwrap_infallible()
+    #[track_caller]
+    pub fn get_i8(&mut self) -> i8 {
+        i8::from_be_bytes(self.get_array())
     }
 
-    /// Tries creates a new `ArcSlice` with the given underlying buffer and its associated metadata,
-    /// returning them if an allocation fails.
-    ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
-    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    /// Tries reading an `i8` from the front of the slice, and advances past it.
     ///
-    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
-    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    /// Returns an error if the slice is empty.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// let metadata = "metadata".to_string();
-    /// let s =
-    ///     ArcSlice::<[u8], ArcLayout<true>>::try_from_buffer_with_metadata(vec![0, 1, 2], metadata)
-    ///         .unwrap();
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.metadata::<String>().unwrap(), "metadata");
-    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// let mut s = <ArcBytes>::from(b"\xff");
+    /// assert_eq!(s.try_get_i8(), Ok(-1));
+    /// assert!(s.try_get_i8().is_err());
     /// ```
-    pub fn try_from_buffer_with_metadata<B: Buffer<S>, M: Send + Sync + 'static>(
-        buffer: B,
-        metadata: M,
-    ) -> Result<Self, (B, M)> {
-        Self::from_buffer_with_metadata_impl::<_, _, AllocError>(buffer, metadata)
-            .map_err(|(_, bm)| bm)
+    pub fn try_get_i8(&mut self) -> Result<i8, TryGetError> {
+        self.try_get_array().map(i8::from_be_bytes)
     }
 
-    /// Creates a new `ArcSlice` with the given underlying buffer with borrowed metadata.
+    get_int! {
+        u16: get_u16, get_u16_le, get_u16_ne, try_get_u16, try_get_u16_le, try_get_u16_ne;
+        i16: get_i16, get_i16_le, get_i16_ne, try_get_i16, try_get_i16_le, try_get_i16_ne;
+        u32: get_u32, get_u32_le, get_u32_ne, try_get_u32, try_get_u32_le, try_get_u32_ne;
+        i32: get_i32, get_i32_le, get_i32_ne, try_get_i32, try_get_i32_le, try_get_i32_ne;
+        u64: get_u64, get_u64_le, get_u64_ne, try_get_u64, try_get_u64_le, try_get_u64_ne;
+        i64: get_i64, get_i64_le, get_i64_ne, try_get_i64, try_get_i64_le, try_get_i64_ne;
+        u128: get_u128, get_u128_le, get_u128_ne, try_get_u128, try_get_u128_le, try_get_u128_ne;
+        i128: get_i128, get_i128_le, get_i128_ne, try_get_i128, try_get_i128_le, try_get_i128_ne;
+        f32: get_f32, get_f32_le, get_f32_ne, try_get_f32, try_get_f32_le, try_get_f32_ne;
+        f64: get_f64, get_f64_le, get_f64_ne, try_get_f64, try_get_f64_le, try_get_f64_ne;
+    }
+
+    #[track_caller]
+    fn try_get_uint_impl<const LE: bool>(&mut self, nbytes: usize) -> Result<u64, TryGetError> {
+        let mut bytes = [0; 8];
+        if nbytes > bytes.len() {
+            panic_end_out_of_range(nbytes, bytes.len());
+        }
+        if nbytes > self.length {
+            return Err(TryGetError {
+                requested: nbytes,
+                available: self.length,
+            });
+        }
+        let n = if LE {
+            bytes[..nbytes].copy_from_slice(&self[..nbytes]);
+            u64::from_le_bytes(bytes)
+        } else {
+            bytes[8 - nbytes..].copy_from_slice(&self[..nbytes]);
+            u64::from_be_bytes(bytes)
+        };
+        self.advance(nbytes);
+        Ok(n)
+    }
+
+    #[track_caller]
+    fn get_uint_impl<const LE: bool>(&mut self, nbytes: usize) -> u64 {
+        match self.try_get_uint_impl::<LE>(nbytes) {
+            Ok(n) => n,
+            Err(err) => panic_start_out_of_range(err.requested, err.available),
+        }
+    }
+
+    /// Reads an unsigned integer of `nbytes` bytes from the front of the slice, in big-endian
+    /// byte order, and advances past it.
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
-    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`, or if there are fewer than `nbytes` bytes in the slice.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{
-    ///     buffer::{BorrowMetadata, Buffer},
-    ///     layout::ArcLayout,
-    ///     ArcSlice,
-    /// };
+    /// use arc_slice::ArcBytes;
     ///
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyBuffer(Vec<u8>);
-    /// impl Buffer<[u8]> for MyBuffer {
-    ///     fn as_slice(&self) -> &[u8] {
-    ///         &self.0
-    ///     }
-    /// }
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyMetadata;
-    /// impl BorrowMetadata for MyBuffer {
-    ///     type Metadata = MyMetadata;
-    ///     fn borrow_metadata(&self) -> &Self::Metadata {
-    ///         &MyMetadata
-    ///     }
-    /// }
-    /// let buffer = MyBuffer(vec![0, 1, 2]);
-    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer_with_borrowed_metadata(buffer);
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
-    /// assert_eq!(
-    ///     s.try_into_buffer::<MyBuffer>().unwrap(),
-    ///     MyBuffer(vec![0, 1, 2])
-    /// );
+    /// let mut s = <ArcBytes>::from(b"\x01\x02\x03");
+    /// assert_eq!(s.get_uint(3), 0x010203);
     /// ```
-    #[cfg(feature = "oom-handling")]
-    pub fn from_buffer_with_borrowed_metadata<B: Buffer<S> + BorrowMetadata>(buffer: B) -> Self {
-        Self::from_dyn_buffer_impl::<_, Infallible>(buffer).unwrap_infallible()
+    #[track_caller]
+    pub fn get_uint(&mut self, nbytes: usize) -> u64 {
+        self.get_uint_impl::<false>(nbytes)
     }
 
-    /// Tries creating a new `ArcSlice` with the given underlying buffer with borrowed metadata,
-    /// returning it if an allocation fails.
+    /// Reads an unsigned integer of `nbytes` bytes from the front of the slice, in
+    /// little-endian byte order, and advances past it.
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
-    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    /// # Panics
     ///
-    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
-    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    /// Panics if `nbytes > 8`, or if there are fewer than `nbytes` bytes in the slice.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{
-    ///     buffer::{BorrowMetadata, Buffer},
-    ///     layout::ArcLayout,
-    ///     ArcSlice,
-    /// };
+    /// use arc_slice::ArcBytes;
     ///
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyBuffer(Vec<u8>);
-    /// impl Buffer<[u8]> for MyBuffer {
-    ///     fn as_slice(&self) -> &[u8] {
-    ///         &self.0
-    ///     }
-    /// }
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyMetadata;
-    /// impl BorrowMetadata for MyBuffer {
-    ///     type Metadata = MyMetadata;
-    ///     fn borrow_metadata(&self) -> &Self::Metadata {
-    ///         &MyMetadata
-    ///     }
-    /// }
-    /// let buffer = MyBuffer(vec![0, 1, 2]);
-    /// let s =
-    ///     ArcSlice::<[u8], ArcLayout<true>>::try_from_buffer_with_borrowed_metadata(buffer).unwrap();
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
-    /// assert_eq!(
-    ///     s.try_into_buffer::<MyBuffer>().unwrap(),
-    ///     MyBuffer(vec![0, 1, 2])
-    /// );
+    /// let mut s = <ArcBytes>::from(b"\x01\x02\x03");
+    /// assert_eq!(s.get_uint_le(3), 0x030201);
     /// ```
-    pub fn try_from_buffer_with_borrowed_metadata<B: Buffer<S> + BorrowMetadata>(
-        buffer: B,
-    ) -> Result<Self, B> {
-        Self::from_dyn_buffer_impl::<_, AllocError>(buffer).map_err(|(_, buffer)| buffer)
-    }
-
-    #[cfg(feature = "raw-buffer")]
-    fn from_raw_buffer_impl<B: DynBuffer + RawBuffer<S>, E: AllocErrorImpl>(
-        buffer: B,
-    ) -> Result<Self, (E, B)> {
-        let ptr = buffer.into_raw();
-        if let Some(data) = L::data_from_raw_buffer::<S, B>(ptr) {
-            let buffer = ManuallyDrop::new(unsafe { B::from_raw(ptr) });
-            let (start, length) = buffer.as_slice().to_raw_parts();
-            return Ok(Self::init(start, length, data));
-        }
-        Self::from_dyn_buffer_impl::<_, E>(unsafe { B::from_raw(ptr) })
+    #[track_caller]
+    pub fn get_uint_le(&mut self, nbytes: usize) -> u64 {
+        self.get_uint_impl::<true>(nbytes)
     }
 
-    /// Creates a new `ArcSlice` with the given underlying raw buffer.
+    /// Reads a signed integer of `nbytes` bytes from the front of the slice, in big-endian
+    /// byte order, and advances past it.
     ///
-    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
-    /// the same as [`from_buffer`](Self::from_buffer).
+    /// # Panics
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer).
+    /// Panics if `nbytes > 8`, or if there are fewer than `nbytes` bytes in the slice.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # #[cfg(not(feature = "portable-atomic-util"))]
-    /// use std::sync::Arc;
-    ///
-    /// # #[cfg(feature = "portable-atomic-util")]
-    /// # use portable_atomic_util::Arc;
-    /// use arc_slice::{layout::RawLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// let s = ArcSlice::<[u8], RawLayout>::from_raw_buffer(Arc::new(vec![0, 1, 2]));
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(
-    ///     s.try_into_buffer::<Arc<Vec<u8>>>().unwrap(),
-    ///     Arc::new(vec![0, 1, 2])
-    /// );
+    /// let mut s = <ArcBytes>::from(b"\xff\xff\xfe");
+    /// assert_eq!(s.get_int(3), -2);
     /// ```
-    #[cfg(all(feature = "raw-buffer", feature = "oom-handling"))]
-    pub fn from_raw_buffer<B: RawBuffer<S>>(buffer: B) -> Self {
-        Self::from_raw_buffer_impl::<_, Infallible>(BufferWithMetadata::new(buffer, ()))
-            .unwrap_infallible()
+    #[track_caller]
+    pub fn get_int(&mut self, nbytes: usize) -> i64 {
+        sign_extend(self.get_uint_impl::<false>(nbytes), nbytes)
     }
 
-    /// Tries creating a new `ArcSlice` with the given underlying raw buffer, returning it if an
-    /// allocation fails.
+    /// Reads a signed integer of `nbytes` bytes from the front of the slice, in little-endian
+    /// byte order, and advances past it.
     ///
-    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
-    /// the same as [`try_from_buffer`](Self::try_from_buffer).
+    /// # Panics
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer).
+    /// Panics if `nbytes > 8`, or if there are fewer than `nbytes` bytes in the slice.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # #[cfg(not(feature = "portable-atomic-util"))]
-    /// use std::sync::Arc;
-    ///
-    /// # #[cfg(feature = "portable-atomic-util")]
-    /// # use portable_atomic_util::Arc;
-    /// use arc_slice::{layout::RawLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// let s = ArcSlice::<[u8], RawLayout>::try_from_raw_buffer(Arc::new(vec![0, 1, 2])).unwrap();
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(
-    ///     s.try_into_buffer::<Arc<Vec<u8>>>().unwrap(),
-    ///     Arc::new(vec![0, 1, 2])
-    /// );
+    /// let mut s = <ArcBytes>::from(b"\xfe\xff\xff");
+    /// assert_eq!(s.get_int_le(3), -2);
     /// ```
-    #[cfg(feature = "raw-buffer")]
-    pub fn try_from_raw_buffer<B: RawBuffer<S>>(buffer: B) -> Result<Self, B> {
-        Self::from_raw_buffer_impl::<_, AllocError>(BufferWithMetadata::new(buffer, ()))
-            .map_err(|(_, b)| b.buffer())
+    #[track_caller]
+    pub fn get_int_le(&mut self, nbytes: usize) -> i64 {
+        sign_extend(self.get_uint_impl::<true>(nbytes), nbytes)
     }
 
-    /// Creates a new `ArcSlice` with the given underlying raw buffer with borrowed metadata.
+    /// Tries reading an unsigned integer of `nbytes` bytes from the front of the slice, in
+    /// big-endian byte order, and advances past it.
     ///
-    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
-    /// the same as [`from_buffer`](Self::from_buffer).
+    /// Returns an error if there are fewer than `nbytes` bytes in the slice, which is then left
+    /// untouched.
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
-    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # #[cfg(not(feature = "portable-atomic-util"))]
-    /// use std::sync::Arc;
+    /// use arc_slice::ArcBytes;
     ///
-    /// # #[cfg(feature = "portable-atomic-util")]
-    /// # use portable_atomic_util::Arc;
-    /// ///
-    /// use arc_slice::buffer::{BorrowMetadata, Buffer};
-    /// use arc_slice::{layout::RawLayout, ArcSlice};
-    ///
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyBuffer(Vec<u8>);
-    /// impl Buffer<[u8]> for MyBuffer {
-    ///     fn as_slice(&self) -> &[u8] {
-    ///         &self.0
-    ///     }
-    /// }
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyMetadata;
-    /// impl BorrowMetadata for MyBuffer {
-    ///     type Metadata = MyMetadata;
-    ///     fn borrow_metadata(&self) -> &Self::Metadata {
-    ///         &MyMetadata
-    ///     }
-    /// }
-    ///
-    /// let buffer = Arc::new(MyBuffer(vec![0, 1, 2]));
-    /// let s = ArcSlice::<[u8], RawLayout>::from_raw_buffer_with_borrowed_metadata(buffer);
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
-    /// assert_eq!(
-    ///     s.try_into_buffer::<Arc<MyBuffer>>().unwrap(),
-    ///     Arc::new(MyBuffer(vec![0, 1, 2]))
-    /// );
+    /// let mut s = <ArcBytes>::from(b"\x01\x02\x03");
+    /// assert!(s.try_get_uint(4).is_err());
+    /// assert_eq!(s.try_get_uint(3), Ok(0x010203));
     /// ```
-    #[cfg(all(feature = "raw-buffer", feature = "oom-handling"))]
-    pub fn from_raw_buffer_with_borrowed_metadata<B: RawBuffer<S> + BorrowMetadata>(
-        buffer: B,
-    ) -> Self {
-        Self::from_dyn_buffer_impl::<_, Infallible>(buffer).unwrap_infallible()
+    #[track_caller]
+    pub fn try_get_uint(&mut self, nbytes: usize) -> Result<u64, TryGetError> {
+        self.try_get_uint_impl::<false>(nbytes)
     }
 
-    /// Tries creating a new `ArcSlice` with the given underlying raw buffer with borrowed metadata,
-    /// returning it if an allocation fails.
+    /// Tries reading an unsigned integer of `nbytes` bytes from the front of the slice, in
+    /// little-endian byte order, and advances past it.
     ///
-    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
-    /// the same as [`from_buffer`](Self::from_buffer).
+    /// Returns an error if there are fewer than `nbytes` bytes in the slice, which is then left
+    /// untouched.
     ///
-    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
-    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    /// # Panics
     ///
-    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
-    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    /// Panics if `nbytes > 8`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # #[cfg(not(feature = "portable-atomic-util"))]
-    /// use std::sync::Arc;
-    ///
-    /// # #[cfg(feature = "portable-atomic-util")]
-    /// # use portable_atomic_util::Arc;
-    /// ///
-    /// use arc_slice::buffer::{BorrowMetadata, Buffer};
-    /// use arc_slice::{layout::RawLayout, ArcSlice};
-    ///
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyBuffer(Vec<u8>);
-    /// impl Buffer<[u8]> for MyBuffer {
-    ///     fn as_slice(&self) -> &[u8] {
-    ///         &self.0
-    ///     }
-    /// }
-    /// #[derive(Debug, PartialEq, Eq)]
-    /// struct MyMetadata;
-    /// impl BorrowMetadata for MyBuffer {
-    ///     type Metadata = MyMetadata;
-    ///     fn borrow_metadata(&self) -> &Self::Metadata {
-    ///         &MyMetadata
-    ///     }
-    /// }
+    /// use arc_slice::ArcBytes;
     ///
-    /// let buffer = Arc::new(MyBuffer(vec![0, 1, 2]));
-    /// let s =
-    ///     ArcSlice::<[u8], RawLayout>::try_from_raw_buffer_with_borrowed_metadata(buffer).unwrap();
-    /// assert_eq!(s, [0, 1, 2]);
-    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
-    /// assert_eq!(
-    ///     s.try_into_buffer::<Arc<MyBuffer>>().unwrap(),
-    ///     Arc::new(MyBuffer(vec![0, 1, 2]))
-    /// );
+    /// let mut s = <ArcBytes>::from(b"\x01\x02\x03");
+    /// assert!(s.try_get_uint_le(4).is_err());
+    /// assert_eq!(s.try_get_uint_le(3), Ok(0x030201));
     /// ```
-    #[cfg(feature = "raw-buffer")]
-    pub fn try_from_raw_buffer_with_borrowed_metadata<B: RawBuffer<S> + BorrowMetadata>(
-        buffer: B,
-    ) -> Result<Self, B> {
-        Self::from_dyn_buffer_impl::<_, AllocError>(buffer).map_err(|(_, buffer)| buffer)
+    #[track_caller]
+    pub fn try_get_uint_le(&mut self, nbytes: usize) -> Result<u64, TryGetError> {
+        self.try_get_uint_impl::<true>(nbytes)
     }
-}
 
-impl<L: StaticLayout> ArcSlice<[u8], L> {
-    /// Creates a new `ArcSlice` from a static slice.
+    /// Tries reading a signed integer of `nbytes` bytes from the front of the slice, in
+    /// big-endian byte order, and advances past it.
     ///
-    /// The operation never allocates.
+    /// Returns an error if there are fewer than `nbytes` bytes in the slice, which is then left
+    /// untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// static HELLO_WORLD: ArcSlice<[u8], ArcLayout<true, true>> =
-    ///     ArcSlice::<[u8], ArcLayout<true, true>>::from_static(b"hello world");
+    /// let mut s = <ArcBytes>::from(b"\xff\xff\xfe");
+    /// assert!(s.try_get_int(4).is_err());
+    /// assert_eq!(s.try_get_int(3), Ok(-2));
     /// ```
-    pub const fn from_static(slice: &'static [u8]) -> Self {
-        // MSRV 1.65 const `<*const _>::cast_mut` + 1.85 const `NonNull::new`
-        let start = unsafe { NonNull::new_unchecked(slice.as_ptr() as _) };
-        let length = slice.len();
-        let data = unsafe { L::STATIC_DATA_UNCHECKED.assume_init() };
-        Self::init(start, length, data)
+    #[track_caller]
+    pub fn try_get_int(&mut self, nbytes: usize) -> Result<i64, TryGetError> {
+        let n = self.try_get_uint_impl::<false>(nbytes)?;
+        Ok(sign_extend(n, nbytes))
     }
-}
 
-impl<L: StaticLayout> ArcSlice<str, L> {
-    /// Creates a new `ArcSlice` from a static str.
+    /// Tries reading a signed integer of `nbytes` bytes from the front of the slice, in
+    /// little-endian byte order, and advances past it.
     ///
-    /// The operation never allocates.
+    /// Returns an error if there are fewer than `nbytes` bytes in the slice, which is then left
+    /// untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    /// use arc_slice::ArcBytes;
     ///
-    /// static HELLO_WORLD: ArcSlice<str, ArcLayout<true, true>> =
-    ///     ArcSlice::<str, ArcLayout<true, true>>::from_static("hello world");
+    /// let mut s = <ArcBytes>::from(b"\xfe\xff\xff");
+    /// assert!(s.try_get_int_le(4).is_err());
+    /// assert_eq!(s.try_get_int_le(3), Ok(-2));
     /// ```
-    pub const fn from_static(slice: &'static str) -> Self {
-        // MSRV 1.65 const `<*const _>::cast_mut` + 1.85 const `NonNull::new`
-        let start = unsafe { NonNull::new_unchecked(slice.as_ptr() as _) };
-        let length = slice.len();
-        let data = unsafe { L::STATIC_DATA_UNCHECKED.assume_init() };
-        Self::init(start, length, data)
-    }
-}
-
-impl<S: Slice + ?Sized, L: Layout> Drop for ArcSlice<S, L> {
-    fn drop(&mut self) {
-        unsafe { L::drop::<S, false>(self.start, self.length, &mut self.data) };
+    #[track_caller]
+    pub fn try_get_int_le(&mut self, nbytes: usize) -> Result<i64, TryGetError> {
+        let n = self.try_get_uint_impl::<true>(nbytes)?;
+        Ok(sign_extend(n, nbytes))
     }
 }
 
 impl<
-        S: Slice + ?Sized,
         #[cfg(feature = "oom-handling")] L: Layout,
         #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
-    > Clone for ArcSlice<S, L>
+    > ArcSlice<[u8], L>
 {
-    fn clone(&self) -> Self {
-        self.clone_impl::<Infallible>().unwrap_infallible()
-    }
-}
-
-impl<S: Slice + ?Sized, L: Layout> Deref for ArcSlice<S, L> {
-    type Target = S;
-
-    fn deref(&self) -> &Self::Target {
-        self.as_slice()
+    /// Splits off the longest valid UTF-8 prefix of the slice, as an `ArcStr`.
+    ///
+    /// The prefix is computed like [`utf8_prefix_len`](Self::utf8_prefix_len), and is
+    /// validated only once. Afterwards `self` starts at the first byte after the prefix, e.g.
+    /// the delimiter if found. This operation does not touch the underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let mut s = ArcSlice::<[u8]>::from(b"Content-Length: 2\n\n\xff\xfe");
+    /// let header = s.split_utf8_prefix(Some(b'\n'));
+    /// assert_eq!(header, "Content-Length: 2");
+    /// assert_eq!(s, b"\n\n\xff\xfe");
+    /// ```
+    pub fn split_utf8_prefix(&mut self, delim: Option<u8>) -> ArcSlice<str, L> {
+        let prefix = self.split_to(self.utf8_prefix_len(delim));
+        unsafe { ArcSlice::from_arc_slice_unchecked(prefix) }
     }
-}
 
-impl<S: Slice + ?Sized, L: Layout> AsRef<S> for ArcSlice<S, L> {
-    fn as_ref(&self) -> &S {
-        self
+    /// Tries splitting off the first `at` bytes of the slice as an `ArcStr`, returning an error
+    /// if they are not valid UTF-8.
+    ///
+    /// Only the first `at` bytes are validated. Afterwards `self` contains bytes `[at, len)`;
+    /// in case of error, `self` is left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let mut s = ArcSlice::<[u8]>::from(b"hello\xff");
+    /// assert!(s.try_split_str_to(6).is_err());
+    /// assert_eq!(s.try_split_str_to(5).unwrap(), "hello");
+    /// assert_eq!(s, b"\xff");
+    /// ```
+    #[track_caller]
+    pub fn try_split_str_to(
+        &mut self,
+        at: usize,
+    ) -> Result<ArcSlice<str, L>, core::str::Utf8Error> {
+        if at > self.length {
+            panic_end_out_of_range(at, self.length);
+        }
+        core::str::from_utf8(&self[..at])?;
+        Ok(unsafe { ArcSlice::from_arc_slice_unchecked(self.split_to(at)) })
     }
 }
 
-impl<S: Hash + Slice + ?Sized, L: Layout> Hash for ArcSlice<S, L> {
-    fn hash<H>(&self, state: &mut H)
-    where
-        H: Hasher,
-    {
-        self.as_slice().hash(state);
+impl<L: Layout> ArcSlice<str, L> {
+    /// Tries converting an `ArcBytes` into an `ArcStr`, returning the bytes back if they are not
+    /// valid UTF-8.
+    ///
+    /// This is an alias of [`try_from_arc_slice`](Self::try_from_arc_slice); the conversion
+    /// does not copy nor allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ArcStr};
+    ///
+    /// let s = ArcStr::try_from_utf8(<ArcBytes>::from(b"hello world")).unwrap();
+    /// assert_eq!(s, "hello world");
+    ///
+    /// let (error, bytes) = ArcStr::try_from_utf8(<ArcBytes>::from(b"\x80\x81")).unwrap_err();
+    /// assert_eq!(error.valid_up_to(), 0);
+    /// assert_eq!(bytes, b"\x80\x81");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_from_utf8(
+        bytes: ArcSlice<[u8], L>,
+    ) -> Result<Self, (core::str::Utf8Error, ArcSlice<[u8], L>)> {
+        Self::try_from_arc_slice(bytes)
     }
-}
 
-impl<S: Slice + ?Sized, L: Layout> Borrow<S> for ArcSlice<S, L> {
-    fn borrow(&self) -> &S {
-        self
+    /// Converts an `ArcBytes` into an `ArcStr`, replacing invalid UTF-8 sequences with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// If the bytes are valid UTF-8, the conversion does not copy nor allocate. Otherwise, a new
+    /// buffer is allocated, like [`String::from_utf8_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ArcStr};
+    ///
+    /// let bytes = <ArcBytes>::from(b"hello world");
+    /// let ptr = bytes.as_ptr();
+    /// let s = ArcStr::from_utf8_lossy(bytes);
+    /// assert_eq!(s, "hello world");
+    /// assert_eq!(s.as_ptr(), ptr);
+    ///
+    /// let s = ArcStr::from_utf8_lossy(<ArcBytes>::from(b"hello \xF0\x90\x80world"));
+    /// assert_eq!(s, "hello \u{FFFD}world");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_utf8_lossy(bytes: ArcSlice<[u8], L>) -> Self {
+        match Self::try_from_utf8(bytes) {
+            Ok(s) => s,
+            Err((_, bytes)) => {
+                ArcSliceMut::<str, ArcLayout<false>>::from_utf8_lossy_slice(&bytes).freeze()
+            }
+        }
     }
-}
 
-impl<S: Emptyable + ?Sized, L: StaticLayout> Default for ArcSlice<S, L> {
-    fn default() -> Self {
-        Self::new_empty(NonNull::dangling(), 0).unwrap_checked()
+    /// Returns the closest char boundary lower or equal to `index`.
+    ///
+    /// If `index` is greater than the string length, the length is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let s = <ArcStr>::from("hé");
+    /// assert_eq!(s.floor_char_boundary(2), 1);
+    /// assert_eq!(s.floor_char_boundary(3), 3);
+    /// assert_eq!(s.floor_char_boundary(42), 3);
+    /// ```
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        floor_char_boundary(self, index)
     }
-}
 
-impl<S: fmt::Debug + Slice + ?Sized, L: Layout> fmt::Debug for ArcSlice<S, L> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        debug_slice(self.as_slice(), f)
+    /// Returns the closest char boundary greater or equal to `index`.
+    ///
+    /// If `index` is greater than the string length, the length is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let s = <ArcStr>::from("hé");
+    /// assert_eq!(s.ceil_char_boundary(2), 3);
+    /// assert_eq!(s.ceil_char_boundary(1), 1);
+    /// assert_eq!(s.ceil_char_boundary(42), 3);
+    /// ```
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        ceil_char_boundary(self, index)
     }
-}
 
-impl<S: fmt::Display + Slice + ?Sized, L: Layout> fmt::Display for ArcSlice<S, L> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.as_slice().fmt(f)
+    /// Tries advancing the start of the string by `offset` bytes, returning an error if the
+    /// offset is out of range or not a char boundary.
+    ///
+    /// The string is left untouched in case of error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::CharBoundaryError, ArcStr};
+    ///
+    /// let mut s = <ArcStr>::from("héllo");
+    /// assert_eq!(
+    ///     s.try_advance_checked(2),
+    ///     Err(CharBoundaryError::NotCharBoundary {
+    ///         index: 2,
+    ///         floor: 1,
+    ///         ceil: 3
+    ///     })
+    /// );
+    /// s.try_advance_checked(3).unwrap();
+    /// assert_eq!(s, "llo");
+    /// ```
+    pub fn try_advance_checked(&mut self, offset: usize) -> Result<(), CharBoundaryError> {
+        try_char_boundary(self, offset)?;
+        self.advance(offset);
+        Ok(())
     }
 }
 
-impl<S: Slice<Item = u8> + ?Sized, L: Layout> fmt::LowerHex for ArcSlice<S, L> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        lower_hex(self.to_slice(), f)
+impl<
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: TruncateNoAllocLayout,
+    > ArcSlice<str, L>
+{
+    /// Tries truncating the string to the first `len` bytes, returning an error if `len` is not
+    /// a char boundary.
+    ///
+    /// If `len` is greater than the string length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::CharBoundaryError, ArcStr};
+    ///
+    /// let mut s = <ArcStr>::from("héllo");
+    /// assert!(matches!(
+    ///     s.try_truncate_checked(2),
+    ///     Err(CharBoundaryError::NotCharBoundary { .. })
+    /// ));
+    /// s.try_truncate_checked(3).unwrap();
+    /// assert_eq!(s, "hé");
+    /// ```
+    pub fn try_truncate_checked(&mut self, len: usize) -> Result<(), CharBoundaryError> {
+        if len < self.len() {
+            try_char_boundary(self, len)?;
+            self.truncate(len);
+        }
+        Ok(())
     }
 }
 
-impl<S: Slice<Item = u8> + ?Sized, L: Layout> fmt::UpperHex for ArcSlice<S, L> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        upper_hex(self.to_slice(), f)
+impl<
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ArcSlice<str, L>
+{
+    /// Tries splitting the string into two at the given index, returning an error if the index
+    /// is out of range or not a char boundary.
+    ///
+    /// Afterwards `self` contains bytes `[at, len)`, and the returned `ArcStr` contains bytes
+    /// `[0, at)`. The string is left untouched in case of error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::CharBoundaryError, ArcStr};
+    ///
+    /// let mut a = <ArcStr>::from("héllo");
+    /// assert_eq!(
+    ///     a.try_split_to_checked(42),
+    ///     Err(CharBoundaryError::OutOfRange { index: 42, len: 6 })
+    /// );
+    /// let b = a.try_split_to_checked(3).unwrap();
+    /// assert_eq!(a, "llo");
+    /// assert_eq!(b, "hé");
+    /// ```
+    pub fn try_split_to_checked(&mut self, at: usize) -> Result<Self, CharBoundaryError> {
+        try_char_boundary(self, at)?;
+        Ok(self.split_to(at))
     }
 }
 
-impl<S: PartialEq + Slice + ?Sized, L: Layout> PartialEq for ArcSlice<S, L> {
-    fn eq(&self, other: &ArcSlice<S, L>) -> bool {
+impl<
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ArcSlice<[u8], L>
+{
+    /// Returns an iterator over subslices separated by `delim`.
+    ///
+    /// Subslices are yielded the same way as [`slice::split`] does: consecutive delimiters
+    /// produce empty subslices, as does a leading or trailing delimiter. Each subslice shares
+    /// the underlying buffer, so no item is copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delim` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"foo\r\nbar\r\n\r\nbaz");
+    /// let parts: Vec<_> = s.split_on(b"\r\n").collect();
+    /// assert_eq!(parts, [&b"foo"[..], b"bar", b"", b"baz"]);
+    /// ```
+    pub fn split_on<'a>(&'a self, delim: &'a [u8]) -> impl Iterator<Item = Self> + 'a {
+        assert!(!delim.is_empty(), "empty delimiter");
+        let mut remaining = Some(self.as_slice());
+        core::iter::from_fn(move || {
+            let rest = remaining?;
+            let part = match find(rest, delim) {
+                Some(pos) => {
+                    remaining = Some(&rest[pos + delim.len()..]);
+                    &rest[..pos]
+                }
+                None => remaining.take()?,
+            };
+            Some(self.subslice_from_ref(part))
+        })
+    }
+
+    /// Returns an iterator over subslices separated by the `delim` byte.
+    ///
+    /// Subslices are yielded the same way as [`slice::split`] does: consecutive delimiters
+    /// produce empty subslices, as does a leading or trailing delimiter. Each subslice shares
+    /// the underlying buffer, so no item is copied.
+    ///
+    /// The iterator can also be consumed from the back, scanning the slice in reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"foo\nbar\n\nbaz\n");
+    /// let lines: Vec<_> = s.split_bytes(b'\n').collect();
+    /// assert_eq!(lines, [&b"foo"[..], b"bar", b"", b"baz", b""]);
+    /// let mut lines = s.split_bytes(b'\n');
+    /// assert_eq!(lines.next_back().unwrap(), b"");
+    /// assert_eq!(lines.next_back().unwrap(), b"baz");
+    /// assert_eq!(lines.next().unwrap(), b"foo");
+    /// ```
+    pub fn split_bytes(&self, delim: u8) -> SplitBytes<'_, L> {
+        SplitBytes {
+            slice: self,
+            remaining: Some(self.as_slice()),
+            delim,
+        }
+    }
+
+    /// Returns a subslice with the prefix removed.
+    ///
+    /// If the slice starts with `prefix`, returns the subslice after the prefix, wrapped in
+    /// `Some`. If `prefix` is empty, simply returns a clone of the slice.
+    ///
+    /// If the slice does not start with `prefix`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// assert_eq!(s.strip_prefix_arc(b"hello ").unwrap(), b"world");
+    /// assert!(s.strip_prefix_arc(b"world").is_none());
+    /// ```
+    pub fn strip_prefix_arc(&self, prefix: &[u8]) -> Option<Self> {
+        Some(self.subslice_from_ref(self.as_slice().strip_prefix(prefix)?))
+    }
+
+    /// Returns a subslice with the suffix removed.
+    ///
+    /// If the slice ends with `suffix`, returns the subslice before the suffix, wrapped in
+    /// `Some`. If `suffix` is empty, simply returns a clone of the slice.
+    ///
+    /// If the slice does not end with `suffix`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// assert_eq!(s.strip_suffix_arc(b" world").unwrap(), b"hello");
+    /// assert!(s.strip_suffix_arc(b"hello").is_none());
+    /// ```
+    pub fn strip_suffix_arc(&self, suffix: &[u8]) -> Option<Self> {
+        Some(self.subslice_from_ref(self.as_slice().strip_suffix(suffix)?))
+    }
+
+    /// Returns a subslice with leading and trailing ASCII whitespace removed.
+    ///
+    /// Whitespace is defined as in [`u8::is_ascii_whitespace`]. The subslice shares the
+    /// underlying buffer, so no byte is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b" \thello world\r\n");
+    /// assert_eq!(s.trim_ascii_arc(), b"hello world");
+    /// ```
+    pub fn trim_ascii_arc(&self) -> Self {
+        self.subslice_from_ref(trim_ascii_end(trim_ascii_start(self.as_slice())))
+    }
+
+    /// Returns a subslice with leading ASCII whitespace removed.
+    ///
+    /// Whitespace is defined as in [`u8::is_ascii_whitespace`]. The subslice shares the
+    /// underlying buffer, so no byte is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b" \thello world\r\n");
+    /// assert_eq!(s.trim_ascii_start_arc(), b"hello world\r\n");
+    /// ```
+    pub fn trim_ascii_start_arc(&self) -> Self {
+        self.subslice_from_ref(trim_ascii_start(self.as_slice()))
+    }
+
+    /// Returns a subslice with trailing ASCII whitespace removed.
+    ///
+    /// Whitespace is defined as in [`u8::is_ascii_whitespace`]. The subslice shares the
+    /// underlying buffer, so no byte is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b" \thello world\r\n");
+    /// assert_eq!(s.trim_ascii_end_arc(), b" \thello world");
+    /// ```
+    pub fn trim_ascii_end_arc(&self) -> Self {
+        self.subslice_from_ref(trim_ascii_end(self.as_slice()))
+    }
+}
+
+impl<
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ArcSlice<str, L>
+{
+    /// Returns a subslice with leading and trailing whitespace removed.
+    ///
+    /// Whitespace is defined as in [`str::trim`]. The subslice shares the underlying buffer,
+    /// so no byte is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let s = <ArcStr>::from(" key = value\n");
+    /// assert_eq!(s.trim_arc(), "key = value");
+    /// ```
+    pub fn trim_arc(&self) -> Self {
+        self.subslice_from_ref(self.as_slice().trim())
+    }
+
+    /// Returns a subslice with leading whitespace removed.
+    ///
+    /// Whitespace is defined as in [`str::trim_start`]. The subslice shares the underlying
+    /// buffer, so no byte is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let s = <ArcStr>::from(" key = value\n");
+    /// assert_eq!(s.trim_start_arc(), "key = value\n");
+    /// ```
+    pub fn trim_start_arc(&self) -> Self {
+        self.subslice_from_ref(self.as_slice().trim_start())
+    }
+
+    /// Returns a subslice with trailing whitespace removed.
+    ///
+    /// Whitespace is defined as in [`str::trim_end`]. The subslice shares the underlying
+    /// buffer, so no byte is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let s = <ArcStr>::from(" key = value\n");
+    /// assert_eq!(s.trim_end_arc(), " key = value");
+    /// ```
+    pub fn trim_end_arc(&self) -> Self {
+        self.subslice_from_ref(self.as_slice().trim_end())
+    }
+
+    /// Returns a subslice with the prefix removed.
+    ///
+    /// If the string starts with `prefix`, returns the subslice after the prefix, wrapped in
+    /// `Some`. If `prefix` is empty, simply returns a clone of the string.
+    ///
+    /// If the string does not start with `prefix`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let s = <ArcStr>::from("key = value");
+    /// assert_eq!(s.strip_prefix_arc("key = ").unwrap(), "value");
+    /// assert!(s.strip_prefix_arc("value").is_none());
+    /// ```
+    pub fn strip_prefix_arc(&self, prefix: &str) -> Option<Self> {
+        Some(self.subslice_from_ref(self.as_slice().strip_prefix(prefix)?))
+    }
+
+    /// Returns a subslice with the suffix removed.
+    ///
+    /// If the string ends with `suffix`, returns the subslice before the suffix, wrapped in
+    /// `Some`. If `suffix` is empty, simply returns a clone of the string.
+    ///
+    /// If the string does not end with `suffix`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStr;
+    ///
+    /// let s = <ArcStr>::from("key = value");
+    /// assert_eq!(s.strip_suffix_arc(" = value").unwrap(), "key");
+    /// assert!(s.strip_suffix_arc("key").is_none());
+    /// ```
+    pub fn strip_suffix_arc(&self, suffix: &str) -> Option<Self> {
+        Some(self.subslice_from_ref(self.as_slice().strip_suffix(suffix)?))
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<S: Slice + ?Sized, L: Layout> ArcSlice<S, L> {
+    /// Replace the layout of the `ArcSlice`.
+    ///
+    /// The [layouts](crate::layout) must be compatible, see [`FromLayout`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use arc_slice::{
+    ///     layout::{ArcLayout, BoxedSliceLayout, VecLayout},
+    ///     ArcSlice,
+    /// };
+    ///
+    /// let a = ArcSlice::<[u8]>::from(b"hello world");
+    ///
+    /// let b = a.with_layout::<VecLayout>();
+    /// ```
+    pub fn with_layout<L2: FromLayout<L>>(self) -> ArcSlice<S, L2> {
+        self.with_layout_impl::<L2, Infallible>().unwrap_checked()
+    }
+
+    /// Returns a copy-on-write transformation of the slice.
+    ///
+    /// If `f` returns `None`, the slice is left unchanged, and a clone sharing the underlying
+    /// buffer is returned. Otherwise, a new `ArcSlice` is created from the returned vector;
+    /// the vector is used as the buffer if the [layout](crate::layout) supports it, or copied
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let underscore = |s: &[u8]| {
+    ///     let replace = |&b| if b == b' ' { b'_' } else { b };
+    ///     s.contains(&b' ')
+    ///         .then(|| s.iter().map(replace).collect::<Vec<_>>())
+    /// };
+    /// assert_eq!(s.map_or_clone(underscore), b"hello_world");
+    /// let s = ArcSlice::<[u8]>::from(b"hello");
+    /// assert_eq!(s.map_or_clone(underscore).as_ptr(), s.as_ptr());
+    /// ```
+    pub fn map_or_clone(&self, f: impl FnOnce(&S) -> Option<S::Vec>) -> Self
+    where
+        S::Item: Copy,
+    {
+        match f(self.as_slice()) {
+            Some(vec) if L::ANY_BUFFER => Self::from_vec(vec),
+            Some(vec) => Self::from_slice(vec.as_slice()),
+            None => self.clone(),
+        }
+    }
+
+    // the copy is converted in place, so there is a single allocation whatever the layout
+    fn convert_or_clone(&self, needs_conversion: bool, convert: impl FnOnce(&mut S)) -> Self
+    where
+        S: SliceMut,
+        S::Item: Copy,
+    {
+        if !needs_conversion {
+            return self.clone();
+        }
+        let mut copy = ArcSliceMut::<S, ArcLayout<false>>::from_slice(self);
+        convert(&mut copy);
+        copy.freeze()
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<L: Layout> ArcSlice<[u8], L> {
+    /// Returns the slice with ASCII letters converted to lowercase, without copy if there is
+    /// none to convert.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"Content-Type");
+    /// assert_eq!(s.to_ascii_lowercase_arc(), b"content-type");
+    /// let s = ArcSlice::<[u8]>::from(b"content-type");
+    /// assert_eq!(s.to_ascii_lowercase_arc().as_ptr(), s.as_ptr());
+    /// ```
+    pub fn to_ascii_lowercase_arc(&self) -> Self {
+        let needs_conversion = self.iter().any(u8::is_ascii_uppercase);
+        self.convert_or_clone(needs_conversion, <[u8]>::make_ascii_lowercase)
+    }
+
+    /// Returns the slice with ASCII letters converted to uppercase, without copy if there is
+    /// none to convert.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"Content-Type");
+    /// assert_eq!(s.to_ascii_uppercase_arc(), b"CONTENT-TYPE");
+    /// let s = ArcSlice::<[u8]>::from(b"CONTENT-TYPE");
+    /// assert_eq!(s.to_ascii_uppercase_arc().as_ptr(), s.as_ptr());
+    /// ```
+    pub fn to_ascii_uppercase_arc(&self) -> Self {
+        let needs_conversion = self.iter().any(u8::is_ascii_lowercase);
+        self.convert_or_clone(needs_conversion, <[u8]>::make_ascii_uppercase)
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<L: Layout> ArcSlice<str, L> {
+    /// Returns the string with ASCII letters converted to lowercase, without copy if there is
+    /// none to convert.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<str>::from("Content-Type");
+    /// assert_eq!(s.to_ascii_lowercase_arc(), "content-type");
+    /// let s = ArcSlice::<str>::from("content-type");
+    /// assert_eq!(s.to_ascii_lowercase_arc().as_ptr(), s.as_ptr());
+    /// ```
+    pub fn to_ascii_lowercase_arc(&self) -> Self {
+        let needs_conversion = self.bytes().any(|b| b.is_ascii_uppercase());
+        self.convert_or_clone(needs_conversion, str::make_ascii_lowercase)
+    }
+
+    /// Returns the string with ASCII letters converted to uppercase, without copy if there is
+    /// none to convert.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<str>::from("Content-Type");
+    /// assert_eq!(s.to_ascii_uppercase_arc(), "CONTENT-TYPE");
+    /// let s = ArcSlice::<str>::from("CONTENT-TYPE");
+    /// assert_eq!(s.to_ascii_uppercase_arc().as_ptr(), s.as_ptr());
+    /// ```
+    pub fn to_ascii_uppercase_arc(&self) -> Self {
+        let needs_conversion = self.bytes().any(|b| b.is_ascii_lowercase());
+        self.convert_or_clone(needs_conversion, str::make_ascii_uppercase)
+    }
+}
+
+#[cfg(not(feature = "oom-handling"))]
+impl<S: Slice + ?Sized, const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool>
+    ArcSlice<S, ArcLayout<ANY_BUFFER, STATIC, SATURATE>>
+{
+    /// Replace the layout of the `ArcSlice`.
+    ///
+    /// The [layouts](crate::layout) must be compatible, see [`FromLayout`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use arc_slice::{
+    ///     layout::{ArcLayout, BoxedSliceLayout, VecLayout},
+    ///     ArcSlice,
+    /// };
+    ///
+    /// let a = ArcSlice::<[u8]>::from(b"hello world");
+    ///
+    /// let b = a.with_layout::<VecLayout>();
+    /// ```
+    pub fn with_layout<L2: FromLayout<ArcLayout<ANY_BUFFER, STATIC, SATURATE>>>(
+        self,
+    ) -> ArcSlice<S, L2> {
+        self.with_layout_impl::<L2, Infallible>().unwrap_checked()
+    }
+}
+
+impl<S: Slice + ?Sized, L: AnyBufferLayout> ArcSlice<S, L> {
+    pub(crate) fn from_dyn_buffer_impl<B: DynBuffer + Buffer<S>, E: AllocErrorImpl>(
+        buffer: B,
+    ) -> Result<Self, (E, B)> {
+        let (arc, start, length) = Arc::new_buffer::<_, E>(buffer)?;
+        let data = L::data_from_arc_buffer::<S, true, B>(arc);
+        Ok(Self::init(start, length, data))
+    }
+
+    pub(crate) fn from_static_impl<E: AllocErrorImpl>(
+        slice: &'static S,
+    ) -> Result<Self, (E, &'static S)> {
+        let (start, length) = slice.to_raw_parts();
+        Ok(Self::init(
+            start,
+            length,
+            L::data_from_static::<_, E>(slice)?,
+        ))
+    }
+
+    fn from_buffer_impl<B: Buffer<S>, E: AllocErrorImpl>(mut buffer: B) -> Result<Self, (E, B)> {
+        match try_transmute::<B, &'static S>(buffer) {
+            Ok(slice) => {
+                return Self::from_static_impl::<E>(slice)
+                    .map_err(|(err, s)| (err, transmute_checked(s)))
+            }
+            Err(b) => buffer = b,
+        }
+        if let Some(slice) = buffer.as_static() {
+            return match Self::from_static_impl::<E>(slice) {
+                Ok(this) => Ok(this),
+                Err((err, _)) => Err((err, buffer)),
+            };
+        }
+        match try_transmute::<B, Box<S>>(buffer) {
+            Ok(boxed) => {
+                let vec = unsafe { S::from_vec_unchecked(boxed.into_boxed_slice().into_vec()) };
+                return match Self::from_vec_impl::<E>(vec) {
+                    Ok(this) => Ok(this),
+                    Err((err, vec)) => Err((
+                        err,
+                        transmute_checked(unsafe {
+                            S::from_boxed_slice_unchecked(S::into_vec(vec).into_boxed_slice())
+                        }),
+                    )),
+                };
+            }
+            Err(b) => buffer = b,
+        }
+        match try_transmute::<B, S::Vec>(buffer) {
+            Ok(vec) => {
+                return Self::from_vec_impl::<E>(vec)
+                    .map_err(|(err, v)| (err, transmute_checked(v)))
+            }
+            Err(b) => buffer = b,
+        }
+        Self::from_dyn_buffer_impl::<_, E>(BufferWithMetadata::new(buffer, ()))
+            .map_err(|(err, b)| (err, b.buffer()))
+    }
+
+    /// Creates a new `ArcSlice` with the given underlying buffer.
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer),
+    /// unless it is backed by static data according to [`Buffer::as_static`], in which case
+    /// the static slice is used directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer(vec![0, 1, 2]);
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_buffer<B: Buffer<S>>(buffer: B) -> Self {
+        Self::from_buffer_impl::<_, Infallible>(buffer).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` with the given underlying buffer, returning it if an
+    /// allocation fails.
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer).
+    ///
+    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
+    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::try_from_buffer(vec![0, 1, 2]).unwrap();
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// ```
+    pub fn try_from_buffer<B: Buffer<S>>(buffer: B) -> Result<Self, B> {
+        Self::from_buffer_impl::<_, AllocError>(buffer).map_err(|(_, buffer)| buffer)
+    }
+
+    fn from_buffer_with_metadata_impl<B: Buffer<S>, M: Send + Sync + 'static, E: AllocErrorImpl>(
+        buffer: B,
+        metadata: M,
+    ) -> Result<Self, (E, (B, M))> {
+        if is!(M, ()) {
+            return Self::from_buffer_impl::<_, E>(buffer).map_err(|(err, b)| (err, (b, metadata)));
+        }
+        Self::from_dyn_buffer_impl::<_, E>(BufferWithMetadata::new(buffer, metadata))
+            .map_err(|(err, b)| (err, b.into_tuple()))
+    }
+
+    /// Creates a new `ArcSlice` with the given underlying buffer and its associated metadata.
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
+    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let metadata = "metadata".to_string();
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], metadata);
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<String>().unwrap(), "metadata");
+    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_buffer_with_metadata<B: Buffer<S>, M: Send + Sync + 'static>(
+        buffer: B,
+        metadata: M,
+    ) -> Self {
+        Self::from_buffer_with_metadata_impl::<_, _, Infallible>(buffer, metadata)
+            .unwrap_infallible()
+    }
+
+    /// Tries creates a new `ArcSlice` with the given underlying buffer and its associated metadata,
+    /// returning them if an allocation fails.
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
+    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    ///
+    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
+    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let metadata = "metadata".to_string();
+    /// let s =
+    ///     ArcSlice::<[u8], ArcLayout<true>>::try_from_buffer_with_metadata(vec![0, 1, 2], metadata)
+    ///         .unwrap();
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<String>().unwrap(), "metadata");
+    /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), vec![0, 1, 2]);
+    /// ```
+    pub fn try_from_buffer_with_metadata<B: Buffer<S>, M: Send + Sync + 'static>(
+        buffer: B,
+        metadata: M,
+    ) -> Result<Self, (B, M)> {
+        Self::from_buffer_with_metadata_impl::<_, _, AllocError>(buffer, metadata)
+            .map_err(|(_, bm)| bm)
+    }
+
+    fn from_buffer_with_metadata_chain_impl<B: Buffer<S>, M: MetadataChain, E: AllocErrorImpl>(
+        buffer: B,
+        metadata: M,
+    ) -> Result<Self, (E, (B, M))> {
+        Self::from_dyn_buffer_impl::<_, E>(BufferWithMetadata::new_chain(buffer, metadata))
+            .map_err(|(err, b)| (err, b.into_tuple()))
+    }
+
+    /// Creates a new `ArcSlice` with the given underlying buffer and a chain of metadata.
+    ///
+    /// Contrary to [`from_buffer_with_metadata`](Self::from_buffer_with_metadata), each element
+    /// of the [chain](MetadataChain) can be retrieved with [`metadata`](Self::metadata), as well
+    /// as the chain itself. The buffer can be extracted back using
+    /// [`try_into_buffer`](Self::try_into_buffer).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcBytes};
+    ///
+    /// let metadata = ("path".to_string(), 42u64);
+    /// let s = ArcBytes::<ArcLayout<true>>::from_buffer_with_metadata_chain(vec![0, 1, 2], metadata);
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<String>().unwrap(), "path");
+    /// assert_eq!(*s.metadata::<u64>().unwrap(), 42);
+    /// assert!(s.metadata::<u32>().is_none());
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_buffer_with_metadata_chain<B: Buffer<S>, M: MetadataChain>(
+        buffer: B,
+        metadata: M,
+    ) -> Self {
+        Self::from_buffer_with_metadata_chain_impl::<_, _, Infallible>(buffer, metadata)
+            .unwrap_infallible()
+    }
+
+    /// Tries creates a new `ArcSlice` with the given underlying buffer and a chain of metadata,
+    /// returning them if an allocation fails.
+    ///
+    /// See [`from_buffer_with_metadata_chain`](Self::from_buffer_with_metadata_chain).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcBytes};
+    ///
+    /// let metadata = ("path".to_string(), 42u64);
+    /// let s =
+    ///     ArcBytes::<ArcLayout<true>>::try_from_buffer_with_metadata_chain(vec![0, 1, 2], metadata)
+    ///         .unwrap();
+    /// assert_eq!(*s.metadata::<u64>().unwrap(), 42);
+    /// ```
+    pub fn try_from_buffer_with_metadata_chain<B: Buffer<S>, M: MetadataChain>(
+        buffer: B,
+        metadata: M,
+    ) -> Result<Self, (B, M)> {
+        Self::from_buffer_with_metadata_chain_impl::<_, _, AllocError>(buffer, metadata)
+            .map_err(|(_, bm)| bm)
+    }
+
+    /// Attaches metadata to the underlying buffer, if the `ArcSlice` is unique, returning the
+    /// `ArcSlice` and the metadata otherwise.
+    ///
+    /// If the buffer is a vector, it is taken out, as returned by
+    /// [`try_into_buffer`](Self::try_into_buffer), and used as the buffer of the new `ArcSlice`,
+    /// along with the metadata, which replaces the previous one, if any. Otherwise, the items are
+    /// moved into a new vector, and the previous buffer is released; items needing drop are not
+    /// supported in this case, as the previous buffer would drop them. The vector can then be
+    /// extracted back with [`try_into_buffer_with_metadata`](Self::try_into_buffer_with_metadata).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    ///
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let mut s = ArcSlice::<[u8], ArcLayout<true>>::from(b"hello world".to_vec());
+    /// s.advance(6);
+    /// let s = s.with_metadata(PathBuf::from("README.md")).unwrap();
+    /// assert_eq!(s, b"world");
+    /// assert_eq!(
+    ///     s.metadata::<PathBuf>().unwrap(),
+    ///     &PathBuf::from("README.md")
+    /// );
+    ///
+    /// let clone = s.clone();
+    /// let (s, metadata) = s.with_metadata(42).unwrap_err();
+    /// assert_eq!((s, metadata), (clone, 42));
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn with_metadata<M: Send + Sync + 'static>(self, metadata: M) -> Result<Self, (Self, M)> {
+        let this = match self.try_into_buffer::<S::Vec>() {
+            Ok(vec) => return Ok(Self::from_buffer_with_metadata(vec, metadata)),
+            Err(this) => this,
+        };
+        if S::needs_drop() || !this.is_unique() {
+            return Err((this, metadata));
+        }
+        // items without drop glue are moved bitwise, the unique buffer being released right after
+        let mut vec = Vec::with_capacity(this.length);
+        unsafe {
+            ptr::copy_nonoverlapping(this.start.as_ptr(), vec.as_mut_ptr(), this.length);
+            vec.set_len(this.length);
+        }
+        drop(this);
+        let vec = unsafe { S::from_vec_unchecked(vec) };
+        Ok(Self::from_buffer_with_metadata(vec, metadata))
+    }
+
+    /// Creates a new `ArcSlice` with the given underlying buffer with borrowed metadata.
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
+    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{
+    ///     buffer::{BorrowMetadata, Buffer},
+    ///     layout::ArcLayout,
+    ///     ArcSlice,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyBuffer(Vec<u8>);
+    /// impl Buffer<[u8]> for MyBuffer {
+    ///     fn as_slice(&self) -> &[u8] {
+    ///         &self.0
+    ///     }
+    /// }
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyMetadata;
+    /// impl BorrowMetadata for MyBuffer {
+    ///     type Metadata = MyMetadata;
+    ///     fn borrow_metadata(&self) -> &Self::Metadata {
+    ///         &MyMetadata
+    ///     }
+    /// }
+    /// let buffer = MyBuffer(vec![0, 1, 2]);
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_buffer_with_borrowed_metadata(buffer);
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
+    /// assert_eq!(
+    ///     s.try_into_buffer::<MyBuffer>().unwrap(),
+    ///     MyBuffer(vec![0, 1, 2])
+    /// );
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_buffer_with_borrowed_metadata<B: Buffer<S> + BorrowMetadata>(buffer: B) -> Self {
+        Self::from_dyn_buffer_impl::<_, Infallible>(buffer).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` with the given underlying buffer with borrowed metadata,
+    /// returning it if an allocation fails.
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
+    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    ///
+    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
+    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{
+    ///     buffer::{BorrowMetadata, Buffer},
+    ///     layout::ArcLayout,
+    ///     ArcSlice,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyBuffer(Vec<u8>);
+    /// impl Buffer<[u8]> for MyBuffer {
+    ///     fn as_slice(&self) -> &[u8] {
+    ///         &self.0
+    ///     }
+    /// }
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyMetadata;
+    /// impl BorrowMetadata for MyBuffer {
+    ///     type Metadata = MyMetadata;
+    ///     fn borrow_metadata(&self) -> &Self::Metadata {
+    ///         &MyMetadata
+    ///     }
+    /// }
+    /// let buffer = MyBuffer(vec![0, 1, 2]);
+    /// let s =
+    ///     ArcSlice::<[u8], ArcLayout<true>>::try_from_buffer_with_borrowed_metadata(buffer).unwrap();
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
+    /// assert_eq!(
+    ///     s.try_into_buffer::<MyBuffer>().unwrap(),
+    ///     MyBuffer(vec![0, 1, 2])
+    /// );
+    /// ```
+    pub fn try_from_buffer_with_borrowed_metadata<B: Buffer<S> + BorrowMetadata>(
+        buffer: B,
+    ) -> Result<Self, B> {
+        Self::from_dyn_buffer_impl::<_, AllocError>(buffer).map_err(|(_, buffer)| buffer)
+    }
+
+    fn from_owner_impl<O: Send + Sync + 'static, E: AllocErrorImpl>(
+        owner: O,
+        slice: impl FnOnce(&O) -> &S,
+    ) -> Result<Self, (E, O)>
+    where
+        S: Emptyable,
+    {
+        let mut this = Self::from_dyn_buffer_impl::<_, E>(OwnerBuffer(owner))
+            .map_err(|(err, buffer)| (err, buffer.0))?;
+        // the owner is only borrowed once stored in the Arc, so the slice can point into it
+        let owner = L::get_metadata::<S, O>(&this.data).unwrap_checked();
+        (this.start, this.length) = slice(owner).to_raw_parts();
+        Ok(this)
+    }
+
+    /// Creates a new `ArcSlice` from an owner, and the slice borrowed from it.
+    ///
+    /// The owner is moved into an Arc before `slice` is called, so the slice can borrow the
+    /// owner itself, not only the memory it points to. The owner is dropped with the last
+    /// `ArcSlice` referencing it, including subslices; it can be retrieved with
+    /// [`metadata`](Self::metadata).
+    ///
+    /// Contrary to [`from_buffer`](Self::from_buffer) with [`AsRefBuffer`], the owner doesn't
+    /// need to implement [`AsRef`], and the projection can be any slice of it.
+    ///
+    /// [`AsRefBuffer`]: crate::buffer::AsRefBuffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::fs::File;
+    ///
+    /// use arc_slice::{layout::ArcLayout, ArcBytes};
+    /// use memmap2::Mmap;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// # #[cfg(not(miri))]
+    /// # {
+    /// let file = File::open("README.md")?;
+    /// // SAFETY: the file is not modified while mapped
+    /// let mmap = unsafe { Mmap::map(&file)? };
+    /// // skip the markdown title prefix
+    /// let bytes = ArcBytes::<ArcLayout<true>>::from_owner(mmap, |mmap| &mmap[2..]);
+    /// assert!(bytes.starts_with(b"arc-slice"));
+    /// assert!(bytes.metadata::<Mmap>().is_some());
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_owner<O: Send + Sync + 'static>(owner: O, slice: impl FnOnce(&O) -> &S) -> Self
+    where
+        S: Emptyable,
+    {
+        Self::from_owner_impl::<_, Infallible>(owner, slice).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` from an owner, and the slice borrowed from it, returning
+    /// the owner if an allocation fails.
+    ///
+    /// See [`from_owner`](Self::from_owner).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcBytes};
+    ///
+    /// let owner = (42, *b"hello world");
+    /// let bytes = ArcBytes::<ArcLayout<true>>::try_from_owner(owner, |(_, b)| &b[..5]).unwrap();
+    /// assert_eq!(bytes, b"hello");
+    /// ```
+    pub fn try_from_owner<O: Send + Sync + 'static>(
+        owner: O,
+        slice: impl FnOnce(&O) -> &S,
+    ) -> Result<Self, O>
+    where
+        S: Emptyable,
+    {
+        Self::from_owner_impl::<_, AllocError>(owner, slice).map_err(|(_, owner)| owner)
+    }
+
+    fn from_cow_impl<E: AllocErrorImpl>(cow: Cow<'static, S>) -> Result<Self, (E, Cow<'static, S>)>
+    where
+        S: ToOwned<Owned = S::Vec>,
+    {
+        match cow {
+            Cow::Borrowed(slice) => {
+                Self::from_static_impl::<E>(slice).map_err(|(err, s)| (err, Cow::Borrowed(s)))
+            }
+            Cow::Owned(vec) => {
+                Self::from_vec_impl::<E>(vec).map_err(|(err, v)| (err, Cow::Owned(v)))
+            }
+        }
+    }
+
+    /// Tries creating a new `ArcSlice` from a [`Cow`], returning it if an allocation fails.
+    ///
+    /// A borrowed slice is stored as a static one, without allocation if the layout implements
+    /// [`StaticLayout`], while an owned one is stored like a vector, see [`From<Vec<T>>`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// use arc_slice::{layout::ArcLayout, ArcStr};
+    ///
+    /// let s = ArcStr::<ArcLayout<true, true>>::try_from_cow(Cow::Borrowed("hello")).unwrap();
+    /// assert!(s.is_static());
+    /// let s = ArcStr::<ArcLayout<true, true>>::try_from_cow(Cow::Owned("hello".into())).unwrap();
+    /// assert!(!s.is_static());
+    /// ```
+    pub fn try_from_cow(cow: Cow<'static, S>) -> Result<Self, Cow<'static, S>>
+    where
+        S: ToOwned<Owned = S::Vec>,
+    {
+        Self::from_cow_impl::<AllocError>(cow).map_err(|(_, cow)| cow)
+    }
+
+    #[cfg(feature = "raw-buffer")]
+    fn from_raw_buffer_impl<B: DynBuffer + RawBuffer<S>, E: AllocErrorImpl>(
+        buffer: B,
+    ) -> Result<Self, (E, B)> {
+        let ptr = buffer.into_raw();
+        if let Some(data) = L::data_from_raw_buffer::<S, B>(ptr) {
+            let buffer = ManuallyDrop::new(unsafe { B::from_raw(ptr) });
+            let (start, length) = buffer.as_slice().to_raw_parts();
+            return Ok(Self::init(start, length, data));
+        }
+        Self::from_dyn_buffer_impl::<_, E>(unsafe { B::from_raw(ptr) })
+    }
+
+    /// Creates a new `ArcSlice` with the given underlying raw buffer.
+    ///
+    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
+    /// the same as [`from_buffer`](Self::from_buffer).
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "portable-atomic-util"))]
+    /// use std::sync::Arc;
+    ///
+    /// # #[cfg(feature = "portable-atomic-util")]
+    /// # use portable_atomic_util::Arc;
+    /// use arc_slice::{layout::RawLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], RawLayout>::from_raw_buffer(Arc::new(vec![0, 1, 2]));
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(
+    ///     s.try_into_buffer::<Arc<Vec<u8>>>().unwrap(),
+    ///     Arc::new(vec![0, 1, 2])
+    /// );
+    /// ```
+    #[cfg(all(feature = "raw-buffer", feature = "oom-handling"))]
+    pub fn from_raw_buffer<B: RawBuffer<S>>(buffer: B) -> Self {
+        Self::from_raw_buffer_impl::<_, Infallible>(BufferWithMetadata::new(buffer, ()))
+            .unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` with the given underlying raw buffer, returning it if an
+    /// allocation fails.
+    ///
+    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
+    /// the same as [`try_from_buffer`](Self::try_from_buffer).
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "portable-atomic-util"))]
+    /// use std::sync::Arc;
+    ///
+    /// # #[cfg(feature = "portable-atomic-util")]
+    /// # use portable_atomic_util::Arc;
+    /// use arc_slice::{layout::RawLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], RawLayout>::try_from_raw_buffer(Arc::new(vec![0, 1, 2])).unwrap();
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(
+    ///     s.try_into_buffer::<Arc<Vec<u8>>>().unwrap(),
+    ///     Arc::new(vec![0, 1, 2])
+    /// );
+    /// ```
+    #[cfg(feature = "raw-buffer")]
+    pub fn try_from_raw_buffer<B: RawBuffer<S>>(buffer: B) -> Result<Self, B> {
+        Self::from_raw_buffer_impl::<_, AllocError>(BufferWithMetadata::new(buffer, ()))
+            .map_err(|(_, b)| b.buffer())
+    }
+
+    /// Creates a new `ArcSlice` with the given underlying raw buffer with borrowed metadata.
+    ///
+    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
+    /// the same as [`from_buffer`](Self::from_buffer).
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
+    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "portable-atomic-util"))]
+    /// use std::sync::Arc;
+    ///
+    /// # #[cfg(feature = "portable-atomic-util")]
+    /// # use portable_atomic_util::Arc;
+    /// ///
+    /// use arc_slice::buffer::{BorrowMetadata, Buffer};
+    /// use arc_slice::{layout::RawLayout, ArcSlice};
+    ///
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyBuffer(Vec<u8>);
+    /// impl Buffer<[u8]> for MyBuffer {
+    ///     fn as_slice(&self) -> &[u8] {
+    ///         &self.0
+    ///     }
+    /// }
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyMetadata;
+    /// impl BorrowMetadata for MyBuffer {
+    ///     type Metadata = MyMetadata;
+    ///     fn borrow_metadata(&self) -> &Self::Metadata {
+    ///         &MyMetadata
+    ///     }
+    /// }
+    ///
+    /// let buffer = Arc::new(MyBuffer(vec![0, 1, 2]));
+    /// let s = ArcSlice::<[u8], RawLayout>::from_raw_buffer_with_borrowed_metadata(buffer);
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
+    /// assert_eq!(
+    ///     s.try_into_buffer::<Arc<MyBuffer>>().unwrap(),
+    ///     Arc::new(MyBuffer(vec![0, 1, 2]))
+    /// );
+    /// ```
+    #[cfg(all(feature = "raw-buffer", feature = "oom-handling"))]
+    pub fn from_raw_buffer_with_borrowed_metadata<B: RawBuffer<S> + BorrowMetadata>(
+        buffer: B,
+    ) -> Self {
+        Self::from_dyn_buffer_impl::<_, Infallible>(buffer).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` with the given underlying raw buffer with borrowed metadata,
+    /// returning it if an allocation fails.
+    ///
+    /// For [layouts](crate::layout) others than [`RawLayout`](crate::layout::RawLayout), it is
+    /// the same as [`from_buffer`](Self::from_buffer).
+    ///
+    /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
+    /// metadata can be retrieved with [`metadata`](Self::metadata).
+    ///
+    /// Having an Arc allocation depends on the [layout](crate::layout) and the buffer type,
+    /// e.g. there will be no allocation for a `Vec` with [`VecLayout`](crate::layout::VecLayout).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "portable-atomic-util"))]
+    /// use std::sync::Arc;
+    ///
+    /// # #[cfg(feature = "portable-atomic-util")]
+    /// # use portable_atomic_util::Arc;
+    /// ///
+    /// use arc_slice::buffer::{BorrowMetadata, Buffer};
+    /// use arc_slice::{layout::RawLayout, ArcSlice};
+    ///
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyBuffer(Vec<u8>);
+    /// impl Buffer<[u8]> for MyBuffer {
+    ///     fn as_slice(&self) -> &[u8] {
+    ///         &self.0
+    ///     }
+    /// }
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyMetadata;
+    /// impl BorrowMetadata for MyBuffer {
+    ///     type Metadata = MyMetadata;
+    ///     fn borrow_metadata(&self) -> &Self::Metadata {
+    ///         &MyMetadata
+    ///     }
+    /// }
+    ///
+    /// let buffer = Arc::new(MyBuffer(vec![0, 1, 2]));
+    /// let s =
+    ///     ArcSlice::<[u8], RawLayout>::try_from_raw_buffer_with_borrowed_metadata(buffer).unwrap();
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<MyMetadata>().unwrap(), &MyMetadata);
+    /// assert_eq!(
+    ///     s.try_into_buffer::<Arc<MyBuffer>>().unwrap(),
+    ///     Arc::new(MyBuffer(vec![0, 1, 2]))
+    /// );
+    /// ```
+    #[cfg(feature = "raw-buffer")]
+    pub fn try_from_raw_buffer_with_borrowed_metadata<B: RawBuffer<S> + BorrowMetadata>(
+        buffer: B,
+    ) -> Result<Self, B> {
+        Self::from_dyn_buffer_impl::<_, AllocError>(buffer).map_err(|(_, buffer)| buffer)
+    }
+
+    fn from_slice_in_impl<A: BufferSource, E: AllocErrorImpl>(
+        slice: &S,
+        alloc: A,
+    ) -> Result<Self, E>
+    where
+        S::Item: Copy,
+    {
+        let (arc, start) = Arc::<S>::new_in::<A, E>(slice, alloc)?;
+        Ok(Self::init(start, slice.len(), L::data_from_arc(arc)))
+    }
+
+    /// Creates a new `ArcSlice` by copying the given slice into a buffer allocated from the given
+    /// source.
+    ///
+    /// The [source](BufferSource) is stored in the inner Arc, next to the buffer, and is used for
+    /// deallocation. The inner Arc and the buffer are always allocated, even if the slice is
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// # use std::{alloc::Layout, ptr::NonNull};
+    /// # use arc_slice::{buffer::BufferSource, error::AllocError};
+    /// # struct Heap;
+    /// # unsafe impl BufferSource for Heap {
+    /// #     fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+    /// #         NonNull::new(unsafe { std::alloc::alloc(layout) }).ok_or(AllocError)
+    /// #     }
+    /// #     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+    /// #         unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) };
+    /// #     }
+    /// # }
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from_slice_in(b"hello world", Heap);
+    /// assert_eq!(s, b"hello world");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_slice_in<A: BufferSource>(slice: &S, alloc: A) -> Self
+    where
+        S::Item: Copy,
+    {
+        Self::from_slice_in_impl::<_, Infallible>(slice, alloc).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSlice` by copying the given slice into a buffer allocated from
+    /// the given source, returning an error if an allocation fails.
+    ///
+    /// See [`from_slice_in`](Self::from_slice_in).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// # use std::{alloc::Layout, ptr::NonNull};
+    /// # use arc_slice::{buffer::BufferSource, error::AllocError};
+    /// # struct Heap;
+    /// # unsafe impl BufferSource for Heap {
+    /// #     fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+    /// #         NonNull::new(unsafe { std::alloc::alloc(layout) }).ok_or(AllocError)
+    /// #     }
+    /// #     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+    /// #         unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) };
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::try_from_slice_in(b"hello world", Heap)?;
+    /// assert_eq!(s, b"hello world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_slice_in<A: BufferSource>(slice: &S, alloc: A) -> Result<Self, AllocError>
+    where
+        S::Item: Copy,
+    {
+        Self::from_slice_in_impl::<_, AllocError>(slice, alloc)
+    }
+}
+
+impl<S: Emptyable + ?Sized, L: Layout> ArcSlice<S, L> {
+    /// An empty `ArcSlice`, equivalent to [`ArcSlice::new`].
+    ///
+    /// It can be used where a constant is required, e.g. to initialize array items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// type Bytes = ArcSlice<[u8], ArcLayout<false, false>>;
+    /// static EMPTY: Bytes = Bytes::EMPTY;
+    /// let slices = [Bytes::EMPTY; 4];
+    /// assert_eq!(EMPTY, []);
+    /// assert!(slices.iter().all(|s| s.is_empty()));
+    /// ```
+    pub const EMPTY: Self = Self::new();
+}
+
+impl<L: StaticLayout> ArcSlice<[u8], L> {
+    /// Creates a new `ArcSlice` from a static slice.
+    ///
+    /// The operation never allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// static HELLO_WORLD: ArcSlice<[u8], ArcLayout<true, true>> =
+    ///     ArcSlice::<[u8], ArcLayout<true, true>>::from_static(b"hello world");
+    /// ```
+    pub const fn from_static(slice: &'static [u8]) -> Self {
+        // MSRV 1.65 const `<*const _>::cast_mut` + 1.85 const `NonNull::new`
+        let start = unsafe { NonNull::new_unchecked(slice.as_ptr() as _) };
+        let length = slice.len();
+        let data = unsafe { L::STATIC_DATA_UNCHECKED.assume_init() };
+        Self::init(start, length, data)
+    }
+}
+
+impl<L: StaticLayout> ArcSlice<str, L> {
+    /// Creates a new `ArcSlice` from a static str.
+    ///
+    /// The operation never allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// static HELLO_WORLD: ArcSlice<str, ArcLayout<true, true>> =
+    ///     ArcSlice::<str, ArcLayout<true, true>>::from_static("hello world");
+    /// ```
+    pub const fn from_static(slice: &'static str) -> Self {
+        // MSRV 1.65 const `<*const _>::cast_mut` + 1.85 const `NonNull::new`
+        let start = unsafe { NonNull::new_unchecked(slice.as_ptr() as _) };
+        let length = slice.len();
+        let data = unsafe { L::STATIC_DATA_UNCHECKED.assume_init() };
+        Self::init(start, length, data)
+    }
+}
+
+impl<S: Slice + ?Sized, L: Layout> Drop for ArcSlice<S, L> {
+    fn drop(&mut self) {
+        unsafe { L::drop::<S, false>(self.start, self.length, &mut self.data) };
+    }
+}
+
+impl<
+        S: Slice + ?Sized,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > Clone for ArcSlice<S, L>
+{
+    fn clone(&self) -> Self {
+        self.clone_impl::<Infallible>().unwrap_infallible()
+    }
+}
+
+impl<S: Slice + ?Sized, L: Layout> Deref for ArcSlice<S, L> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<S: Slice + ?Sized, L: Layout> AsRef<S> for ArcSlice<S, L> {
+    fn as_ref(&self) -> &S {
+        self
+    }
+}
+
+impl<S: Hash + Slice + ?Sized, L: Layout> Hash for ArcSlice<S, L> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<S: Slice + ?Sized, L: Layout> Borrow<S> for ArcSlice<S, L> {
+    fn borrow(&self) -> &S {
+        self
+    }
+}
+
+impl<S: Emptyable + ?Sized, L: Layout> Default for ArcSlice<S, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: fmt::Debug + Slice + ?Sized, L: Layout> fmt::Debug for ArcSlice<S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_slice(self.as_slice(), f)
+    }
+}
+
+impl<S: fmt::Display + Slice + ?Sized, L: Layout> fmt::Display for ArcSlice<S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<S: Slice<Item = u8> + ?Sized, L: Layout> fmt::LowerHex for ArcSlice<S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        lower_hex(self.to_slice(), f)
+    }
+}
+
+impl<S: Slice<Item = u8> + ?Sized, L: Layout> fmt::UpperHex for ArcSlice<S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        upper_hex(self.to_slice(), f)
+    }
+}
+
+impl<S: PartialEq + Slice + ?Sized, L: Layout> PartialEq for ArcSlice<S, L> {
+    fn eq(&self, other: &ArcSlice<S, L>) -> bool {
         self.as_slice() == other.as_slice()
     }
-}
+}
+
+impl<S: PartialEq + Slice + ?Sized, L: Layout> Eq for ArcSlice<S, L> {}
+
+impl<S: PartialOrd + Slice + ?Sized, L: Layout> PartialOrd for ArcSlice<S, L> {
+    fn partial_cmp(&self, other: &ArcSlice<S, L>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<S: Ord + Slice + ?Sized, L: Layout> Ord for ArcSlice<S, L> {
+    fn cmp(&self, other: &ArcSlice<S, L>) -> cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<S: PartialEq + Slice + ?Sized, L: Layout> PartialEq<S> for ArcSlice<S, L> {
+    fn eq(&self, other: &S) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a, S: PartialEq + Slice + ?Sized, L: Layout> PartialEq<&'a S> for ArcSlice<S, L> {
+    fn eq(&self, other: &&'a S) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static, L: Layout, const N: usize> PartialEq<[T; N]>
+    for ArcSlice<[T], L>
+{
+    fn eq(&self, other: &[T; N]) -> bool {
+        *other == **self
+    }
+}
+
+impl<'a, T: PartialEq + Send + Sync + 'static, L: Layout, const N: usize> PartialEq<&'a [T; N]>
+    for ArcSlice<[T], L>
+{
+    fn eq(&self, other: &&'a [T; N]) -> bool {
+        **other == **self
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static, L: Layout, const N: usize> PartialEq<ArcSlice<[T], L>>
+    for [T; N]
+{
+    fn eq(&self, other: &ArcSlice<[T], L>) -> bool {
+        **other == *self
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static, L: Layout> PartialEq<ArcSlice<[T], L>> for [T] {
+    fn eq(&self, other: &ArcSlice<[T], L>) -> bool {
+        **other == *self
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<str, L>> for str {
+    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
+        **other == *self
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static, L: Layout> PartialEq<Vec<T>> for ArcSlice<[T], L> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        **self == **other
+    }
+}
+
+impl<L: Layout> PartialEq<String> for ArcSlice<str, L> {
+    fn eq(&self, other: &String) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static, L: Layout> PartialEq<ArcSlice<[T], L>> for Vec<T> {
+    fn eq(&self, other: &ArcSlice<[T], L>) -> bool {
+        **self == **other
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<str, L>> for String {
+    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
+        **self == **other
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<[u8], L>> for ArcSlice<str, L> {
+    fn eq(&self, other: &ArcSlice<[u8], L>) -> bool {
+        self.as_bytes() == &**other
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<str, L>> for ArcSlice<[u8], L> {
+    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
+        &**self == other.as_bytes()
+    }
+}
+
+impl<L: Layout> PartialOrd<ArcSlice<[u8], L>> for ArcSlice<str, L> {
+    fn partial_cmp(&self, other: &ArcSlice<[u8], L>) -> Option<cmp::Ordering> {
+        Some(self.as_bytes().cmp(other))
+    }
+}
+
+impl<L: Layout> PartialOrd<ArcSlice<str, L>> for ArcSlice<[u8], L> {
+    fn partial_cmp(&self, other: &ArcSlice<str, L>) -> Option<cmp::Ordering> {
+        Some((**self).cmp(other.as_bytes()))
+    }
+}
+
+impl<L: Layout> PartialEq<[u8]> for ArcSlice<str, L> {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+impl<'a, L: Layout> PartialEq<&'a [u8]> for ArcSlice<str, L> {
+    fn eq(&self, other: &&'a [u8]) -> bool {
+        self.as_bytes() == *other
+    }
+}
+
+impl<L: Layout, const N: usize> PartialEq<[u8; N]> for ArcSlice<str, L> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+impl<'a, L: Layout, const N: usize> PartialEq<&'a [u8; N]> for ArcSlice<str, L> {
+    fn eq(&self, other: &&'a [u8; N]) -> bool {
+        self.as_bytes() == *other
+    }
+}
+
+impl<L: Layout> PartialEq<Vec<u8>> for ArcSlice<str, L> {
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        self.as_bytes() == other.as_slice()
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<str, L>> for [u8] {
+    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
+        self == other.as_bytes()
+    }
+}
+
+impl<L: Layout, const N: usize> PartialEq<ArcSlice<str, L>> for [u8; N] {
+    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
+        self == other.as_bytes()
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<str, L>> for Vec<u8> {
+    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
+        self.as_slice() == other.as_bytes()
+    }
+}
+
+impl<L: Layout> PartialEq<str> for ArcSlice<[u8], L> {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other.as_bytes()
+    }
+}
+
+impl<'a, L: Layout> PartialEq<&'a str> for ArcSlice<[u8], L> {
+    fn eq(&self, other: &&'a str) -> bool {
+        **self == *other.as_bytes()
+    }
+}
+
+impl<L: Layout> PartialEq<String> for ArcSlice<[u8], L> {
+    fn eq(&self, other: &String) -> bool {
+        **self == *other.as_bytes()
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<[u8], L>> for str {
+    fn eq(&self, other: &ArcSlice<[u8], L>) -> bool {
+        *self.as_bytes() == **other
+    }
+}
+
+impl<L: Layout> PartialEq<ArcSlice<[u8], L>> for String {
+    fn eq(&self, other: &ArcSlice<[u8], L>) -> bool {
+        *self.as_bytes() == **other
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<S: Slice + ?Sized, L: Layout> From<&S> for ArcSlice<S, L>
+where
+    S::Item: Copy,
+{
+    fn from(value: &S) -> Self {
+        Self::from_slice(value)
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<T: Copy + Send + Sync + 'static, L: Layout, const N: usize> From<&[T; N]>
+    for ArcSlice<[T], L>
+{
+    fn from(value: &[T; N]) -> Self {
+        Self::from_slice(value)
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<T: Send + Sync + 'static, L: Layout, const N: usize> From<[T; N]> for ArcSlice<[T], L> {
+    fn from(value: [T; N]) -> Self {
+        Self::from_array(value)
+    }
+}
+
+#[cfg(not(feature = "oom-handling"))]
+impl<S: Slice + ?Sized> From<Box<S>> for ArcSlice<S, BoxedSliceLayout> {
+    fn from(value: Box<S>) -> Self {
+        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+    }
+}
+#[cfg(not(feature = "oom-handling"))]
+impl<S: Slice + ?Sized> From<Box<S>> for ArcSlice<S, VecLayout> {
+    fn from(value: Box<S>) -> Self {
+        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+    }
+}
+#[cfg(feature = "oom-handling")]
+impl<S: Slice + ?Sized, L: AnyBufferLayout> From<Box<S>> for ArcSlice<S, L> {
+    fn from(value: Box<S>) -> Self {
+        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+    }
+}
+
+#[cfg(not(feature = "oom-handling"))]
+impl<T: Send + Sync + 'static> From<Vec<T>> for ArcSlice<[T], VecLayout> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_vec(value)
+    }
+}
+#[cfg(feature = "oom-handling")]
+impl<T: Send + Sync + 'static, L: AnyBufferLayout> From<Vec<T>> for ArcSlice<[T], L> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_vec(value)
+    }
+}
+
+#[cfg(not(feature = "oom-handling"))]
+impl From<String> for ArcSlice<str, crate::layout::VecLayout> {
+    fn from(value: String) -> Self {
+        Self::from_vec(value)
+    }
+}
+#[cfg(feature = "oom-handling")]
+impl<L: AnyBufferLayout> From<String> for ArcSlice<str, L> {
+    fn from(value: String) -> Self {
+        Self::from_vec(value)
+    }
+}
+
+#[cfg(not(feature = "oom-handling"))]
+impl<S: Slice + ToOwned<Owned = S::Vec> + ?Sized> From<Cow<'static, S>> for ArcSlice<S, VecLayout> {
+    fn from(value: Cow<'static, S>) -> Self {
+        Self::from_cow_impl::<Infallible>(value).unwrap_infallible()
+    }
+}
+#[cfg(feature = "oom-handling")]
+impl<S: Slice + ToOwned<Owned = S::Vec> + ?Sized, L: AnyBufferLayout> From<Cow<'static, S>>
+    for ArcSlice<S, L>
+{
+    fn from(value: Cow<'static, S>) -> Self {
+        Self::from_cow_impl::<Infallible>(value).unwrap_infallible()
+    }
+}
+
+impl<T: Send + Sync + 'static, L: Layout, const N: usize> TryFrom<ArcSlice<[T], L>> for [T; N] {
+    type Error = ArcSlice<[T], L>;
+    fn try_from(value: ArcSlice<[T], L>) -> Result<Self, Self::Error> {
+        let mut this = ManuallyDrop::new(value);
+        unsafe { L::take_array::<T, N>(this.start, this.length, &mut this.data) }
+            .ok_or_else(|| ManuallyDrop::into_inner(this))
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+impl<L: Layout> core::str::FromStr for ArcSlice<str, L> {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    extern crate std;
+
+    impl<L: Layout> std::io::Read for ArcSlice<[u8], L> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = cmp::min(self.len(), buf.len());
+            buf[..n].copy_from_slice(&self[..n]);
+            self.advance(n);
+            Ok(n)
+        }
+    }
+
+    impl<S: Slice<Item = u8> + ?Sized, L: Layout> std::io::Read for ArcSliceCursor<S, L> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let remaining = self.remaining();
+            let n = cmp::min(remaining.len(), buf.len());
+            buf[..n].copy_from_slice(&remaining[..n]);
+            self.position += n;
+            Ok(n)
+        }
+    }
+
+    impl<S: Slice<Item = u8> + ?Sized, L: Layout> std::io::BufRead for ArcSliceCursor<S, L> {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            Ok(self.remaining())
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.advance_position(amt);
+        }
+    }
+
+    impl<S: Slice + ?Sized, L: Layout> std::io::Seek for ArcSliceCursor<S, L> {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            let (base, offset) = match pos {
+                std::io::SeekFrom::Start(position) => {
+                    self.position = usize::try_from(position).unwrap_or(usize::MAX);
+                    return Ok(position);
+                }
+                std::io::SeekFrom::End(offset) => (self.slice.len(), offset),
+                std::io::SeekFrom::Current(offset) => (self.position, offset),
+            };
+            let position = i64::try_from(base)
+                .ok()
+                .and_then(|base| base.checked_add(offset))
+                .and_then(|position| usize::try_from(position).ok())
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "invalid seek to a negative or overflowing position",
+                    )
+                })?;
+            self.position = position;
+            Ok(position as u64)
+        }
+
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            Ok(self.position as u64)
+        }
+    }
+};
+
+struct OwnerBuffer<O>(O);
+
+impl<S: Emptyable + ?Sized, O: Send + Sync + 'static> Buffer<S> for OwnerBuffer<O> {
+    fn as_slice(&self) -> &S {
+        // the actual slice is set by `ArcSlice::from_owner`
+        unsafe { S::from_raw_parts(NonNull::dangling(), 0) }
+    }
+}
+
+impl<O: Sync + 'static> BorrowMetadata for OwnerBuffer<O> {
+    type Metadata = O;
+    fn borrow_metadata(&self) -> &Self::Metadata {
+        &self.0
+    }
+}
+
+/// A borrowed view of an [`ArcSlice`].
+///
+/// `ArcSliceBorrow` is roughly equivalent to `(&S, &ArcSlice<S, L>)`. A new `ArcSlice` instance
+/// can be obtained with [`clone_arc`].
+/// <br>
+/// One advantage of using `ArcSliceBorrow` is that [`clone_arc`] doesn't need to perform bound
+/// checks that was done at `ArcSliceBorrow` creation; using `(&S, &ArcSlice<S, L>)` however
+/// would require to check bounds a second time when using [`ArcSlice::subslice_from_ref`].
+///
+/// When using [`ArcLayout`], `ArcSliceBorrow` is even more efficient, because it can directly
+/// embed the internal Arc instead of an `ArcSlice` reference, saving one indirection.
+///
+/// Creating and dropping an `ArcSliceBorrow` doesn't touch the refcount of the underlying
+/// buffer, [`clone_arc`] being the only operation that does. Subslices which are inspected
+/// before being kept or discarded should then be borrowed, and only the kept ones cloned,
+/// instead of paying two atomic operations for each discarded [`ArcSlice::subslice`]; see
+/// [`ArcSlice::borrow_chunks`].
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::ArcSlice;
+///
+/// let s = ArcSlice::<[u8]>::from(b"hello world");
+/// let borrow = s.borrow(..5);
+/// assert_eq!(&borrow[..], b"hello");
+/// let s2: ArcSlice<[u8]> = borrow.clone_arc();
+/// ```
+///
+/// [`clone_arc`]: Self::clone_arc
+/// [`ArcLayout`]: crate::layout::ArcLayout
+pub struct ArcSliceBorrow<'a, S: Slice + ?Sized, L: Layout = DefaultLayout> {
+    start: NonNull<S::Item>,
+    length: usize,
+    ptr: *const (),
+    _phantom: PhantomData<&'a ArcSlice<S, L>>,
+}
+
+unsafe impl<S: Slice + ?Sized, L: Layout> Send for ArcSliceBorrow<'_, S, L> {}
+unsafe impl<S: Slice + ?Sized, L: Layout> Sync for ArcSliceBorrow<'_, S, L> {}
+
+impl<S: Slice + ?Sized, L: Layout> Clone for ArcSliceBorrow<'_, S, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Slice + ?Sized, L: Layout> Copy for ArcSliceBorrow<'_, S, L> {}
+
+impl<S: Slice + ?Sized, L: Layout> Deref for ArcSliceBorrow<'_, S, L> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<S: fmt::Debug + Slice + ?Sized, L: Layout> fmt::Debug for ArcSliceBorrow<'_, S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_slice(&**self, f)
+    }
+}
+
+impl<'a, S: Slice + ?Sized, L: Layout> ArcSliceBorrow<'a, S, L> {
+    fn clone_arc_impl<E: AllocErrorImpl>(self) -> Result<ArcSlice<S, L>, E> {
+        if let Some(empty) = ArcSlice::new_empty(self.start, self.length) {
+            return Ok(empty);
+        }
+        // `clone_borrowed_data` must be tried first, as it doesn't dereference the borrowed
+        // `ArcSlice`, which `into_arc` relies on
+        let clone = || {
+            let arc_slice = unsafe { &*self.ptr.cast::<ArcSlice<S, L>>() };
+            L::clone::<S, E>(arc_slice.start, arc_slice.length, &arc_slice.data)
+        };
+        let data = L::clone_borrowed_data::<S>(self.ptr).map_or_else(clone, Ok)?;
+        Ok(ArcSlice {
+            start: self.start,
+            length: self.length,
+            data: ManuallyDrop::new(data),
+        })
+    }
+
+    /// Tries cloning the `ArcSliceBorrow` into a subslice of the borrowed [`ArcSlice`], returning
+    /// an error if an allocation fails.
+    ///
+    /// The returned [`ArcSlice`] has the same slice as the original borrow.
+    ///
+    /// The operation may not allocate, see
+    /// [`CloneNoAllocLayout`](crate::layout::CloneNoAllocLayout) documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let borrow = s.borrow(..5);
+    /// assert_eq!(&borrow[..], b"hello");
+    /// let s2: ArcSlice<[u8]> = borrow.try_clone_arc().unwrap();
+    /// assert_eq!(s2, b"hello");
+    /// ```
+    pub fn try_clone_arc(self) -> Result<ArcSlice<S, L>, AllocError> {
+        self.clone_arc_impl::<AllocError>()
+    }
+
+    /// Returns the borrowed slice.
+    ///
+    /// Roughly equivalent to `&self[..]`, but using the borrow lifetime instead of self's one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// assert_eq!(s.as_slice(), b"hello world");
+    /// ```
+    pub fn as_slice(&self) -> &'a S {
+        unsafe { S::from_raw_parts(self.start, self.length) }
+    }
+
+    /// Reborrows a subslice of an `ArcSliceBorrow` with a given range.
+    ///
+    /// The range is applied to the `ArcSliceBorrow` slice, not to the underlying `ArcSlice` one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let borrow = s.borrow(..5);
+    /// assert_eq!(&borrow[..], b"hello");
+    /// let reborrow = borrow.reborrow(2..4);
+    /// assert_eq!(&reborrow[..], b"ll");
+    /// ```
+    #[track_caller]
+    pub fn reborrow(&self, range: impl RangeBounds<usize>) -> ArcSliceBorrow<'a, S, L>
+    where
+        S: Subsliceable,
+    {
+        unsafe { self.reborrow_impl(range_offset_len(self.as_slice(), range)) }
+    }
+
+    /// Reborrows a subslice of an `ArcSliceBorrow` from a slice reference.
+    ///
+    /// The slice reference must be contained into the `ArcSliceBorrow` slice, not into the underlying `ArcSlice` one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let hello = &s[..5];
+    /// let borrow = s.borrow_from_ref(hello);
+    /// assert_eq!(&borrow[..], b"hello");
+    /// let ll = &borrow[2..4];
+    /// let reborrow = borrow.reborrow_from_ref(ll);
+    /// assert_eq!(&reborrow[..], b"ll");
+    /// ```
+    #[track_caller]
+    pub fn reborrow_from_ref(&self, subset: &S) -> ArcSliceBorrow<'a, S, L>
+    where
+        S: Subsliceable,
+    {
+        unsafe { self.reborrow_impl(subslice_offset_len(self.as_slice(), subset)) }
+    }
 
-impl<S: PartialEq + Slice + ?Sized, L: Layout> Eq for ArcSlice<S, L> {}
+    unsafe fn reborrow_impl(&self, (offset, len): (usize, usize)) -> ArcSliceBorrow<'a, S, L>
+    where
+        S: Subsliceable,
+    {
+        ArcSliceBorrow {
+            start: unsafe { self.start.add(offset) },
+            length: len,
+            ptr: self.ptr,
+            _phantom: PhantomData,
+        }
+    }
 
-impl<S: PartialOrd + Slice + ?Sized, L: Layout> PartialOrd for ArcSlice<S, L> {
-    fn partial_cmp(&self, other: &ArcSlice<S, L>) -> Option<cmp::Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+    /// Divides the `ArcSliceBorrow` into two at an index.
+    ///
+    /// The first will contain all indices from `[0, at)` and the second will contain all
+    /// indices from `[at, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let (hello, world) = s.borrow(..).split_at(5);
+    /// assert_eq!(&hello[..], b"hello");
+    /// assert_eq!(&world[..], b" world");
+    /// ```
+    #[track_caller]
+    pub fn split_at(self, at: usize) -> (Self, Self)
+    where
+        S: Subsliceable,
+    {
+        if at > self.length {
+            panic_end_out_of_range(at, self.length);
+        }
+        unsafe { self.as_slice().check_split(at) };
+        unsafe {
+            (
+                self.reborrow_impl((0, at)),
+                self.reborrow_impl((at, self.length - at)),
+            )
+        }
     }
 }
 
-impl<S: Ord + Slice + ?Sized, L: Layout> Ord for ArcSlice<S, L> {
-    fn cmp(&self, other: &ArcSlice<S, L>) -> cmp::Ordering {
-        self.as_slice().cmp(other.as_slice())
+impl<
+        S: Slice + ?Sized,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ArcSliceBorrow<'_, S, L>
+{
+    /// Clone the `ArcSliceBorrow` into a subslice of the borrowed [`ArcSlice`].
+    ///
+    /// The returned [`ArcSlice`] has the same slice as the original borrow, starting at the same
+    /// pointer even if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let s = ArcSlice::<[u8]>::from(b"hello world");
+    /// let borrow = s.borrow(..5);
+    /// assert_eq!(&borrow[..], b"hello");
+    /// let s2: ArcSlice<[u8]> = borrow.clone_arc();
+    /// assert_eq!(s2, b"hello");
+    /// ```
+    pub fn clone_arc(self) -> ArcSlice<S, L> {
+        self.clone_arc_impl::<Infallible>().unwrap_infallible()
+    }
+
+    /// Converts the `ArcSliceBorrow` into a subslice of the borrowed [`ArcSlice`].
+    ///
+    /// This is the same operation as [`clone_arc`](Self::clone_arc), but it also documents
+    /// a stronger guarantee: with [`ArcLayout`](crate::layout::ArcLayout), the borrow already
+    /// holds a pointer to the shared buffer, so the borrowed `ArcSlice` itself is never
+    /// dereferenced. The conversion stays sound even if the parent `ArcSlice` has been moved or
+    /// dropped in the meantime, as long as another reference keeps the buffer alive, which is
+    /// useful in self-referential patterns where the borrow lifetime has been erased.
+    ///
+    /// Other layouts may need to read the borrowed `ArcSlice`, and give no such guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout>::from(b"hello world");
+    /// let s2: ArcSlice<[u8], ArcLayout> = s.borrow(..5).into_arc();
+    /// assert_eq!(s2, b"hello");
+    /// ```
+    pub fn into_arc(self) -> ArcSlice<S, L> {
+        self.clone_arc_impl::<Infallible>().unwrap_infallible()
     }
 }
 
-impl<S: PartialEq + Slice + ?Sized, L: Layout> PartialEq<S> for ArcSlice<S, L> {
-    fn eq(&self, other: &S) -> bool {
-        self.as_slice() == other
+/// The raw parts of an [`ArcSlice`].
+///
+/// This struct is created by [`ArcSlice::into_raw_parts`], and can be converted back with
+/// [`ArcSlice::from_raw_parts`]. It owns the slice, but dropping it leaks the underlying buffer.
+/// It can be passed through FFI, `start` and `length` being the raw parts of the slice, while
+/// the remaining data is opaque and depends on the [layout](crate::layout).
+///
+/// The parts can only be converted back once:
+/// ```compile_fail
+/// use arc_slice::ArcSlice;
+///
+/// let parts = ArcSlice::<[u8]>::from(b"hello world").into_raw_parts();
+/// let s1 = unsafe { ArcSlice::from_raw_parts(parts) };
+/// let s2 = unsafe { ArcSlice::from_raw_parts(parts) };
+/// ```
+#[repr(C)]
+pub struct ArcSliceRawParts<S: Slice + ?Sized, L: Layout = DefaultLayout> {
+    /// The pointer to the slice's first item.
+    pub start: NonNull<S::Item>,
+    /// The number of items in the slice.
+    pub length: usize,
+    data: ManuallyDrop<<L as ArcSliceLayout>::Data>,
+}
+
+unsafe impl<S: Slice + ?Sized, L: Layout> Send for ArcSliceRawParts<S, L> {}
+unsafe impl<S: Slice + ?Sized, L: Layout> Sync for ArcSliceRawParts<S, L> {}
+
+impl<S: Slice + ?Sized, L: Layout> fmt::Debug for ArcSliceRawParts<S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcSliceRawParts")
+            .field("start", &self.start)
+            .field("length", &self.length)
+            .finish_non_exhaustive()
     }
 }
 
-impl<'a, S: PartialEq + Slice + ?Sized, L: Layout> PartialEq<&'a S> for ArcSlice<S, L> {
-    fn eq(&self, other: &&'a S) -> bool {
-        self.as_slice() == *other
+/// A weak pointer to an [`ArcSlice`].
+///
+/// It doesn't keep the underlying buffer alive, but can be upgraded back into an `ArcSlice`
+/// with [`upgrade`](Self::upgrade) as long as there is a strong reference left, like
+/// [`alloc::sync::Weak`].
+///
+/// This struct is created by [`ArcSlice::downgrade`].
+///
+/// # Examples
+///
+/// ```rust
+/// use arc_slice::{layout::ArcLayout, ArcSlice};
+///
+/// let s = ArcSlice::<[u8], ArcLayout<true>>::from(b"hello world".to_vec());
+/// let weak = s.downgrade();
+/// assert_eq!(weak.upgrade().unwrap(), b"hello world");
+/// drop(s);
+/// assert!(weak.upgrade().is_none());
+/// ```
+pub struct ArcSliceWeak<S: Slice + ?Sized, L: Layout = DefaultLayout> {
+    start: NonNull<S::Item>,
+    length: usize,
+    ptr: Option<NonNull<()>>,
+    _phantom: PhantomData<L>,
+}
+
+unsafe impl<S: Slice + ?Sized, L: Layout> Send for ArcSliceWeak<S, L> {}
+unsafe impl<S: Slice + ?Sized, L: Layout> Sync for ArcSliceWeak<S, L> {}
+
+impl<S: Slice + ?Sized, L: Layout> Drop for ArcSliceWeak<S, L> {
+    fn drop(&mut self) {
+        if let Some(ptr) = self.ptr {
+            unsafe { Arc::<S, true>::drop_weak(ptr) };
+        }
     }
 }
 
-impl<T: PartialEq + Send + Sync + 'static, L: Layout, const N: usize> PartialEq<[T; N]>
-    for ArcSlice<[T], L>
-{
-    fn eq(&self, other: &[T; N]) -> bool {
-        *other == **self
+impl<S: Slice + ?Sized, L: Layout> Clone for ArcSliceWeak<S, L> {
+    fn clone(&self) -> Self {
+        if let Some(ptr) = self.ptr {
+            unsafe { Arc::<S, true>::clone_weak(ptr) };
+        }
+        Self {
+            start: self.start,
+            length: self.length,
+            ptr: self.ptr,
+            _phantom: PhantomData,
+        }
     }
 }
 
-impl<'a, T: PartialEq + Send + Sync + 'static, L: Layout, const N: usize> PartialEq<&'a [T; N]>
-    for ArcSlice<[T], L>
-{
-    fn eq(&self, other: &&'a [T; N]) -> bool {
-        **other == **self
+impl<S: Slice + ?Sized, L: Layout> fmt::Debug for ArcSliceWeak<S, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(Weak)")
     }
 }
 
-impl<T: PartialEq + Send + Sync + 'static, L: Layout, const N: usize> PartialEq<ArcSlice<[T], L>>
-    for [T; N]
-{
-    fn eq(&self, other: &ArcSlice<[T], L>) -> bool {
-        **other == *self
+impl<S: Slice + ?Sized, L: Layout> ArcSliceWeak<S, L> {
+    /// Attempts to upgrade the weak pointer into an [`ArcSlice`], returning `None` if the
+    /// underlying buffer has been dropped, or if the weak pointer is dangling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true>>::from(b"hello world".to_vec());
+    /// let weak = s.downgrade();
+    /// assert_eq!(weak.upgrade().unwrap(), b"hello world");
+    /// drop(s);
+    /// assert!(weak.upgrade().is_none());
+    ///
+    /// let s = ArcSlice::<[u8], ArcLayout<true, true>>::from_static(b"hello world");
+    /// assert!(s.downgrade().upgrade().is_none());
+    /// ```
+    pub fn upgrade(&self) -> Option<ArcSlice<S, L>> {
+        let data = unsafe { L::upgrade::<S>(self.ptr?) }?;
+        Some(ArcSlice {
+            start: self.start,
+            length: self.length,
+            data: ManuallyDrop::new(data),
+        })
     }
 }
 
-impl<T: PartialEq + Send + Sync + 'static, L: Layout> PartialEq<ArcSlice<[T], L>> for [T] {
-    fn eq(&self, other: &ArcSlice<[T], L>) -> bool {
-        **other == *self
+/// An iterator over subslices of an [`ArcSlice`] separated by a byte.
+///
+/// This struct is created by [`ArcSlice::split_bytes`].
+pub struct SplitBytes<'a, L: Layout = DefaultLayout> {
+    slice: &'a ArcSlice<[u8], L>,
+    remaining: Option<&'a [u8]>,
+    delim: u8,
+}
+
+impl<L: Layout> fmt::Debug for SplitBytes<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitBytes")
+            .field("remaining", &self.remaining)
+            .field("delim", &self.delim)
+            .finish()
     }
 }
 
-impl<L: Layout> PartialEq<ArcSlice<str, L>> for str {
-    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
-        **other == *self
+impl<
+        'a,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > Iterator for SplitBytes<'a, L>
+{
+    type Item = ArcSlice<[u8], L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.remaining?;
+        let part = match rest.iter().position(|&b| b == self.delim) {
+            Some(pos) => {
+                self.remaining = Some(&rest[pos + 1..]);
+                &rest[..pos]
+            }
+            None => self.remaining.take()?,
+        };
+        Some(self.slice.subslice_from_ref(part))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(rest) => (1, Some(rest.len() + 1)),
+            None => (0, Some(0)),
+        }
     }
 }
 
-impl<T: PartialEq + Send + Sync + 'static, L: Layout> PartialEq<Vec<T>> for ArcSlice<[T], L> {
-    fn eq(&self, other: &Vec<T>) -> bool {
-        **self == **other
+impl<
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > DoubleEndedIterator for SplitBytes<'_, L>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rest = self.remaining?;
+        let part = match rest.iter().rposition(|&b| b == self.delim) {
+            Some(pos) => {
+                self.remaining = Some(&rest[..pos]);
+                &rest[pos + 1..]
+            }
+            None => self.remaining.take()?,
+        };
+        Some(self.slice.subslice_from_ref(part))
     }
 }
 
-impl<L: Layout> PartialEq<String> for ArcSlice<str, L> {
-    fn eq(&self, other: &String) -> bool {
-        **self == **other
+impl<
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > FusedIterator for SplitBytes<'_, L>
+{
+}
+
+/// An owning iterator over the items of an [`ArcSlice`].
+///
+/// If the slice is unique when the iterator is created, and its buffer is mutable, e.g. an arc
+/// slice or a vector, items are moved out of the buffer. Otherwise, the buffer is shared, and
+/// each item is cloned, which may be expensive for items owning resources.
+///
+/// The iterator holds the slice, and releases it when dropped, dropping the remaining items if
+/// they have been moved.
+///
+/// This struct is created by [`ArcSlice::iter_cloned`], or by the [`IntoIterator`]
+/// implementation of [`ArcSlice`].
+pub struct IntoIter<T: Send + Sync + 'static, L: Layout = DefaultLayout> {
+    slice: IntoIterSlice<T, L>,
+    start: usize,
+    end: usize,
+}
+
+enum IntoIterSlice<T: Send + Sync + 'static, L: Layout> {
+    Shared(ArcSlice<[T], L>),
+    Unique(ArcSliceMut<[T], VecLayout>),
+}
+
+impl<T: Send + Sync + 'static, L: Layout> IntoIter<T, L> {
+    fn new(slice: ArcSlice<[T], L>) -> Self {
+        let end = slice.len();
+        let slice = match slice.try_into_mut() {
+            Ok(slice) => IntoIterSlice::Unique(slice),
+            Err(slice) => IntoIterSlice::Shared(slice),
+        };
+        Self {
+            slice,
+            start: 0,
+            end,
+        }
+    }
+
+    fn slice(&self) -> &[T] {
+        match &self.slice {
+            IntoIterSlice::Shared(slice) => slice,
+            IntoIterSlice::Unique(slice) => slice,
+        }
+    }
+
+    fn read_item(&self, index: usize) -> T
+    where
+        T: Clone,
+    {
+        match &self.slice {
+            IntoIterSlice::Shared(slice) => slice[index].clone(),
+            // SAFETY: the item is in the remaining range, so it has not been moved yet
+            IntoIterSlice::Unique(slice) => unsafe { ptr::read(&slice[index]) },
+        }
+    }
+
+    /// Returns `true` if the items are moved out of the buffer, `false` if they are cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSlice};
+    ///
+    /// let s = ArcSlice::<[String], ArcLayout>::from_array(["hello".into()]);
+    /// let s2 = s.clone();
+    /// assert!(!s.into_iter().is_moving());
+    /// assert!(s2.into_iter().is_moving());
+    /// ```
+    pub fn is_moving(&self) -> bool {
+        matches!(self.slice, IntoIterSlice::Unique(_))
+    }
+
+    /// Returns the remaining items of the iterator as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let mut iter = ArcSlice::<[u8]>::from(b"abc").into_iter();
+    /// iter.next();
+    /// assert_eq!(iter.as_slice(), b"bc");
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.slice()[self.start..self.end]
     }
 }
 
-impl<T: PartialEq + Send + Sync + 'static, L: Layout> PartialEq<ArcSlice<[T], L>> for Vec<T> {
-    fn eq(&self, other: &ArcSlice<[T], L>) -> bool {
-        **self == **other
+impl<T: Send + Sync + 'static, L: Layout> Drop for IntoIter<T, L> {
+    fn drop(&mut self) {
+        if let IntoIterSlice::Unique(slice) = &mut self.slice {
+            let remaining = ptr::slice_from_raw_parts_mut(
+                unsafe { slice.as_mut_ptr().add(self.start) },
+                self.end - self.start,
+            );
+            // the length is reset first, so moved items are not dropped again, even if
+            // dropping a remaining one panics
+            unsafe { slice.set_len(0) };
+            unsafe { ptr::drop_in_place(remaining) };
+        }
     }
 }
 
-impl<L: Layout> PartialEq<ArcSlice<str, L>> for String {
-    fn eq(&self, other: &ArcSlice<str, L>) -> bool {
-        **self == **other
+impl<T: fmt::Debug + Send + Sync + 'static, L: Layout> fmt::Debug for IntoIter<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
     }
 }
 
-#[cfg(feature = "oom-handling")]
-impl<S: Slice + ?Sized, L: Layout> From<&S> for ArcSlice<S, L>
-where
-    S::Item: Copy,
-{
-    fn from(value: &S) -> Self {
-        Self::from_slice(value)
+impl<T: Clone + Send + Sync + 'static, L: Layout> Iterator for IntoIter<T, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(self.read_item(self.start - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
     }
 }
 
-#[cfg(feature = "oom-handling")]
-impl<T: Copy + Send + Sync + 'static, L: Layout, const N: usize> From<&[T; N]>
-    for ArcSlice<[T], L>
-{
-    fn from(value: &[T; N]) -> Self {
-        Self::from_slice(value)
+impl<T: Clone + Send + Sync + 'static, L: Layout> DoubleEndedIterator for IntoIter<T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.read_item(self.end))
     }
 }
 
-#[cfg(feature = "oom-handling")]
-impl<T: Send + Sync + 'static, L: Layout, const N: usize> From<[T; N]> for ArcSlice<[T], L> {
-    fn from(value: [T; N]) -> Self {
-        Self::from_array(value)
+impl<T: Clone + Send + Sync + 'static, L: Layout> ExactSizeIterator for IntoIter<T, L> {}
+
+impl<T: Clone + Send + Sync + 'static, L: Layout> FusedIterator for IntoIter<T, L> {}
+
+/// Iterates over the items by value, see [`IntoIter`].
+///
+/// Before this implementation, `slice.into_iter()` resolved to the slice method through
+/// auto-deref, yielding references; it now yields owned items. Use
+/// [`iter`](slice::iter) to keep iterating by reference.
+impl<T: Clone + Send + Sync + 'static, L: Layout> IntoIterator for ArcSlice<[T], L> {
+    type Item = T;
+    type IntoIter = IntoIter<T, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
     }
 }
 
-#[cfg(not(feature = "oom-handling"))]
-impl<S: Slice + ?Sized> From<Box<S>> for ArcSlice<S, BoxedSliceLayout> {
-    fn from(value: Box<S>) -> Self {
-        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+/// An owning iterator over subslices of length 1 of an [`ArcSlice`].
+///
+/// This struct is created by [`ArcSlice::into_iter_arc`].
+pub struct IntoIterArc<T: Send + Sync + 'static, L: Layout = DefaultLayout> {
+    slice: ArcSlice<[T], L>,
+    start: usize,
+    end: usize,
+}
+
+impl<T: fmt::Debug + Send + Sync + 'static, L: Layout> fmt::Debug for IntoIterArc<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIterArc")
+            .field(&&self.slice[self.start..self.end])
+            .finish()
     }
 }
-#[cfg(not(feature = "oom-handling"))]
-impl<S: Slice + ?Sized> From<Box<S>> for ArcSlice<S, VecLayout> {
-    fn from(value: Box<S>) -> Self {
-        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+
+impl<
+        T: Send + Sync + 'static,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > Iterator for IntoIterArc<T, L>
+{
+    type Item = ArcSlice<[T], L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(self.slice.subslice(self.start - 1..self.start))
     }
-}
-#[cfg(feature = "oom-handling")]
-impl<S: Slice + ?Sized, L: AnyBufferLayout> From<Box<S>> for ArcSlice<S, L> {
-    fn from(value: Box<S>) -> Self {
-        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
     }
 }
 
-#[cfg(not(feature = "oom-handling"))]
-impl<T: Send + Sync + 'static> From<Vec<T>> for ArcSlice<[T], VecLayout> {
-    fn from(value: Vec<T>) -> Self {
-        Self::from_vec(value)
+impl<
+        T: Send + Sync + 'static,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > DoubleEndedIterator for IntoIterArc<T, L>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.slice.subslice(self.end..self.end + 1))
     }
 }
-#[cfg(feature = "oom-handling")]
-impl<T: Send + Sync + 'static, L: AnyBufferLayout> From<Vec<T>> for ArcSlice<[T], L> {
-    fn from(value: Vec<T>) -> Self {
-        Self::from_vec(value)
-    }
+
+impl<
+        T: Send + Sync + 'static,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > ExactSizeIterator for IntoIterArc<T, L>
+{
 }
 
-#[cfg(not(feature = "oom-handling"))]
-impl From<String> for ArcSlice<str, crate::layout::VecLayout> {
-    fn from(value: String) -> Self {
-        Self::from_vec(value)
-    }
+impl<
+        T: Send + Sync + 'static,
+        #[cfg(feature = "oom-handling")] L: Layout,
+        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
+    > FusedIterator for IntoIterArc<T, L>
+{
 }
-#[cfg(feature = "oom-handling")]
-impl<L: AnyBufferLayout> From<String> for ArcSlice<str, L> {
-    fn from(value: String) -> Self {
-        Self::from_vec(value)
-    }
+
+/// An iterator over borrowed chunks of an [`ArcSlice`].
+///
+/// This struct is created by [`ArcSlice::borrow_chunks`].
+pub struct BorrowChunks<'a, T: Send + Sync + 'static, L: Layout = DefaultLayout> {
+    slice: &'a ArcSlice<[T], L>,
+    start: usize,
+    end: usize,
+    chunk_size: usize,
 }
 
-impl<T: Send + Sync + 'static, L: Layout, const N: usize> TryFrom<ArcSlice<[T], L>> for [T; N] {
-    type Error = ArcSlice<[T], L>;
-    fn try_from(value: ArcSlice<[T], L>) -> Result<Self, Self::Error> {
-        let mut this = ManuallyDrop::new(value);
-        unsafe { L::take_array::<T, N>(this.start, this.length, &mut this.data) }
-            .ok_or_else(|| ManuallyDrop::into_inner(this))
+impl<T: fmt::Debug + Send + Sync + 'static, L: Layout> fmt::Debug for BorrowChunks<'_, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BorrowChunks")
+            .field("remaining", &&self.slice[self.start..self.end])
+            .field("chunk_size", &self.chunk_size)
+            .finish()
     }
 }
 
-#[cfg(feature = "oom-handling")]
-impl<L: Layout> core::str::FromStr for ArcSlice<str, L> {
-    type Err = Infallible;
+impl<'a, T: Send + Sync + 'static, L: Layout> Iterator for BorrowChunks<'a, T, L> {
+    type Item = ArcSliceBorrow<'a, [T], L>;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(s.into())
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let len = cmp::min(self.chunk_size, self.end - self.start);
+        let chunk = unsafe { self.slice.borrow_impl((self.start, len)) };
+        self.start += len;
+        Some(chunk)
     }
-}
 
-#[cfg(feature = "std")]
-const _: () = {
-    extern crate std;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.start;
+        let len = n / self.chunk_size + usize::from(n % self.chunk_size != 0);
+        (len, Some(len))
+    }
+}
 
-    impl<L: Layout> std::io::Read for ArcSlice<[u8], L> {
-        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-            let n = cmp::min(self.len(), buf.len());
-            buf[..n].copy_from_slice(&self[..n]);
-            Ok(n)
+impl<T: Send + Sync + 'static, L: Layout> DoubleEndedIterator for BorrowChunks<'_, T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
         }
+        let len = match (self.end - self.start) % self.chunk_size {
+            0 => self.chunk_size,
+            rem => rem,
+        };
+        self.end -= len;
+        Some(unsafe { self.slice.borrow_impl((self.end, len)) })
     }
-};
+}
 
-/// A borrowed view of an [`ArcSlice`].
+impl<T: Send + Sync + 'static, L: Layout> ExactSizeIterator for BorrowChunks<'_, T, L> {}
+
+impl<T: Send + Sync + 'static, L: Layout> FusedIterator for BorrowChunks<'_, T, L> {}
+
+/// A cursor over an [`ArcSlice`], which is left untouched.
 ///
-/// `ArcSliceBorrow` is roughly equivalent to `(&S, &ArcSlice<S, L>)`. A new `ArcSlice` instance
-/// can be obtained with [`clone_arc`].
+/// Unlike reading the `ArcSlice` directly, which consumes it, the read position is tracked
+/// separately, so the slice can be rewound, re-read, or recovered once reading is done.
 /// <br>
-/// One advantage of using `ArcSliceBorrow` is that [`clone_arc`] doesn't need to perform bound
-/// checks that was done at `ArcSliceBorrow` creation; using `(&S, &ArcSlice<S, L>)` however
-/// would require to check bounds a second time when using [`ArcSlice::subslice_from_ref`].
+/// With `std` feature, it implements `Read`, `BufRead` and `Seek` for slices of bytes.
 ///
-/// When using [`ArcLayout`], `ArcSliceBorrow` is even more efficient, because it can directly
-/// embed the internal Arc instead of an `ArcSlice` reference, saving one indirection.
+/// This struct is created by [`ArcSlice::into_cursor`].
 ///
 /// # Examples
 ///
 /// ```rust
+/// use std::io::{Read, Seek, SeekFrom};
+///
 /// use arc_slice::ArcSlice;
 ///
-/// let s = ArcSlice::<[u8]>::from(b"hello world");
-/// let borrow = s.borrow(..5);
-/// assert_eq!(&borrow[..], b"hello");
-/// let s2: ArcSlice<[u8]> = borrow.clone_arc();
+/// let mut cursor = ArcSlice::<[u8]>::from(b"hello world").into_cursor();
+/// let mut buf = [0; 5];
+/// cursor.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"hello");
+/// cursor.seek(SeekFrom::Current(-2)).unwrap();
+/// cursor.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"lo wo");
+/// assert_eq!(cursor.into_inner(), b"hello world");
 /// ```
-///
-/// [`clone_arc`]: Self::clone_arc
-/// [`ArcLayout`]: crate::layout::ArcLayout
-pub struct ArcSliceBorrow<'a, S: Slice + ?Sized, L: Layout = DefaultLayout> {
-    start: NonNull<S::Item>,
-    length: usize,
-    ptr: *const (),
-    _phantom: PhantomData<&'a ArcSlice<S, L>>,
-}
-
-unsafe impl<S: Slice + ?Sized, L: Layout> Send for ArcSliceBorrow<'_, S, L> {}
-unsafe impl<S: Slice + ?Sized, L: Layout> Sync for ArcSliceBorrow<'_, S, L> {}
-
-impl<S: Slice + ?Sized, L: Layout> Clone for ArcSliceBorrow<'_, S, L> {
-    fn clone(&self) -> Self {
-        *self
-    }
-}
-
-impl<S: Slice + ?Sized, L: Layout> Copy for ArcSliceBorrow<'_, S, L> {}
-
-impl<S: Slice + ?Sized, L: Layout> Deref for ArcSliceBorrow<'_, S, L> {
-    type Target = S;
-
-    fn deref(&self) -> &Self::Target {
-        self.as_slice()
-    }
+pub struct ArcSliceCursor<S: Slice + ?Sized, L: Layout = DefaultLayout> {
+    slice: ArcSlice<S, L>,
+    position: usize,
 }
 
-impl<S: fmt::Debug + Slice + ?Sized, L: Layout> fmt::Debug for ArcSliceBorrow<'_, S, L> {
+impl<S: fmt::Debug + Slice + ?Sized, L: Layout> fmt::Debug for ArcSliceCursor<S, L> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        debug_slice(&**self, f)
+        f.debug_struct("ArcSliceCursor")
+            .field("slice", &self.slice)
+            .field("position", &self.position)
+            .finish()
     }
 }
 
-impl<'a, S: Slice + ?Sized, L: Layout> ArcSliceBorrow<'a, S, L> {
-    fn clone_arc_impl<E: AllocErrorImpl>(self) -> Result<ArcSlice<S, L>, E> {
-        if let Some(empty) = ArcSlice::new_empty(self.start, self.length) {
-            return Ok(empty);
-        }
-        let clone = || {
-            let arc_slice = unsafe { &*self.ptr.cast::<ArcSlice<S, L>>() };
-            L::clone::<S, E>(arc_slice.start, arc_slice.length, &arc_slice.data)
-        };
-        let data = L::clone_borrowed_data::<S>(self.ptr).map_or_else(clone, Ok)?;
-        Ok(ArcSlice {
-            start: self.start,
-            length: self.length,
-            data: ManuallyDrop::new(data),
-        })
+impl<S: Slice + ?Sized, L: Layout> ArcSliceCursor<S, L> {
+    /// Creates a new cursor starting at the beginning of the slice.
+    pub fn new(slice: ArcSlice<S, L>) -> Self {
+        Self { slice, position: 0 }
     }
 
-    /// Tries cloning the `ArcSliceBorrow` into a subslice of the borrowed [`ArcSlice`], returning
-    /// an error if an allocation fails.
-    ///
-    /// The returned [`ArcSlice`] has the same slice as the original borrow.
-    ///
-    /// The operation may not allocate, see
-    /// [`CloneNoAllocLayout`](crate::layout::CloneNoAllocLayout) documentation.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use arc_slice::ArcSlice;
-    ///
-    /// let s = ArcSlice::<[u8]>::from(b"hello world");
-    /// let borrow = s.borrow(..5);
-    /// assert_eq!(&borrow[..], b"hello");
-    /// let s2: ArcSlice<[u8]> = borrow.try_clone_arc().unwrap();
-    /// assert_eq!(s2, b"hello");
-    /// ```
-    pub fn try_clone_arc(self) -> Result<ArcSlice<S, L>, AllocError> {
-        self.clone_arc_impl::<AllocError>()
+    /// Returns the current position.
+    pub fn position(&self) -> usize {
+        self.position
     }
 
-    /// Returns the borrowed slice.
-    ///
-    /// Roughly equivalent to `&self[..]`, but using the borrow lifetime instead of self's one.
+    /// Sets the position.
     ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use arc_slice::ArcSlice;
-    ///
-    /// let s = ArcSlice::<[u8]>::from(b"hello world");
-    /// assert_eq!(s.as_slice(), b"hello world");
-    /// ```
-    pub fn as_slice(&self) -> &'a S {
-        unsafe { S::from_raw_parts(self.start, self.length) }
+    /// A position past the end of the slice is allowed, and results in empty reads.
+    pub fn set_position(&mut self, position: usize) {
+        self.position = position;
     }
 
-    /// Reborrows a subslice of an `ArcSliceBorrow` with a given range.
-    ///
-    /// The range is applied to the `ArcSliceBorrow` slice, not to the underlying `ArcSlice` one.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use arc_slice::ArcSlice;
-    ///
-    /// let s = ArcSlice::<[u8]>::from(b"hello world");
-    /// let borrow = s.borrow(..5);
-    /// assert_eq!(&borrow[..], b"hello");
-    /// let reborrow = borrow.reborrow(2..4);
-    /// assert_eq!(&reborrow[..], b"ll");
-    /// ```
-    pub fn reborrow(&self, range: impl RangeBounds<usize>) -> ArcSliceBorrow<'a, S, L>
-    where
-        S: Subsliceable,
-    {
-        unsafe { self.reborrow_impl(range_offset_len(self.as_slice(), range)) }
+    /// Returns the items not read yet.
+    pub fn remaining(&self) -> &[S::Item] {
+        let slice = self.slice.to_slice();
+        &slice[cmp::min(self.position, slice.len())..]
     }
 
-    /// Reborrows a subslice of an `ArcSliceBorrow` from a slice reference.
-    ///
-    /// The slice reference must be contained into the `ArcSliceBorrow` slice, not into the underlying `ArcSlice` one.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use arc_slice::ArcSlice;
-    ///
-    /// let s = ArcSlice::<[u8]>::from(b"hello world");
-    /// let hello = &s[..5];
-    /// let borrow = s.borrow_from_ref(hello);
-    /// assert_eq!(&borrow[..], b"hello");
-    /// let ll = &borrow[2..4];
-    /// let reborrow = borrow.reborrow_from_ref(ll);
-    /// assert_eq!(&reborrow[..], b"ll");
-    /// ```
-    pub fn reborrow_from_ref(&self, subset: &S) -> ArcSliceBorrow<'a, S, L>
-    where
-        S: Subsliceable,
-    {
-        unsafe { self.reborrow_impl(subslice_offset_len(self.as_slice(), subset)) }
+    #[cfg(feature = "std")]
+    pub(crate) fn advance_position(&mut self, amt: usize) {
+        self.position = cmp::min(self.position.saturating_add(amt), self.slice.len());
     }
 
-    unsafe fn reborrow_impl(&self, (offset, len): (usize, usize)) -> ArcSliceBorrow<'a, S, L>
-    where
-        S: Subsliceable,
-    {
-        ArcSliceBorrow {
-            start: unsafe { self.start.add(offset) },
-            length: len,
-            ptr: self.ptr,
-            _phantom: PhantomData,
-        }
+    /// Returns a reference to the underlying slice.
+    pub fn get_ref(&self) -> &ArcSlice<S, L> {
+        &self.slice
     }
-}
 
-impl<
-        S: Slice + ?Sized,
-        #[cfg(feature = "oom-handling")] L: Layout,
-        #[cfg(not(feature = "oom-handling"))] L: CloneNoAllocLayout,
-    > ArcSliceBorrow<'_, S, L>
-{
-    /// Clone the `ArcSliceBorrow` into a subslice of the borrowed [`ArcSlice`].
-    ///
-    /// The returned [`ArcSlice`] has the same slice as the original borrow.
-    ///
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use arc_slice::ArcSlice;
-    ///
-    /// let s = ArcSlice::<[u8]>::from(b"hello world");
-    /// let borrow = s.borrow(..5);
-    /// assert_eq!(&borrow[..], b"hello");
-    /// let s2: ArcSlice<[u8]> = borrow.clone_arc();
-    /// assert_eq!(s2, b"hello");
-    /// ```
-    pub fn clone_arc(self) -> ArcSlice<S, L> {
-        self.clone_arc_impl::<Infallible>().unwrap_infallible()
+    /// Consumes the cursor, returning the underlying slice.
+    pub fn into_inner(self) -> ArcSlice<S, L> {
+        self.slice
     }
 }
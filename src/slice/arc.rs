@@ -1,6 +1,6 @@
 use core::{
     any::Any,
-    hint, mem,
+    mem,
     mem::{ManuallyDrop, MaybeUninit},
     ptr::NonNull,
 };
@@ -11,7 +11,7 @@ use crate::{
     arc::Arc,
     buffer::{Buffer, BufferWithMetadata, Slice, SliceExt},
     error::AllocErrorImpl,
-    layout::ArcLayout,
+    layout::{ArcLayout, Repr},
     msrv::ptr,
     slice::ArcSliceLayout,
     slice_mut,
@@ -19,24 +19,24 @@ use crate::{
     utils::{assert_checked, try_transmute},
 };
 
-impl<const ANY_BUFFER: bool, const STATIC: bool> ArcLayout<ANY_BUFFER, STATIC> {
+impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool>
+    ArcLayout<ANY_BUFFER, STATIC, SATURATE>
+{
+    // `None` is static data if `STATIC`, otherwise it can only be the data of empty slices
     fn arc<S: Slice + ?Sized>(
         data: &<Self as ArcSliceLayout>::Data,
     ) -> Option<ManuallyDrop<Arc<S, ANY_BUFFER>>> {
-        match data {
-            Some(ptr) => Some(ManuallyDrop::new(unsafe { Arc::from_raw(*ptr) })),
-            None if STATIC => None,
-            None => unsafe { hint::unreachable_unchecked() },
-        }
+        data.map(|ptr| ManuallyDrop::new(unsafe { Arc::from_raw(ptr) }))
     }
 }
 
-unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceLayout
-    for ArcLayout<ANY_BUFFER, STATIC>
+unsafe impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool> ArcSliceLayout
+    for ArcLayout<ANY_BUFFER, STATIC, SATURATE>
 {
     type Data = Option<NonNull<()>>;
     const DATA_COPY: bool = true;
     const ANY_BUFFER: bool = ANY_BUFFER;
+    const EMPTY_DATA: Self::Data = None;
     const STATIC_DATA: Option<Self::Data> = if STATIC { Some(None) } else { None };
     const STATIC_DATA_UNCHECKED: MaybeUninit<Self::Data> = if STATIC {
         MaybeUninit::new(None)
@@ -67,8 +67,8 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceLayout
     fn data_from_static<S: Slice + ?Sized, E: AllocErrorImpl>(
         slice: &'static S,
     ) -> Result<Self::Data, (E, &'static S)> {
-        if let Some(data) = Self::STATIC_DATA {
-            return Ok(data);
+        if STATIC || slice.to_slice().is_empty() {
+            return Ok(None);
         }
         assert_checked(ANY_BUFFER);
         let (arc, _, _) = Arc::new_buffer::<_, E>(BufferWithMetadata::new(slice, ()))
@@ -89,7 +89,19 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceLayout
         data: &Self::Data,
     ) -> Result<Self::Data, E> {
         if let Some(arc) = Self::arc::<S>(data) {
-            mem::forget((*arc).clone());
+            mem::forget(arc.clone_with::<SATURATE>());
+        }
+        Ok(*data)
+    }
+
+    fn clone_many<S: Slice + ?Sized, E: AllocErrorImpl>(
+        _start: NonNull<S::Item>,
+        _length: usize,
+        data: &Self::Data,
+        count: usize,
+    ) -> Result<Self::Data, E> {
+        if let Some(arc) = Self::arc::<S>(data) {
+            arc.forget_clones_with::<SATURATE>(count);
         }
         Ok(*data)
     }
@@ -110,17 +122,41 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceLayout
 
     fn clone_borrowed_data<S: Slice + ?Sized>(ptr: *const ()) -> Option<Self::Data> {
         let data = NonNull::new(ptr.cast_mut());
-        Some(Self::arc::<S>(&data).map(|arc| (*arc).clone().into_raw()))
+        Some(Self::arc::<S>(&data).map(|arc| arc.clone_with::<SATURATE>().into_raw()))
+    }
+
+    fn downgrade<S: Slice + ?Sized>(data: &Self::Data) -> Option<NonNull<()>> {
+        Self::arc::<S>(data)?.downgrade()
+    }
+
+    unsafe fn upgrade<S: Slice + ?Sized>(ptr: NonNull<()>) -> Option<Self::Data> {
+        let arc = unsafe { Arc::<S, ANY_BUFFER>::upgrade::<SATURATE>(ptr) }?;
+        Some(Self::data_from_arc(arc))
     }
 
     fn is_unique<S: Slice + ?Sized>(data: &Self::Data) -> bool {
         Self::arc::<S>(data).is_some_and(|arc| arc.is_buffer_unique())
     }
 
+    fn buffer_capacity<S: Slice + ?Sized>(data: &Self::Data) -> Option<usize> {
+        Self::arc::<S>(data)?.buffer_capacity()
+    }
+
     fn get_metadata<S: Slice + ?Sized, M: Any>(data: &Self::Data) -> Option<&M> {
         Some(unsafe { &*ptr::from_ref(Self::arc::<S>(data)?.get_metadata::<M>()?) })
     }
 
+    fn repr<S: Slice + ?Sized>(data: &Self::Data) -> Repr {
+        Self::arc::<S>(data).map_or(Repr::Static, |arc| arc.repr())
+    }
+
+    #[cfg(feature = "arbitrary")]
+    fn validate<S: Slice + ?Sized>(start: NonNull<S::Item>, length: usize, data: &Self::Data) {
+        if let Some(arc) = Self::arc::<S>(data) {
+            arc.validate(start, length, false);
+        }
+    }
+
     unsafe fn take_buffer<S: Slice + ?Sized, B: Buffer<S>>(
         start: NonNull<S::Item>,
         length: usize,
@@ -136,6 +172,17 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceLayout
         }
     }
 
+    unsafe fn take_buffer_with_metadata<S: Slice + ?Sized, B: Buffer<S>, M: Any>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &mut ManuallyDrop<Self::Data>,
+    ) -> Option<(B, M)> {
+        let arc = Self::arc::<S>(data)?;
+        unsafe { ManuallyDrop::into_inner(arc).take_buffer_with_metadata::<B, M>(start, length) }
+            .map_err(mem::forget)
+            .ok()
+    }
+
     unsafe fn take_array<T: Send + Sync + 'static, const N: usize>(
         start: NonNull<T>,
         length: usize,
@@ -154,7 +201,7 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceLayout
     ) -> Option<(usize, Option<slice_mut::Data<true>>)> {
         match Self::arc::<S>(data) {
             Some(mut arc) => Some((
-                unsafe { arc.capacity(start)? },
+                unsafe { arc.mut_capacity(start, length)? },
                 Some(L::try_data_from_arc(arc)?),
             )),
             None => (length == 0).then_some((0, None)),
@@ -168,7 +215,7 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceLayout
     ) -> Option<L::Data> {
         match Self::arc::<S>(&data) {
             Some(arc) => L::try_data_from_arc(arc),
-            None if L::STATIC_DATA.is_some() || L::ANY_BUFFER => {
+            None if L::STATIC_DATA.is_some() || L::ANY_BUFFER || length == 0 => {
                 L::data_from_static::<_, E>(unsafe { S::from_raw_parts(start, length) }).ok()
             }
             None => None,
@@ -11,13 +11,13 @@ use crate::{
     arc::{vtable as arc_vtable, Arc},
     buffer::{Buffer, DynBuffer, RawBuffer, Slice, SliceExt},
     error::AllocErrorImpl,
-    layout::RawLayout,
+    layout::{RawLayout, Repr},
     msrv::ptr,
     slice::ArcSliceLayout,
     slice_mut,
     slice_mut::ArcSliceMutLayout,
     utils::try_transmute,
-    vtable::{generic_take_buffer, VTable},
+    vtable::{generic_take_buffer, generic_take_buffer_with_metadata, VTable},
 };
 
 mod static_vtable {
@@ -27,6 +27,7 @@ mod static_vtable {
     use crate::msrv::NonNullExt;
     use crate::{
         error::AllocError,
+        layout::Repr,
         vtable::{no_capacity, VTable},
     };
 
@@ -39,6 +40,7 @@ mod static_vtable {
     }
     unsafe fn take_buffer(
         _buffer: NonNull<()>,
+        _metadata: Option<(NonNull<()>, TypeId)>,
         _ptr: *const (),
         _type_id: TypeId,
         _start: NonNull<()>,
@@ -58,6 +60,7 @@ mod static_vtable {
 
     pub(super) const VTABLE: &VTable = &VTable {
         deallocate,
+        free: None,
         drop,
         drop_with_unique_hint,
         clone,
@@ -66,8 +69,11 @@ mod static_vtable {
         take_buffer,
         capacity: no_capacity,
         try_reserve: None,
+        buffer_mut: None,
+        set_len: None,
         into_arc,
         into_arc_fallible,
+        repr: Repr::Static,
     };
 }
 
@@ -80,6 +86,7 @@ mod raw_vtable {
         arc::Arc,
         buffer::{DynBuffer, RawBuffer, Slice, SliceExt},
         error::{AllocError, AllocErrorImpl},
+        layout::Repr,
         macros::{is, is_not},
         utils::UnwrapInfallible,
         vtable::{no_capacity, VTable},
@@ -97,19 +104,24 @@ mod raw_vtable {
         ptr: *const (),
         type_id: TypeId,
     ) -> Option<NonNull<()>> {
-        if is!(B::Metadata, ()) || is_not!({ type_id }, B::Metadata) {
+        if is!(B::Metadata, ()) {
             return None;
         }
-        Some(NonNull::from(ManuallyDrop::new(unsafe { B::from_raw(ptr) }).get_metadata()).cast())
+        ManuallyDrop::new(unsafe { B::from_raw(ptr) }).get_metadata_by_id(type_id)
     }
 
     unsafe fn take_buffer<S: Slice + ?Sized, B: DynBuffer + RawBuffer<S>>(
         buffer: NonNull<()>,
+        metadata: Option<(NonNull<()>, TypeId)>,
         ptr: *const (),
         type_id: TypeId,
         _start: NonNull<()>,
         length: usize,
     ) -> Option<NonNull<()>> {
+        // raw buffers don't carry metadata
+        if metadata.is_some() {
+            return None;
+        }
         let raw_buffer = ManuallyDrop::new(unsafe { B::from_raw(ptr) });
         if is_not!({ type_id }, B::Buffer) || raw_buffer.as_slice().len() != length {
             return None;
@@ -122,7 +134,7 @@ mod raw_vtable {
         mem::drop(unsafe { B::from_raw(ptr) });
     }
     unsafe fn clone<S: ?Sized, B: RawBuffer<S>>(ptr: *const ()) {
-        let _ = (*ManuallyDrop::new(unsafe { B::from_raw(ptr) })).clone();
+        mem::forget((*ManuallyDrop::new(unsafe { B::from_raw(ptr) })).clone());
     }
 
     unsafe fn into_arc<S: Slice + ?Sized, B: DynBuffer + RawBuffer<S>>(
@@ -146,6 +158,7 @@ mod raw_vtable {
     ) -> &'static VTable {
         &VTable {
             deallocate,
+            free: None,
             drop: drop::<S, B>,
             drop_with_unique_hint: drop::<S, B>,
             clone: clone::<S, B>,
@@ -154,8 +167,11 @@ mod raw_vtable {
             take_buffer: take_buffer::<S, B>,
             capacity: no_capacity,
             try_reserve: None,
+            buffer_mut: None,
+            set_len: None,
             into_arc: into_arc::<S, B>,
             into_arc_fallible: into_arc_fallible::<S, B>,
+            repr: Repr::Raw,
         }
     }
 }
@@ -183,6 +199,7 @@ unsafe impl ArcSliceLayout for RawLayout {
     type Data = (*const (), Option<&'static VTable>);
     const DATA_COPY: bool = true;
     const ANY_BUFFER: bool = true;
+    const EMPTY_DATA: Self::Data = (ptr::null(), Some(static_vtable::VTABLE));
     const STATIC_DATA: Option<Self::Data> = Some((ptr::null(), Some(static_vtable::VTABLE)));
     const STATIC_DATA_UNCHECKED: MaybeUninit<Self::Data> =
         MaybeUninit::new((ptr::null(), Some(static_vtable::VTABLE)));
@@ -231,6 +248,22 @@ unsafe impl ArcSliceLayout for RawLayout {
         Ok(*data)
     }
 
+    fn clone_many<S: Slice + ?Sized, E: AllocErrorImpl>(
+        _start: NonNull<S::Item>,
+        _length: usize,
+        data: &Self::Data,
+        count: usize,
+    ) -> Result<Self::Data, E> {
+        match arc_or_vtable::<S>(*data) {
+            ArcOrVTable::Arc(arc) => arc.forget_clones(count),
+            // raw buffers can only be cloned one at a time
+            ArcOrVTable::Vtable { ptr, vtable } => {
+                (0..count).for_each(|_| unsafe { (vtable.clone)(ptr) });
+            }
+        }
+        Ok(*data)
+    }
+
     unsafe fn drop<S: Slice + ?Sized, const UNIQUE_HINT: bool>(
         _start: NonNull<S::Item>,
         _length: usize,
@@ -254,6 +287,14 @@ unsafe impl ArcSliceLayout for RawLayout {
         }
     }
 
+    fn buffer_capacity<S: Slice + ?Sized>(data: &Self::Data) -> Option<usize> {
+        match arc_or_vtable::<S>(*data) {
+            ArcOrVTable::Arc(arc) => arc.buffer_capacity(),
+            ArcOrVTable::Vtable { ptr, vtable } => Some(unsafe { (vtable.capacity)(ptr, None) })
+                .filter(|&capacity| capacity != usize::MAX),
+        }
+    }
+
     fn get_metadata<S: Slice + ?Sized, M: Any>(data: &Self::Data) -> Option<&M> {
         match arc_or_vtable::<S>(*data) {
             ArcOrVTable::Arc(arc) => Some(unsafe { &*ptr::from_ref(arc.get_metadata::<M>()?) }),
@@ -264,6 +305,13 @@ unsafe impl ArcSliceLayout for RawLayout {
         }
     }
 
+    fn repr<S: Slice + ?Sized>(data: &Self::Data) -> Repr {
+        match arc_or_vtable::<S>(*data) {
+            ArcOrVTable::Arc(arc) => arc.repr(),
+            ArcOrVTable::Vtable { vtable, .. } => vtable.repr,
+        }
+    }
+
     unsafe fn take_buffer<S: Slice + ?Sized, B: Buffer<S>>(
         start: NonNull<S::Item>,
         length: usize,
@@ -277,7 +325,7 @@ unsafe impl ArcSliceLayout for RawLayout {
             }
             ArcOrVTable::Vtable { ptr, vtable } => {
                 match unsafe { generic_take_buffer(ptr, vtable, start.cast(), length) } {
-                    None if ptr::eq(vtable, static_vtable::VTABLE) => {
+                    None if vtable.repr == Repr::Static => {
                         try_transmute(unsafe { S::from_raw_parts::<'static>(start, length) }).ok()
                     }
                     buffer => buffer,
@@ -286,6 +334,23 @@ unsafe impl ArcSliceLayout for RawLayout {
         }
     }
 
+    unsafe fn take_buffer_with_metadata<S: Slice + ?Sized, B: Buffer<S>, M: Any>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &mut ManuallyDrop<Self::Data>,
+    ) -> Option<(B, M)> {
+        match arc_or_vtable::<S>(**data) {
+            ArcOrVTable::Arc(arc) => unsafe {
+                ManuallyDrop::into_inner(arc).take_buffer_with_metadata::<B, M>(start, length)
+            }
+            .map_err(mem::forget)
+            .ok(),
+            ArcOrVTable::Vtable { ptr, vtable } => unsafe {
+                generic_take_buffer_with_metadata(ptr, vtable, start.cast(), length)
+            },
+        }
+    }
+
     unsafe fn take_array<T: Send + Sync + 'static, const N: usize>(
         start: NonNull<T>,
         length: usize,
@@ -303,16 +368,20 @@ unsafe impl ArcSliceLayout for RawLayout {
 
     unsafe fn mut_data<S: Slice + ?Sized, L: ArcSliceMutLayout>(
         start: NonNull<S::Item>,
-        _length: usize,
+        length: usize,
         data: &mut ManuallyDrop<Self::Data>,
     ) -> Option<(usize, Option<slice_mut::Data<true>>)> {
         match arc_or_vtable::<S>(**data) {
             ArcOrVTable::Arc(mut arc) => Some((
-                unsafe { arc.capacity(start)? },
+                unsafe { arc.mut_capacity(start, length)? },
                 Some(L::try_data_from_arc(arc)?),
             )),
+            // like other layouts, empty static slices become slices without data
+            ArcOrVTable::Vtable { vtable, .. } if length == 0 && vtable.repr == Repr::Static => {
+                Some((0, None))
+            }
             ArcOrVTable::Vtable { ptr, vtable } => {
-                let capacity = unsafe { (vtable.capacity)(ptr, start.cast()) };
+                let capacity = unsafe { (vtable.capacity)(ptr, Some((start.cast(), length))) };
                 (capacity != usize::MAX).then(|| {
                     let data = slice_mut::Data(unsafe { NonNull::new_unchecked(ptr.cast_mut()) });
                     (capacity, Some(data))
@@ -329,7 +398,7 @@ unsafe impl ArcSliceLayout for RawLayout {
         let res = match arc_or_vtable::<S>(data) {
             ArcOrVTable::Arc(arc) => return L::try_data_from_arc(arc),
             ArcOrVTable::Vtable { vtable, .. } if !L::ANY_BUFFER => {
-                if L::STATIC_DATA.is_none() || !ptr::eq(vtable, static_vtable::VTABLE) {
+                if vtable.repr != Repr::Static || (L::STATIC_DATA.is_none() && length != 0) {
                     return None;
                 }
                 Ok(None)
@@ -10,10 +10,10 @@ use core::{
 use crate::msrv::{BoolExt, OffsetFromUnsignedExt, StrictProvenance};
 use crate::{
     arc::Arc,
-    atomic::{AtomicPtr, Ordering},
+    atomic::{ConstAtomicPtr, Ordering},
     buffer::{Buffer, BufferExt, BufferMut, BufferMutExt, Slice, SliceExt},
     error::{AllocError, AllocErrorImpl},
-    layout::{BoxedSliceLayout, VecLayout},
+    layout::{BoxedSliceLayout, Repr, VecLayout},
     macros::is,
     msrv::{ptr, NonZero},
     slice::ArcSliceLayout,
@@ -45,11 +45,11 @@ impl<S: Slice + ?Sized> Data<S> {
 }
 
 #[allow(missing_debug_implementations)]
-pub struct DataPtr(AtomicPtr<()>);
+pub struct DataPtr(ConstAtomicPtr<()>);
 
 impl DataPtr {
     const fn new_static() -> Self {
-        Self(AtomicPtr::new(ptr::null_mut()))
+        Self(ConstAtomicPtr::null())
     }
 
     fn capacity_as_ptr(capacity: usize) -> *mut () {
@@ -57,11 +57,11 @@ impl DataPtr {
     }
 
     fn new_capacity(capacity: usize) -> Self {
-        Self(AtomicPtr::new(Self::capacity_as_ptr(capacity)))
+        Self(ConstAtomicPtr::new(Self::capacity_as_ptr(capacity)))
     }
 
     fn new_arc<S: Slice + ?Sized, const ANY_BUFFER: bool>(arc: Arc<S, ANY_BUFFER>) -> Self {
-        Self(AtomicPtr::new(arc.into_raw().as_ptr()))
+        Self(ConstAtomicPtr::new(arc.into_raw().as_ptr()))
     }
 
     fn get<S: Slice + ?Sized>(&self) -> Data<S> {
@@ -69,7 +69,7 @@ impl DataPtr {
     }
 
     fn get_mut<S: Slice + ?Sized>(&mut self) -> Data<S> {
-        Data::from_ptr(*self.0.get_mut())
+        Data::from_ptr(self.0.load_mut())
     }
 
     #[cold]
@@ -98,6 +98,7 @@ impl DataPtr {
 pub trait BoxedSliceOrVecLayout {
     type Base: Copy;
     const TRUNCATABLE: bool;
+    const INLINED_REPR: Repr;
     fn get_base<S: Slice + ?Sized>(_vec: &mut S::Vec) -> Option<Self::Base>;
     unsafe fn rebuild_vec<S: Slice + ?Sized>(
         start: NonNull<S::Item>,
@@ -111,6 +112,7 @@ impl BoxedSliceOrVecLayout for BoxedSliceLayout {
     type Base = ();
 
     const TRUNCATABLE: bool = false;
+    const INLINED_REPR: Repr = Repr::BoxedSlice;
 
     fn get_base<S: Slice + ?Sized>(vec: &mut S::Vec) -> Option<Self::Base> {
         (vec.len() == vec.capacity()).then_some(())
@@ -132,6 +134,7 @@ impl BoxedSliceOrVecLayout for VecLayout {
     type Base = NonNull<()>;
 
     const TRUNCATABLE: bool = true;
+    const INLINED_REPR: Repr = Repr::Vec;
 
     fn get_base<S: Slice + ?Sized>(vec: &mut S::Vec) -> Option<Self::Base> {
         Some(S::vec_start(vec).cast())
@@ -154,6 +157,8 @@ unsafe impl<L: BoxedSliceOrVecLayout + 'static> ArcSliceLayout for L {
     const DATA_COPY: bool = false;
     const ANY_BUFFER: bool = true;
     #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY_DATA: Self::Data = (DataPtr::new_static(), MaybeUninit::uninit());
+    #[allow(clippy::declare_interior_mutable_const)]
     const STATIC_DATA: Option<Self::Data> = Some((DataPtr::new_static(), MaybeUninit::uninit()));
     #[allow(clippy::declare_interior_mutable_const)]
     const STATIC_DATA_UNCHECKED: MaybeUninit<Self::Data> =
@@ -194,6 +199,20 @@ unsafe impl<L: BoxedSliceOrVecLayout + 'static> ArcSliceLayout for L {
         Ok((new_ptr, MaybeUninit::uninit()))
     }
 
+    fn clone_many<S: Slice + ?Sized, E: AllocErrorImpl>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &Self::Data,
+        count: usize,
+    ) -> Result<Self::Data, E> {
+        // the first clone promotes the vector if needed
+        let clone = Self::clone::<S, E>(start, length, data)?;
+        if let Data::Arc(arc) = clone.0.get::<S>() {
+            arc.forget_clones(count - 1);
+        }
+        Ok(clone)
+    }
+
     unsafe fn drop<S: Slice + ?Sized, const UNIQUE_HINT: bool>(
         start: NonNull<S::Item>,
         length: usize,
@@ -235,6 +254,15 @@ unsafe impl<L: BoxedSliceOrVecLayout + 'static> ArcSliceLayout for L {
         }
     }
 
+    fn buffer_capacity<S: Slice + ?Sized>(data: &Self::Data) -> Option<usize> {
+        let (ptr, _) = data;
+        match ptr.get::<S>() {
+            Data::Static => None,
+            Data::Arc(arc) => arc.buffer_capacity(),
+            Data::Capacity(capacity) => Some(capacity.get()),
+        }
+    }
+
     fn get_metadata<S: Slice + ?Sized, M: Any>(data: &Self::Data) -> Option<&M> {
         let (ptr, _) = data;
         match ptr.get::<S>() {
@@ -243,6 +271,30 @@ unsafe impl<L: BoxedSliceOrVecLayout + 'static> ArcSliceLayout for L {
         }
     }
 
+    fn repr<S: Slice + ?Sized>(data: &Self::Data) -> Repr {
+        let (ptr, _) = data;
+        match ptr.get::<S>() {
+            Data::Static => Repr::Static,
+            Data::Arc(arc) => arc.repr(),
+            Data::Capacity(_) => L::INLINED_REPR,
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    fn validate<S: Slice + ?Sized>(start: NonNull<S::Item>, length: usize, data: &Self::Data) {
+        let (ptr, _) = data;
+        match ptr.get::<S>() {
+            Data::Static => {}
+            Data::Arc(arc) => arc.validate(start, length, false),
+            Data::Capacity(capacity) => {
+                assert!(
+                    length <= capacity.get(),
+                    "slice exceeds the vector capacity"
+                );
+            }
+        }
+    }
+
     unsafe fn take_buffer<S: Slice + ?Sized, B: Buffer<S>>(
         start: NonNull<S::Item>,
         length: usize,
@@ -275,6 +327,22 @@ unsafe impl<L: BoxedSliceOrVecLayout + 'static> ArcSliceLayout for L {
         }
     }
 
+    unsafe fn take_buffer_with_metadata<S: Slice + ?Sized, B: Buffer<S>, M: Any>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &mut ManuallyDrop<Self::Data>,
+    ) -> Option<(B, M)> {
+        let (ptr, _) = &mut **data;
+        match ptr.get_mut::<S>() {
+            Data::Arc(arc) => unsafe {
+                ManuallyDrop::into_inner(arc).take_buffer_with_metadata::<B, M>(start, length)
+            }
+            .map_err(mem::forget)
+            .ok(),
+            _ => None,
+        }
+    }
+
     unsafe fn take_array<T: Send + Sync + 'static, const N: usize>(
         start: NonNull<T>,
         length: usize,
@@ -300,7 +368,7 @@ unsafe impl<L: BoxedSliceOrVecLayout + 'static> ArcSliceLayout for L {
         match ptr.get_mut::<S>() {
             Data::Static => (length == 0).then_some((0, None)),
             Data::Arc(mut arc) => Some((
-                unsafe { arc.capacity(start)? },
+                unsafe { arc.mut_capacity(start, length)? },
                 Some(L2::try_data_from_arc(arc)?),
             )),
             Data::Capacity(capacity) => {
@@ -321,7 +389,7 @@ unsafe impl<L: BoxedSliceOrVecLayout + 'static> ArcSliceLayout for L {
         let (mut ptr, base) = data;
         match ptr.get_mut::<S>() {
             Data::Arc(arc) => L2::try_data_from_arc(arc),
-            Data::Static if L2::STATIC_DATA.is_some() || L2::ANY_BUFFER => {
+            Data::Static if L2::STATIC_DATA.is_some() || L2::ANY_BUFFER || length == 0 => {
                 L2::data_from_static::<_, E>(unsafe { S::from_raw_parts(start, length) }).ok()
             }
             _ if !L2::ANY_BUFFER => None,
@@ -1,4 +1,4 @@
-use alloc::{string::String, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::{
     any::Any,
     borrow::{Borrow, BorrowMut},
@@ -9,34 +9,45 @@ use core::{
     marker::PhantomData,
     mem,
     mem::{ManuallyDrop, MaybeUninit},
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, RangeBounds},
     ptr::NonNull,
     slice,
 };
 
+#[cfg(feature = "inlined")]
+use crate::inlined::{SmallArcSlice, SmallSlice};
 #[cfg(not(feature = "oom-handling"))]
-use crate::layout::{ArcLayout, CloneNoAllocLayout, VecLayout};
+use crate::layout::{ArcLayout, VecLayout};
 #[allow(unused_imports)]
 use crate::msrv::{NonNullExt, OptionExt, StrictProvenance};
+#[cfg(any(feature = "arbitrary", feature = "serde"))]
+use crate::utils::assert_checked;
+#[cfg(feature = "oom-handling")]
+use crate::utils::panic_reserve;
 use crate::{
     arc::Arc,
     buffer::{
-        BorrowMetadata, BufferExt, BufferMut, BufferWithMetadata, Concatenable, DynBuffer,
-        Emptyable, Extendable, Slice, SliceExt, Zeroable,
+        check_char_boundary, BorrowMetadata, Buffer, BufferExt, BufferMut, BufferSource,
+        BufferWithMetadata, Concatenable, DynBuffer, Emptyable, Extendable, MetadataChain, Slice,
+        SliceExt, SliceMut, SliceMutExt, Subsliceable, Zeroable,
+    },
+    error::{AllocError, AllocErrorImpl, CharBoundaryError, TryReserveError},
+    layout::{
+        AnyBufferLayout, CloneNoAllocLayout, DefaultLayoutMut, FromLayout, Layout, LayoutMut, Repr,
     },
-    error::{AllocError, AllocErrorImpl, TryReserveError},
-    layout::{AnyBufferLayout, DefaultLayoutMut, FromLayout, Layout, LayoutMut},
     macros::{assume, is},
     msrv::ptr,
     slice::ArcSliceLayout,
     utils::{
-        debug_slice, lower_hex, min_non_zero_cap, panic_out_of_range, transmute_checked,
-        try_transmute, upper_hex, UnwrapChecked, UnwrapInfallible,
+        ceil_char_boundary, concat_len, debug_slice, floor_char_boundary, lower_hex,
+        min_non_zero_cap, panic_advance_back_out_of_range, panic_boundary_order,
+        panic_end_out_of_range, panic_insert_out_of_range, panic_remove_out_of_range,
+        panic_remove_unsupported, panic_start_out_of_range, range_offset_len, transmute_checked,
+        try_char_boundary, try_transmute, upper_hex, within_offset_len, UnwrapChecked,
+        UnwrapInfallible,
     },
     ArcSlice,
 };
-#[cfg(feature = "serde")]
-use crate::{buffer::Buffer, utils::assert_checked};
 
 mod arc;
 mod vec;
@@ -44,7 +55,7 @@ mod vec;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Data<const UNIQUE: bool>(pub(crate) NonNull<()>);
 
-impl<S: Slice + ?Sized, const ANY_BUFFER: bool> From<Arc<S, ANY_BUFFER>> for Data<true> {
+impl<S: SliceMut + ?Sized, const ANY_BUFFER: bool> From<Arc<S, ANY_BUFFER>> for Data<true> {
     fn from(value: Arc<S, ANY_BUFFER>) -> Self {
         Self(value.into_raw())
     }
@@ -86,10 +97,27 @@ pub unsafe trait ArcSliceMutLayout {
         _data: &mut Data<UNIQUE>,
     ) {
     }
+    // sets the length of the buffer of a unique slice, returning `false` if not supported
+    fn set_unique_length<S: Slice + ?Sized, const UNIQUE: bool>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &mut Data<UNIQUE>,
+    ) -> bool;
     fn get_metadata<S: Slice + ?Sized, M: Any, const UNIQUE: bool>(
         data: &Data<UNIQUE>,
     ) -> Option<&M>;
-    unsafe fn take_buffer<S: Slice + ?Sized, B: BufferMut<S>, const UNIQUE: bool>(
+    fn repr<S: Slice + ?Sized, const UNIQUE: bool>(data: &Data<UNIQUE>) -> Repr;
+    #[cfg(feature = "arbitrary")]
+    fn validate<S: Slice + ?Sized, const UNIQUE: bool>(
+        _start: NonNull<S::Item>,
+        _capacity: usize,
+        _data: &Data<UNIQUE>,
+    ) {
+    }
+    fn get_buffer_mut<S: Slice + ?Sized, B: BufferMut<S>, const UNIQUE: bool>(
+        data: &mut Data<UNIQUE>,
+    ) -> Option<&mut B>;
+    unsafe fn take_buffer<S: Slice + ?Sized, B: Buffer<S>, const UNIQUE: bool>(
         start: NonNull<S::Item>,
         length: usize,
         capacity: usize,
@@ -101,6 +129,11 @@ pub unsafe trait ArcSliceMutLayout {
         data: Data<UNIQUE>,
     ) -> Option<[T; N]>;
     fn is_unique<S: Slice + ?Sized, const UNIQUE: bool>(data: &mut Data<UNIQUE>) -> bool;
+    // identifies the underlying buffer independently of the layout, `None` if the data doesn't
+    // identify it, e.g. a vector offset
+    fn buffer_ptr<S: Slice + ?Sized, const UNIQUE: bool>(
+        data: &Data<UNIQUE>,
+    ) -> Option<NonNull<()>>;
     fn try_reserve<S: Slice + ?Sized, const UNIQUE: bool>(
         start: NonNull<S::Item>,
         length: usize,
@@ -199,7 +232,7 @@ pub unsafe trait ArcSliceMutLayout {
 /// [`ArcBytesMut`]: crate::ArcBytesMut
 /// [`ArcStrMut`]: crate::ArcStrMut
 pub struct ArcSliceMut<
-    S: Slice + ?Sized,
+    S: SliceMut + ?Sized,
     L: LayoutMut = DefaultLayoutMut,
     const UNIQUE: bool = true,
 > {
@@ -210,7 +243,7 @@ pub struct ArcSliceMut<
     _phantom: PhantomData<L>,
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQUE> {
     /// Returns the number of items in the slice.
     ///
     /// # Examples
@@ -301,7 +334,16 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         self.capacity
     }
 
-    fn spare_capacity(&self) -> usize {
+    /// Returns the number of items that can be appended without reserving capacity.
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(64);
+    /// s.push(0);
+    /// assert_eq!(s.spare_capacity(), 63);
+    /// ```
+    pub const fn spare_capacity(&self) -> usize {
         self.capacity - self.length
     }
 
@@ -367,6 +409,55 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         self.length = new_len;
     }
 
+    /// Returns a writer filling the spare capacity in windows of at most `chunk_size` items,
+    /// e.g. to issue bounded reads, or `None` if the buffer doesn't support it.
+    ///
+    /// Contrary to [`spare_capacity_mut`](Self::spare_capacity_mut), this method is safe, because
+    /// it is only supported by buffers allocated by the crate or by vectors, i.e. with
+    /// [`Repr::ArcSlice`] or [`Repr::Vec`] representation, for which writing uninitialized
+    /// memory in the spare capacity is sound. An empty `ArcSliceMut` without buffer is
+    /// supported as well, but has no spare capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(10);
+    /// let mut chunks = s.spare_capacity_chunks_mut(4).unwrap();
+    /// while let Some(chunk) = chunks.next_chunk() {
+    ///     // reads may be partial
+    ///     let n = chunk.len().min(3);
+    ///     for uninit in &mut chunk[..n] {
+    ///         uninit.write(b'a');
+    ///     }
+    ///     // SAFETY: the first `n` items of the chunk are initialized
+    ///     unsafe { chunks.commit(n) };
+    /// }
+    /// assert_eq!(s, b"aaaaaaaaaa");
+    /// ```
+    #[track_caller]
+    pub fn spare_capacity_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> Option<SpareCapacityChunksMut<'_, S, L, UNIQUE>>
+    where
+        S: Extendable,
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        match self.repr() {
+            Repr::ArcSlice | Repr::Vec | Repr::Static => Some(SpareCapacityChunksMut {
+                slice: self,
+                chunk_size,
+            }),
+            _ => None,
+        }
+    }
+
     /// Tries appending an element to the end of the slice, returning an error if the capacity
     /// reservation fails.
     ///
@@ -461,6 +552,89 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         self.try_reserve_impl(additional, true)
     }
 
+    /// Tries reserving capacity for at least `additional` more items, or for `capacity_hint`
+    /// items in total if the buffer has to grow, returning an error if the operation fails.
+    ///
+    /// Does nothing if the spare capacity is greater than `additional`; the hint is only used
+    /// when a reservation is needed anyway, e.g. to make the first allocation of a network
+    /// buffer big enough, instead of growing it progressively from a few items. See
+    /// [`try_reserve`](Self::try_reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut s = ArcSliceMut::<[u8]>::new();
+    /// s.try_reserve_with_hint(1, 4096)?;
+    /// assert!(s.capacity() >= 4096);
+    /// let ptr = s.as_ptr();
+    /// for i in 0..4096 {
+    ///     s.push(i as u8);
+    /// }
+    /// assert_eq!(s.as_ptr(), ptr);
+    /// // the hint is ignored if the spare capacity is enough
+    /// s.truncate(0);
+    /// s.try_reserve_with_hint(1, 8192)?;
+    /// assert_eq!(s.as_ptr(), ptr);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_reserve_with_hint(
+        &mut self,
+        additional: usize,
+        capacity_hint: usize,
+    ) -> Result<(), TryReserveError> {
+        if additional <= self.spare_capacity() {
+            return Ok(());
+        }
+        let hinted = capacity_hint.saturating_sub(self.length);
+        self.try_reserve_impl(cmp::max(additional, hinted), true)
+    }
+
+    /// Reserves capacity for as many items as possible up to `additional`, returning the number
+    /// of items that can be appended without reserving capacity, capped to `additional`.
+    ///
+    /// This is the infallible counterpart of [`try_reserve`](Self::try_reserve): when the
+    /// allocation fails or exceeds the maximal capacity, the largest amount that can be reserved
+    /// is used instead; when the reservation is not possible at all, for example because the
+    /// `ArcSliceMut` is shared or because the underlying buffer has a fixed capacity, the current
+    /// spare capacity is returned, which may be 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{buffer::AsMutBuffer, layout::ArcLayout, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::new();
+    /// assert_eq!(s.try_reserve_at_most(3), 3);
+    /// assert!(s.capacity() >= 3);
+    ///
+    /// let buffer = unsafe { AsMutBuffer::new([0u8; 8]) };
+    /// let mut s = ArcSliceMut::<[u8], ArcLayout<true>>::from_buffer(buffer);
+    /// s.truncate(6);
+    /// assert_eq!(s.try_reserve_at_most(4), 2);
+    /// assert_eq!(s.capacity(), 8);
+    /// ```
+    pub fn try_reserve_at_most(&mut self, additional: usize) -> usize {
+        let spare_capacity = self.spare_capacity();
+        let max = (isize::MAX as usize / cmp::max(mem::size_of::<S::Item>(), 1))
+            .saturating_sub(self.length);
+        let mut reserved = cmp::min(additional, max);
+        while reserved > spare_capacity {
+            match self.try_reserve(reserved) {
+                Ok(()) => return reserved,
+                // a smaller reservation may still succeed, so the excess is halved
+                Err(TryReserveError::AllocError | TryReserveError::CapacityOverflow) => {
+                    reserved = spare_capacity + (reserved - spare_capacity) / 2;
+                }
+                Err(TryReserveError::NotUnique | TryReserveError::Unsupported) => break,
+            }
+        }
+        cmp::min(spare_capacity, additional)
+    }
+
     fn try_reserve_impl(
         &mut self,
         additional: usize,
@@ -530,7 +704,7 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         Ok(())
     }
 
-    unsafe fn extend_from_slice_unchecked(&mut self, slice: &[S::Item])
+    pub(crate) unsafe fn extend_from_slice_unchecked(&mut self, slice: &[S::Item])
     where
         S: Concatenable,
         S::Item: Copy,
@@ -542,6 +716,85 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         }
     }
 
+    fn format_impl(&mut self, args: fmt::Arguments<'_>) -> Result<(), Option<TryReserveError>>
+    where
+        S: SliceMut<Item = u8> + Concatenable,
+    {
+        if let Some(s) = args.as_str() {
+            self.try_reserve(s.len())?;
+            unsafe { self.extend_from_slice_unchecked(s.as_bytes()) };
+            return Ok(());
+        }
+        let length = self.length;
+        // format directly into the spare capacity, counting the length which doesn't fit
+        let mut writer = FormatWriter {
+            slice: self,
+            overflow: Some(0),
+            error: None,
+        };
+        let res = fmt::write(&mut writer, args);
+        let overflow = writer.overflow;
+        if res.is_err() {
+            unsafe { self.truncate_unchecked(length) };
+            return Err(None);
+        }
+        let overflow = match overflow {
+            Some(0) | None => return Ok(()),
+            Some(overflow) => overflow,
+        };
+        // format again, the whole length being reserved at once
+        let formatted = self.length - length;
+        unsafe { self.truncate_unchecked(length) };
+        self.try_reserve(formatted.saturating_add(overflow))?;
+        let mut writer = FormatWriter {
+            slice: self,
+            overflow: None,
+            error: None,
+        };
+        if fmt::write(&mut writer, args).is_err() {
+            let error = writer.error;
+            unsafe { self.truncate_unchecked(length) };
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Tries appending formatted arguments to the slice, returning an error if the capacity
+    /// reservation fails.
+    ///
+    /// Unlike [`write!`], which goes through [`fmt::Write`] and only returns an opaque
+    /// [`fmt::Error`], the reservation error is returned. The arguments are formatted directly
+    /// into the spare capacity; if it is not enough, the formatted length is computed at the same
+    /// time, so capacity is reserved only once before formatting again. The slice is left
+    /// unchanged if an error occurs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::TryReserveError, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<str>::new();
+    /// s.format(format_args!("{}-{}", 4, 2)).unwrap();
+    /// assert_eq!(s, "4-2");
+    ///
+    /// let mut s = s.into_shared();
+    /// let _other = s.split_off(3);
+    /// let err = s.format(format_args!("{:>64}", 42)).unwrap_err();
+    /// assert_eq!(err, TryReserveError::NotUnique);
+    /// assert_eq!(s, "4-2");
+    /// ```
+    pub fn format(&mut self, args: fmt::Arguments<'_>) -> Result<(), TryReserveError>
+    where
+        S: SliceMut<Item = u8> + Concatenable,
+    {
+        self.format_impl(args)
+            .map_err(|err| err.expect("a formatting trait implementation returned an error"))
+    }
+
     /// Advances the start of the slice by `offset` items.
     ///
     /// This operation does not touch the underlying buffer.
@@ -559,10 +812,15 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
     /// s.advance(6);
     /// assert_eq!(s, b"world");
     /// ```
-    pub fn advance(&mut self, offset: usize) {
+    #[track_caller]
+    pub fn advance(&mut self, offset: usize)
+    where
+        S: Subsliceable,
+    {
         if offset > self.length {
-            panic_out_of_range();
+            panic_start_out_of_range(offset, self.length);
         }
+        unsafe { self.check_advance(offset) };
         L::advance::<S, UNIQUE>(self.data.as_mut(), offset);
         self.start = unsafe { self.start.add(offset) };
         self.length -= offset;
@@ -580,11 +838,33 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
     /// s.truncate(5);
     /// assert_eq!(s, b"hello");
     /// ```
-    pub fn truncate(&mut self, len: usize) {
-        if len >= self.length {
-            return;
+    #[track_caller]
+    pub fn truncate(&mut self, len: usize)
+    where
+        S: Subsliceable,
+    {
+        if len < self.length {
+            unsafe { self.check_truncate(len) };
+            unsafe { self.truncate_unchecked(len) };
         }
+    }
+
+    /// # Safety
+    ///
+    /// `len` must not be greater than the slice length, and must be a valid subslice end.
+    pub(crate) unsafe fn truncate_unchecked(&mut self, len: usize) {
         if S::needs_drop() {
+            let set_unique_length = <L as ArcSliceMutLayout>::set_unique_length::<S, UNIQUE>;
+            let data = unsafe { self.data.as_mut().unwrap_unchecked() };
+            // a unique slice drops the truncated items like a `Vec`, the length being set first
+            // so a panicking drop cannot cause a double drop
+            if UNIQUE && set_unique_length(self.start, len, data) {
+                let tail = unsafe { self.start.as_ptr().add(len) };
+                let tail_len = self.length - len;
+                self.length = len;
+                unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, tail_len)) };
+                return;
+            }
             let truncate = <L as ArcSliceMutLayout>::truncate::<S, UNIQUE>;
             let data = unsafe { self.data.as_mut().unwrap_unchecked() };
             truncate(self.start, self.length, self.capacity, data);
@@ -594,8 +874,40 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         self.length = len;
     }
 
+    /// Removes the last `count` items of the slice.
+    ///
+    /// This is equivalent to `self.truncate(self.len() - count)`, but panics if the count is
+    /// out of range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count > self.len()`, or if the new end is not a valid subslice end, e.g. not
+    /// a char boundary for `str`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello world");
+    /// s.advance_back(6);
+    /// assert_eq!(s, b"hello");
+    /// ```
+    #[track_caller]
+    pub fn advance_back(&mut self, count: usize)
+    where
+        S: Subsliceable,
+    {
+        if count > self.length {
+            panic_advance_back_out_of_range(count, self.length);
+        }
+        self.truncate(self.length - count);
+    }
+
     /// Accesses the metadata of the underlying buffer if it can be successfully downcast.
     ///
+    /// If the metadata is a [`MetadataChain`], each of its elements can also be accessed.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -610,6 +922,113 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         <L as ArcSliceMutLayout>::get_metadata::<S, M, UNIQUE>(self.data.as_ref()?)
     }
 
+    /// Returns the runtime representation of the underlying buffer.
+    ///
+    /// See [`Repr`]; an empty `ArcSliceMut` without allocated buffer is [`Repr::Static`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{
+    ///     layout::{Repr, VecLayout},
+    ///     ArcSliceMut,
+    /// };
+    ///
+    /// let s = ArcSliceMut::<[u8], VecLayout>::from(vec![0, 1, 2]);
+    /// assert_eq!(s.repr(), Repr::Vec);
+    /// assert_eq!(ArcSliceMut::<[u8]>::new().repr(), Repr::Static);
+    /// ```
+    pub fn repr(&self) -> Repr {
+        match &self.data {
+            Some(data) => <L as ArcSliceMutLayout>::repr::<S, UNIQUE>(data),
+            None => Repr::Static,
+        }
+    }
+
+    /// Decomposes the `ArcSliceMut` into its raw parts.
+    ///
+    /// The returned [`ArcSliceMutRawParts`] takes over the ownership of the slice, which can be
+    /// recovered with [`from_raw_parts`](Self::from_raw_parts); dropping the parts leaks the
+    /// underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(16);
+    /// s.extend_from_slice(b"hello world");
+    /// let parts = s.into_raw_parts();
+    /// assert_eq!((parts.length, parts.capacity), (11, 16));
+    /// let s = unsafe { ArcSliceMut::from_raw_parts(parts) };
+    /// assert_eq!(s, b"hello world");
+    /// ```
+    pub fn into_raw_parts(self) -> ArcSliceMutRawParts<S, L, UNIQUE> {
+        let this = ManuallyDrop::new(self);
+        ArcSliceMutRawParts {
+            start: this.start,
+            length: this.length,
+            capacity: this.capacity,
+            data: this.data.map(|data| data.0),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Reconstructs an `ArcSliceMut` from its raw parts.
+    ///
+    /// # Safety
+    ///
+    /// The parts must have been returned by [`into_raw_parts`](Self::into_raw_parts), with
+    /// their `start`, `length` and `capacity` fields unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let s = ArcSliceMut::<[u8]>::from(b"hello world");
+    /// let parts = s.into_raw_parts();
+    /// let s = unsafe { ArcSliceMut::from_raw_parts(parts) };
+    /// assert_eq!(s, b"hello world");
+    /// ```
+    pub unsafe fn from_raw_parts(parts: ArcSliceMutRawParts<S, L, UNIQUE>) -> Self {
+        Self {
+            start: parts.start,
+            length: parts.length,
+            capacity: parts.capacity,
+            data: parts.data.map(Data),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Checks the internal invariants of the slice, panicking if one of them is broken.
+    ///
+    /// The length must not exceed the capacity, which must lie within the underlying buffer;
+    /// the buffer refcount must not be zero, and must be one if the slice is known to be unique.
+    /// It is intended for tests and fuzzing, e.g. after each operation of a random sequence;
+    /// a broken invariant means a bug in this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello world").into_shared();
+    /// let s2 = s.split_off(5);
+    /// s.debug_validate();
+    /// s2.debug_validate();
+    /// ```
+    #[cfg(feature = "arbitrary")]
+    pub fn debug_validate(&self) {
+        assert!(self.length <= self.capacity, "length exceeds capacity");
+        match &self.data {
+            Some(data) => {
+                <L as ArcSliceMutLayout>::validate::<S, UNIQUE>(self.start, self.capacity, data);
+            }
+            None => assert_eq!(self.capacity, 0, "static slice with capacity"),
+        }
+    }
+
     /// Tries downcasting the `ArcSliceMut` to its underlying buffer.
     ///
     /// # Examples
@@ -619,8 +1038,13 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
     ///
     /// let s = ArcSliceMut::<[u8], ArcLayout<true>>::from(vec![0, 1, 2]);
     /// assert_eq!(s.try_into_buffer::<Vec<u8>>().unwrap(), [0, 1, 2]);
+    ///
+    /// // a boxed slice can be recovered if the slice spans the whole buffer
+    /// let boxed: Box<[u8]> = Box::new([0, 1, 2]);
+    /// let s = ArcSliceMut::<[u8], ArcLayout<true>>::from(boxed);
+    /// assert_eq!(*s.try_into_buffer::<Box<[u8]>>().unwrap(), [0, 1, 2]);
     /// ```
-    pub fn try_into_buffer<B: BufferMut<S>>(self) -> Result<B, Self> {
+    pub fn try_into_buffer<B: Buffer<S>>(self) -> Result<B, Self> {
         // MSRV 1.65 let-else
         let data = match self.data {
             Some(data) => data,
@@ -632,6 +1056,62 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
             .ok_or_else(|| ManuallyDrop::into_inner(this))
     }
 
+    /// Tries reclaiming the underlying buffer as a boxed slice, returning the original slice if
+    /// it fails.
+    ///
+    /// The conversion doesn't copy nor allocate; it succeeds only if the buffer is a unique vector
+    /// or boxed slice, and if the slice spans the whole buffer, i.e. without advanced items nor
+    /// spare capacity. Buffers allocated by the crate, e.g. with
+    /// [`from_slice`](Self::from_slice), are not vectors, so their conversion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSliceMut};
+    ///
+    /// let s = ArcSliceMut::<[u8], ArcLayout<true>>::from(vec![0, 1, 2]);
+    /// assert_eq!(*s.try_into_boxed_slice().unwrap(), [0, 1, 2]);
+    ///
+    /// let mut s = ArcSliceMut::<[u8], ArcLayout<true>>::from(vec![0, 1, 2]);
+    /// s.truncate(2);
+    /// assert!(s.try_into_boxed_slice().is_err());
+    ///
+    /// let s = ArcSliceMut::<[u8], ArcLayout<false>>::from_slice(b"abc");
+    /// assert!(s.try_into_boxed_slice().is_err());
+    /// ```
+    pub fn try_into_boxed_slice(self) -> Result<Box<S>, Self> {
+        self.try_into_buffer()
+    }
+
+    /// Returns a mutable reference to the underlying buffer, if it is unique and has the
+    /// requested type.
+    ///
+    /// Only buffers wrapped with [`from_buffer`](Self::from_buffer) and similar constructors
+    /// can be accessed this way; vectors are stored in a different way, so `None` is returned
+    /// for `Vec` and `String`.
+    ///
+    /// # Safety
+    ///
+    /// The `ArcSliceMut` keeps pointing into the buffer slice, so the buffer must not be
+    /// reallocated, nor have its slice moved or shrunk, through the returned reference.
+    ///
+    /// Items written through the `ArcSliceMut` may not be reflected in the buffer length yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{buffer::AsMutBuffer, layout::ArcLayout, ArcSliceMut};
+    ///
+    /// let buffer = unsafe { AsMutBuffer::new([0u8, 1, 2]) };
+    /// let mut s = ArcSliceMut::<[u8], ArcLayout<true>>::from_buffer(buffer);
+    /// let buffer = unsafe { s.buffer_mut::<AsMutBuffer<[u8; 3]>>() }.unwrap();
+    /// assert_eq!(**buffer, [0, 1, 2]);
+    /// assert!(unsafe { s.buffer_mut::<Vec<u8>>() }.is_none());
+    /// ```
+    pub unsafe fn buffer_mut<B: BufferMut<S>>(&mut self) -> Option<&mut B> {
+        <L as ArcSliceMutLayout>::get_buffer_mut::<S, B, UNIQUE>(self.data.as_mut()?)
+    }
+
     /// Tries turning the shared `ArcSliceMut` into a unique one.
     ///
     /// # Examples
@@ -672,21 +1152,15 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
     }
 
     fn freeze_impl<L2: Layout, E: AllocErrorImpl>(self) -> Result<ArcSlice<S, L2>, Self> {
-        let mut this = ManuallyDrop::new(self);
+        let this = ManuallyDrop::new(self);
         let frozen_data = L::frozen_data::<S, L2, E, UNIQUE>;
         let data = match this.data {
             Some(data) => frozen_data(this.start, this.length, this.capacity, data),
-            None if L2::STATIC_DATA.is_some() || L2::ANY_BUFFER => {
+            // slices without data are empty, so they can be frozen without allocation
+            None => {
                 L2::data_from_static::<_, E>(unsafe { S::from_raw_parts(this.start, this.length) })
                     .ok()
             }
-            None => match Arc::new_array::<E, 0>([]) {
-                Ok((arc, start)) => {
-                    this.start = start;
-                    Some(L2::data_from_arc_slice::<S>(arc))
-                }
-                Err(_) => None,
-            },
         };
         match data {
             Some(data) => Ok(ArcSlice::init(this.start, this.length, data)),
@@ -700,6 +1174,9 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
     ///
     /// The conversion may allocate depending on the given [layouts](crate::layout), but allocation
     /// errors are caught and the original slice is returned in this case.
+    /// <br>
+    /// The buffer [metadata](Self::metadata) is preserved; if the target layout doesn't support
+    /// it, the original slice is returned. See [layouts documentation](crate::layout#buffer-metadata).
     ///
     /// # Examples
     ///
@@ -715,6 +1192,70 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         self.freeze_impl::<L2, AllocError>()
     }
 
+    #[track_caller]
+    fn freeze_parts_impl<L2: Layout, E: AllocErrorImpl>(
+        self,
+        boundaries: &[usize],
+    ) -> Result<Vec<ArcSlice<S, L2>>, Self>
+    where
+        S: Subsliceable,
+    {
+        let mut prev = 0;
+        for &boundary in boundaries {
+            if boundary < prev {
+                panic_boundary_order(prev, boundary);
+            } else if boundary > self.length {
+                panic_end_out_of_range(boundary, self.length);
+            }
+            unsafe { self.check_split(boundary) };
+            prev = boundary;
+        }
+        let mut parts = Vec::new();
+        // in the infallible case, a failed reservation is handled by the pushes
+        if parts.try_reserve_exact(boundaries.len() + 1).is_err() && E::FALLIBLE {
+            return Err(self);
+        }
+        let frozen = self.freeze_impl::<L2, E>()?;
+        unsafe { frozen.split_parts(boundaries, &mut parts) };
+        Ok(parts)
+    }
+
+    /// Tries freezing the slice into consecutive parts delimited by `boundaries`, returning the
+    /// original slice if the conversion fails.
+    ///
+    /// The parts are `[0, b₀)`, `[b₀, b₁)`, …, `[bₙ, len)`; the slice is frozen once, and the
+    /// references of all the non-empty parts are added at once. Empty parts are not refcounted.
+    ///
+    /// The target layout must not allocate when cloned. See [`try_freeze`](Self::try_freeze) for
+    /// cases where the conversion fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the boundaries are not sorted, if one of them is greater than `self.len()`, or
+    /// if it's not a valid split index, e.g. not a char boundary for `str`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcBytes, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(16);
+    /// s.extend_from_slice(b"hello world");
+    ///
+    /// let parts: Vec<ArcBytes<ArcLayout>> = s.try_freeze_parts(&[5, 6]).unwrap();
+    /// assert_eq!(parts, [&b"hello"[..], b" ", b"world"]);
+    /// ```
+    #[track_caller]
+    pub fn try_freeze_parts<L2: CloneNoAllocLayout>(
+        self,
+        boundaries: &[usize],
+    ) -> Result<Vec<ArcSlice<S, L2>>, Self>
+    where
+        S: Subsliceable,
+    {
+        self.freeze_parts_impl::<L2, AllocError>(boundaries)
+    }
+
     fn with_layout_impl<L2: LayoutMut, E: AllocErrorImpl>(
         self,
     ) -> Result<ArcSliceMut<S, L2, UNIQUE>, Self> {
@@ -781,7 +1322,7 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
 
     /// Tries converting an item slice into the given `ArcSliceMut`.
     ///
-    /// The conversion uses [`Slice::try_from_slice_mut`].
+    /// The conversion uses [`SliceMut::try_from_slice_mut`].
     ///
     /// # Examples
     ///
@@ -808,7 +1349,7 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
     ///
     /// # Safety
     ///
-    /// The operation has the same contract as [`Slice::from_slice_mut_unchecked`].
+    /// The operation has the same contract as [`SliceMut::from_slice_mut_unchecked`].
     ///
     /// # Examples
     ///
@@ -837,10 +1378,11 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
 }
 
 #[cfg(feature = "oom-handling")]
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQUE> {
     /// Freeze the slice, returning an immutable [`ArcSlice`].
     ///
     /// If the mutable slice was split into several parts, only the current one is frozen.
+    /// The buffer [metadata](Self::metadata) is preserved.
     ///
     /// # Examples
     ///
@@ -856,11 +1398,79 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
         self.freeze_impl::<L2, Infallible>().unwrap_checked()
     }
 
-    /// Replace the layout of the `ArcSliceMut`.
+    /// Freezes the slice into consecutive parts delimited by `boundaries`.
     ///
-    /// The [layouts](crate::layout) must be compatible, see [`FromLayout`].
+    /// The parts are `[0, b₀)`, `[b₀, b₁)`, …, `[bₙ, len)`, so there is always one more part
+    /// than boundaries. It is equivalent to freezing the slice and taking a subslice for each
+    /// part, but the references of all the non-empty parts are added at once. Empty parts are
+    /// not refcounted.
     ///
-    /// # Examples
+    /// # Panics
+    ///
+    /// Panics if the boundaries are not sorted, if one of them is greater than `self.len()`, or
+    /// if it's not a valid split index, e.g. not a char boundary for `str`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(16);
+    /// s.extend_from_slice(b"hello world");
+    ///
+    /// let parts: Vec<ArcBytes> = s.freeze_parts(&[5, 6]);
+    /// assert_eq!(parts, [&b"hello"[..], b" ", b"world"]);
+    /// ```
+    #[track_caller]
+    pub fn freeze_parts<L2: FromLayout<L>>(self, boundaries: &[usize]) -> Vec<ArcSlice<S, L2>>
+    where
+        S: Subsliceable,
+    {
+        self.freeze_parts_impl::<L2, Infallible>(boundaries)
+            .unwrap_checked()
+    }
+
+    /// Converts the `ArcSliceMut` into a boxed slice.
+    ///
+    /// If the underlying buffer is a unique vector, or can be reclaimed as a boxed slice, it is
+    /// shrunk to fit the slice, like [`Vec::into_boxed_slice`], which may reallocate.
+    /// Otherwise, the slice is copied into a new boxed slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSliceMut};
+    ///
+    /// let mut vec = Vec::with_capacity(16);
+    /// vec.extend_from_slice(b"hello world");
+    /// let mut s = ArcSliceMut::<[u8], ArcLayout<true>>::from(vec);
+    /// s.advance(6);
+    /// assert_eq!(*s.into_boxed_slice(), *b"world");
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello world").into_shared();
+    /// let s2 = s.split_off(5);
+    /// assert_eq!(*s.into_boxed_slice(), *b"hello");
+    /// ```
+    pub fn into_boxed_slice(self) -> Box<S>
+    where
+        S::Item: Copy,
+    {
+        let this = match self.try_into_buffer::<Box<S>>() {
+            Ok(boxed) => return boxed,
+            Err(this) => this,
+        };
+        let boxed = match this.try_into_buffer::<S::Vec>() {
+            Ok(vec) => S::into_vec(vec).into_boxed_slice(),
+            Err(this) => this.to_slice().into(),
+        };
+        unsafe { S::from_boxed_slice_unchecked(boxed) }
+    }
+
+    /// Replace the layout of the `ArcSliceMut`.
+    ///
+    /// The [layouts](crate::layout) must be compatible, see [`FromLayout`].
+    ///
+    /// # Examples
     /// ```rust
     /// use arc_slice::{
     ///     layout::{ArcLayout, BoxedSliceLayout, VecLayout},
@@ -877,12 +1487,18 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQ
 }
 
 #[cfg(not(feature = "oom-handling"))]
-impl<S: Slice + ?Sized, const ANY_BUFFER: bool, const STATIC: bool, const UNIQUE: bool>
-    ArcSliceMut<S, ArcLayout<ANY_BUFFER, STATIC>, UNIQUE>
+impl<
+        S: SliceMut + ?Sized,
+        const ANY_BUFFER: bool,
+        const STATIC: bool,
+        const SATURATE: bool,
+        const UNIQUE: bool,
+    > ArcSliceMut<S, ArcLayout<ANY_BUFFER, STATIC, SATURATE>, UNIQUE>
 {
     /// Freeze the slice, returning an immutable [`ArcSlice`].
     ///
     /// If the mutable slice was split into several parts, only the current one is frozen.
+    /// The buffer [metadata](Self::metadata) is preserved.
     ///
     /// # Examples
     ///
@@ -894,7 +1510,9 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool, const STATIC: bool, const UNIQUE
     ///
     /// let frozen: ArcSlice<[u8]> = s.freeze();
     /// ```
-    pub fn freeze<L2: FromLayout<ArcLayout<ANY_BUFFER, STATIC>>>(self) -> ArcSlice<S, L2> {
+    pub fn freeze<L2: FromLayout<ArcLayout<ANY_BUFFER, STATIC, SATURATE>>>(
+        self,
+    ) -> ArcSlice<S, L2> {
         self.freeze_impl::<L2, Infallible>().unwrap_checked()
     }
 
@@ -913,14 +1531,14 @@ impl<S: Slice + ?Sized, const ANY_BUFFER: bool, const STATIC: bool, const UNIQUE
     ///
     /// let b = a.with_layout::<VecLayout>();
     /// ```
-    pub fn with_layout<L2: LayoutMut + FromLayout<ArcLayout<ANY_BUFFER, STATIC>>>(
+    pub fn with_layout<L2: LayoutMut + FromLayout<ArcLayout<ANY_BUFFER, STATIC, SATURATE>>>(
         self,
     ) -> ArcSliceMut<S, L2, UNIQUE> {
         self.with_layout_impl::<L2, Infallible>().unwrap_checked()
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L> {
+impl<S: SliceMut + ?Sized, L: LayoutMut> ArcSliceMut<S, L> {
     pub(crate) const fn init(
         start: NonNull<S::Item>,
         length: usize,
@@ -1021,7 +1639,7 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L> {
         Self::from_slice_impl::<AllocError>(slice)
     }
 
-    #[cfg(feature = "serde")]
+    #[cfg(any(feature = "arbitrary", feature = "serde"))]
     pub(crate) fn new_bytes(slice: &S) -> Self {
         assert_checked(is!(S::Item, u8));
         let (arc, start) = unsafe {
@@ -1030,11 +1648,11 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L> {
         Self::init(start, slice.len(), slice.len(), Some(arc.into()))
     }
 
-    #[cfg(feature = "serde")]
+    #[cfg(any(feature = "arbitrary", feature = "serde"))]
     pub(crate) fn new_byte_vec(vec: S::Vec) -> Self {
         assert_checked(is!(S::Item, u8));
         if !<L as ArcSliceMutLayout>::ANY_BUFFER {
-            return Self::new_bytes(ManuallyDrop::new(vec).as_slice());
+            return Self::new_bytes(vec.as_slice());
         }
         Self::from_vec(vec)
     }
@@ -1119,6 +1737,253 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L> {
         Self::with_capacity_impl::<AllocError, false>(capacity)
     }
 
+    fn deep_clone_impl<E: AllocErrorImpl>(&self) -> Result<Self, E>
+    where
+        S::Item: Copy,
+    {
+        let mut clone = Self::with_capacity_impl::<E, false>(self.capacity)?;
+        let (src, dst) = (self.start.as_ptr(), clone.start.as_ptr());
+        unsafe { ptr::copy_nonoverlapping(src, dst, self.length) };
+        clone.length = self.length;
+        Ok(clone)
+    }
+
+    /// Copies the slice into a new buffer with the same capacity.
+    ///
+    /// `ArcSliceMut` doesn't implement [`Clone`], as it is unique, so the deep copy must be
+    /// explicit. The copy is unique too, and independent of the original slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let s = ArcSliceMut::<[u8]>::from(b"hello");
+    /// let mut copy = s.deep_clone();
+    /// copy[0] = b'j';
+    /// assert_eq!(s, b"hello");
+    /// assert_eq!(copy, b"jello");
+    /// assert_eq!(copy.capacity(), s.capacity());
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn deep_clone(&self) -> Self
+    where
+        S::Item: Copy,
+    {
+        self.deep_clone_impl::<Infallible>().unwrap_infallible()
+    }
+
+    /// Tries copying the slice into a new buffer with the same capacity, returning an error if
+    /// an allocation fails.
+    ///
+    /// See [`deep_clone`](Self::deep_clone).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// let s = ArcSliceMut::<str>::from("hello");
+    /// let copy = s.try_deep_clone()?;
+    /// assert_eq!(copy, "hello");
+    /// assert_ne!(copy.as_ptr(), s.as_ptr());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_deep_clone(&self) -> Result<Self, AllocError>
+    where
+        S::Item: Copy,
+    {
+        self.deep_clone_impl::<AllocError>()
+    }
+
+    #[cfg(feature = "oom-handling")]
+    unsafe fn build_impl<E: AllocErrorImpl>(
+        len_hint: usize,
+        f: impl FnOnce(&mut [MaybeUninit<S::Item>]) -> usize,
+    ) -> Result<Self, E>
+    where
+        S: Emptyable + Extendable,
+    {
+        let mut this = Self::with_capacity_impl::<E, false>(len_hint)?;
+        // SAFETY: the buffer is allocated by the crate, so uninit items can be written
+        let len = f(unsafe { this.spare_capacity_mut() });
+        assert!(len <= this.capacity, "initialized count exceeds capacity");
+        // SAFETY: the first `len` items are initialized per the function contract
+        unsafe { this.set_len(len) };
+        Ok(this)
+    }
+
+    /// Creates a new `ArcSliceMut` with at least `len_hint` capacity, filled by `f`.
+    ///
+    /// `f` receives the uninitialized spare capacity and returns the number of items it has
+    /// initialized at its start, which becomes the length of the slice. Contrary to
+    /// [`spare_capacity_mut`](Self::spare_capacity_mut), writing uninitialized items is always
+    /// sound, as the buffer is allocated by the crate.
+    ///
+    /// # Safety
+    ///
+    /// The first items, up to the count returned by `f`, must be initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes, or if `f`
+    /// returns a count greater than the length of the spare capacity it receives, i.e. the
+    /// capacity of the slice, which is at least `len_hint`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// // SAFETY: the first 3 items are initialized
+    /// let s = unsafe {
+    ///     ArcSliceMut::<[u32]>::build(4, |uninit| {
+    ///         for (i, item) in uninit[..3].iter_mut().enumerate() {
+    ///             item.write(i as u32);
+    ///         }
+    ///         3
+    ///     })
+    /// };
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.capacity(), 4);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub unsafe fn build(
+        len_hint: usize,
+        f: impl FnOnce(&mut [MaybeUninit<S::Item>]) -> usize,
+    ) -> Self
+    where
+        S: Emptyable + Extendable,
+    {
+        unsafe { Self::build_impl::<Infallible>(len_hint, f) }.unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSliceMut` with at least `len_hint` capacity, filled by `f`,
+    /// returning an error if an allocation fails.
+    ///
+    /// See [`build`](Self::build); `f` is not called if the allocation fails.
+    ///
+    /// # Safety
+    ///
+    /// The first items, up to the count returned by `f`, must be initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes, or if `f`
+    /// returns a count greater than the length of the spare capacity it receives, i.e. the
+    /// capacity of the slice, which is at least `len_hint`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// // SAFETY: the first 5 bytes are initialized
+    /// let s = unsafe {
+    ///     ArcSliceMut::<[u8]>::try_build(8, |uninit| {
+    ///         for (uninit, &b) in uninit.iter_mut().zip(b"hello") {
+    ///             uninit.write(b);
+    ///         }
+    ///         5
+    ///     })?
+    /// };
+    /// assert_eq!(s, b"hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub unsafe fn try_build(
+        len_hint: usize,
+        f: impl FnOnce(&mut [MaybeUninit<S::Item>]) -> usize,
+    ) -> Result<Self, AllocError>
+    where
+        S: Emptyable + Extendable,
+    {
+        unsafe { Self::build_impl::<AllocError>(len_hint, f) }
+    }
+
+    fn duplicate_impl<E: AllocErrorImpl>(&self) -> Result<Self, E>
+    where
+        S::Item: Clone,
+    {
+        if self.capacity == 0 {
+            return Ok(unsafe { Self::empty() });
+        }
+        let (arc, start) = Arc::<S>::with_capacity::<E, false>(self.capacity)?;
+        let mut duplicate = Self::init(start, 0, self.capacity, Some(arc.into()));
+        for item in self.to_slice() {
+            let end = unsafe { duplicate.start.as_ptr().add(duplicate.length) };
+            unsafe { end.write(item.clone()) };
+            duplicate.length += 1;
+        }
+        Ok(duplicate)
+    }
+
+    /// Creates an independent copy of the `ArcSliceMut`, with the same capacity.
+    ///
+    /// Items are cloned into a newly allocated buffer; metadata is not copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(64);
+    /// s.extend_from_slice(b"hello");
+    /// let mut s2 = s.duplicate();
+    /// s2[0] = b'j';
+    /// assert_eq!(s, b"hello");
+    /// assert_eq!(s2, b"jello");
+    /// assert_eq!(s2.capacity(), 64);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn duplicate(&self) -> Self
+    where
+        S::Item: Clone,
+    {
+        self.duplicate_impl::<Infallible>().unwrap_infallible()
+    }
+
+    /// Tries creating an independent copy of the `ArcSliceMut`, with the same capacity,
+    /// returning an error if the allocation fails.
+    ///
+    /// Items are cloned into a newly allocated buffer; metadata is not copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// let s = ArcSliceMut::<[u8]>::from(b"hello");
+    /// let s2 = s.try_duplicate()?;
+    /// assert_eq!(s2, b"hello");
+    /// assert_ne!(s2.as_ptr(), s.as_ptr());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_duplicate(&self) -> Result<Self, AllocError>
+    where
+        S::Item: Clone,
+    {
+        self.duplicate_impl::<AllocError>()
+    }
+
     /// Creates a new zeroed `ArcSliceMut` with the given capacity.
     ///
     /// This operation allocates if `capacity > 0`. All the items are initialized to `0`.
@@ -1197,15 +2062,31 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L> {
     #[cfg(feature = "oom-handling")]
     pub fn reserve(&mut self, additional: usize) {
         if let Err(err) = self.try_reserve(additional) {
-            #[cold]
-            fn panic_reserve(err: TryReserveError) -> ! {
-                match err {
-                    TryReserveError::AllocError => {
-                        alloc::alloc::handle_alloc_error(core::alloc::Layout::new::<()>())
-                    }
-                    err => panic!("{err:?}"),
-                }
-            }
+            panic_reserve(err);
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more items, or for `capacity_hint` items in
+    /// total if the buffer has to grow.
+    ///
+    /// See [`try_reserve_with_hint`](ArcSliceMut::try_reserve_with_hint).
+    ///
+    /// # Panics
+    ///
+    /// See [`reserve`](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::new();
+    /// s.reserve_with_hint(1, 4096);
+    /// assert!(s.capacity() >= 4096);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn reserve_with_hint(&mut self, additional: usize, capacity_hint: usize) {
+        if let Err(err) = self.try_reserve_with_hint(additional, capacity_hint) {
             panic_reserve(err);
         }
     }
@@ -1267,80 +2148,1275 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L> {
         self.reserve(slice.len());
         unsafe { self.extend_from_slice_unchecked(slice.to_slice()) }
     }
-}
-
-impl<T: Send + Sync + 'static, L: LayoutMut> ArcSliceMut<[T], L> {
-    pub(crate) fn from_array_impl<E: AllocErrorImpl, const N: usize>(
-        array: [T; N],
-    ) -> Result<Self, (E, [T; N])> {
-        if N == 0 {
-            return Ok(Self::new());
-        }
-        let (arc, start) = Arc::<[T], false>::new_array::<E, N>(array)?;
-        Ok(Self::init(start, N, N, Some(arc.into())))
-    }
 
-    /// Creates a new `ArcSliceMut` by moving the given array.
+    /// Appends `additional` zeroed items to the end of the slice.
+    ///
+    /// The buffer might have to reserve additional capacity to do the appending. Only the
+    /// appended items are zeroed, the existing ones are left untouched.
     ///
     /// # Panics
     ///
-    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    /// See [reserve](Self::reserve).
     ///
     /// # Examples
     ///
     /// ```rust
     /// use arc_slice::ArcSliceMut;
     ///
-    /// let s = ArcSliceMut::<[u8]>::from_array([0, 1, 2]);
-    /// assert_eq!(s, [0, 1, 2]);
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello");
+    /// s.extend_zeroed(3);
+    /// assert_eq!(s, b"hello\0\0\0");
     /// ```
     #[cfg(feature = "oom-handling")]
-    pub fn from_array<const N: usize>(array: [T; N]) -> Self {
-        Self::from_array_impl::<Infallible, N>(array).unwrap_infallible()
+    pub fn extend_zeroed(&mut self, additional: usize)
+    where
+        S: Zeroable,
+    {
+        self.reserve(additional);
+        unsafe { self.extend_zeroed_unchecked(additional) };
     }
 
-    /// Tries creating a new `ArcSliceMut` by moving the given array,
-    /// returning it if an allocation fails.
+    /// Tries appending `additional` zeroed items to the end of the slice, returning an error if
+    /// the capacity reservation fails.
+    ///
+    /// The buffer might have to reserve additional capacity to do the appending. Only the
+    /// appended items are zeroed, the existing ones are left untouched.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use arc_slice::ArcSliceMut;
     ///
-    /// let s = ArcSliceMut::<[u8]>::try_from_array([0, 1, 2]).unwrap();
-    /// assert_eq!(s, [0, 1, 2]);
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut s = ArcSliceMut::<[u8]>::try_with_capacity(8)?;
+    /// s.try_extend_zeroed(4)?;
+    /// assert_eq!(s, [0, 0, 0, 0]);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn try_from_array<const N: usize>(array: [T; N]) -> Result<Self, [T; N]> {
-        Self::from_array_impl::<AllocError, N>(array).map_err(|(_, array)| array)
+    pub fn try_extend_zeroed(&mut self, additional: usize) -> Result<(), TryReserveError>
+    where
+        S: Zeroable,
+    {
+        self.try_reserve(additional)?;
+        unsafe { self.extend_zeroed_unchecked(additional) };
+        Ok(())
     }
-}
 
-impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L, false> {
-    unsafe fn clone_impl<E: AllocErrorImpl>(&mut self) -> Result<Self, E> {
-        if self.data.is_none() {
-            let (arc, start) =
-                Arc::<[S::Item], false>::new_array::<E, 0>([]).map_err(|(err, _)| err)?;
-            self.start = start;
-            self.data = Some(Data(arc.into_raw()));
+    unsafe fn extend_zeroed_unchecked(&mut self, additional: usize)
+    where
+        S: Zeroable,
+    {
+        unsafe {
+            let end = self.start.as_ptr().add(self.length);
+            ptr::write_bytes(end, 0, additional);
+            self.length += additional;
         }
-        <L as ArcSliceMutLayout>::clone::<S, E, false>(
-            self.start,
-            self.length,
-            self.capacity,
-            self.data.as_mut().unwrap_checked(),
-        )?;
-        Ok(Self {
-            start: self.start,
-            length: self.length,
+    }
+
+    fn concat_impl<E: AllocErrorImpl>(parts: &[&S]) -> Result<Self, E>
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        let mut this = Self::with_capacity_impl::<E, false>(concat_len(parts))?;
+        for part in parts {
+            unsafe { this.extend_from_slice_unchecked(part.to_slice()) };
+        }
+        Ok(this)
+    }
+
+    /// Creates a new `ArcSliceMut` by concatenating the given slices.
+    ///
+    /// The total length is computed first, so the capacity is reserved only once, and each part
+    /// is copied without further capacity check.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let s = ArcSliceMut::<[u8]>::concat(&[b"hello", b"", b" world"]);
+    /// assert_eq!(s, b"hello world");
+    /// assert_eq!(s.capacity(), 11);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn concat(parts: &[&S]) -> Self
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        Self::concat_impl::<Infallible>(parts).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSliceMut` by concatenating the given slices, returning an error
+    /// if the allocation fails.
+    ///
+    /// See [`concat`](Self::concat).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// let s = ArcSliceMut::<str>::try_concat(&["hello", " ", "world"])?;
+    /// assert_eq!(s, "hello world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_concat(parts: &[&S]) -> Result<Self, AllocError>
+    where
+        S: Concatenable,
+        S::Item: Copy,
+    {
+        Self::concat_impl::<AllocError>(parts)
+    }
+}
+
+impl<L: LayoutMut> ArcSliceMut<str, L> {
+    /// Creates a new `ArcStrMut` from formatted arguments.
+    ///
+    /// This is the `no_std` counterpart of [`format!`](alloc::format), also available as the
+    /// [`arc_format!`](crate::arc_format) macro. The formatted length is computed first, so
+    /// the buffer is allocated only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error, or if the allocation fails,
+    /// see [`reserve`](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcStr, ArcStrMut};
+    ///
+    /// let s = <ArcStrMut>::from_fmt(format_args!("{}-{}", 4, 2));
+    /// assert_eq!(s, "4-2");
+    /// let s: ArcStr = s.freeze();
+    /// assert_eq!(s, "4-2");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        let mut this = Self::new();
+        if let Err(err) = this.format(args) {
+            panic_reserve(err);
+        }
+        this
+    }
+
+    /// Tries creating a new `ArcStrMut` from formatted arguments, returning an error if the
+    /// allocation fails.
+    ///
+    /// The formatted length is computed first, so the buffer is allocated only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStrMut;
+    ///
+    /// let s = <ArcStrMut>::try_from_fmt(format_args!("{}-{}", 4, 2)).unwrap();
+    /// assert_eq!(s, "4-2");
+    /// ```
+    pub fn try_from_fmt(args: fmt::Arguments<'_>) -> Result<Self, TryReserveError> {
+        let mut this = Self::new();
+        this.format(args)?;
+        Ok(this)
+    }
+
+    /// Converts an `ArcBytesMut` into an `ArcStrMut`, replacing invalid UTF-8 sequences with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// If the bytes are valid UTF-8, the conversion does not copy nor allocate. Otherwise, a new
+    /// buffer is allocated, like [`String::from_utf8_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytesMut, ArcStrMut};
+    ///
+    /// let bytes = <ArcBytesMut>::from(b"hello world");
+    /// let ptr = bytes.as_ptr();
+    /// let s = ArcStrMut::from_utf8_lossy_owned(bytes);
+    /// assert_eq!(s, "hello world");
+    /// assert_eq!(s.as_ptr(), ptr);
+    ///
+    /// let s = ArcStrMut::from_utf8_lossy_owned(<ArcBytesMut>::from(b"hello \x80world"));
+    /// assert_eq!(s, "hello \u{FFFD}world");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_utf8_lossy_owned(bytes: ArcSliceMut<[u8], L>) -> Self {
+        match Self::try_from_arc_slice_mut(bytes) {
+            Ok(s) => s,
+            Err((_, bytes)) => Self::from_utf8_lossy_slice(&bytes),
+        }
+    }
+
+    // invalid sequences are replaced while copying, without an intermediate `String`
+    #[cfg(feature = "oom-handling")]
+    pub(crate) fn from_utf8_lossy_slice(mut bytes: &[u8]) -> Self {
+        let mut this = Self::with_capacity(bytes.len());
+        loop {
+            match core::str::from_utf8(bytes) {
+                Ok(valid) => {
+                    this.extend_from_slice(valid);
+                    return this;
+                }
+                Err(err) => {
+                    let (valid, invalid) = bytes.split_at(err.valid_up_to());
+                    this.extend_from_slice(unsafe { core::str::from_utf8_unchecked(valid) });
+                    this.extend_from_slice("\u{FFFD}");
+                    // a truncated sequence at the end is replaced by a single character
+                    match err.error_len() {
+                        Some(len) => bytes = &invalid[len..],
+                        None => return this,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Copies `src` into the string at byte position `index`, shifting all bytes after it to the
+    /// right.
+    ///
+    /// The buffer might have to reserve additional capacity to do the insertion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`, if `index` is not on a char boundary, or see
+    /// [`reserve`](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStrMut;
+    ///
+    /// let mut s = <ArcStrMut>::from("héllo world");
+    /// s.insert_from_slice(6, ",");
+    /// assert_eq!(s, "héllo, world");
+    /// s.insert_from_slice(0, "> ");
+    /// assert_eq!(s, "> héllo, world");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    #[track_caller]
+    pub fn insert_from_slice(&mut self, index: usize, src: &str) {
+        self.check_insert(index);
+        self.reserve(src.len());
+        unsafe { self.insert_unchecked(index, src) };
+    }
+
+    /// Tries copying `src` into the string at byte position `index`, shifting all bytes after it
+    /// to the right, returning an error if the capacity reservation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`, or if `index` is not on a char boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStrMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut s = <ArcStrMut>::from("héllo");
+    /// s.try_insert_from_slice(6, " world")?;
+    /// assert_eq!(s, "héllo world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn try_insert_from_slice(
+        &mut self,
+        index: usize,
+        src: &str,
+    ) -> Result<(), TryReserveError> {
+        self.check_insert(index);
+        self.try_reserve(src.len())?;
+        unsafe { self.insert_unchecked(index, src) };
+        Ok(())
+    }
+
+    #[track_caller]
+    fn check_insert(&self, index: usize) {
+        if index > self.length {
+            panic_insert_out_of_range(index, self.length);
+        }
+        check_char_boundary(self, index);
+    }
+
+    unsafe fn insert_unchecked(&mut self, index: usize, src: &str) {
+        unsafe {
+            let ptr = self.start.as_ptr().add(index);
+            ptr::copy(ptr, ptr.add(src.len()), self.length - index);
+            ptr::copy_nonoverlapping(src.as_ptr(), ptr, src.len());
+        }
+        self.length += src.len();
+    }
+}
+
+impl<L: LayoutMut, const UNIQUE: bool> ArcSliceMut<str, L, UNIQUE> {
+    /// Returns the closest char boundary lower or equal to `index`.
+    ///
+    /// If `index` is greater than the string length, the length is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStrMut;
+    ///
+    /// let s = <ArcStrMut>::from("hé");
+    /// assert_eq!(s.floor_char_boundary(2), 1);
+    /// assert_eq!(s.floor_char_boundary(42), 3);
+    /// ```
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        floor_char_boundary(self, index)
+    }
+
+    /// Returns the closest char boundary greater or equal to `index`.
+    ///
+    /// If `index` is greater than the string length, the length is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcStrMut;
+    ///
+    /// let s = <ArcStrMut>::from("hé");
+    /// assert_eq!(s.ceil_char_boundary(2), 3);
+    /// assert_eq!(s.ceil_char_boundary(42), 3);
+    /// ```
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        ceil_char_boundary(self, index)
+    }
+
+    /// Tries advancing the start of the string by `offset` bytes, returning an error if the
+    /// offset is out of range or not a char boundary.
+    ///
+    /// The string is left untouched in case of error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::CharBoundaryError, ArcStrMut};
+    ///
+    /// let mut s = <ArcStrMut>::from("héllo");
+    /// assert_eq!(
+    ///     s.try_advance_checked(2),
+    ///     Err(CharBoundaryError::NotCharBoundary {
+    ///         index: 2,
+    ///         floor: 1,
+    ///         ceil: 3
+    ///     })
+    /// );
+    /// s.try_advance_checked(3).unwrap();
+    /// assert_eq!(s, "llo");
+    /// ```
+    pub fn try_advance_checked(&mut self, offset: usize) -> Result<(), CharBoundaryError> {
+        try_char_boundary(self, offset)?;
+        self.advance(offset);
+        Ok(())
+    }
+
+    /// Tries truncating the string to the first `len` bytes, returning an error if `len` is not
+    /// a char boundary.
+    ///
+    /// If `len` is greater than the string length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::CharBoundaryError, ArcStrMut};
+    ///
+    /// let mut s = <ArcStrMut>::from("héllo");
+    /// assert!(matches!(
+    ///     s.try_truncate_checked(2),
+    ///     Err(CharBoundaryError::NotCharBoundary { .. })
+    /// ));
+    /// s.try_truncate_checked(3).unwrap();
+    /// assert_eq!(s, "hé");
+    /// ```
+    pub fn try_truncate_checked(&mut self, len: usize) -> Result<(), CharBoundaryError> {
+        if len < self.len() {
+            try_char_boundary(self, len)?;
+            self.truncate(len);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "oom-handling")]
+macro_rules! put_int {
+    ($($ty:ident: $put_be:ident, $put_le:ident, $put_ne:ident;)*) => {$(
+        put_int!(@method $ty, $put_be, to_be_bytes, "big-endian");
+        put_int!(@method $ty, $put_le, to_le_bytes, "little-endian");
+        put_int!(@method $ty, $put_ne, to_ne_bytes, "native-endian");
+    )*};
+    (@method $ty:ident, $put:ident, $to_bytes:ident, $order:literal) => {
+        #[doc = concat!("Appends a `", stringify!($ty), "` to the end of the slice, in ", $order, " byte order.")]
+        ///
+        /// # Panics
+        ///
+        /// See [reserve](Self::reserve).
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use arc_slice::ArcBytesMut;
+        ///
+        /// let mut s = <ArcBytesMut>::new();
+        #[doc = concat!("s.", stringify!($put), "(42 as ", stringify!($ty), ");")]
+        #[doc = concat!("assert_eq!(s, (42 as ", stringify!($ty), ").", stringify!($to_bytes), "());")]
+        /// ```
+        pub fn $put(&mut self, n: $ty) {
+            self.extend_from_slice(&n.$to_bytes());
+        }
+    };
+}
+
+#[cfg(feature = "oom-handling")]
+impl<L: LayoutMut> ArcSliceMut<[u8], L> {
+    /// Appends a `u8` to the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// See [reserve](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytesMut;
+    ///
+    /// let mut s = <ArcBytesMut>::new();
+    /// s.put_u8(42);
+    /// assert_eq!(s, [42]);
+    /// ```
+    pub fn put_u8(&mut self, n: u8) {
+        self.push(n);
+    }
+
+    /// Appends an `i8` to the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// See [reserve](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytesMut;
+    ///
+    /// let mut s = <ArcBytesMut>::new();
+    /// s.put_i8(-1);
+    /// assert_eq!(s, [0xff]);
+    /// ```
+    pub fn put_i8(&mut self, n: i8) {
+        self.push(n as u8);
+    }
+
+    put_int! {
+        u16: put_u16, put_u16_le, put_u16_ne;
+        i16: put_i16, put_i16_le, put_i16_ne;
+        u32: put_u32, put_u32_le, put_u32_ne;
+        i32: put_i32, put_i32_le, put_i32_ne;
+        u64: put_u64, put_u64_le, put_u64_ne;
+        i64: put_i64, put_i64_le, put_i64_ne;
+        u128: put_u128, put_u128_le, put_u128_ne;
+        i128: put_i128, put_i128_le, put_i128_ne;
+        f32: put_f32, put_f32_le, put_f32_ne;
+        f64: put_f64, put_f64_le, put_f64_ne;
+    }
+
+    /// Appends the `nbytes` low-order bytes of an unsigned integer to the end of the slice, in
+    /// big-endian byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`, see also [reserve](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytesMut;
+    ///
+    /// let mut s = <ArcBytesMut>::new();
+    /// s.put_uint(0x010203, 3);
+    /// assert_eq!(s, [1, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub fn put_uint(&mut self, n: u64, nbytes: usize) {
+        let bytes = n.to_be_bytes();
+        match bytes.len().checked_sub(nbytes) {
+            Some(start) => self.extend_from_slice(&bytes[start..]),
+            None => panic_end_out_of_range(nbytes, bytes.len()),
+        }
+    }
+
+    /// Appends the `nbytes` low-order bytes of an unsigned integer to the end of the slice, in
+    /// little-endian byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`, see also [reserve](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytesMut;
+    ///
+    /// let mut s = <ArcBytesMut>::new();
+    /// s.put_uint_le(0x010203, 3);
+    /// assert_eq!(s, [3, 2, 1]);
+    /// ```
+    #[track_caller]
+    pub fn put_uint_le(&mut self, n: u64, nbytes: usize) {
+        let bytes = n.to_le_bytes();
+        match bytes.get(..nbytes) {
+            Some(bytes) => self.extend_from_slice(bytes),
+            None => panic_end_out_of_range(nbytes, bytes.len()),
+        }
+    }
+
+    /// Appends the `nbytes` low-order bytes of a signed integer to the end of the slice, in
+    /// big-endian byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`, see also [reserve](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytesMut;
+    ///
+    /// let mut s = <ArcBytesMut>::new();
+    /// s.put_int(-2, 3);
+    /// assert_eq!(s, [0xff, 0xff, 0xfe]);
+    /// ```
+    #[track_caller]
+    pub fn put_int(&mut self, n: i64, nbytes: usize) {
+        self.put_uint(n as u64, nbytes);
+    }
+
+    /// Appends the `nbytes` low-order bytes of a signed integer to the end of the slice, in
+    /// little-endian byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes > 8`, see also [reserve](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcBytesMut;
+    ///
+    /// let mut s = <ArcBytesMut>::new();
+    /// s.put_int_le(-2, 3);
+    /// assert_eq!(s, [0xfe, 0xff, 0xff]);
+    /// ```
+    #[track_caller]
+    pub fn put_int_le(&mut self, n: i64, nbytes: usize) {
+        self.put_uint_le(n as u64, nbytes);
+    }
+}
+
+impl<T: Send + Sync + 'static, L: LayoutMut> ArcSliceMut<[T], L> {
+    pub(crate) fn from_array_impl<E: AllocErrorImpl, const N: usize>(
+        array: [T; N],
+    ) -> Result<Self, (E, [T; N])> {
+        if N == 0 {
+            return Ok(Self::new());
+        }
+        let (arc, start) = Arc::<[T], false>::new_array::<E, N>(array)?;
+        Ok(Self::init(start, N, N, Some(arc.into())))
+    }
+
+    /// Creates a new `ArcSliceMut` by moving the given array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let s = ArcSliceMut::<[u8]>::from_array([0, 1, 2]);
+    /// assert_eq!(s, [0, 1, 2]);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_array<const N: usize>(array: [T; N]) -> Self {
+        Self::from_array_impl::<Infallible, N>(array).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSliceMut` by moving the given array,
+    /// returning it if an allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let s = ArcSliceMut::<[u8]>::try_from_array([0, 1, 2]).unwrap();
+    /// assert_eq!(s, [0, 1, 2]);
+    /// ```
+    pub fn try_from_array<const N: usize>(array: [T; N]) -> Result<Self, [T; N]> {
+        Self::from_array_impl::<AllocError, N>(array).map_err(|(_, array)| array)
+    }
+
+    // Removed items are moved out of the buffer, so its length must be set beforehand, for it
+    // not to drop them again.
+    #[track_caller]
+    fn set_length_before_removal(&mut self, length: usize) {
+        if mem::needs_drop::<T>() {
+            let set_unique_length = <L as ArcSliceMutLayout>::set_unique_length::<[T], true>;
+            let data = unsafe { self.data.as_mut().unwrap_unchecked() };
+            if !set_unique_length(self.start, length, data) {
+                panic_remove_unsupported();
+            }
+        }
+        self.length = length;
+    }
+
+    /// Removes the last item from the slice and returns it, or `None` if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items need drop and the underlying buffer doesn't support setting its
+    /// length, e.g. [`AsMutBuffer`](crate::buffer::AsMutBuffer).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[String]>::from_array(["a".into(), "b".into()]);
+    /// assert_eq!(s.pop().unwrap(), "b");
+    /// assert_eq!(s[..], ["a"]);
+    /// ```
+    #[track_caller]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+        self.set_length_before_removal(self.length - 1);
+        Some(unsafe { self.start.as_ptr().add(self.length).read() })
+    }
+
+    /// Removes an item from the slice and returns it.
+    ///
+    /// The removed item is replaced by the last item of the slice. This does not preserve
+    /// ordering, but is *O*(1). If you need to preserve the item order, use
+    /// [`remove`](Self::remove) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= self.len()`, or see [`pop`](Self::pop).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from_array([0, 1, 2, 3]);
+    /// assert_eq!(s.swap_remove(1), 1);
+    /// assert_eq!(s, [0, 3, 2]);
+    /// ```
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= self.length {
+            panic_remove_out_of_range("swap_remove index", index, self.length);
+        }
+        self.set_length_before_removal(self.length - 1);
+        unsafe {
+            let item = self.start.as_ptr().add(index).read();
+            let last = self.start.as_ptr().add(self.length);
+            ptr::copy(last, self.start.as_ptr().add(index), 1);
+            item
+        }
+    }
+
+    /// Removes an item from the slice and returns it, shifting all items after it to the left.
+    ///
+    /// Because this shifts over the remaining items, it has a worst-case performance of
+    /// *O*(*n*). If you don't need the order of items to be preserved, use
+    /// [`swap_remove`](Self::swap_remove) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= self.len()`, or see [`pop`](Self::pop).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from_array([0, 1, 2, 3]);
+    /// assert_eq!(s.remove(1), 1);
+    /// assert_eq!(s, [0, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        if index >= self.length {
+            panic_remove_out_of_range("removal index", index, self.length);
+        }
+        self.set_length_before_removal(self.length - 1);
+        unsafe {
+            let ptr = self.start.as_ptr().add(index);
+            let item = ptr.read();
+            ptr::copy(ptr.add(1), ptr, self.length - index);
+            item
+        }
+    }
+
+    /// Inserts an item at position `index`, shifting all items after it to the right.
+    ///
+    /// The buffer might have to reserve additional capacity to do the insertion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`, or see [`reserve`](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from_array([0, 1, 3]);
+    /// s.insert(2, 2);
+    /// assert_eq!(s, [0, 1, 2, 3]);
+    /// s.insert(4, 4);
+    /// assert_eq!(s, [0, 1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, item: T) {
+        if index > self.length {
+            panic_insert_out_of_range(index, self.length);
+        }
+        self.reserve(1);
+        unsafe { self.insert_unchecked(index, item) };
+    }
+
+    /// Tries inserting an item at position `index`, shifting all items after it to the right,
+    /// returning an error if the capacity reservation fails.
+    ///
+    /// The item is dropped if the reservation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut s = ArcSliceMut::<[u8]>::from_array([1, 2]);
+    /// s.try_insert(0, 0)?;
+    /// assert_eq!(s, [0, 1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, item: T) -> Result<(), TryReserveError> {
+        if index > self.length {
+            panic_insert_out_of_range(index, self.length);
+        }
+        self.try_reserve(1)?;
+        unsafe { self.insert_unchecked(index, item) };
+        Ok(())
+    }
+
+    /// Copies all items from `src` into the slice at position `index`, shifting all items after
+    /// it to the right.
+    ///
+    /// The buffer might have to reserve additional capacity to do the insertion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`, or see [`reserve`](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello world");
+    /// s.insert_from_slice(5, b",");
+    /// assert_eq!(s, b"hello, world");
+    /// s.insert_from_slice(0, b"> ");
+    /// assert_eq!(s, b"> hello, world");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    #[track_caller]
+    pub fn insert_from_slice(&mut self, index: usize, src: &[T])
+    where
+        T: Copy,
+    {
+        if index > self.length {
+            panic_insert_out_of_range(index, self.length);
+        }
+        self.reserve(src.len());
+        unsafe { self.replace_range_unchecked(index, 0, src) };
+    }
+
+    /// Tries copying all items from `src` into the slice at position `index`, shifting all items
+    /// after it to the right, returning an error if the capacity reservation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello");
+    /// s.try_insert_from_slice(5, b" world")?;
+    /// assert_eq!(s, b"hello world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn try_insert_from_slice(&mut self, index: usize, src: &[T]) -> Result<(), TryReserveError>
+    where
+        T: Copy,
+    {
+        if index > self.length {
+            panic_insert_out_of_range(index, self.length);
+        }
+        self.try_reserve(src.len())?;
+        unsafe { self.replace_range_unchecked(index, 0, src) };
+        Ok(())
+    }
+
+    unsafe fn insert_unchecked(&mut self, index: usize, item: T) {
+        unsafe {
+            let ptr = self.start.as_ptr().add(index);
+            ptr::copy(ptr, ptr.add(1), self.length - index);
+            ptr.write(item);
+        }
+        self.length += 1;
+    }
+
+    /// Replaces the given range with the items of `src`, shifting the items after the range.
+    ///
+    /// The replacement doesn't need to have the same length as the range; the buffer might have
+    /// to reserve additional capacity if it is longer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or see [`reserve`](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"a&amp;b");
+    /// s.replace_range(1..6, b"&");
+    /// assert_eq!(s, b"a&b");
+    /// s.replace_range(1..2, b" and ");
+    /// assert_eq!(s, b"a and b");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    #[track_caller]
+    pub fn replace_range(&mut self, range: impl RangeBounds<usize>, src: &[T])
+    where
+        T: Copy,
+    {
+        let (offset, len) = range_offset_len(self.as_slice(), range);
+        if src.len() > len {
+            self.reserve(src.len() - len);
+        }
+        unsafe { self.replace_range_unchecked(offset, len, src) };
+    }
+
+    /// Appends the items of the given range of the slice to its end.
+    ///
+    /// Contrary to [`Vec::extend_from_within`], the range may end past the current length, as
+    /// long as it starts before it: items are copied one by one in forward order, so items
+    /// appended during the copy are copied again. This is the back-reference semantic of
+    /// LZ-style decompressors, where a distance smaller than the length repeats the pattern;
+    /// it differs from [`copy_within`](prim@slice#method.copy_within), which copies as if through a
+    /// temporary buffer.
+    ///
+    /// The buffer might have to reserve additional capacity to do the appending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after the end of the slice, or if it ends past the end of the
+    /// slice while starting at it, or see [`reserve`](Self::reserve).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"abc");
+    /// s.extend_from_within(..2);
+    /// assert_eq!(s, b"abcab");
+    /// // distance 2, length 5
+    /// s.extend_from_within(3..8);
+    /// assert_eq!(s, b"abcabababa");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    #[track_caller]
+    pub fn extend_from_within(&mut self, src: impl RangeBounds<usize>)
+    where
+        T: Copy,
+    {
+        let (offset, len) = within_offset_len(self.length, src);
+        self.reserve(len);
+        unsafe { self.extend_from_within_unchecked(offset, len) };
+    }
+
+    /// Creates a new `ArcSliceMut` of `len` uninitialized items.
+    ///
+    /// This operation allocates if `len > 0`. Items must be `Copy`, like for
+    /// [`assume_init`](ArcSliceMut::assume_init), as the buffer is allocated for items without
+    /// drop glue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - size_of::<usize>()` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u32]>::new_uninit(3);
+    /// for (i, item) in s.iter_mut().enumerate() {
+    ///     item.write(i as u32);
+    /// }
+    /// let s = unsafe { s.assume_init() };
+    /// assert_eq!(s, [0, 1, 2]);
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn new_uninit(len: usize) -> ArcSliceMut<[MaybeUninit<T>], L>
+    where
+        T: Copy,
+    {
+        let mut this = ArcSliceMut::<[MaybeUninit<T>], L>::with_capacity(len);
+        // SAFETY: `MaybeUninit` items don't need to be initialized
+        unsafe { this.set_len(len) };
+        this
+    }
+}
+
+impl<T: Copy + Send + Sync + 'static, L: LayoutMut> ArcSliceMut<[MaybeUninit<T>], L> {
+    /// Converts to `ArcSliceMut<[T], L>`, reusing the buffer.
+    ///
+    /// Items must be `Copy`, as the buffer was allocated for items without drop glue.
+    ///
+    /// # Safety
+    ///
+    /// All items must be initialized, see [`MaybeUninit::assume_init`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u32]>::new_uninit(2);
+    /// s[0].write(4);
+    /// s[1].write(2);
+    /// let s = unsafe { s.assume_init() };
+    /// assert_eq!(s, [4, 2]);
+    /// ```
+    pub unsafe fn assume_init(self) -> ArcSliceMut<[T], L> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`, and neither needs drop
+        ArcSliceMut {
+            start: this.start.cast(),
+            length: this.length,
+            capacity: this.capacity,
+            data: this.data,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<[T], L, UNIQUE> {
+    /// Tries replacing the given range with the items of `src`, shifting the items after the
+    /// range, returning an error if the capacity reservation fails.
+    ///
+    /// See [`replace_range`](ArcSliceMut::replace_range).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello world");
+    /// s.try_replace_range(..5, b"goodbye")?;
+    /// assert_eq!(s, b"goodbye world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn try_replace_range(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        src: &[T],
+    ) -> Result<(), TryReserveError>
+    where
+        T: Copy,
+    {
+        let (offset, len) = range_offset_len(self.as_slice(), range);
+        if src.len() > len {
+            self.try_reserve(src.len() - len)?;
+        }
+        unsafe { self.replace_range_unchecked(offset, len, src) };
+        Ok(())
+    }
+
+    /// Tries appending the items of the given range of the slice to its end, returning an error
+    /// if the capacity reservation fails.
+    ///
+    /// See [`extend_from_within`](ArcSliceMut::extend_from_within) for the copy semantic when
+    /// the range ends past the current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after the end of the slice, or if it ends past the end of the
+    /// slice while starting at it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// # fn main() -> Result<(), arc_slice::error::TryReserveError> {
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"ab");
+    /// s.try_extend_from_within(1..5)?;
+    /// assert_eq!(s, b"abbbbb");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn try_extend_from_within(
+        &mut self,
+        src: impl RangeBounds<usize>,
+    ) -> Result<(), TryReserveError>
+    where
+        T: Copy,
+    {
+        let (offset, len) = within_offset_len(self.length, src);
+        self.try_reserve(len)?;
+        unsafe { self.extend_from_within_unchecked(offset, len) };
+        Ok(())
+    }
+
+    unsafe fn extend_from_within_unchecked(&mut self, offset: usize, len: usize)
+    where
+        T: Copy,
+    {
+        let ptr = self.start.as_ptr();
+        // chunks of at most `distance` items never overlap
+        let distance = self.length - offset;
+        let mut copied = 0;
+        while copied < len {
+            let count = cmp::min(distance, len - copied);
+            unsafe {
+                let src = ptr.add(offset + copied);
+                ptr::copy_nonoverlapping(src, ptr.add(self.length + copied), count);
+            }
+            copied += count;
+        }
+        self.length += len;
+    }
+
+    unsafe fn replace_range_unchecked(&mut self, offset: usize, len: usize, src: &[T])
+    where
+        T: Copy,
+    {
+        unsafe {
+            let ptr = self.start.as_ptr().add(offset);
+            let tail = self.length - offset - len;
+            ptr::copy(ptr.add(len), ptr.add(src.len()), tail);
+            ptr::copy_nonoverlapping(src.as_ptr(), ptr, src.len());
+        }
+        self.length = self.length - len + src.len();
+    }
+
+    /// Copies all items from `src` into the slice, starting at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + src.len() > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello world");
+    /// s.write_at(6, b"there");
+    /// assert_eq!(s, b"hello there");
+    /// ```
+    #[track_caller]
+    pub fn write_at(&mut self, offset: usize, src: &[T])
+    where
+        T: Copy,
+    {
+        match offset.checked_add(src.len()) {
+            Some(end) if end <= self.length => self[offset..end].copy_from_slice(src),
+            _ => panic_end_out_of_range(offset.saturating_add(src.len()), self.length),
+        }
+    }
+
+    /// Fills the slice with `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello");
+    /// s.fill(0);
+    /// assert_eq!(s, [0; 5]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        self.as_mut().fill(value);
+    }
+
+    /// Returns a mutable array reference to the first `N` items, and the rest of the slice, or
+    /// `None` if the slice is shorter than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"\x00\x05hello");
+    /// let (header, body) = s.split_first_chunk_mut::<2>().unwrap();
+    /// header[1] = 4;
+    /// body[4] = b'!';
+    /// assert_eq!(s, b"\x00\x04hell!");
+    /// assert!(s.split_first_chunk_mut::<8>().is_none());
+    /// ```
+    pub fn split_first_chunk_mut<const N: usize>(&mut self) -> Option<(&mut [T; N], &mut [T])> {
+        if self.length < N {
+            return None;
+        }
+        let (first, rest) = self.as_mut_slice().split_at_mut(N);
+        Some((first.try_into().unwrap(), rest))
+    }
+}
+
+#[cfg(feature = "inlined")]
+impl<S: SliceMut<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQUE> {
+    /// Tries freezing the slice into a [`SmallArcSlice`], storing it inlined if it fits.
+    ///
+    /// When the slice is inlined, its bytes are copied and the `ArcSliceMut` is dropped,
+    /// releasing the reference to the backing buffer. Otherwise, the slice is frozen with
+    /// [`try_freeze`](Self::try_freeze), and the original slice is returned if it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{inlined::SmallArcSlice, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(16);
+    /// s.extend_from_slice(b"hello");
+    /// let small: SmallArcSlice<[u8]> = s.try_freeze_small().unwrap();
+    /// assert!(small.is_inlined());
+    /// assert_eq!(small, b"hello");
+    ///
+    /// let s = ArcSliceMut::<[u8]>::from_array([0; 256]);
+    /// let small: SmallArcSlice<[u8]> = s.try_freeze_small().unwrap();
+    /// assert!(!small.is_inlined());
+    /// ```
+    pub fn try_freeze_small<L2: Layout>(self) -> Result<SmallArcSlice<S, L2>, Self> {
+        match SmallSlice::new(&*self) {
+            Some(small) => Ok(small.into()),
+            None => Ok(self.try_freeze()?.into()),
+        }
+    }
+}
+
+#[cfg(all(feature = "inlined", feature = "oom-handling"))]
+impl<S: SliceMut<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> ArcSliceMut<S, L, UNIQUE> {
+    /// Freezes the slice into a [`SmallArcSlice`], storing it inlined if it fits.
+    ///
+    /// When the slice is inlined, its bytes are copied and the `ArcSliceMut` is dropped,
+    /// releasing the reference to the backing buffer. Otherwise, the slice is frozen with
+    /// [`freeze`](Self::freeze).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{inlined::SmallArcSlice, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(16);
+    /// s.extend_from_slice(b"hello");
+    /// let small: SmallArcSlice<[u8]> = s.freeze_small();
+    /// assert!(small.is_inlined());
+    /// assert_eq!(small, b"hello");
+    /// ```
+    pub fn freeze_small<L2: FromLayout<L>>(self) -> SmallArcSlice<S, L2> {
+        match SmallSlice::new(&*self) {
+            Some(small) => small.into(),
+            None => self.freeze().into(),
+        }
+    }
+}
+
+impl<S: SliceMut + ?Sized, L: LayoutMut> ArcSliceMut<S, L, false> {
+    unsafe fn clone_impl<E: AllocErrorImpl>(&mut self) -> Result<Self, E> {
+        if self.data.is_none() {
+            let (arc, start) =
+                Arc::<[S::Item], false>::new_array::<E, 0>([]).map_err(|(err, _)| err)?;
+            self.start = start;
+            self.data = Some(Data(arc.into_raw()));
+        }
+        <L as ArcSliceMutLayout>::clone::<S, E, false>(
+            self.start,
+            self.length,
+            self.capacity,
+            self.data.as_mut().unwrap_checked(),
+        )?;
+        Ok(Self {
+            start: self.start,
+            length: self.length,
             capacity: self.capacity,
             data: self.data,
             _phantom: self._phantom,
         })
     }
 
-    fn split_off_impl<E: AllocErrorImpl>(&mut self, at: usize) -> Result<Self, E> {
+    #[track_caller]
+    fn split_off_impl<E: AllocErrorImpl>(&mut self, at: usize) -> Result<Self, E>
+    where
+        S: Subsliceable,
+    {
         if at > self.capacity {
-            panic_out_of_range();
+            panic_start_out_of_range(at, self.capacity);
+        }
+        if at <= self.length {
+            unsafe { self.check_split(at) };
         }
         let mut clone = unsafe { self.clone_impl()? };
         clone.start = unsafe { clone.start.add(at) };
@@ -1382,14 +3458,23 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L, false> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn try_split_off(&mut self, at: usize) -> Result<Self, AllocError> {
+    #[track_caller]
+    pub fn try_split_off(&mut self, at: usize) -> Result<Self, AllocError>
+    where
+        S: Subsliceable,
+    {
         self.split_off_impl::<AllocError>(at)
     }
 
-    fn split_to_impl<E: AllocErrorImpl>(&mut self, at: usize) -> Result<Self, E> {
+    #[track_caller]
+    fn split_to_impl<E: AllocErrorImpl>(&mut self, at: usize) -> Result<Self, E>
+    where
+        S: Subsliceable,
+    {
         if at > self.length {
-            panic_out_of_range();
+            panic_end_out_of_range(at, self.length);
         }
+        unsafe { self.check_split(at) };
         let mut clone = unsafe { self.clone_impl()? };
         clone.capacity = at;
         clone.length = at;
@@ -1426,12 +3511,127 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L, false> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn try_split_to(&mut self, at: usize) -> Result<Self, AllocError> {
+    #[track_caller]
+    pub fn try_split_to(&mut self, at: usize) -> Result<Self, AllocError>
+    where
+        S: Subsliceable,
+    {
         self.split_to_impl::<AllocError>(at)
     }
 
+    #[track_caller]
+    fn freeze_to_impl<L2: Layout, E: AllocErrorImpl>(
+        &mut self,
+        at: usize,
+    ) -> Option<ArcSlice<S, L2>>
+    where
+        S: Subsliceable,
+    {
+        let front = self.split_to_impl::<E>(at).ok()?;
+        match front.freeze_impl::<L2, E>() {
+            Ok(frozen) => Some(frozen),
+            // the front part is dropped, and its items given back to `self`
+            Err(front) => {
+                self.start = front.start;
+                self.length += at;
+                self.capacity += at;
+                None
+            }
+        }
+    }
+
+    /// Tries freezing the elements `[0, at)` into an [`ArcSlice`], returning `None` if the
+    /// conversion fails.
+    ///
+    /// Afterwards `self` contains elements `[at, len)`, as well as the spare capacity, and the
+    /// frozen slice shares the same buffer. The buffer is not unique as long as the frozen slice
+    /// is alive, but reservation can reuse it in place once it is dropped. If the conversion
+    /// fails, `self` is left untouched.
+    ///
+    /// See [`try_freeze`](Self::try_freeze) for cases where the conversion fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::from(b"hello world").into_shared();
+    /// let frame: ArcBytes = s.try_freeze_to(5).unwrap();
+    /// assert_eq!(frame, b"hello");
+    /// assert_eq!(s, b" world");
+    /// ```
+    #[track_caller]
+    pub fn try_freeze_to<L2: Layout>(&mut self, at: usize) -> Option<ArcSlice<S, L2>>
+    where
+        S: Subsliceable,
+    {
+        self.freeze_to_impl::<L2, AllocError>(at)
+    }
+
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    fn split_into_impl<E: AllocErrorImpl>(mut self, at: usize) -> Result<(Self, Self), (E, Self)>
+    where
+        S: Subsliceable,
+    {
+        if at > self.length {
+            panic_start_out_of_range(at, self.length);
+        }
+        match self.split_off_impl(at) {
+            Ok(tail) => Ok((self, tail)),
+            Err(err) => Err((err, self)),
+        }
+    }
+
+    /// Tries splitting the slice into two at the given index, consuming it, returning an error
+    /// together with the slice if an allocation fails.
+    ///
+    /// The first returned `ArcSliceMut` contains elements `[0, at)`, and the second one contains
+    /// elements `[at, len)` as well as the spare capacity. This operation does not touch the
+    /// underlying buffer, and the reference of the consumed slice is reused for the first half,
+    /// so the refcount is incremented only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let s = ArcSliceMut::<[u8]>::from(b"hello world").into_shared();
+    /// let (a, b) = s.try_split_into(5).unwrap();
+    ///
+    /// assert_eq!(a, b"hello");
+    /// assert_eq!(b, b" world");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn try_split_into(self, at: usize) -> Result<(Self, Self), (AllocError, Self)>
+    where
+        S: Subsliceable,
+    {
+        self.split_into_impl::<AllocError>(at)
+    }
+
     /// Tries unsplitting two parts of a previously split slice.
     ///
+    /// The parts can be given in any order, i.e. `other` can be either right after or right
+    /// before `self` in the buffer. Parts must be contiguous, so the first one must have no
+    /// spare capacity, unless the second one is empty. Empty parts without capacity can always
+    /// be unsplit.
+    ///
+    /// A truncated first part cannot be unsplit with a non-empty second part: as split parts
+    /// never overlap, the second part starts after the spare capacity of the first one, and
+    /// closing the gap would require moving its items.
+    ///
+    /// If unsplitting fails, `other` is returned unchanged.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -1445,7 +3645,11 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L, false> {
     /// a.try_unsplit(b).unwrap();
     /// assert_eq!(a, b"hello world");
     ///
-    /// assert!(a
+    /// let mut b = a.split_off(5);
+    /// b.try_unsplit(a).unwrap();
+    /// assert_eq!(b, b"hello world");
+    ///
+    /// assert!(b
     ///     .try_unsplit(ArcSliceMut::from(b"other").into_shared())
     ///     .is_err());
     /// ```
@@ -1453,18 +3657,96 @@ impl<S: Slice + ?Sized, L: LayoutMut> ArcSliceMut<S, L, false> {
         &mut self,
         other: ArcSliceMut<S, L, false>,
     ) -> Result<(), ArcSliceMut<S, L, false>> {
+        if other.capacity == 0 {
+            return Ok(());
+        }
+        if self.capacity == 0 {
+            *self = other;
+            return Ok(());
+        }
+        let mut data = match self.data {
+            Some(data) if self.data == other.data => data,
+            _ => return Err(other),
+        };
+        // parts of a split slice share the buffer, so it cannot be unique; this also rejects
+        // layouts whose data doesn't identify the buffer, e.g. a vector offset
+        if <L as ArcSliceMutLayout>::is_unique::<S, false>(&mut data) {
+            return Err(other);
+        }
+        self.unsplit_contiguous(other)
+    }
+
+    /// Tries unsplitting two parts of a previously split slice, even if they have different
+    /// layouts.
+    ///
+    /// Contrary to [`try_unsplit`](Self::try_unsplit), the parts are compared by the identity of
+    /// their underlying buffer, so one of them may have been converted to another layout since
+    /// the split. The unsplit slice keeps the layout of `self`; if `self` is empty without
+    /// capacity, `other` is converted to it, see [`try_with_layout`](Self::try_with_layout).
+    ///
+    /// If unsplitting fails, `other` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSliceMut};
+    ///
+    /// let mut a = ArcSliceMut::<[u8], ArcLayout<true>>::from(b"hello world").into_shared();
+    /// let b = a.split_off(5);
+    /// let b = b.try_with_layout::<ArcLayout<false>>().unwrap();
+    /// a.try_unsplit_any(b).unwrap();
+    /// assert_eq!(a, b"hello world");
+    ///
+    /// let other = ArcSliceMut::<[u8], ArcLayout<false>>::from(b"other").into_shared();
+    /// assert!(a.try_unsplit_any(other).is_err());
+    /// ```
+    pub fn try_unsplit_any<L2: LayoutMut>(
+        &mut self,
+        other: ArcSliceMut<S, L2, false>,
+    ) -> Result<(), ArcSliceMut<S, L2, false>> {
+        if other.capacity == 0 {
+            return Ok(());
+        }
+        if self.capacity == 0 {
+            *self = other.try_with_layout()?;
+            return Ok(());
+        }
+        // both parts hold a reference to the buffer, so they can be compared by its identity,
+        // which vector offsets don't carry
+        let same_buffer = match (self.data, other.data) {
+            (Some(data), Some(other_data)) => {
+                let ptr = <L as ArcSliceMutLayout>::buffer_ptr::<S, false>(&data);
+                ptr.is_some() && ptr == L2::buffer_ptr::<S, false>(&other_data)
+            }
+            _ => false,
+        };
+        if !same_buffer {
+            return Err(other);
+        }
+        self.unsplit_contiguous(other)
+    }
+
+    fn unsplit_contiguous<L2: LayoutMut>(
+        &mut self,
+        other: ArcSliceMut<S, L2, false>,
+    ) -> Result<(), ArcSliceMut<S, L2, false>> {
         let end = unsafe { self.start.add(self.capacity) };
-        if self.length == self.capacity && self.data == other.data && end == other.start {
+        let other_end = unsafe { other.start.add(other.capacity) };
+        if end == other.start && (self.length == self.capacity || other.length == 0) {
             self.length += other.length;
-            self.capacity += other.capacity;
-            return Ok(());
+        } else if other_end == self.start && (other.length == other.capacity || self.length == 0) {
+            self.start = other.start;
+            self.length += other.length;
+        } else {
+            return Err(other);
         }
-        Err(other)
+        self.capacity += other.capacity;
+        Ok(())
     }
 }
 
 impl<
-        S: Slice + ?Sized,
+        S: SliceMut + ?Sized,
         #[cfg(feature = "oom-handling")] L: LayoutMut,
         #[cfg(not(feature = "oom-handling"))] L: LayoutMut + CloneNoAllocLayout,
     > ArcSliceMut<S, L, false>
@@ -1490,7 +3772,11 @@ impl<
     /// assert_eq!(b, b" world");
     /// ```
     #[must_use = "consider `ArcSliceMut::truncate` if you don't need the other half"]
-    pub fn split_off(&mut self, at: usize) -> Self {
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        S: Subsliceable,
+    {
         self.split_off_impl::<Infallible>(at).unwrap_infallible()
     }
 
@@ -1515,12 +3801,115 @@ impl<
     /// assert_eq!(b, b"hello");
     /// ```
     #[must_use = "consider `ArcSliceMut::advance` if you don't need the other half"]
-    pub fn split_to(&mut self, at: usize) -> Self {
+    #[track_caller]
+    pub fn split_to(&mut self, at: usize) -> Self
+    where
+        S: Subsliceable,
+    {
         self.split_to_impl::<Infallible>(at).unwrap_infallible()
     }
+
+    /// Freezes the elements `[0, at)` into an [`ArcSlice`].
+    ///
+    /// Afterwards `self` contains elements `[at, len)`, as well as the spare capacity, and the
+    /// frozen slice shares the same buffer. The buffer is not unique as long as the frozen slice
+    /// is alive, but reservation can reuse it in place once it is dropped.
+    ///
+    /// This is the usual codec pattern: write a frame, freeze it to send it, and keep writing the
+    /// next frame in the remaining capacity. A unique slice can be converted with
+    /// [`into_shared`](ArcSliceMut::into_shared), and converted back with
+    /// [`try_into_unique`](Self::try_into_unique) once the frozen slices are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{ArcBytes, ArcSliceMut};
+    ///
+    /// let mut s = ArcSliceMut::<[u8]>::with_capacity(64).into_shared();
+    /// s.try_extend_from_slice(b"first frame").unwrap();
+    /// let frame: ArcBytes = s.freeze_to(s.len());
+    /// s.try_extend_from_slice(b"second frame").unwrap();
+    /// assert_eq!(frame, b"first frame");
+    /// assert_eq!(s, b"second frame");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    #[track_caller]
+    pub fn freeze_to<L2: FromLayout<L>>(&mut self, at: usize) -> ArcSlice<S, L2>
+    where
+        S: Subsliceable,
+    {
+        self.freeze_to_impl::<L2, Infallible>(at).unwrap_checked()
+    }
+
+    /// Splits the slice into two at the given index, consuming it.
+    ///
+    /// The first returned `ArcSliceMut` contains elements `[0, at)`, and the second one contains
+    /// elements `[at, len)` as well as the spare capacity. This operation does not touch the
+    /// underlying buffer, and the reference of the consumed slice is reused for the first half,
+    /// so the refcount is incremented only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSliceMut;
+    ///
+    /// let s = ArcSliceMut::<[u8]>::from(b"hello world").into_shared();
+    /// let (a, b) = s.split_into(5);
+    ///
+    /// assert_eq!(a, b"hello");
+    /// assert_eq!(b, b" world");
+    /// ```
+    #[track_caller]
+    pub fn split_into(self, at: usize) -> (Self, Self)
+    where
+        S: Subsliceable,
+    {
+        self.split_into_impl::<Infallible>(at)
+            .map_err(|(err, _)| err)
+            .unwrap_infallible()
+    }
+}
+
+impl<
+        #[cfg(feature = "oom-handling")] L: LayoutMut,
+        #[cfg(not(feature = "oom-handling"))] L: LayoutMut + CloneNoAllocLayout,
+    > ArcSliceMut<str, L, false>
+{
+    /// Tries splitting the string into two at the given index, returning an error if the index
+    /// is out of range or not a char boundary.
+    ///
+    /// Afterwards `self` contains bytes `[at, len)`, and the returned `ArcStrMut` contains bytes
+    /// `[0, at)`. The string is left untouched in case of error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{error::CharBoundaryError, ArcStrMut};
+    ///
+    /// let mut a = <ArcStrMut>::from("héllo").into_shared();
+    /// assert_eq!(
+    ///     a.try_split_to_checked(42),
+    ///     Err(CharBoundaryError::OutOfRange { index: 42, len: 6 })
+    /// );
+    /// let b = a.try_split_to_checked(3).unwrap();
+    /// assert_eq!(a, "llo");
+    /// assert_eq!(b, "hé");
+    /// ```
+    pub fn try_split_to_checked(&mut self, at: usize) -> Result<Self, CharBoundaryError> {
+        try_char_boundary(self, at)?;
+        Ok(self.split_to(at))
+    }
 }
 
-impl<S: Slice + ?Sized, L: AnyBufferLayout + LayoutMut> ArcSliceMut<S, L> {
+impl<S: SliceMut + ?Sized, L: AnyBufferLayout + LayoutMut> ArcSliceMut<S, L> {
     pub(crate) fn from_dyn_buffer_impl<B: DynBuffer + BufferMut<S>, E: AllocErrorImpl>(
         buffer: B,
     ) -> Result<Self, (E, B)> {
@@ -1652,6 +4041,73 @@ impl<S: Slice + ?Sized, L: AnyBufferLayout + LayoutMut> ArcSliceMut<S, L> {
             .map_err(|(_, bm)| bm)
     }
 
+    fn from_buffer_with_metadata_chain_impl<
+        B: BufferMut<S>,
+        M: MetadataChain,
+        E: AllocErrorImpl,
+    >(
+        buffer: B,
+        metadata: M,
+    ) -> Result<Self, (E, (B, M))> {
+        Self::from_dyn_buffer_impl::<_, E>(BufferWithMetadata::new_chain(buffer, metadata))
+            .map_err(|(err, b)| (err, b.into_tuple()))
+    }
+
+    /// Creates a new `ArcSliceMut` with the given underlying buffer and a chain of metadata.
+    ///
+    /// Contrary to [`from_buffer_with_metadata`](Self::from_buffer_with_metadata), each element
+    /// of the [chain](MetadataChain) can be retrieved with [`metadata`](Self::metadata), as well
+    /// as the chain itself. The buffer can be extracted back using
+    /// [`try_into_buffer`](Self::try_into_buffer).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcBytesMut};
+    ///
+    /// let metadata = ("path".to_string(), 42u64);
+    /// let s =
+    ///     ArcBytesMut::<ArcLayout<true>>::from_buffer_with_metadata_chain(vec![0, 1, 2], metadata);
+    /// assert_eq!(s, [0, 1, 2]);
+    /// assert_eq!(s.metadata::<String>().unwrap(), "path");
+    /// assert_eq!(*s.metadata::<u64>().unwrap(), 42);
+    /// assert!(s.metadata::<u32>().is_none());
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn from_buffer_with_metadata_chain<B: BufferMut<S>, M: MetadataChain>(
+        buffer: B,
+        metadata: M,
+    ) -> Self {
+        Self::from_buffer_with_metadata_chain_impl::<_, _, Infallible>(buffer, metadata)
+            .unwrap_infallible()
+    }
+
+    /// Tries creates a new `ArcSliceMut` with the given underlying buffer and a chain of metadata,
+    /// returning them if an allocation fails.
+    ///
+    /// See [`from_buffer_with_metadata_chain`](Self::from_buffer_with_metadata_chain).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcBytesMut};
+    ///
+    /// let metadata = ("path".to_string(), 42u64);
+    /// let s = ArcBytesMut::<ArcLayout<true>>::try_from_buffer_with_metadata_chain(
+    ///     vec![0, 1, 2],
+    ///     metadata,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(*s.metadata::<u64>().unwrap(), 42);
+    /// ```
+    pub fn try_from_buffer_with_metadata_chain<B: BufferMut<S>, M: MetadataChain>(
+        buffer: B,
+        metadata: M,
+    ) -> Result<Self, (B, M)> {
+        Self::from_buffer_with_metadata_chain_impl::<_, _, AllocError>(buffer, metadata)
+            .map_err(|(_, bm)| bm)
+    }
+
     /// Creates a new `ArcSliceMut` with the given underlying buffer with borrowed metadata.
     ///
     /// The buffer can be extracted back using [`try_into_buffer`](Self::try_into_buffer);
@@ -1779,18 +4235,114 @@ impl<S: Slice + ?Sized, L: AnyBufferLayout + LayoutMut> ArcSliceMut<S, L> {
     ) -> Result<Self, B> {
         Self::from_dyn_buffer_impl::<_, AllocError>(buffer).map_err(|(_, buffer)| buffer)
     }
+
+    fn with_capacity_in_impl<A: BufferSource, E: AllocErrorImpl>(
+        capacity: usize,
+        alloc: A,
+    ) -> Result<Self, E>
+    where
+        S::Item: Copy,
+    {
+        let (arc, start) = Arc::<S>::with_capacity_in::<A, E>(capacity, alloc)?;
+        Ok(Self::init(start, 0, capacity, Some(arc.into())))
+    }
+
+    /// Creates a new `ArcSliceMut` with the given capacity, allocated from the given source.
+    ///
+    /// The [source](BufferSource) is stored in the inner Arc, next to the buffer, and is used
+    /// for every further allocation, i.e. when reserving additional capacity, as well as for
+    /// deallocation. If the source cannot grow memory blocks, reservation fails with
+    /// [`TryReserveError::Unsupported`]. The inner Arc and the buffer are always allocated, even
+    /// if `capacity == 0`.
+    ///
+    /// Items are required to be `Copy`, as the buffer doesn't track the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSliceMut};
+    ///
+    /// # use std::{alloc::Layout, ptr::NonNull};
+    /// # use arc_slice::{buffer::BufferSource, error::AllocError};
+    /// # struct Heap;
+    /// # unsafe impl BufferSource for Heap {
+    /// #     fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+    /// #         NonNull::new(unsafe { std::alloc::alloc(layout) }).ok_or(AllocError)
+    /// #     }
+    /// #     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+    /// #         unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) };
+    /// #     }
+    /// # }
+    /// let mut s = ArcSliceMut::<[u8], ArcLayout<true>>::with_capacity_in(64, Heap);
+    /// assert_eq!(s, []);
+    /// assert_eq!(s.capacity(), 64);
+    /// s.extend_from_slice(b"hello world");
+    /// assert_eq!(s, b"hello world");
+    /// ```
+    #[cfg(feature = "oom-handling")]
+    pub fn with_capacity_in<A: BufferSource>(capacity: usize, alloc: A) -> Self
+    where
+        S: Emptyable,
+        S::Item: Copy,
+    {
+        Self::with_capacity_in_impl::<_, Infallible>(capacity, alloc).unwrap_infallible()
+    }
+
+    /// Tries creating a new `ArcSliceMut` with the given capacity, allocated from the given
+    /// source, returning an error if an allocation fails.
+    ///
+    /// See [`with_capacity_in`](Self::with_capacity_in).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::{layout::ArcLayout, ArcSliceMut};
+    ///
+    /// # use std::{alloc::Layout, ptr::NonNull};
+    /// # use arc_slice::{buffer::BufferSource, error::AllocError};
+    /// # struct Heap;
+    /// # unsafe impl BufferSource for Heap {
+    /// #     fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+    /// #         NonNull::new(unsafe { std::alloc::alloc(layout) }).ok_or(AllocError)
+    /// #     }
+    /// #     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+    /// #         unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) };
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), arc_slice::error::AllocError> {
+    /// let s = ArcSliceMut::<[u8], ArcLayout<true>>::try_with_capacity_in(64, Heap)?;
+    /// assert_eq!(s, []);
+    /// assert_eq!(s.capacity(), 64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_with_capacity_in<A: BufferSource>(
+        capacity: usize,
+        alloc: A,
+    ) -> Result<Self, AllocError>
+    where
+        S: Emptyable,
+        S::Item: Copy,
+    {
+        Self::with_capacity_in_impl::<_, AllocError>(capacity, alloc)
+    }
 }
 
-unsafe impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Send
+unsafe impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Send
     for ArcSliceMut<S, L, UNIQUE>
 {
 }
-unsafe impl<S: Slice + ?Sized, L: AnyBufferLayout + LayoutMut, const UNIQUE: bool> Sync
+unsafe impl<S: SliceMut + ?Sized, L: AnyBufferLayout + LayoutMut, const UNIQUE: bool> Sync
     for ArcSliceMut<S, L, UNIQUE>
 {
 }
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Unpin for ArcSliceMut<S, L, UNIQUE> {}
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Drop for ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Drop for ArcSliceMut<S, L, UNIQUE> {
     fn drop(&mut self) {
         if let Some(data) = self.data {
             let drop = <L as ArcSliceMutLayout>::drop::<S, UNIQUE>;
@@ -1799,7 +4351,7 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Drop for ArcSliceMut<S
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Deref for ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Deref for ArcSliceMut<S, L, UNIQUE> {
     type Target = S;
 
     fn deref(&self) -> &Self::Target {
@@ -1807,25 +4359,31 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Deref for ArcSliceMut<
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> DerefMut for ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> DerefMut
+    for ArcSliceMut<S, L, UNIQUE>
+{
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut_slice()
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> AsRef<S> for ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> AsRef<S>
+    for ArcSliceMut<S, L, UNIQUE>
+{
     fn as_ref(&self) -> &S {
         self
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> AsMut<S> for ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> AsMut<S>
+    for ArcSliceMut<S, L, UNIQUE>
+{
     fn as_mut(&mut self) -> &mut S {
         self
     }
 }
 
-impl<S: Hash + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Hash
+impl<S: Hash + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Hash
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn hash<H>(&self, state: &mut H)
@@ -1836,13 +4394,15 @@ impl<S: Hash + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Hash
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Borrow<S> for ArcSliceMut<S, L, UNIQUE> {
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Borrow<S>
+    for ArcSliceMut<S, L, UNIQUE>
+{
     fn borrow(&self) -> &S {
         self
     }
 }
 
-impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> BorrowMut<S>
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> BorrowMut<S>
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn borrow_mut(&mut self) -> &mut S {
@@ -1850,13 +4410,13 @@ impl<S: Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> BorrowMut<S>
     }
 }
 
-impl<S: Emptyable + ?Sized, L: LayoutMut> Default for ArcSliceMut<S, L> {
+impl<S: SliceMut + Emptyable + ?Sized, L: LayoutMut> Default for ArcSliceMut<S, L> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<S: fmt::Debug + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Debug
+impl<S: fmt::Debug + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Debug
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1864,7 +4424,7 @@ impl<S: fmt::Debug + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Debu
     }
 }
 
-impl<S: fmt::Display + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Display
+impl<S: fmt::Display + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Display
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1872,7 +4432,7 @@ impl<S: fmt::Display + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Di
     }
 }
 
-impl<S: Slice<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::LowerHex
+impl<S: SliceMut<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::LowerHex
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1880,7 +4440,7 @@ impl<S: Slice<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::LowerH
     }
 }
 
-impl<S: Slice<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::UpperHex
+impl<S: SliceMut<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::UpperHex
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1888,7 +4448,7 @@ impl<S: Slice<Item = u8> + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::UpperH
     }
 }
 
-impl<S: PartialEq + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq
+impl<S: PartialEq + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn eq(&self, other: &ArcSliceMut<S, L, UNIQUE>) -> bool {
@@ -1896,12 +4456,12 @@ impl<S: PartialEq + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq
     }
 }
 
-impl<S: PartialEq + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Eq
+impl<S: PartialEq + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Eq
     for ArcSliceMut<S, L, UNIQUE>
 {
 }
 
-impl<S: PartialOrd + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialOrd
+impl<S: PartialOrd + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialOrd
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn partial_cmp(&self, other: &ArcSliceMut<S, L, UNIQUE>) -> Option<cmp::Ordering> {
@@ -1909,13 +4469,47 @@ impl<S: PartialOrd + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialOr
     }
 }
 
-impl<S: Ord + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> Ord for ArcSliceMut<S, L, UNIQUE> {
+impl<S: Ord + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Ord
+    for ArcSliceMut<S, L, UNIQUE>
+{
     fn cmp(&self, other: &ArcSliceMut<S, L, UNIQUE>) -> cmp::Ordering {
         self.as_slice().cmp(other.as_slice())
     }
 }
 
-impl<S: PartialEq + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq<S>
+impl<S: PartialEq + SliceMut + ?Sized, L: LayoutMut, L2: Layout, const UNIQUE: bool>
+    PartialEq<ArcSlice<S, L2>> for ArcSliceMut<S, L, UNIQUE>
+{
+    fn eq(&self, other: &ArcSlice<S, L2>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<S: PartialEq + SliceMut + ?Sized, L: Layout, L2: LayoutMut, const UNIQUE: bool>
+    PartialEq<ArcSliceMut<S, L2, UNIQUE>> for ArcSlice<S, L>
+{
+    fn eq(&self, other: &ArcSliceMut<S, L2, UNIQUE>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<S: PartialOrd + SliceMut + ?Sized, L: LayoutMut, L2: Layout, const UNIQUE: bool>
+    PartialOrd<ArcSlice<S, L2>> for ArcSliceMut<S, L, UNIQUE>
+{
+    fn partial_cmp(&self, other: &ArcSlice<S, L2>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<S: PartialOrd + SliceMut + ?Sized, L: Layout, L2: LayoutMut, const UNIQUE: bool>
+    PartialOrd<ArcSliceMut<S, L2, UNIQUE>> for ArcSlice<S, L>
+{
+    fn partial_cmp(&self, other: &ArcSliceMut<S, L2, UNIQUE>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<S: PartialEq + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq<S>
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn eq(&self, other: &S) -> bool {
@@ -1923,7 +4517,7 @@ impl<S: PartialEq + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq<
     }
 }
 
-impl<'a, S: PartialEq + Slice + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq<&'a S>
+impl<'a, S: PartialEq + SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> PartialEq<&'a S>
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn eq(&self, other: &&'a S) -> bool {
@@ -2003,7 +4597,7 @@ impl<L: LayoutMut, const UNIQUE: bool> PartialEq<ArcSliceMut<str, L, UNIQUE>> fo
 }
 
 #[cfg(feature = "oom-handling")]
-impl<S: Slice + ?Sized, L: LayoutMut> From<&S> for ArcSliceMut<S, L>
+impl<S: SliceMut + ?Sized, L: LayoutMut> From<&S> for ArcSliceMut<S, L>
 where
     S::Item: Copy,
 {
@@ -2044,6 +4638,20 @@ impl<T: Send + Sync + 'static> From<Vec<T>> for ArcSliceMut<[T], VecLayout> {
     }
 }
 
+#[cfg(feature = "oom-handling")]
+impl<S: SliceMut + ?Sized, L: AnyBufferLayout + LayoutMut> From<Box<S>> for ArcSliceMut<S, L> {
+    fn from(value: Box<S>) -> Self {
+        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+    }
+}
+
+#[cfg(not(feature = "oom-handling"))]
+impl<S: SliceMut + ?Sized> From<Box<S>> for ArcSliceMut<S, VecLayout> {
+    fn from(value: Box<S>) -> Self {
+        Self::from_vec(unsafe { S::from_vec_unchecked(value.into_boxed_slice().into_vec()) })
+    }
+}
+
 impl<T: Send + Sync + 'static, L: LayoutMut, const N: usize, const UNIQUE: bool>
     TryFrom<ArcSliceMut<[T], L, UNIQUE>> for [T; N]
 {
@@ -2061,19 +4669,148 @@ impl<T: Send + Sync + 'static, L: LayoutMut, const N: usize, const UNIQUE: bool>
     }
 }
 
+/// The raw parts of an [`ArcSliceMut`].
+///
+/// This struct is created by [`ArcSliceMut::into_raw_parts`], and can be converted back with
+/// [`ArcSliceMut::from_raw_parts`]. It owns the slice, but dropping it leaks the underlying
+/// buffer. It can be passed through FFI, `start`, `length` and `capacity` being the raw parts of
+/// the slice, while the remaining data word is opaque and depends on the [layout](crate::layout).
+///
+/// The parts can only be converted back once:
+/// ```compile_fail
+/// use arc_slice::ArcSliceMut;
+///
+/// let parts = ArcSliceMut::<[u8]>::from(b"hello world").into_raw_parts();
+/// let s1 = unsafe { ArcSliceMut::from_raw_parts(parts) };
+/// let s2 = unsafe { ArcSliceMut::from_raw_parts(parts) };
+/// ```
+#[repr(C)]
+pub struct ArcSliceMutRawParts<
+    S: SliceMut + ?Sized,
+    L: LayoutMut = DefaultLayoutMut,
+    const UNIQUE: bool = true,
+> {
+    /// The pointer to the slice's first item.
+    pub start: NonNull<S::Item>,
+    /// The number of items in the slice.
+    pub length: usize,
+    /// The capacity of the slice.
+    pub capacity: usize,
+    data: Option<NonNull<()>>,
+    _phantom: PhantomData<L>,
+}
+
+unsafe impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Send
+    for ArcSliceMutRawParts<S, L, UNIQUE>
+{
+}
+unsafe impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> Sync
+    for ArcSliceMutRawParts<S, L, UNIQUE>
+{
+}
+
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Debug
+    for ArcSliceMutRawParts<S, L, UNIQUE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcSliceMutRawParts")
+            .field("start", &self.start)
+            .field("length", &self.length)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A writer filling the spare capacity of an [`ArcSliceMut`] chunk by chunk.
+///
+/// This struct is created by [`ArcSliceMut::spare_capacity_chunks_mut`].
+pub struct SpareCapacityChunksMut<
+    'a,
+    S: SliceMut + ?Sized,
+    L: LayoutMut = DefaultLayoutMut,
+    const UNIQUE: bool = true,
+> {
+    slice: &'a mut ArcSliceMut<S, L, UNIQUE>,
+    chunk_size: usize,
+}
+
+impl<S: SliceMut + Extendable + ?Sized, L: LayoutMut, const UNIQUE: bool>
+    SpareCapacityChunksMut<'_, S, L, UNIQUE>
+{
+    /// Returns the next window of spare capacity, of at most `chunk_size` items, or `None` if
+    /// there is no spare capacity left.
+    ///
+    /// The window starts at the end of the slice, so it is the same until items are
+    /// [committed](Self::commit).
+    pub fn next_chunk(&mut self) -> Option<&mut [MaybeUninit<S::Item>]> {
+        let chunk_size = cmp::min(self.chunk_size, self.slice.spare_capacity());
+        if chunk_size == 0 {
+            return None;
+        }
+        // SAFETY: the buffer supports writing uninitialized memory, see
+        // `ArcSliceMut::spare_capacity_chunks_mut`
+        Some(&mut unsafe { self.slice.spare_capacity_mut() }[..chunk_size])
+    }
+
+    /// Appends the first `n` items of the last window to the slice.
+    ///
+    /// # Safety
+    ///
+    /// The first `n` items of the window returned by the last call to
+    /// [`next_chunk`](Self::next_chunk) must be initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the window length.
+    #[track_caller]
+    pub unsafe fn commit(&mut self, n: usize) {
+        assert!(
+            n <= cmp::min(self.chunk_size, self.slice.spare_capacity()),
+            "committed items out of window"
+        );
+        // SAFETY: same function contract
+        unsafe { self.slice.set_len(self.slice.len() + n) };
+    }
+
+    /// Returns the number of items that can still be written.
+    pub fn spare_capacity(&self) -> usize {
+        self.slice.spare_capacity()
+    }
+}
+
+impl<S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Debug
+    for SpareCapacityChunksMut<'_, S, L, UNIQUE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpareCapacityChunksMut")
+            .field("len", &self.slice.len())
+            .field("spare_capacity", &self.slice.spare_capacity())
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
 #[cfg(feature = "oom-handling")]
-impl<S: Emptyable + Extendable + ?Sized, L: LayoutMut> Extend<S::Item> for ArcSliceMut<S, L> {
+impl<S: SliceMut + Emptyable + Extendable + ?Sized, L: LayoutMut> Extend<S::Item>
+    for ArcSliceMut<S, L>
+{
     fn extend<I: IntoIterator<Item = S::Item>>(&mut self, iter: I) {
-        let iter = iter.into_iter();
+        let mut iter = iter.into_iter();
         self.reserve(iter.size_hint().0);
-        for item in iter {
+        while let Some(item) = iter.next() {
+            if self.spare_capacity() == 0 {
+                // like `Vec`, only the lower bound is used, as the upper one may be huge
+                self.reserve(iter.size_hint().0.saturating_add(1));
+            }
             self.push(item);
         }
     }
 }
 
 #[cfg(feature = "oom-handling")]
-impl<S: Emptyable + Extendable + ?Sized, L: LayoutMut> FromIterator<S::Item> for ArcSliceMut<S, L> {
+impl<S: SliceMut + Emptyable + Extendable + ?Sized, L: LayoutMut> FromIterator<S::Item>
+    for ArcSliceMut<S, L>
+{
     fn from_iter<T: IntoIterator<Item = S::Item>>(iter: T) -> Self {
         let mut this = Self::new();
         this.extend(iter);
@@ -2090,7 +4827,7 @@ impl<L: LayoutMut> core::str::FromStr for ArcSliceMut<str, L> {
     }
 }
 
-impl<S: Slice<Item = u8> + Extendable + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Write
+impl<S: SliceMut<Item = u8> + Extendable + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Write
     for ArcSliceMut<S, L, UNIQUE>
 {
     fn write_str(&mut self, s: &str) -> fmt::Result {
@@ -2100,7 +4837,7 @@ impl<S: Slice<Item = u8> + Extendable + ?Sized, L: LayoutMut, const UNIQUE: bool
     }
 
     fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
-        fmt::write(self, args)
+        self.format_impl(args).map_err(|_| fmt::Error)
     }
 }
 
@@ -2110,7 +4847,38 @@ impl<L: LayoutMut, const UNIQUE: bool> fmt::Write for ArcSliceMut<str, L, UNIQUE
     }
 
     fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
-        fmt::write(self, args)
+        self.format_impl(args).map_err(|_| fmt::Error)
+    }
+}
+
+struct FormatWriter<'a, S: SliceMut + ?Sized, L: LayoutMut, const UNIQUE: bool> {
+    slice: &'a mut ArcSliceMut<S, L, UNIQUE>,
+    // length not fitting in the spare capacity, `None` if capacity is reserved instead
+    overflow: Option<usize>,
+    error: Option<TryReserveError>,
+}
+
+impl<S: SliceMut<Item = u8> + Concatenable + ?Sized, L: LayoutMut, const UNIQUE: bool> fmt::Write
+    for FormatWriter<'_, S, L, UNIQUE>
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match &mut self.overflow {
+            // once overflowed, the following strings are only counted to keep the order
+            Some(overflow) if *overflow > 0 || s.len() > self.slice.spare_capacity() => {
+                *overflow = overflow.saturating_add(s.len());
+                return Ok(());
+            }
+            Some(_) => {}
+            // formatting may not be deterministic, so the reservation is checked again
+            None => {
+                if let Err(err) = self.slice.try_reserve(s.len()) {
+                    self.error = Some(err);
+                    return Err(fmt::Error);
+                }
+            }
+        }
+        unsafe { self.slice.extend_from_slice_unchecked(s.as_bytes()) };
+        Ok(())
     }
 }
 
@@ -2137,4 +4905,54 @@ const _: () = {
             Ok(())
         }
     }
+
+    #[cfg(read_buf)]
+    impl<L: LayoutMut, const UNIQUE: bool> ArcSliceMut<[u8], L, UNIQUE> {
+        /// Reads at most `max` bytes from the reader into the spare capacity, returning the
+        /// number of bytes read.
+        ///
+        /// The spare capacity is passed uninitialized to [`Read::read_buf`], so it is not
+        /// zeroed before reading. No capacity is reserved, so [`reserve`](Self::reserve) should
+        /// be called first.
+        ///
+        /// Like [`spare_capacity_chunks_mut`](Self::spare_capacity_chunks_mut), it is only
+        /// supported by buffers allocated by the crate or by vectors, for which writing
+        /// uninitialized memory is sound; other buffers return an
+        /// [`Unsupported`](std::io::ErrorKind::Unsupported) error.
+        ///
+        /// This method requires nightly Rust, and to build with `--cfg read_buf` rustc flag.
+        ///
+        /// [`Read::read_buf`]: std::io::Read::read_buf
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use arc_slice::ArcBytesMut;
+        ///
+        /// let mut s = <ArcBytesMut>::with_capacity(16);
+        /// let mut reader = &b"hello world"[..];
+        /// assert_eq!(s.read_buf_from(&mut reader, 5)?, 5);
+        /// assert_eq!(s.read_buf_from(&mut reader, 16)?, 6);
+        /// assert_eq!(s, b"hello world");
+        /// # Ok::<_, std::io::Error>(())
+        /// ```
+        pub fn read_buf_from<R: std::io::Read + ?Sized>(
+            &mut self,
+            reader: &mut R,
+            max: usize,
+        ) -> std::io::Result<usize> {
+            if !matches!(self.repr(), Repr::ArcSlice | Repr::Vec | Repr::Static) {
+                return Err(std::io::ErrorKind::Unsupported.into());
+            }
+            let len = cmp::min(max, self.spare_capacity());
+            // SAFETY: uninit bytes can be written in the supported buffers
+            let spare_capacity = unsafe { &mut self.spare_capacity_mut()[..len] };
+            let mut buf = std::io::BorrowedBuf::from(spare_capacity);
+            reader.read_buf(buf.unfilled())?;
+            let n = buf.len();
+            // SAFETY: the first `n` bytes of the spare capacity have been filled
+            unsafe { self.set_len(self.length + n) };
+            Ok(n)
+        }
+    }
 };
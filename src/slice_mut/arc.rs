@@ -4,9 +4,9 @@ use core::{any::Any, mem, mem::ManuallyDrop, ptr::NonNull};
 use crate::msrv::StrictProvenance;
 use crate::{
     arc::Arc,
-    buffer::{BufferMut, Slice},
-    error::AllocErrorImpl,
-    layout::ArcLayout,
+    buffer::{Buffer, BufferMut, Slice},
+    error::{AllocErrorImpl, TryReserveError},
+    layout::{ArcLayout, Repr},
     msrv::ptr,
     slice::ArcSliceLayout,
     slice_mut::{ArcSliceMutLayout, Data, TryReserveResult},
@@ -83,15 +83,19 @@ impl<const UNIQUE: bool> Data<UNIQUE> {
     }
 }
 
-unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceMutLayout
-    for ArcLayout<ANY_BUFFER, STATIC>
+unsafe impl<const ANY_BUFFER: bool, const STATIC: bool, const SATURATE: bool> ArcSliceMutLayout
+    for ArcLayout<ANY_BUFFER, STATIC, SATURATE>
 {
     const ANY_BUFFER: bool = ANY_BUFFER;
     fn try_data_from_arc<S: Slice + ?Sized, const ANY_BUFFER2: bool, const UNIQUE: bool>(
         arc: ManuallyDrop<Arc<S, ANY_BUFFER2>>,
     ) -> Option<Data<UNIQUE>> {
-        ManuallyDrop::into_inner(arc)
-            .try_into_arc_slice()
+        let arc = ManuallyDrop::into_inner(arc);
+        // mutable buffers are stored in the arc with a vtable
+        if ANY_BUFFER {
+            return Some(Data::from_arc(arc));
+        }
+        arc.try_into_arc_slice()
             .map_err(mem::forget)
             .ok()
             .map(Data::from_arc)
@@ -109,7 +113,7 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceMutLayout
         _capacity: usize,
         data: &mut Data<UNIQUE>,
     ) -> Result<(), E> {
-        mem::forget((*data.get_arc::<S, ANY_BUFFER>()).clone());
+        mem::forget(data.get_arc::<S, ANY_BUFFER>().clone_with::<SATURATE>());
         data.make_shared();
         Ok(())
     }
@@ -129,13 +133,47 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceMutLayout
         }
     }
 
+    fn set_unique_length<S: Slice + ?Sized, const UNIQUE: bool>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &mut Data<UNIQUE>,
+    ) -> bool {
+        assert_checked(data.is_unique());
+        unsafe {
+            data.get_arc::<S, ANY_BUFFER>()
+                .set_unique_length(start, length)
+        }
+    }
+
     fn get_metadata<S: Slice + ?Sized, M: Any, const UNIQUE: bool>(
         data: &Data<UNIQUE>,
     ) -> Option<&M> {
         Some(unsafe { &*ptr::from_ref((*data).get_arc::<S, ANY_BUFFER>().get_metadata()?) })
     }
 
-    unsafe fn take_buffer<S: Slice + ?Sized, B: BufferMut<S>, const UNIQUE: bool>(
+    fn repr<S: Slice + ?Sized, const UNIQUE: bool>(data: &Data<UNIQUE>) -> Repr {
+        data.get_arc::<S, ANY_BUFFER>().repr()
+    }
+
+    #[cfg(feature = "arbitrary")]
+    fn validate<S: Slice + ?Sized, const UNIQUE: bool>(
+        start: NonNull<S::Item>,
+        capacity: usize,
+        data: &Data<UNIQUE>,
+    ) {
+        // a shared data may actually be unique, but a unique one must not be shared
+        let arc = data.get_arc::<S, ANY_BUFFER>();
+        arc.validate(start, capacity, data.is_unique());
+    }
+
+    fn get_buffer_mut<S: Slice + ?Sized, B: BufferMut<S>, const UNIQUE: bool>(
+        data: &mut Data<UNIQUE>,
+    ) -> Option<&mut B> {
+        let buffer = (*data).get_arc::<S, ANY_BUFFER>().get_buffer_mut::<B>()? as *mut B;
+        Some(unsafe { &mut *buffer })
+    }
+
+    unsafe fn take_buffer<S: Slice + ?Sized, B: Buffer<S>, const UNIQUE: bool>(
         start: NonNull<S::Item>,
         length: usize,
         _capacity: usize,
@@ -170,6 +208,12 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceMutLayout
         false
     }
 
+    fn buffer_ptr<S: Slice + ?Sized, const UNIQUE: bool>(
+        data: &Data<UNIQUE>,
+    ) -> Option<NonNull<()>> {
+        Some(ManuallyDrop::into_inner(data.get_arc::<S, ANY_BUFFER>()).into_raw())
+    }
+
     fn try_reserve<S: Slice + ?Sized, const UNIQUE: bool>(
         start: NonNull<S::Item>,
         length: usize,
@@ -178,8 +222,13 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceMutLayout
         additional: usize,
         allocate: bool,
     ) -> TryReserveResult<S::Item> {
+        // use the same check as `try_into_unique`, so a shared data whose other parts have been
+        // dropped is downgraded to unique, and later reservations skip the refcount check
+        if !UNIQUE && !<Self as ArcSliceMutLayout>::is_unique::<S, UNIQUE>(data) {
+            return (Err(TryReserveError::NotUnique), start);
+        }
         let mut arc = (*data).get_arc::<S, ANY_BUFFER>();
-        let res = unsafe { arc.try_reserve::<UNIQUE>(start, length, additional, allocate) };
+        let res = unsafe { arc.try_reserve::<true>(start, length, additional, allocate) };
         if res.0.is_ok() {
             // Arc::try_reserve may reallocate the arc, but only if it succeeds, and in that case
             // the data is unique
@@ -189,12 +238,14 @@ unsafe impl<const ANY_BUFFER: bool, const STATIC: bool> ArcSliceMutLayout
     }
 
     fn frozen_data<S: Slice + ?Sized, L: ArcSliceLayout, E: AllocErrorImpl, const UNIQUE: bool>(
-        _start: NonNull<S::Item>,
-        _length: usize,
+        start: NonNull<S::Item>,
+        length: usize,
         _capacity: usize,
         data: Data<UNIQUE>,
     ) -> Option<L::Data> {
-        L::try_data_from_arc(data.get_arc::<S, ANY_BUFFER>())
+        let mut arc = data.get_arc::<S, ANY_BUFFER>();
+        arc.set_length::<UNIQUE>(start, length);
+        L::try_data_from_arc(arc)
     }
 
     fn update_layout<
@@ -1,13 +1,13 @@
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use core::{any::Any, convert::Infallible, mem, mem::ManuallyDrop, ptr::NonNull};
 
 #[allow(unused_imports)]
 use crate::msrv::{NonNullExt, StrictProvenance};
 use crate::{
     arc::Arc,
-    buffer::{BufferMut, BufferMutExt, Slice, SliceExt},
+    buffer::{Buffer, BufferMut, BufferMutExt, Slice, SliceExt},
     error::AllocErrorImpl,
-    layout::VecLayout,
+    layout::{Repr, VecLayout},
     macros::{assume, is},
     msrv::ptr,
     slice::ArcSliceLayout,
@@ -145,6 +145,19 @@ unsafe impl ArcSliceMutLayout for VecLayout {
         }
     }
 
+    fn set_unique_length<S: Slice + ?Sized, const UNIQUE: bool>(
+        start: NonNull<S::Item>,
+        length: usize,
+        data: &mut Data<UNIQUE>,
+    ) -> bool {
+        assert_checked(UNIQUE);
+        match data.offset_or_arc::<S>() {
+            OffsetOrArc::Arc(mut arc) => unsafe { arc.set_unique_length(start, length) },
+            // the vector is rebuilt with the slice length
+            OffsetOrArc::Offset(_) => true,
+        }
+    }
+
     fn get_metadata<S: Slice + ?Sized, M: Any, const UNIQUE: bool>(
         data: &Data<UNIQUE>,
     ) -> Option<&M> {
@@ -154,7 +167,37 @@ unsafe impl ArcSliceMutLayout for VecLayout {
         }
     }
 
-    unsafe fn take_buffer<S: Slice + ?Sized, B: BufferMut<S>, const UNIQUE: bool>(
+    fn repr<S: Slice + ?Sized, const UNIQUE: bool>(data: &Data<UNIQUE>) -> Repr {
+        match data.offset_or_arc::<S>() {
+            OffsetOrArc::Arc(arc) => arc.repr(),
+            OffsetOrArc::Offset(_) => Repr::Vec,
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    fn validate<S: Slice + ?Sized, const UNIQUE: bool>(
+        start: NonNull<S::Item>,
+        capacity: usize,
+        data: &Data<UNIQUE>,
+    ) {
+        if let OffsetOrArc::Arc(arc) = data.offset_or_arc::<S>() {
+            arc.validate(start, capacity, UNIQUE);
+        }
+    }
+
+    fn get_buffer_mut<S: Slice + ?Sized, B: BufferMut<S>, const UNIQUE: bool>(
+        data: &mut Data<UNIQUE>,
+    ) -> Option<&mut B> {
+        match data.offset_or_arc::<S>() {
+            OffsetOrArc::Arc(mut arc) => {
+                let buffer = arc.get_buffer_mut::<B>()? as *mut B;
+                Some(unsafe { &mut *buffer })
+            }
+            OffsetOrArc::Offset(_) => None,
+        }
+    }
+
+    unsafe fn take_buffer<S: Slice + ?Sized, B: Buffer<S>, const UNIQUE: bool>(
         start: NonNull<S::Item>,
         length: usize,
         capacity: usize,
@@ -173,6 +216,13 @@ unsafe impl ArcSliceMutLayout for VecLayout {
                 }
                 Some(transmute_checked(vec))
             }
+            OffsetOrArc::Offset(0) if is!(B, Box<S>) && length == capacity => {
+                let vec = unsafe { rebuild_vec::<S>(start, length, capacity, 0) };
+                let boxed = S::into_vec(vec).into_boxed_slice();
+                Some(transmute_checked(unsafe {
+                    S::from_boxed_slice_unchecked(boxed)
+                }))
+            }
             _ => None,
         }
     }
@@ -200,6 +250,15 @@ unsafe impl ArcSliceMutLayout for VecLayout {
         }
     }
 
+    fn buffer_ptr<S: Slice + ?Sized, const UNIQUE: bool>(
+        data: &Data<UNIQUE>,
+    ) -> Option<NonNull<()>> {
+        match data.offset_or_arc::<S>() {
+            OffsetOrArc::Arc(_) => Some(data.0),
+            OffsetOrArc::Offset(_) => None,
+        }
+    }
+
     fn try_reserve<S: Slice + ?Sized, const UNIQUE: bool>(
         start: NonNull<S::Item>,
         length: usize,
@@ -233,7 +292,10 @@ unsafe impl ArcSliceMutLayout for VecLayout {
         data: Data<UNIQUE>,
     ) -> Option<L::Data> {
         match data.offset_or_arc::<S>() {
-            OffsetOrArc::Arc(arc) => L::try_data_from_arc(arc),
+            OffsetOrArc::Arc(mut arc) => {
+                arc.set_length::<UNIQUE>(start, length);
+                L::try_data_from_arc(arc)
+            }
             OffsetOrArc::Offset(offset) if L::ANY_BUFFER => {
                 let vec = unsafe { rebuild_vec::<S>(start, length, capacity, offset) };
                 L::data_from_vec::<S, E>(vec).map_err(mem::forget).ok()
@@ -0,0 +1,92 @@
+extern crate std;
+
+use core::{
+    cmp,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::io;
+
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::{
+    buffer::Slice,
+    layout::{Layout, LayoutMut},
+    ArcSlice, ArcSliceCursor, ArcSliceMut,
+};
+
+impl<L: Layout> AsyncRead for ArcSlice<[u8], L> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let n = cmp::min(this.len(), buf.remaining());
+        buf.put_slice(&this[..n]);
+        this.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<L: Layout> AsyncBufRead for ArcSlice<[u8], L> {
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().advance(amt);
+    }
+}
+
+impl<S: Slice<Item = u8> + ?Sized, L: Layout> AsyncRead for ArcSliceCursor<S, L> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let remaining = this.remaining();
+        let n = cmp::min(remaining.len(), buf.remaining());
+        buf.put_slice(&remaining[..n]);
+        this.advance_position(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S: Slice<Item = u8> + ?Sized, L: Layout> AsyncBufRead for ArcSliceCursor<S, L> {
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut().remaining()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().advance_position(amt);
+    }
+}
+
+/// Writes are appended to the slice, reserving capacity as needed, and always complete
+/// synchronously.
+///
+/// Unlike a socket, the buffer grows instead of applying backpressure, which is thus purely
+/// driven by allocation: a write is only short when the capacity cannot be reserved, e.g.
+/// because the slice is not unique or the buffer doesn't support reservation.
+impl<L: LayoutMut, const UNIQUE: bool> AsyncWrite for ArcSliceMut<[u8], L, UNIQUE> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let n = this.try_reserve_at_most(buf.len());
+        unsafe { this.extend_from_slice_unchecked(&buf[..n]) };
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
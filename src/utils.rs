@@ -5,6 +5,7 @@ use core::{
     mem::MaybeUninit,
     num::NonZeroUsize,
     ops::{Bound, RangeBounds},
+    ptr,
     ptr::NonNull,
 };
 
@@ -12,6 +13,7 @@ use core::{
 use crate::msrv::StrictProvenance;
 use crate::{
     buffer::{Slice, SliceExt, Subsliceable},
+    error::CharBoundaryError,
     macros::{is, is_not},
 };
 
@@ -54,57 +56,245 @@ pub(crate) fn upper_hex(slice: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result
     Ok(())
 }
 
+#[track_caller]
 pub(crate) fn range_offset_len<S: Subsliceable + ?Sized>(
     slice: &S,
     range: impl RangeBounds<usize>,
 ) -> (usize, usize) {
+    let (offset, end) = range_bounds(range, slice.len());
+    if end > slice.len() {
+        panic_end_out_of_range(end, slice.len());
+    }
+    unsafe { slice.check_subslice(offset, end) };
+    (offset, end - offset)
+}
+
+// the range may end past `len`, but must start before it in this case
+#[track_caller]
+pub(crate) fn within_offset_len(len: usize, range: impl RangeBounds<usize>) -> (usize, usize) {
+    let (offset, end) = range_bounds(range, len);
+    if offset > len || (end > len && offset == len) {
+        panic_start_out_of_range(offset, len);
+    }
+    (offset, end - offset)
+}
+
+// the end bound is not checked against `len`
+#[track_caller]
+fn range_bounds(range: impl RangeBounds<usize>, len: usize) -> (usize, usize) {
     let offset = match range.start_bound() {
         Bound::Included(&n) => n,
-        Bound::Excluded(&n) => n.checked_add(1).unwrap_or_else(|| panic_invalid_range()),
+        Bound::Excluded(&n) => match n.checked_add(1) {
+            Some(n) => n,
+            None => panic_start_overflow(),
+        },
         Bound::Unbounded => 0,
     };
     let end = match range.end_bound() {
-        Bound::Included(&n) => n.checked_add(1).unwrap_or_else(|| panic_invalid_range()),
+        Bound::Included(&n) => match n.checked_add(1) {
+            Some(n) => n,
+            None => panic_end_overflow(),
+        },
         Bound::Excluded(&n) => n,
-        Bound::Unbounded => slice.len(),
+        Bound::Unbounded => len,
     };
-    if end > slice.len() {
-        panic_out_of_range();
+    if offset > end {
+        panic_index_order(offset, end);
     }
-    let len = end
-        .checked_sub(offset)
-        .unwrap_or_else(|| panic_invalid_range());
-    unsafe { slice.check_subslice(offset, end) };
-    (offset, len)
+    (offset, end)
 }
 
+#[track_caller]
 pub(crate) fn subslice_offset_len<S: Subsliceable + ?Sized>(
     slice: &S,
     subslice: &S,
 ) -> (usize, usize) {
     let sub_start = subslice.as_ptr().addr().get();
     let start = slice.as_ptr().addr().get();
-    let offset = sub_start
-        .checked_sub(start)
-        .unwrap_or_else(|| panic_out_of_range());
-    if offset + subslice.len() > slice.len() {
-        panic_out_of_range()
+    let offset = match sub_start.checked_sub(start) {
+        Some(offset) => offset,
+        None => panic_subslice_before_start(),
+    };
+    let end = offset + subslice.len();
+    if end > slice.len() {
+        panic_subslice_out_of_range(offset, end, slice.len());
     }
-    unsafe { slice.check_subslice(offset, offset + subslice.len()) };
+    unsafe { slice.check_subslice(offset, end) };
     (offset, subslice.len())
 }
 
+/// Total length of concatenated parts, saturating so that overflow is reported as a capacity
+/// overflow by the allocation.
+pub(crate) fn concat_len<S: Slice + ?Sized>(parts: &[&S]) -> usize {
+    parts
+        .iter()
+        .fold(0, |len, part| len.saturating_add(part.len()))
+}
+
+pub(crate) fn find<T: PartialEq + 'static>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    if is!(T, u8) {
+        let as_bytes = |s: &[T]| unsafe { core::slice::from_raw_parts(s.as_ptr().cast(), s.len()) };
+        return memchr::memmem::find(as_bytes(haystack), as_bytes(needle));
+    }
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+// MSRV 1.80 `<[u8]>::trim_ascii_start`
+pub(crate) fn trim_ascii_start(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|b| !b.is_ascii_whitespace());
+    &bytes[start.unwrap_or(bytes.len())..]
+}
+
+// MSRV 1.80 `<[u8]>::trim_ascii_end`
+pub(crate) fn trim_ascii_end(bytes: &[u8]) -> &[u8] {
+    let end = bytes.iter().rposition(|b| !b.is_ascii_whitespace());
+    &bytes[..end.map_or(0, |end| end + 1)]
+}
+
+// unstable `str::floor_char_boundary`
+pub(crate) fn floor_char_boundary(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+    (0..=index)
+        .rev()
+        .find(|&i| s.is_char_boundary(i))
+        .unwrap_or(0)
+}
+
+// unstable `str::ceil_char_boundary`
+pub(crate) fn ceil_char_boundary(s: &str, index: usize) -> usize {
+    if index > s.len() {
+        return s.len();
+    }
+    (index..s.len())
+        .find(|&i| s.is_char_boundary(i))
+        .unwrap_or(s.len())
+}
+
+pub(crate) fn try_char_boundary(s: &str, index: usize) -> Result<(), CharBoundaryError> {
+    if index > s.len() {
+        return Err(CharBoundaryError::OutOfRange {
+            index,
+            len: s.len(),
+        });
+    }
+    if !s.is_char_boundary(index) {
+        return Err(CharBoundaryError::NotCharBoundary {
+            index,
+            floor: floor_char_boundary(s, index),
+            ceil: ceil_char_boundary(s, index),
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (a, b) in a.iter().zip(b) {
+        // volatile read prevents the compiler from short-circuiting once `diff` is saturated
+        diff = unsafe { ptr::read_volatile(&diff) } | (a ^ b);
+    }
+    unsafe { ptr::read_volatile(&diff) == 0 }
+}
+
+pub(crate) fn sign_extend(n: u64, nbytes: usize) -> i64 {
+    match 64 - 8 * nbytes as u32 {
+        64 => 0,
+        shift => ((n << shift) as i64) >> shift,
+    }
+}
+
+#[cold]
+#[track_caller]
+fn panic_start_overflow() -> ! {
+    panic!("attempted to index slice from after maximum usize")
+}
+
+#[cold]
+#[track_caller]
+fn panic_end_overflow() -> ! {
+    panic!("attempted to index slice up to maximum usize")
+}
+
+#[cold]
+#[track_caller]
+fn panic_index_order(start: usize, end: usize) -> ! {
+    panic!("slice index starts at {start} but ends at {end}")
+}
+
+#[cold]
+#[track_caller]
+pub(crate) fn panic_start_out_of_range(index: usize, len: usize) -> ! {
+    panic!("range start index {index} out of range for slice of length {len}")
+}
+
+#[cold]
+#[track_caller]
+pub(crate) fn panic_end_out_of_range(index: usize, len: usize) -> ! {
+    panic!("range end index {index} out of range for slice of length {len}")
+}
+
+#[cold]
+#[track_caller]
+pub(crate) fn panic_advance_back_out_of_range(count: usize, len: usize) -> ! {
+    panic!("advance back count {count} out of range for slice of length {len}")
+}
+
+#[cold]
+#[track_caller]
+pub(crate) fn panic_remove_out_of_range(name: &str, index: usize, len: usize) -> ! {
+    panic!("{name} (is {index}) should be < len (is {len})")
+}
+
 #[cold]
-fn panic_invalid_range() -> ! {
-    panic!("invalid range")
+#[track_caller]
+pub(crate) fn panic_remove_unsupported() -> ! {
+    panic!("buffer doesn't support removing items needing drop")
 }
 
 #[cold]
-pub(crate) fn panic_out_of_range() -> ! {
-    panic!("out of range")
+#[track_caller]
+pub(crate) fn panic_boundary_order(prev: usize, boundary: usize) -> ! {
+    panic!("boundary {boundary} is lower than previous boundary {prev}")
+}
+
+#[cold]
+#[track_caller]
+pub(crate) fn panic_insert_out_of_range(index: usize, len: usize) -> ! {
+    panic!("insertion index (is {index}) should be <= len (is {len})")
+}
+
+#[cold]
+#[track_caller]
+fn panic_subslice_before_start() -> ! {
+    panic!("subslice starts before the slice")
+}
+
+#[cold]
+#[track_caller]
+fn panic_subslice_out_of_range(start: usize, end: usize, len: usize) -> ! {
+    panic!("subslice range {start}..{end} out of range for slice of length {len}")
+}
+
+#[cfg(feature = "oom-handling")]
+#[cold]
+pub(crate) fn panic_reserve(err: crate::error::TryReserveError) -> ! {
+    match err {
+        crate::error::TryReserveError::AllocError => {
+            alloc::alloc::handle_alloc_error(core::alloc::Layout::new::<()>())
+        }
+        err => panic!("{err:?}"),
+    }
 }
 
-#[cfg(feature = "abort-on-refcount-overflow")]
 #[inline(never)]
 #[cold]
 pub(crate) fn abort() -> ! {
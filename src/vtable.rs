@@ -12,17 +12,25 @@ use crate::{slice_mut::TryReserveResult, utils::NewChecked};
 #[derive(Debug)]
 pub struct VTable {
     pub(crate) deallocate: unsafe fn(ptr: *mut ()),
+    // frees the arc allocation after its buffer has been dropped, `None` if weak references
+    // are not supported
+    pub(crate) free: Option<unsafe fn(ptr: *mut ())>,
     pub(crate) is_buffer_unique: unsafe fn(ptr: *const ()) -> bool,
     pub(crate) get_metadata: unsafe fn(ptr: *const (), type_id: TypeId) -> Option<NonNull<()>>,
+    // metadata is dropped if `None`, moved out otherwise
     pub(crate) take_buffer: unsafe fn(
         buffer: NonNull<()>,
+        metadata: Option<(NonNull<()>, TypeId)>,
         ptr: *const (),
         type_id: TypeId,
         start: NonNull<()>,
         length: usize,
     ) -> Option<NonNull<()>>,
-    // capacity -> usize::MAX means either not unique or not mutable
-    pub(crate) capacity: unsafe fn(ptr: *const (), start: NonNull<()>) -> usize,
+    // capacity -> usize::MAX means either not unique or not mutable; without slice, the whole
+    // buffer capacity is returned even if not unique, as it can only be modified when unique;
+    // with slice `[start, start + length)`, the items after its end are dropped, as they become
+    // spare capacity
+    pub(crate) capacity: unsafe fn(ptr: *const (), slice: Option<(NonNull<()>, usize)>) -> usize,
     pub(crate) try_reserve: Option<
         unsafe fn(
             ptr: NonNull<()>,
@@ -32,6 +40,17 @@ pub struct VTable {
             allocate: bool,
         ) -> TryReserveResult<()>,
     >,
+    pub(crate) buffer_mut:
+        Option<unsafe fn(ptr: NonNull<()>, type_id: TypeId) -> Option<NonNull<()>>>,
+    // sets the length of a unique buffer to the end of the slice `[start, start + length)`,
+    // only if it grows without `shrink`, `None` if not mutable; returns `false` if the buffer
+    // doesn't support it
+    pub(crate) set_len: Option<
+        unsafe fn(ptr: NonNull<()>, start: NonNull<()>, length: usize, shrink: bool) -> bool,
+    >,
+    // `RawLayout` cannot rely on vtable addresses, as constants may be duplicated
+    #[cfg(feature = "raw-buffer")]
+    pub(crate) repr: crate::layout::Repr,
     #[cfg(feature = "raw-buffer")]
     pub(crate) drop: unsafe fn(ptr: *const ()),
     #[cfg(feature = "raw-buffer")]
@@ -45,7 +64,7 @@ pub struct VTable {
         unsafe fn(ptr: *const ()) -> Result<Option<NonNull<()>>, crate::error::AllocError>,
 }
 
-pub(crate) unsafe fn no_capacity(_ptr: *const (), _start: NonNull<()>) -> usize {
+pub(crate) unsafe fn no_capacity(_ptr: *const (), _slice: Option<(NonNull<()>, usize)>) -> usize {
     usize::MAX
 }
 
@@ -59,6 +78,24 @@ pub(crate) unsafe fn generic_take_buffer<B: Any>(
     let buffer_ptr = NonNull::new_checked(buffer.as_mut_ptr()).cast();
     let type_id = TypeId::of::<B>();
     let buffer_ptr =
-        unsafe { (vtable.take_buffer)(buffer_ptr, ptr, type_id, start.cast(), length)? };
+        unsafe { (vtable.take_buffer)(buffer_ptr, None, ptr, type_id, start.cast(), length)? };
     unsafe { Some(buffer_ptr.cast().read()) }
 }
+
+pub(crate) unsafe fn generic_take_buffer_with_metadata<B: Any, M: Any>(
+    ptr: *const (),
+    vtable: &'static VTable,
+    start: NonNull<()>,
+    length: usize,
+) -> Option<(B, M)> {
+    let mut buffer = MaybeUninit::<B>::uninit();
+    let mut metadata = MaybeUninit::<M>::uninit();
+    let buffer_ptr = NonNull::new_checked(buffer.as_mut_ptr()).cast();
+    let metadata_ptr = NonNull::new_checked(metadata.as_mut_ptr()).cast();
+    let metadata_arg = Some((metadata_ptr, TypeId::of::<M>()));
+    let type_id = TypeId::of::<B>();
+    let buffer_ptr = unsafe {
+        (vtable.take_buffer)(buffer_ptr, metadata_arg, ptr, type_id, start.cast(), length)?
+    };
+    unsafe { Some((buffer_ptr.cast().read(), metadata_ptr.cast().read())) }
+}
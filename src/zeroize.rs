@@ -0,0 +1,41 @@
+use zeroize::Zeroize;
+
+use crate::{
+    layout::{ArcLayout, Layout, LayoutMut},
+    ArcSlice, ArcSliceMut,
+};
+
+/// Zeroes the bytes in place.
+///
+/// Only the initialized bytes, i.e. `[0, len)`, are zeroed, not the spare capacity. As an
+/// `ArcSliceMut` has exclusive access to its bytes, even if its buffer is shared (`UNIQUE=false`),
+/// the other parts of the buffer are left untouched.
+impl<L: LayoutMut, const UNIQUE: bool> Zeroize for ArcSliceMut<[u8], L, UNIQUE> {
+    fn zeroize(&mut self) {
+        self.as_mut_slice().zeroize();
+    }
+}
+
+impl<L: Layout> ArcSlice<[u8], L> {
+    /// Tries zeroing the bytes in place, returning the original `ArcSlice` if it fails.
+    ///
+    /// The `ArcSlice` must be unique, and its buffer mutable, see
+    /// [`try_into_mut`](Self::try_into_mut); only the bytes of the slice are zeroed. The slice
+    /// is consumed, as there is no way to access the zeroed bytes afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use arc_slice::ArcSlice;
+    ///
+    /// let token = ArcSlice::<[u8]>::from(b"secret");
+    /// let token2 = token.clone();
+    /// let token = token.try_zeroize().unwrap_err();
+    /// drop(token2);
+    /// token.try_zeroize().unwrap();
+    /// ```
+    pub fn try_zeroize(self) -> Result<(), Self> {
+        self.try_into_mut::<ArcLayout<true>>()?.zeroize();
+        Ok(())
+    }
+}
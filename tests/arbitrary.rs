@@ -0,0 +1,163 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "raw-buffer")]
+use arc_slice::layout::RawLayout;
+use arc_slice::{
+    layout::{ArcLayout, BoxedSliceLayout, Layout, LayoutMut, VecLayout},
+    ArcBytes, ArcBytesMut,
+};
+
+// xorshift generator, feeding `Unstructured` with deterministic pseudo-random bytes
+fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+fn mutate<LM: LayoutMut>(
+    u: &mut Unstructured,
+    mut bytes: ArcBytesMut<LM>,
+    model: &mut Vec<u8>,
+) -> arbitrary::Result<ArcBytesMut<LM>> {
+    match u.int_in_range(0..=3)? {
+        0 => {
+            let additional = u.int_in_range(0..=256)?;
+            if bytes.try_reserve(additional).is_ok() {
+                assert!(bytes.capacity() - bytes.len() >= additional);
+            }
+        }
+        1 => {
+            let slice = <&[u8]>::arbitrary(u)?;
+            bytes.extend_from_slice(slice);
+            model.extend_from_slice(slice);
+        }
+        2 => {
+            let at = u.int_in_range(0..=bytes.len())?;
+            let mut shared = bytes.into_shared();
+            let other = shared.split_off(at);
+            shared.debug_validate();
+            other.debug_validate();
+            assert_eq!(other, model[at..]);
+            drop(other);
+            model.truncate(at);
+            bytes = shared.try_into_unique().unwrap();
+        }
+        _ => {
+            let at = u.int_in_range(0..=bytes.len())?;
+            bytes.advance(at);
+            model.drain(..at);
+        }
+    }
+    bytes.debug_validate();
+    assert_eq!(bytes, model[..]);
+    Ok(bytes)
+}
+
+fn random_operations<L: Layout, LM: LayoutMut>(u: &mut Unstructured) -> arbitrary::Result<()> {
+    let mut slices: Vec<(ArcBytes<L>, Vec<u8>)> = Vec::new();
+    while !u.is_empty() {
+        if slices.is_empty() || u.ratio(1, 8)? {
+            let bytes = ArcBytes::<L>::arbitrary(u)?;
+            let model = bytes.to_vec();
+            slices.push((bytes, model));
+        }
+        let index = u.choose_index(slices.len())?;
+        let (bytes, model) = &mut slices[index];
+        let at = u.int_in_range(0..=bytes.len())?;
+        match u.int_in_range(0..=6)? {
+            0 => {
+                let clone = (bytes.clone(), model.clone());
+                slices.push(clone);
+            }
+            1 => {
+                bytes.advance(at);
+                model.drain(..at);
+            }
+            2 => {
+                bytes.truncate(at);
+                model.truncate(at);
+            }
+            3 => {
+                let other = (bytes.split_off(at), model.split_off(at));
+                slices.push(other);
+            }
+            4 => {
+                let other = (bytes.split_to(at), model.drain(..at).collect());
+                slices.push(other);
+            }
+            5 => drop(slices.swap_remove(index)),
+            _ => {
+                let (bytes, mut model) = slices.swap_remove(index);
+                let bytes = match bytes.try_into_mut::<LM>() {
+                    Ok(mut bytes) => {
+                        bytes.debug_validate();
+                        for _ in 0..u.int_in_range(0..=4)? {
+                            bytes = mutate(u, bytes, &mut model)?;
+                        }
+                        match bytes.try_freeze() {
+                            Ok(bytes) => bytes,
+                            Err(_) => continue,
+                        }
+                    }
+                    Err(bytes) => bytes,
+                };
+                slices.push((bytes, model));
+            }
+        }
+        for (bytes, model) in &slices {
+            bytes.debug_validate();
+            assert_eq!(**bytes, model[..]);
+        }
+    }
+    Ok(())
+}
+
+fn check_random_operations<L: Layout, LM: LayoutMut>() {
+    for seed in 0..64 {
+        let data = random_bytes(seed, 4096);
+        random_operations::<L, LM>(&mut Unstructured::new(&data)).unwrap();
+        let data = random_bytes(seed, 256);
+        let mut u = Unstructured::new(&data);
+        while let Ok(bytes) = ArcBytesMut::<LM>::arbitrary(&mut u) {
+            bytes.debug_validate();
+            if u.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+// random operation sequences never break slice invariants, whatever the layout
+#[test]
+fn random_operations_across_layouts() {
+    check_random_operations::<ArcLayout<false, false>, ArcLayout<false>>();
+    check_random_operations::<ArcLayout<true, true>, ArcLayout<true>>();
+    check_random_operations::<VecLayout, VecLayout>();
+    check_random_operations::<BoxedSliceLayout, ArcLayout<true>>();
+    #[cfg(feature = "raw-buffer")]
+    check_random_operations::<RawLayout, VecLayout>();
+}
+
+// generated small slices are either inlined or backed by an arbitrary `ArcSlice`
+#[cfg(feature = "inlined")]
+#[test]
+fn arbitrary_small_arc_slice() {
+    use arc_slice::inlined::SmallArcSlice;
+
+    for seed in 0..64 {
+        let data = random_bytes(seed, 256);
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            SmallArcSlice::<[u8], ArcLayout<true, true>>::arbitrary(&mut u)
+                .unwrap()
+                .debug_validate();
+        }
+    }
+}
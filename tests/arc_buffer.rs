@@ -0,0 +1,74 @@
+#![cfg(any(not(feature = "portable-atomic"), feature = "portable-atomic-util"))]
+
+#[cfg(not(feature = "portable-atomic"))]
+use std::sync::Arc;
+use std::{
+    alloc::{GlobalAlloc, Layout as AllocLayout, System},
+    cell::Cell,
+};
+
+#[cfg(feature = "raw-buffer")]
+use arc_slice::layout::RawLayout;
+use arc_slice::{
+    layout::{AnyBufferLayout, ArcLayout},
+    ArcBytes, ArcStr,
+};
+#[cfg(feature = "portable-atomic-util")]
+use portable_atomic_util::Arc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: AllocLayout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: AllocLayout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn arc_buffer_allocations<L: AnyBufferLayout>() {
+    let arc: Arc<[u8]> = Arc::from(&b"hello world"[..]);
+    let before = allocations();
+    let bytes = ArcBytes::<L>::from_buffer(arc.clone());
+    assert_eq!(allocations(), before + 1);
+    let clone = bytes.clone();
+    let hello = bytes.subslice(..5);
+    assert_eq!(allocations(), before + 1);
+    assert_eq!(bytes.as_ptr(), arc.as_ptr());
+    assert_eq!(hello, b"hello");
+    assert_eq!(Arc::strong_count(&arc), 2);
+    drop((bytes, clone, hello));
+    assert_eq!(Arc::strong_count(&arc), 1);
+
+    let arc: Arc<str> = Arc::from("hello world");
+    let before = allocations();
+    let s = ArcStr::<L>::from_buffer(arc.clone());
+    assert_eq!(allocations(), before + 1);
+    assert_eq!(s.as_ptr(), arc.as_ptr());
+    assert_eq!(Arc::strong_count(&arc), 2);
+    drop(s);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+// `Arc<[T]>` buffers are wrapped with a single header allocation, without copying the items,
+// and their refcount is released with the last slice
+#[test]
+fn arc_buffer() {
+    arc_buffer_allocations::<ArcLayout<true>>();
+    #[cfg(feature = "raw-buffer")]
+    arc_buffer_allocations::<RawLayout>();
+}
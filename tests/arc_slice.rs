@@ -1,12 +1,22 @@
 use std::{
-    mem, ptr,
+    borrow::Cow,
+    cell::Cell,
+    mem,
+    ops::Bound,
+    panic::{self, UnwindSafe},
+    ptr,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Once,
     },
 };
 
-use arc_slice::{layout::BoxedSliceLayout, ArcBytes};
+use arc_slice::{
+    buffer::Buffer,
+    error::{CharBoundaryError, TryGetError},
+    layout::{AnyBufferLayout, ArcLayout, BoxedSliceLayout, Layout, Repr, VecLayout},
+    ArcBytes, ArcBytesMut, ArcSlice, ArcSliceBorrow, ArcStr, ArcStrMut, ErasedArcBytes,
+};
 
 // empty vec subslices doesn't trigger promotion to an arc, so it can still be downcast
 #[test]
@@ -182,3 +192,1644 @@ fn metadata() {
 //     assert_eq!(bytes.split_off(2), [2, 3]);
 //     assert_eq!(bytes, [0, 1]);
 // }
+
+// split_on yields the same subslices as slice::split, including empty ones
+#[test]
+fn split_on() {
+    let inputs: [&[u8]; 7] = [b"", b",", b"a", b"a,b", b",a,,b,", b",,", b"abc,,def"];
+    for input in inputs {
+        let bytes = ArcBytes::<BoxedSliceLayout>::from(input.to_vec());
+        let parts: Vec<_> = bytes.split_on(b",").collect();
+        let expected: Vec<_> = input.split(|&b| b == b',').collect();
+        assert_eq!(parts, expected);
+        for part in &parts {
+            let offset = part.as_ptr() as usize - bytes.as_ptr() as usize;
+            assert_eq!(bytes[offset..offset + part.len()], **part);
+        }
+    }
+}
+
+// trimming and stripping match their std counterparts, and share the buffer
+#[test]
+fn trim_and_strip() {
+    let inputs = [
+        "",
+        " ",
+        "a",
+        " \t a b \r\n",
+        "\u{3000}é\u{3000}",
+        "key=value ",
+    ];
+    for input in inputs {
+        let s = ArcStr::<ArcLayout<true>>::from(input.to_string());
+        let bytes = ArcBytes::<ArcLayout<true>>::from(input.as_bytes().to_vec());
+        let in_buffer = |part: &[u8], buffer: &[u8]| {
+            let range = buffer.as_ptr_range();
+            range.start <= part.as_ptr() && part.as_ptr_range().end <= range.end
+        };
+        for (trimmed, expected) in [
+            (s.trim_arc(), input.trim()),
+            (s.trim_start_arc(), input.trim_start()),
+            (s.trim_end_arc(), input.trim_end()),
+        ] {
+            assert_eq!(trimmed, expected);
+            assert!(in_buffer(trimmed.as_bytes(), s.as_bytes()));
+        }
+        for (trimmed, expected) in [
+            (
+                bytes.trim_ascii_arc(),
+                input.trim_matches(|c: char| c.is_ascii_whitespace()),
+            ),
+            (
+                bytes.trim_ascii_start_arc(),
+                input.trim_start_matches(|c: char| c.is_ascii_whitespace()),
+            ),
+            (
+                bytes.trim_ascii_end_arc(),
+                input.trim_end_matches(|c: char| c.is_ascii_whitespace()),
+            ),
+        ] {
+            assert_eq!(trimmed, expected.as_bytes());
+            assert!(in_buffer(&trimmed, &bytes));
+        }
+        for affix in ["", " ", "key", "value ", "é"] {
+            assert_eq!(
+                s.strip_prefix_arc(affix).as_deref(),
+                input.strip_prefix(affix)
+            );
+            assert_eq!(
+                s.strip_suffix_arc(affix).as_deref(),
+                input.strip_suffix(affix)
+            );
+        }
+    }
+}
+
+// ArcLayout borrow can be converted without dereferencing the (possibly freed) parent
+#[test]
+fn borrow_into_arc_without_parent() {
+    let parent = Box::new(ArcBytes::<ArcLayout>::from(b"hello world"));
+    let _keep_alive = parent.clone();
+    let borrow = parent.borrow(6..);
+    let borrow: ArcSliceBorrow<'static, [u8], ArcLayout> = unsafe { mem::transmute(borrow) };
+    drop(parent);
+    assert_eq!(borrow.into_arc(), b"world");
+}
+
+#[cfg(feature = "raw-buffer")]
+#[test]
+fn raw_borrow_into_arc() {
+    use arc_slice::layout::RawLayout;
+    let parent = ArcBytes::<RawLayout>::from(b"hello world".to_vec());
+    let (hello, world) = parent.borrow(..).split_at(5);
+    assert_eq!(hello.into_arc(), b"hello");
+    assert_eq!(world.into_arc(), b" world");
+}
+
+// cloning a raw buffer slice keeps a reference to the buffer, which is released once
+#[cfg(feature = "raw-buffer")]
+#[test]
+fn raw_buffer_clone() {
+    #[cfg(not(feature = "portable-atomic-util"))]
+    use std::sync::Arc as RawArc;
+
+    use arc_slice::layout::RawLayout;
+    #[cfg(feature = "portable-atomic-util")]
+    use portable_atomic_util::Arc as RawArc;
+    let buffer = RawArc::new(b"hello".to_vec());
+    let bytes = ArcBytes::<RawLayout>::from_raw_buffer(buffer.clone());
+    let clone = bytes.clone();
+    assert_eq!(RawArc::strong_count(&buffer), 3);
+    drop(bytes);
+    assert_eq!(clone, b"hello");
+    drop(clone);
+    assert_eq!(RawArc::strong_count(&buffer), 1);
+}
+
+// strings compare with bytes as their UTF-8 encoding
+#[test]
+fn str_bytes_cmp() {
+    let pairs = [
+        ("", &b""[..]),
+        ("a", b"b"),
+        ("b", b"a"),
+        ("ab", b"a"),
+        ("é", b"\xc3"),
+    ];
+    for (s, b) in pairs {
+        let arc_str: ArcStr = ArcStr::from(s);
+        let arc_bytes: ArcBytes = ArcBytes::from(b);
+        assert_eq!(arc_str == arc_bytes, s.as_bytes() == b);
+        assert_eq!(arc_bytes == arc_str, b == s.as_bytes());
+        assert_eq!(arc_str.partial_cmp(&arc_bytes), Some(s.as_bytes().cmp(b)));
+        assert_eq!(arc_bytes.partial_cmp(&arc_str), Some(b.cmp(s.as_bytes())));
+        let (eq, vec, string) = (s.as_bytes() == b, b.to_vec(), s.to_string());
+        assert_eq!((arc_str == *b, arc_str == b, arc_str == vec), (eq, eq, eq));
+        assert_eq!((*b == arc_str, vec == arc_str), (eq, eq));
+        assert_eq!(
+            (arc_bytes == *s, arc_bytes == s, arc_bytes == string),
+            (eq, eq, eq)
+        );
+        assert_eq!((*s == arc_bytes, string == arc_bytes), (eq, eq));
+    }
+    assert_eq!(<ArcStr>::from("hello"), b"hello");
+    assert_eq!(*b"hello", <ArcStr>::from("hello"));
+}
+
+#[test]
+fn cross_layout_cmp() {
+    let arc = ArcBytes::<ArcLayout<false, false>>::from(b"hello");
+    let vec = ArcBytes::<VecLayout>::from(b"hello".to_vec());
+    let boxed = ArcBytes::<BoxedSliceLayout>::from(b"help".to_vec().into_boxed_slice());
+    // slices of the same type are compared across layouts through their deref
+    assert_eq!(*arc, *vec);
+    assert_ne!(*vec, *boxed);
+    assert!(*arc < *boxed && *boxed > *vec);
+    let mut mutable = ArcBytesMut::<VecLayout>::from(b"hello".to_vec());
+    assert_eq!(mutable, arc);
+    assert_eq!(vec, mutable);
+    mutable.extend_from_slice(b"!");
+    assert!(mutable > arc);
+    assert!(arc < mutable);
+    let arc_str = ArcStr::<ArcLayout<false, false>>::from("hello");
+    assert_eq!(*arc_str, *ArcStr::<VecLayout>::from("hello"));
+    // comparisons with literals and same-layout slices keep inferring
+    assert_eq!(arc, b"hello");
+    assert!(arc == *b"hello" && arc == b"hello"[..] && arc_str == "hello");
+    assert_eq!(mutable, b"hello!");
+    let default: ArcBytes = ArcSlice::from(&b"hello"[..]);
+    assert_eq!(default, ArcSlice::from(&b"hello"[..]));
+    #[cfg(feature = "inlined")]
+    {
+        use arc_slice::inlined::SmallArcSlice;
+        let small = SmallArcSlice::<[u8], VecLayout>::from_slice(b"hello");
+        let big = SmallArcSlice::<[u8], ArcLayout<false, false>>::from(arc.clone());
+        assert_eq!(*small, *big);
+        assert_eq!(small, arc);
+        assert_eq!(vec, small);
+        assert!(small < boxed);
+        assert!(boxed > small);
+        assert_eq!(small, b"hello");
+    }
+}
+
+thread_local! {
+    static PANIC_FILE: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+#[track_caller]
+fn assert_panic(f: impl FnOnce() + UnwindSafe, expected: &str) {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            PANIC_FILE.with(|file| file.set(info.location().map(|loc| loc.file().into())));
+            default_hook(info);
+        }));
+    });
+    let payload = panic::catch_unwind(f).unwrap_err();
+    let msg = match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => payload.downcast::<&str>().unwrap().to_string(),
+    };
+    assert_eq!(msg, expected);
+    assert_eq!(PANIC_FILE.with(Cell::take).unwrap(), file!());
+}
+
+// panic messages include the offending indices, and point at the caller
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn panic_messages() {
+    let bytes = ArcBytes::<ArcLayout<true>>::from(b"hello world".to_vec());
+    assert_panic(
+        || drop(bytes.subslice(5..3)),
+        "slice index starts at 5 but ends at 3",
+    );
+    assert_panic(
+        || drop(bytes.subslice(..=20)),
+        "range end index 21 out of range for slice of length 11",
+    );
+    assert_panic(
+        || drop(bytes.subslice((Bound::Excluded(usize::MAX), Bound::Unbounded))),
+        "attempted to index slice from after maximum usize",
+    );
+    assert_panic(
+        || {
+            bytes.borrow(..=usize::MAX);
+        },
+        "attempted to index slice up to maximum usize",
+    );
+    assert_panic(
+        || drop(bytes.clone().split_off(12)),
+        "range start index 12 out of range for slice of length 11",
+    );
+    assert_panic(
+        || drop(bytes.clone().split_to(12)),
+        "range end index 12 out of range for slice of length 11",
+    );
+    assert_panic(
+        || bytes.clone().advance(12),
+        "range start index 12 out of range for slice of length 11",
+    );
+    assert_panic(
+        || drop(ArcStr::<ArcLayout>::from("été").subslice(1..)),
+        "byte index 1 is not a char boundary",
+    );
+    let mut bytes_mut = ArcBytesMut::<ArcLayout<true>>::from(vec![0; 4]).into_shared();
+    assert_panic(
+        move || drop(bytes_mut.split_to(5)),
+        "range end index 5 out of range for slice of length 4",
+    );
+    assert_panic(
+        || ArcStrMut::<ArcLayout>::from("été").advance(1),
+        "byte index 1 is not a char boundary",
+    );
+    assert_panic(
+        || ArcStrMut::<ArcLayout>::from("été").truncate(4),
+        "byte index 4 is not a char boundary",
+    );
+    let mut str_mut = ArcStrMut::<ArcLayout>::from("été").into_shared();
+    assert_panic(
+        move || drop(str_mut.split_off(1)),
+        "byte index 1 is not a char boundary",
+    );
+}
+
+// checked variants return an error instead of panicking, the out-of-range error being distinct
+// from the char boundary one
+#[test]
+fn char_boundary() {
+    let s = ArcStr::<ArcLayout>::from("a€b");
+    for (index, floor, ceil) in [
+        (0, 0, 0),
+        (1, 1, 1),
+        (2, 1, 4),
+        (3, 1, 4),
+        (5, 5, 5),
+        (9, 5, 5),
+    ] {
+        assert_eq!(s.floor_char_boundary(index), floor);
+        assert_eq!(s.ceil_char_boundary(index), ceil);
+        assert_eq!(
+            ArcStrMut::<ArcLayout>::from("a€b").floor_char_boundary(index),
+            floor
+        );
+        assert_eq!(
+            ArcStrMut::<ArcLayout>::from("a€b").ceil_char_boundary(index),
+            ceil
+        );
+    }
+    let not_boundary = Err(CharBoundaryError::NotCharBoundary {
+        index: 2,
+        floor: 1,
+        ceil: 4,
+    });
+    let out_of_range = Err(CharBoundaryError::OutOfRange { index: 6, len: 5 });
+
+    let mut s2 = s.clone();
+    assert_eq!(s2.try_split_to_checked(2), not_boundary);
+    assert_eq!(s2.try_split_to_checked(6), out_of_range);
+    assert_eq!(s2.try_advance_checked(2), not_boundary.clone().map(drop));
+    assert_eq!(s2.try_advance_checked(6), out_of_range.clone().map(drop));
+    assert_eq!(s2.try_truncate_checked(2), not_boundary.clone().map(drop));
+    assert_eq!(s2.try_truncate_checked(6), Ok(()));
+    assert_eq!(s2, "a€b");
+    assert_eq!(s2.try_split_to_checked(5).unwrap(), "a€b");
+    assert_eq!(s2, "");
+    let mut s2 = s.clone();
+    s2.try_advance_checked(1).unwrap();
+    s2.try_truncate_checked(3).unwrap();
+    assert_eq!(s2, "€");
+
+    let mut s_mut = ArcStrMut::<ArcLayout>::from("a€b").into_shared();
+    assert_eq!(
+        s_mut.try_split_to_checked(2).map(drop),
+        not_boundary.clone().map(drop)
+    );
+    assert_eq!(
+        s_mut.try_split_to_checked(6).map(drop),
+        out_of_range.clone().map(drop)
+    );
+    assert_eq!(s_mut.try_advance_checked(2), not_boundary.clone().map(drop));
+    assert_eq!(s_mut.try_advance_checked(6), out_of_range.map(drop));
+    assert_eq!(s_mut.try_truncate_checked(2), not_boundary.map(drop));
+    assert_eq!(s_mut, "a€b");
+    assert_eq!(s_mut.try_split_to_checked(1).unwrap(), "a");
+    assert_eq!(s_mut.try_advance_checked(3), Ok(()));
+    assert_eq!(s_mut.try_truncate_checked(1), Ok(()));
+    assert_eq!(s_mut, "b");
+}
+
+// cast slices keep the original bytes alive and can be mutated in place
+#[cfg(feature = "bytemuck")]
+#[test]
+fn cast_slice() {
+    let bytes = ArcBytes::<ArcLayout<true>>::from(vec![0u8; 8]);
+    let ptr = bytes.as_ptr();
+    let bytes = bytes.try_cast_slice::<[u8; 3]>().unwrap_err();
+    let cast = bytes.clone().cast_slice::<[u8; 4]>();
+    assert_eq!(cast.as_ptr().cast(), ptr);
+    assert_eq!(cast, [[0; 4]; 2]);
+    drop(bytes);
+    assert_eq!(cast, [[0; 4]; 2]);
+
+    let mut bytes_mut = ArcBytesMut::<ArcLayout>::from(vec![0u8; 6]);
+    bytes_mut.truncate(4);
+    let mut cast_mut = bytes_mut.cast_slice_mut::<[u8; 2]>();
+    cast_mut[1] = [1, 2];
+    assert_eq!(cast_mut.capacity(), 3);
+    cast_mut.push([3, 4]);
+    assert_eq!(cast_mut, [[0, 0], [1, 2], [3, 4]]);
+    assert!(cast_mut.try_reserve(1).is_err());
+}
+
+// packed headers are read at any offset, while typed views must be aligned
+#[cfg(feature = "bytemuck")]
+#[test]
+fn pod_at() {
+    use arc_slice::error::PodSliceError;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(C, packed)]
+    struct Header {
+        magic: [u8; 3],
+        version: u8,
+        len: u32,
+    }
+    unsafe impl bytemuck::Zeroable for Header {}
+    unsafe impl bytemuck::Pod for Header {}
+
+    let header = Header {
+        magic: *b"ARC",
+        version: 1,
+        len: 2,
+    };
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from(vec![0u8; 32]);
+    // odd offsets are misaligned for the header fields
+    let offset = bytes.as_ptr().align_offset(4) + 1;
+    bytes.write_pod_at(offset, &header);
+    bytes.write_pod_at(offset + 10, &[7u32, 8]);
+    let bytes = bytes.freeze::<ArcLayout<true>>();
+    assert_eq!(bytes.pod_at::<Header>(offset), Some(header));
+    assert_eq!(bytes.pod_at::<[u32; 2]>(offset + 10), Some([7, 8]));
+    assert_eq!(bytes.pod_at::<u64>(25), None);
+    assert_eq!(bytes.pod_at::<u8>(usize::MAX), None);
+
+    assert_eq!(
+        bytes.pod_slice_at::<u32>(offset + 10, 2),
+        Err(PodSliceError::Misaligned)
+    );
+    assert_eq!(
+        bytes.pod_slice_at::<u32>(offset, usize::MAX),
+        Err(PodSliceError::OutOfRange)
+    );
+    let items = bytes.pod_slice_at::<u32>(offset - 1, 4).unwrap();
+    assert_eq!(items.as_ptr().cast(), bytes[offset - 1..].as_ptr());
+    drop(bytes);
+    assert_eq!(items[0], u32::from_ne_bytes([0, b'A', b'R', b'C']));
+
+    let mut bytes = <ArcBytesMut>::from(b"abc");
+    assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| bytes.write_pod_at(1, &0u32))).is_err());
+}
+
+// advance_back removes trailing items, checking bounds and char boundaries
+#[test]
+fn advance_back() {
+    let mut bytes = <ArcBytes>::from(b"header|payload|footer");
+    bytes.advance(7);
+    bytes.advance_back(7);
+    assert_eq!(bytes, b"payload");
+    bytes.advance_back(7);
+    assert_eq!(bytes, b"");
+    assert_panic(
+        move || bytes.advance_back(1),
+        "advance back count 1 out of range for slice of length 0",
+    );
+
+    let mut s = <ArcBytesMut>::from(b"hello world");
+    s.advance_back(6);
+    assert_eq!(s, b"hello");
+    s.push(b'!');
+    assert_eq!(s, b"hello!");
+
+    let mut s = <ArcStr>::from("hello€");
+    s.advance_back(3);
+    assert_eq!(s, "hello");
+    let mut s = <ArcStr>::from("hello€");
+    assert_panic(
+        move || s.advance_back(1),
+        "byte index 7 is not a char boundary",
+    );
+}
+
+// Arc slices are used as buffers without copy, and can be recovered
+#[cfg(any(not(feature = "portable-atomic"), feature = "portable-atomic-util"))]
+#[test]
+fn arc_slice_buffer() {
+    #[cfg(not(feature = "portable-atomic"))]
+    use std::sync::Arc;
+
+    #[cfg(feature = "portable-atomic-util")]
+    use portable_atomic_util::Arc;
+
+    let arc: Arc<[u8]> = Arc::from(&b"hello world"[..]);
+    let bytes = ArcBytes::<ArcLayout<true>>::from_buffer(arc.clone());
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert_eq!(bytes.as_ptr(), arc.as_ptr());
+    let hello = bytes.subslice(..5);
+    let bytes = bytes.try_into_buffer::<Arc<[u8]>>().unwrap_err();
+    drop(hello);
+    let arc2 = bytes.try_into_buffer::<Arc<[u8]>>().unwrap();
+    assert!(Arc::ptr_eq(&arc, &arc2));
+    drop(arc2);
+    assert_eq!(Arc::strong_count(&arc), 1);
+
+    let arc: Arc<str> = Arc::from("hello world");
+    let s = ArcStr::<ArcLayout<true>>::from_buffer(arc.clone());
+    assert_eq!(s, "hello world");
+    assert_eq!(s.try_into_buffer::<Arc<str>>().unwrap(), arc);
+}
+
+// split_bytes yields the same segments as slice::split, from both ends
+#[test]
+fn split_bytes() {
+    for input in [
+        &b""[..],
+        b"\n",
+        b"a",
+        b"a\n",
+        b"\na",
+        b"a\n\nb",
+        b"\n\n",
+        b"ab\ncd\n",
+    ] {
+        let bytes = <ArcBytes>::from(input);
+        let expected: Vec<&[u8]> = input.split(|&b| b == b'\n').collect();
+        assert_eq!(bytes.split_bytes(b'\n').collect::<Vec<_>>(), expected);
+        let expected_rev: Vec<&[u8]> = input.split(|&b| b == b'\n').rev().collect();
+        assert_eq!(
+            bytes.split_bytes(b'\n').rev().collect::<Vec<_>>(),
+            expected_rev
+        );
+        let mut iter = bytes.split_bytes(b'\n');
+        let mut std_iter = input.split(|&b| b == b'\n');
+        loop {
+            let (front, back) = (iter.next(), iter.next_back());
+            assert_eq!(front.as_deref(), std_iter.next());
+            assert_eq!(back.as_deref(), std_iter.next_back());
+            if front.is_none() {
+                break;
+            }
+        }
+    }
+    let bytes = <ArcBytes>::from(b"a\nb");
+    assert!(bytes
+        .split_bytes(b'\n')
+        .all(|part| bytes.as_ptr_range().contains(&part.as_ptr())));
+}
+
+// async readers consume the slice synchronously
+#[cfg(any(feature = "futures", feature = "tokio"))]
+#[test]
+fn async_read() {
+    use futures::executor::block_on;
+
+    #[cfg(feature = "futures")]
+    {
+        use futures::{AsyncBufReadExt, AsyncReadExt};
+
+        let mut bytes = <ArcBytes>::from(b"hello\nworld");
+        let mut line = String::new();
+        block_on(bytes.read_line(&mut line)).unwrap();
+        assert_eq!(line, "hello\n");
+        let mut buf = Vec::new();
+        block_on(bytes.read_to_end(&mut buf)).unwrap();
+        assert_eq!(buf, b"world");
+        assert_eq!(bytes, b"");
+    }
+    #[cfg(feature = "tokio")]
+    {
+        use tokio::io::{AsyncBufReadExt, AsyncReadExt};
+
+        let mut bytes = <ArcBytes>::from(b"hello\nworld");
+        let mut line = String::new();
+        block_on(bytes.read_line(&mut line)).unwrap();
+        assert_eq!(line, "hello\n");
+        let mut buf = Vec::new();
+        block_on(bytes.read_to_end(&mut buf)).unwrap();
+        assert_eq!(buf, b"world");
+        assert_eq!(bytes, b"");
+    }
+}
+
+// std reader consumes the slice
+#[cfg(feature = "std")]
+#[test]
+fn read() {
+    use std::io::Read;
+
+    let mut bytes = <ArcBytes>::from(b"hello world");
+    let mut buf = [0; 5];
+    bytes.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+    let mut rest = Vec::new();
+    bytes.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b" world");
+}
+
+// lossy UTF-8 conversion reuses valid buffers, and replaces invalid or truncated sequences
+#[test]
+fn from_utf8_lossy() {
+    use arc_slice::{ArcBytesMut, ArcStr, ArcStrMut};
+
+    let bytes = <ArcBytes>::from(b"caf\xC3\xA9");
+    let ptr = bytes.as_ptr();
+    let s = ArcStr::from_utf8_lossy(bytes);
+    assert_eq!(s, "café");
+    assert_eq!(s.as_ptr(), ptr);
+    let s = ArcStr::from_utf8_lossy(<ArcBytes>::from(b"a\xFFb\xFEc"));
+    assert_eq!(s, "a\u{FFFD}b\u{FFFD}c");
+    let s = ArcStr::from_utf8_lossy(<ArcBytes>::from(b"caf\xC3"));
+    assert_eq!(s, "caf\u{FFFD}");
+
+    let bytes = <ArcBytesMut>::from(b"caf\xC3\xA9");
+    let ptr = bytes.as_ptr();
+    let s = ArcStrMut::from_utf8_lossy_owned(bytes);
+    assert_eq!(s, "café");
+    assert_eq!(s.as_ptr(), ptr);
+    let s = ArcStrMut::from_utf8_lossy_owned(<ArcBytesMut>::from(b"a\xFFb"));
+    assert_eq!(s, "a\u{FFFD}b");
+    let s = ArcStrMut::from_utf8_lossy_owned(<ArcBytesMut>::from(b"caf\xE2\x82"));
+    assert_eq!(s, "caf\u{FFFD}");
+    let invalid = b"\xE2\x82a\xF0\x90\x80\xFF\xC3";
+    let s = ArcStrMut::from_utf8_lossy_owned(<ArcBytesMut>::from(invalid));
+    assert_eq!(s, *String::from_utf8_lossy(invalid));
+
+    let (error, bytes) = ArcStr::try_from_utf8(<ArcBytes>::from(b"caf\xC3")).unwrap_err();
+    assert_eq!((error.valid_up_to(), error.error_len()), (3, None));
+    assert_eq!(bytes, b"caf\xC3");
+}
+
+// consuming split reuses the slice reference, and checks char boundaries
+#[test]
+fn split_into() {
+    use arc_slice::{layout::ArcLayout, ArcBytesMut, ArcStr};
+
+    let bytes = <ArcBytes>::from_slice(b"hello world");
+    // slice `split_at` still borrows
+    let (hello, world) = bytes.split_at(5);
+    assert_eq!(
+        (hello, world, bytes.len()),
+        (&b"hello"[..], &b" world"[..], 11)
+    );
+    let (hello, world) = bytes.split_into(5);
+    assert_eq!((&hello[..], &world[..]), (&b"hello"[..], &b" world"[..]));
+
+    let bytes = ArcBytes::<ArcLayout<false, true>>::from_slice(b"hello");
+    let (empty, hello) = bytes.split_into(0);
+    assert_eq!((&empty[..], &hello[..]), (&b""[..], &b"hello"[..]));
+
+    let s = <ArcStr>::from("café");
+    assert!(std::panic::catch_unwind(|| s.clone().split_into(4)).is_err());
+    let (caf, e) = s.split_into(3);
+    assert_eq!((&caf[..], &e[..]), ("caf", "é"));
+
+    let mut bytes = <ArcBytesMut>::with_capacity(16);
+    bytes.extend_from_slice(b"hello world");
+    let (hello, world) = bytes.into_shared().split_into(5);
+    assert_eq!((&hello[..], &world[..]), (&b"hello"[..], &b" world"[..]));
+    assert!(world.capacity() >= 11);
+}
+
+// the refcount overflow behavior of a layout doesn't prevent converting to another one
+#[test]
+fn saturate_layout() {
+    use arc_slice::layout::ArcLayout;
+
+    let bytes = ArcBytes::<ArcLayout<false, false, true>>::from_slice(b"hello");
+    let clone = bytes.clone();
+    let aborting = clone.with_layout::<ArcLayout<false, false, false>>();
+    assert_eq!(aborting.subslice(1..), b"ello");
+    drop(bytes);
+    assert!(aborting.is_unique());
+}
+
+// `Option<ArcSlice>` is free for every layout, even with `inlined` feature
+#[test]
+fn niche() {
+    fn assert_niche<L: Layout>() {
+        assert_eq!(
+            mem::size_of::<Option<ArcBytes<L>>>(),
+            mem::size_of::<ArcBytes<L>>()
+        );
+        assert_eq!(
+            mem::size_of::<Option<ArcStr<L>>>(),
+            mem::size_of::<ArcStr<L>>()
+        );
+    }
+    assert_niche::<ArcLayout<false, false>>();
+    assert_niche::<ArcLayout<true, true>>();
+    assert_niche::<BoxedSliceLayout>();
+    assert_niche::<VecLayout>();
+    #[cfg(feature = "inlined")]
+    {
+        use arc_slice::inlined::SmallArcSlice;
+        type Small = SmallArcSlice<[u8], ArcLayout<false, false>>;
+        assert_eq!(
+            mem::size_of::<Option<Small>>(),
+            mem::size_of::<Small>() + mem::size_of::<usize>()
+        );
+    }
+}
+
+// inlining a small slice releases the backing buffer
+#[cfg(feature = "inlined")]
+#[test]
+fn inline() {
+    use arc_slice::inlined::SmallArcSlice;
+
+    struct DropTracked(Vec<u8>, Arc<AtomicBool>);
+    impl Buffer<[u8]> for DropTracked {
+        fn as_slice(&self) -> &[u8] {
+            &self.0
+        }
+    }
+    impl Drop for DropTracked {
+        fn drop(&mut self) {
+            self.1.store(true, Ordering::Relaxed);
+        }
+    }
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let buffer = DropTracked(b"hello world".repeat(100), dropped.clone());
+    let bytes = ArcBytes::<ArcLayout<true>>::from_buffer(buffer);
+    let mut small = SmallArcSlice::from(bytes.subslice(..5));
+    let mut inlined = SmallArcSlice::from_arc_inline(bytes.subslice(6..11));
+    let mut big = SmallArcSlice::from(bytes.clone());
+    drop(bytes);
+    assert!(!small.is_inlined() && inlined.is_inlined() && !big.is_inlined());
+    assert!(small.inline());
+    assert!(inlined.inline());
+    assert!(!big.inline());
+    assert!(!dropped.load(Ordering::Relaxed));
+    drop(big);
+    assert!(dropped.load(Ordering::Relaxed));
+    assert_eq!((&small[..], &inlined[..]), (&b"hello"[..], &b"world"[..]));
+}
+
+// buffer and metadata are recovered together, only if unique and matching types
+#[test]
+fn into_buffer_with_metadata() {
+    let path = "/tmp/data".to_string();
+    let bytes = ArcBytes::<ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], path);
+    let clone = bytes.clone();
+    let bytes = bytes
+        .try_into_buffer_with_metadata::<Vec<u8>, String>()
+        .unwrap_err();
+    drop(clone);
+    let bytes = bytes
+        .try_into_buffer_with_metadata::<Vec<u8>, ()>()
+        .unwrap_err();
+    let bytes = bytes
+        .try_into_buffer_with_metadata::<Box<[u8]>, String>()
+        .unwrap_err();
+    let (vec, path) = bytes
+        .try_into_buffer_with_metadata::<Vec<u8>, String>()
+        .unwrap();
+    assert_eq!(vec, [0, 1, 2]);
+    assert_eq!(path, "/tmp/data");
+
+    let bytes = ArcBytes::<ArcLayout<true>>::from(vec![0, 1, 2]);
+    assert!(bytes
+        .try_into_buffer_with_metadata::<Vec<u8>, String>()
+        .is_err());
+}
+
+// io helpers round trip through a duplex stream, and don't retain the written slices
+#[cfg(feature = "tokio")]
+#[test]
+fn io_round_trip() {
+    use arc_slice::io::{write_all_arc, write_all_arc_vectored, ArcBytesCursorRead};
+    use futures::executor::block_on;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+
+    let mut bytes = ArcBytes::<ArcLayout<true>>::from(b"hello world".to_vec());
+    let world = bytes.split_off(5);
+    let segments = [bytes, ArcBytes::new(), world];
+    let (mut writer, mut reader) = tokio::io::duplex(4);
+    let write = async {
+        write_all_arc_vectored(&mut writer, &segments)
+            .await
+            .unwrap();
+        write_all_arc(&mut writer, &segments[0]).await.unwrap();
+        writer.shutdown().await.unwrap();
+    };
+    let mut buf = Vec::new();
+    block_on(async { futures::join!(write, reader.read_to_end(&mut buf)).1 }).unwrap();
+    assert_eq!(buf, b"hello worldhello");
+    let mut vectored = Vec::new();
+    block_on(write_all_arc_vectored(&mut vectored, &segments)).unwrap();
+    assert_eq!(vectored, b"hello world");
+    let [hello, _, world] = segments;
+    drop(world);
+    assert!(hello.is_unique());
+
+    let mut reader = ArcBytesCursorRead::new(ArcBytes::<ArcLayout<true>>::from(b"a\nb".to_vec()));
+    let mut line = String::new();
+    block_on(reader.read_line(&mut line)).unwrap();
+    assert_eq!(line, "a\n");
+    assert_eq!(reader.remaining(), b"b");
+    reader.set_position(0);
+    let mut buf = Vec::new();
+    block_on(reader.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, b"a\nb");
+    assert_eq!(reader.position(), 3);
+    let bytes = reader.into_inner();
+    assert_eq!(bytes.try_into_buffer::<Vec<u8>>().unwrap(), b"a\nb");
+}
+
+// cursors read and seek without consuming the slice
+#[cfg(feature = "std")]
+#[test]
+fn cursor() {
+    use std::io::{BufRead, Read, Seek, SeekFrom};
+
+    let mut cursor = ArcBytes::<ArcLayout<true>>::from(b"hello\nworld".to_vec()).into_cursor();
+    let mut line = String::new();
+    cursor.read_line(&mut line).unwrap();
+    assert_eq!(line, "hello\n");
+    assert_eq!(cursor.seek(SeekFrom::End(-2)).unwrap(), 9);
+    let mut buf = String::new();
+    cursor.read_to_string(&mut buf).unwrap();
+    assert_eq!(buf, "ld");
+    assert!(cursor.seek(SeekFrom::Current(-12)).is_err());
+    assert_eq!(cursor.stream_position().unwrap(), 11);
+    assert_eq!(cursor.seek(SeekFrom::Start(42)).unwrap(), 42);
+    assert_eq!(cursor.read(&mut [0; 4]).unwrap(), 0);
+    cursor.rewind().unwrap();
+    let mut buf = Vec::new();
+    cursor.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"hello\nworld");
+    let bytes = cursor.into_inner();
+    assert_eq!(bytes.try_into_buffer::<Vec<u8>>().unwrap(), b"hello\nworld");
+
+    let mut cursor = <ArcStr>::from("héllo").into_cursor();
+    cursor.seek(SeekFrom::Start(1)).unwrap();
+    assert_eq!(
+        cursor.remaining(),
+        "é".as_bytes()
+            .iter()
+            .chain(b"llo")
+            .copied()
+            .collect::<Vec<_>>()
+    );
+}
+
+// utf8 prefixes stop before invalid or truncated sequences, and can be empty
+#[test]
+fn split_utf8_prefix() {
+    let mut bytes = <ArcBytes>::from(b"\xe2\x82\xac10\r\n\xe2\x82");
+    let price = bytes.split_utf8_prefix(Some(b'\r'));
+    assert_eq!(price, "€10");
+    assert_eq!(bytes, b"\r\n\xe2\x82");
+    assert_eq!(bytes.split_utf8_prefix(Some(b'\r')), "");
+    assert_eq!(bytes.split_utf8_prefix(None), "\r\n");
+    assert_eq!(bytes, b"\xe2\x82");
+    assert_eq!(bytes.split_utf8_prefix(None), "");
+    assert_eq!(bytes, b"\xe2\x82");
+
+    let mut bytes = <ArcBytes>::from(b"\xc3\xa9\xff");
+    assert!(bytes.try_split_str_to(1).is_err());
+    let err = bytes.try_split_str_to(3).unwrap_err();
+    assert_eq!(err.valid_up_to(), 2);
+    assert_eq!(bytes.try_split_str_to(0).unwrap(), "");
+    assert_eq!(bytes.try_split_str_to(2).unwrap(), "é");
+    assert_eq!(bytes, b"\xff");
+    assert_eq!(bytes.utf8_prefix_len(None), 0);
+}
+
+// integer readers consume the front of the slice, and leave it untouched on error
+#[test]
+fn get_int() {
+    let mut bytes = <ArcBytes>::from(b"\x01\x02\x03\x04\x05\x06\x07\xff\xff\x0a\x0b\x0c\xfe\xff");
+    assert_eq!(bytes.get_u8(), 1);
+    assert_eq!(bytes.get_u16(), 0x0203);
+    assert_eq!(bytes.get_u32_le(), 0x0706_0504);
+    assert_eq!(bytes.get_i16(), -1);
+    assert_eq!(bytes.get_uint(3), 0x0a0b0c);
+    assert_eq!(
+        bytes.try_get_u32(),
+        Err(TryGetError {
+            requested: 4,
+            available: 2
+        })
+    );
+    assert_eq!(bytes.try_get_int_le(3).unwrap_err().requested, 3);
+    assert_eq!(bytes.get_uint(0), 0);
+    assert_eq!(bytes.get_int(0), 0);
+    assert_eq!(bytes.get_int_le(2), -2);
+    assert!(bytes.is_empty());
+    assert!(panic::catch_unwind(move || bytes.get_u8()).is_err());
+}
+
+// owned iterators are double-ended with exact size, and release the slice when dropped
+#[test]
+fn into_iter() {
+    let item = Arc::new(0);
+    let slice = ArcSlice::<[Arc<usize>]>::from_array([(); 4].map(|_| item.clone()));
+    assert_eq!(Arc::strong_count(&item), 5);
+    let mut iter = slice.iter_cloned();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    let first = iter.next().unwrap();
+    let last = iter.next_back().unwrap();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(Arc::strong_count(&item), 7);
+    drop(slice);
+    assert_eq!(Arc::strong_count(&item), 7);
+    drop(iter);
+    assert_eq!(Arc::strong_count(&item), 3);
+    drop((first, last));
+
+    let bytes = <ArcBytes>::from(b"abcd");
+    let mut iter = bytes.clone().into_iter();
+    assert_eq!(iter.next_back(), Some(b'd'));
+    assert_eq!(iter.as_slice(), b"abc");
+    assert_eq!(iter.collect::<Vec<_>>(), b"abc");
+    let mut iter = bytes.into_iter_arc();
+    assert_eq!(iter.next_back().unwrap(), b"d");
+    assert_eq!(iter.next().unwrap(), b"a");
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [b"c", b"b"]);
+}
+
+// unique slices move their items out, shared ones clone them, and no item leaks either way
+#[test]
+fn into_iter_moving() {
+    fn check<L: Layout>(mut slice: ArcSlice<[Arc<usize>], L>, truncate: bool, item: &Arc<usize>) {
+        slice.advance(1);
+        if truncate {
+            slice.truncate(3);
+        }
+        let len = slice.len();
+        let mut iter = slice.into_iter();
+        assert!(iter.is_moving());
+        let count = Arc::strong_count(item);
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        assert_eq!(iter.len(), len - 2);
+        assert_eq!(Arc::strong_count(item), count);
+        drop((first, last));
+        drop(iter);
+        assert_eq!(Arc::strong_count(item), 1);
+
+        let shared = ArcSlice::<[Arc<usize>], L>::from_array([item.clone(), item.clone()]);
+        let _clone = shared.clone();
+        let iter = shared.into_iter();
+        assert!(!iter.is_moving());
+        assert_eq!(iter.map(|_| Arc::strong_count(item)).max(), Some(4));
+    }
+    let item = Arc::new(0);
+    let items = || [(); 6].map(|_| item.clone());
+    check(
+        ArcSlice::<_, ArcLayout<false>>::from_array(items()),
+        false,
+        &item,
+    );
+    // items truncated from an arc slice are dropped when it becomes mutable
+    check(
+        ArcSlice::<_, ArcLayout<false>>::from_array(items()),
+        true,
+        &item,
+    );
+    // so are items truncated from a vector
+    for truncate in [false, true] {
+        check(
+            ArcSlice::<_, ArcLayout<true>>::from(Vec::from(items())),
+            truncate,
+            &item,
+        );
+        check(
+            ArcSlice::<_, VecLayout>::from(Vec::from(items())),
+            truncate,
+            &item,
+        );
+        check(
+            ArcSlice::<_, BoxedSliceLayout>::from(Vec::from(items())),
+            truncate,
+            &item,
+        );
+    }
+    assert_eq!(Arc::strong_count(&item), 1);
+}
+
+// bytes of different layouts are stored together once erased, and can be converted back
+#[test]
+fn erased() {
+    let mut erased: Vec<ErasedArcBytes> = vec![
+        ArcBytes::<ArcLayout<false>>::from(b"hello").into(),
+        ArcBytes::<VecLayout>::from_buffer_with_metadata(b" ".to_vec(), 42).into(),
+        ArcBytes::<BoxedSliceLayout>::from(b"world".to_vec()).into(),
+    ];
+    assert_eq!(erased.concat(), b"hello world");
+    assert_eq!(erased[1].metadata::<i32>(), Some(&42));
+    let world = erased.pop().unwrap();
+    let mut parts = world.clone();
+    let wo = parts.split_to(2);
+    assert_eq!((&wo[..], &parts[..]), (&b"wo"[..], &b"rld"[..]));
+    assert_eq!(world.subslice(1..4), b"orl");
+    let world = world.try_into_layout::<ArcLayout<false>>().unwrap_err();
+    assert_eq!(
+        world.try_into_layout::<BoxedSliceLayout>().unwrap(),
+        b"world"
+    );
+    let space = erased.pop().unwrap();
+    let space = space.try_into_layout::<VecLayout>().unwrap();
+    assert_eq!(space.metadata::<i32>(), Some(&42));
+    let hello = erased.pop().unwrap();
+    assert_eq!(
+        hello.try_into_layout::<ArcLayout<false>>().unwrap(),
+        b"hello"
+    );
+}
+
+// copying into a large enough unique target reuses its buffer
+#[test]
+fn copy_into_mut() {
+    let mut scratch = <ArcBytesMut>::with_capacity(8);
+    let ptr = scratch.as_ptr();
+    for bytes in [&b"hello"[..], b"", b"world!!!"] {
+        <ArcBytes>::from(bytes).copy_into_mut(&mut scratch);
+        assert_eq!(scratch, bytes);
+        assert_eq!(scratch.as_ptr(), ptr);
+    }
+    <ArcBytes>::from(b"hello world").copy_into_mut(&mut scratch);
+    assert_eq!(scratch, b"hello world");
+
+    let mut shared = scratch.into_shared();
+    let _other = shared.split_off(16);
+    let bytes = <ArcBytes>::from(b"hello world!!!!!!");
+    assert!(bytes.try_copy_into_mut(&mut shared).is_err());
+    assert!(shared.is_empty());
+    bytes.subslice(..5).try_copy_into_mut(&mut shared).unwrap();
+    assert_eq!(shared, b"hello");
+
+    // `ToOwned::clone_into` still resolves
+    let mut target = bytes.subslice(..5);
+    bytes.clone_into(&mut target);
+    assert_eq!(target, bytes);
+}
+
+// each representation is classified according to how the slice was built
+#[test]
+fn repr() {
+    let static_bytes = ArcBytes::<ArcLayout<false, true>>::from_static(b"hello");
+    assert_eq!(static_bytes.repr(), Repr::Static);
+    assert!(static_bytes.is_static());
+    assert_eq!(
+        ArcBytes::<ArcLayout<false, false>>::from(b"").repr(),
+        Repr::Static
+    );
+    assert_eq!(ArcBytes::<VecLayout>::new().repr(), Repr::Static);
+
+    let arc_slice = ArcBytes::<ArcLayout<false>>::from(b"hello");
+    assert_eq!(arc_slice.repr(), Repr::ArcSlice);
+    assert!(!arc_slice.is_static());
+    assert_eq!(arc_slice.subslice(..0).repr(), Repr::Static);
+    let arc_buffer = ArcBytes::<ArcLayout<true>>::from(b"hello".to_vec());
+    assert_eq!(arc_buffer.repr(), Repr::ArcBuffer);
+    assert_eq!(
+        ArcBytes::<ArcLayout<true>>::from_buffer(b"hello".to_vec().into_boxed_slice()).repr(),
+        Repr::ArcBuffer
+    );
+
+    let vec = ArcBytes::<VecLayout>::from(b"hello".to_vec());
+    assert_eq!(vec.repr(), Repr::Vec);
+    // cloning promotes the vector to an arc
+    assert_eq!(vec.clone().repr(), Repr::ArcBuffer);
+    assert_eq!(vec.repr(), Repr::ArcBuffer);
+    let boxed_slice = ArcBytes::<BoxedSliceLayout>::from(b"hello".to_vec());
+    assert_eq!(boxed_slice.repr(), Repr::BoxedSlice);
+    assert_eq!(
+        arc_slice.with_layout::<BoxedSliceLayout>().repr(),
+        Repr::ArcSlice
+    );
+
+    assert_eq!(<ArcBytesMut>::new().repr(), Repr::Static);
+    assert_eq!(<ArcBytesMut>::from(b"hello").repr(), Repr::ArcSlice);
+    let vec_mut = ArcBytesMut::<VecLayout>::from(b"hello".to_vec());
+    assert_eq!(vec_mut.repr(), Repr::Vec);
+    assert_eq!(vec_mut.freeze::<VecLayout>().repr(), Repr::Vec);
+    assert_eq!(
+        ArcBytesMut::<ArcLayout<true>>::from(b"hello".to_vec()).repr(),
+        Repr::ArcBuffer
+    );
+
+    #[cfg(feature = "raw-buffer")]
+    {
+        #[cfg(not(feature = "portable-atomic-util"))]
+        use std::sync::Arc as RawArc;
+
+        use arc_slice::layout::RawLayout;
+        #[cfg(feature = "portable-atomic-util")]
+        use portable_atomic_util::Arc as RawArc;
+        let raw = ArcBytes::<RawLayout>::from_raw_buffer(RawArc::new(b"hello".to_vec()));
+        assert_eq!(raw.repr(), Repr::Raw);
+        assert_eq!(raw.clone().repr(), Repr::Raw);
+        assert_eq!(ArcBytes::<RawLayout>::new().repr(), Repr::Static);
+        assert_eq!(ArcBytes::<RawLayout>::from(b"hello").repr(), Repr::ArcSlice);
+        assert_eq!(
+            ArcBytes::<RawLayout>::from(b"hello".to_vec()).repr(),
+            Repr::ArcBuffer
+        );
+    }
+
+    #[cfg(feature = "inlined")]
+    {
+        use arc_slice::inlined::SmallArcSlice;
+        assert_eq!(
+            SmallArcSlice::<[u8]>::from_slice(b"hello").repr(),
+            Repr::Inlined
+        );
+        assert_eq!(
+            SmallArcSlice::<[u8]>::from_array([0; 256]).repr(),
+            Repr::ArcSlice
+        );
+    }
+}
+
+// the owner is borrowed once stored, and dropped with the last slice referencing it
+#[test]
+fn from_owner() {
+    struct Owner<'a>([u8; 11], &'a AtomicBool);
+    impl Drop for Owner<'_> {
+        fn drop(&mut self) {
+            self.1.store(true, Ordering::Relaxed);
+        }
+    }
+    static DROPPED: AtomicBool = AtomicBool::new(false);
+    let bytes =
+        ArcBytes::<ArcLayout<true>>::from_owner(Owner(*b"hello world", &DROPPED), |o| &o.0[6..]);
+    assert_eq!(bytes, b"world");
+    let owner = bytes.metadata::<Owner>().unwrap();
+    assert_eq!(bytes.as_ptr(), owner.0[6..].as_ptr());
+    let subslice = bytes.subslice(1..3);
+    drop(bytes);
+    assert!(!DROPPED.load(Ordering::Relaxed));
+    assert_eq!(subslice, b"or");
+    drop(subslice);
+    assert!(DROPPED.load(Ordering::Relaxed));
+
+    let bytes = ArcBytes::<VecLayout>::from_owner(b"hello".to_vec(), |v| &v[1..]);
+    assert_eq!(bytes.clone(), b"ello");
+    assert!(bytes.try_into_buffer::<Vec<u8>>().is_err());
+    let s = ArcStr::<ArcLayout<true>>::from_owner(String::from("hello"), |s| &s[..4]);
+    assert_eq!(s, "hell");
+}
+
+// shared or immutable buffers are copied, unique mutable ones are reused
+#[test]
+fn into_mut_or_clone() {
+    let bytes = ArcBytes::<ArcLayout<true, true>>::from_static(b"hello");
+    let ptr = bytes.as_ptr();
+    let bytes_mut = bytes.into_mut_or_clone::<ArcLayout>();
+    assert_eq!(bytes_mut, b"hello");
+    assert_ne!(bytes_mut.as_ptr(), ptr);
+
+    let bytes = ArcBytes::<VecLayout>::from(b"hello".to_vec());
+    let clone = bytes.clone();
+    let ptr = bytes.as_ptr();
+    let mut bytes_mut = bytes.into_mut_or_clone::<VecLayout>();
+    assert_ne!(bytes_mut.as_ptr(), ptr);
+    bytes_mut.extend_from_slice(b" world");
+    assert_eq!(bytes_mut, b"hello world");
+    let clone_mut = clone.try_into_mut_or_clone::<VecLayout>().unwrap();
+    assert_eq!(clone_mut, b"hello");
+    assert_eq!(clone_mut.as_ptr(), ptr);
+}
+
+// items cut off the end of a unique buffer are dropped when it becomes mutable
+#[test]
+fn try_into_mut_drop_tail() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    #[derive(Debug)]
+    struct Droppable(u8);
+    impl Drop for Droppable {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    fn check<L: AnyBufferLayout>() {
+        DROPS.store(0, Ordering::Relaxed);
+        let slice =
+            ArcSlice::<[Droppable], L>::from(vec![Droppable(0), Droppable(1), Droppable(2)]);
+        let subslice = slice.subslice(1..2);
+        drop(slice);
+        let slice_mut = subslice.try_into_mut::<VecLayout>().unwrap();
+        assert_eq!(DROPS.load(Ordering::Relaxed), 1);
+        assert_eq!(slice_mut[0].0, 1);
+        drop(slice_mut);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 3);
+    }
+    check::<ArcLayout<true>>();
+    check::<VecLayout>();
+}
+
+// borrowed cows are stored as static slices, owned ones as vectors, and converted back the same
+#[test]
+fn cow() {
+    type StaticStr = ArcStr<ArcLayout<true, true>>;
+    let s = StaticStr::from(Cow::Borrowed("hello"));
+    assert!(s.is_static());
+    assert!(matches!(s.into_cow(), Cow::Borrowed("hello")));
+
+    let string = String::from("hello");
+    let ptr = string.as_ptr();
+    let s = StaticStr::from(Cow::Owned(string));
+    assert_eq!(s.repr(), Repr::ArcBuffer);
+    match s.clone().into_cow() {
+        Cow::Owned(string) => assert_ne!(string.as_ptr(), ptr),
+        Cow::Borrowed(_) => panic!("expected owned"),
+    }
+    match s.into_cow() {
+        Cow::Owned(string) => assert_eq!(string.as_ptr(), ptr),
+        Cow::Borrowed(_) => panic!("expected owned"),
+    }
+
+    let bytes = ArcBytes::<VecLayout>::from(Cow::Borrowed(&b"hello"[..]));
+    assert_eq!(bytes.repr(), Repr::Static);
+    let bytes = ArcBytes::<VecLayout>::from(Cow::<[u8]>::Owned(b"hello".to_vec()));
+    assert_eq!(bytes.repr(), Repr::Vec);
+    // slices of non-static layouts are never borrowed
+    let bytes = ArcBytes::<ArcLayout<true, false>>::from(Cow::Borrowed(&b"hello"[..]));
+    assert_eq!(bytes, b"hello");
+    assert!(!bytes.is_static());
+    assert!(matches!(bytes.into_cow(), Cow::Owned(v) if v == b"hello"));
+
+    #[cfg(feature = "inlined")]
+    {
+        use arc_slice::inlined::SmallArcSlice;
+        let s = SmallArcSlice::<str>::from(Cow::Owned(String::from("hello")));
+        assert_eq!(s.repr(), Repr::Inlined);
+        let s = SmallArcSlice::<[u8], ArcLayout<true, true>>::from(Cow::Borrowed(&[0; 256][..]));
+        assert_eq!(s.repr(), Repr::Static);
+    }
+}
+
+// borrowed chunks are yielded from both ends, the last one being shorter
+#[test]
+fn borrow_chunks() {
+    let bytes = <ArcBytes>::from(b"hello world");
+    let chunks = bytes.borrow_chunks(3);
+    assert_eq!(chunks.len(), 4);
+    let chunks: Vec<_> = chunks.map(|chunk| chunk.to_vec()).collect();
+    assert_eq!(chunks, [&b"hel"[..], b"lo ", b"wor", b"ld"]);
+    let mut chunks = bytes.borrow_chunks(3);
+    assert_eq!(&chunks.next_back().unwrap()[..], b"ld");
+    assert_eq!(&chunks.next().unwrap()[..], b"hel");
+    assert_eq!(&chunks.next_back().unwrap()[..], b"wor");
+    assert_eq!(chunks.len(), 1);
+    let last = chunks.next().unwrap().clone_arc();
+    assert!(chunks.next().is_none() && chunks.next_back().is_none());
+    drop(bytes);
+    assert_eq!(last, b"lo ");
+    assert_eq!(<ArcBytes>::from(b"").borrow_chunks(3).count(), 0);
+    assert_eq!(<ArcBytes>::from(b"hello").borrow_chunks(5).count(), 1);
+    let hello = <ArcBytes>::from(b"hello");
+    let mut chunks = hello.borrow_chunks(usize::MAX);
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(&chunks.next().unwrap()[..], b"hello");
+    assert_eq!(chunks.len(), 0);
+}
+
+// local slices items are dropped once, when the last clone is dropped, whatever the layout
+#[test]
+fn local_arc_slice() {
+    use std::rc::Rc;
+
+    use arc_slice::local::LocalArcSlice;
+
+    fn check<L: arc_slice::layout::AnyBufferLayout>() {
+        let item = Rc::new(0);
+        let s = LocalArcSlice::<_, L>::try_from_vec(vec![item.clone(), item.clone()]).unwrap();
+        let s2 = s.try_subslice(1..).unwrap();
+        drop(s);
+        assert_eq!(Rc::strong_count(&item), 3);
+        assert_eq!(*s2[0], 0);
+        drop(s2);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+    check::<ArcLayout<true, false>>();
+    check::<ArcLayout<true, true>>();
+    check::<BoxedSliceLayout>();
+    check::<VecLayout>();
+    let cells = LocalArcSlice::<_, VecLayout>::try_from_vec(vec![Cell::new(0)]).unwrap();
+    cells.try_clone().unwrap()[0].set(1);
+    assert_eq!(cells.try_into_vec().unwrap(), [Cell::new(1)]);
+}
+
+// only unique slices spanning a whole `Arc<[T]>` buffer are converted without copy
+#[cfg(not(feature = "portable-atomic"))]
+#[test]
+fn into_std_arc() {
+    let arc: Arc<[u8]> = Arc::new(*b"hello world");
+    let mut bytes = ArcBytes::<ArcLayout<true>>::from_buffer(arc.clone());
+    let hello = bytes.split_to(6);
+    let copy = hello.into_std_arc();
+    assert_eq!(*copy, *b"hello ");
+    assert!(!Arc::ptr_eq(&copy, &arc));
+    let clone = bytes.clone();
+    assert_eq!(bytes.try_into_std_arc().unwrap_err(), b"world");
+    drop(clone);
+    assert!(ArcBytes::<VecLayout>::from(arc.to_vec())
+        .try_into_std_arc()
+        .is_err());
+    let bytes = ArcBytes::<ArcLayout<true>>::from_buffer(arc.clone());
+    let clone = bytes.clone();
+    let bytes = bytes.try_into_std_arc().unwrap_err();
+    drop(clone);
+    assert!(Arc::ptr_eq(&bytes.into_std_arc(), &arc));
+}
+
+// constant-time comparison gives the same results as regular equality
+#[test]
+fn ct_eq() {
+    use std::hint::black_box;
+
+    let token = <ArcBytes>::from(b"secret token");
+    assert!(black_box(&token).ct_eq(black_box(b"secret token")));
+    for i in 0..token.len() {
+        let mut other = token.to_vec();
+        other[i] ^= 1;
+        assert!(!black_box(&token).ct_eq(black_box(&other)));
+    }
+    assert!(!token.ct_eq(b"secret"));
+    assert!(!token.ct_eq(b"secret token!"));
+    assert!(<ArcBytes>::from(b"").ct_eq(b""));
+}
+
+// arrays are kept inline as buffers, and can be recovered, including after mutation
+#[test]
+fn array_buffer() {
+    let bytes = ArcBytes::<ArcLayout<true>>::from_buffer(*b"hello");
+    assert_eq!(bytes, b"hello");
+    let bytes = bytes.try_into_buffer::<[u8; 4]>().unwrap_err();
+    assert_eq!(bytes.try_into_buffer::<[u8; 5]>().unwrap(), *b"hello");
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer([0; 4]);
+    assert_eq!(bytes.capacity(), 4);
+    bytes.copy_from_slice(b"abcd");
+    assert!(bytes.try_reserve(1).is_err());
+    let bytes = bytes.freeze::<ArcLayout<true>>();
+    assert_eq!(bytes.try_into_buffer::<[u8; 4]>().unwrap(), *b"abcd");
+}
+
+// raw pointer pairs give the same subslices as references, including empty ones at the end
+#[test]
+fn subslice_from_raw() {
+    let s = <ArcStr>::from("hello world");
+    let range = s[..5].as_bytes().as_ptr_range();
+    assert_eq!(
+        unsafe { s.subslice_from_raw(range.start, range.end) },
+        "hello"
+    );
+    let end = s.as_bytes().as_ptr_range().end;
+    let empty = unsafe { s.subslice_from_raw(end, end) };
+    assert!(empty.is_empty());
+    assert_eq!(empty.as_ptr(), end);
+}
+
+// joining puts the separator only between parts, and doesn't allocate for an empty result
+#[test]
+fn join() {
+    let parts = [<ArcStr>::from("a"), "bc".into(), "".into(), "d".into()];
+    assert_eq!(<ArcStr>::join(&parts, ", "), "a, bc, , d");
+    assert_eq!(<ArcStr>::join(&parts[..1], ", "), "a");
+    assert_eq!(<ArcStr>::try_join(&parts[..2], "").unwrap(), "abc");
+    let empty = ArcStr::<ArcLayout<true, true>>::join(&[] as &[&str], ", ");
+    assert!(empty.is_empty());
+    assert!(empty.is_static());
+    let empty = ArcStr::<ArcLayout<true, true>>::join(&["", ""], "");
+    assert!(empty.is_static());
+    assert_eq!(
+        ArcBytes::<VecLayout>::join(&[&[1, 2][..], &[3]], &[0]),
+        [1, 2, 0, 3]
+    );
+}
+
+// each element of a metadata chain can be retrieved, on both shared and mutable slices
+#[test]
+fn metadata_chain() {
+    #[derive(Debug, PartialEq)]
+    struct Generation(u64);
+    #[derive(Debug, PartialEq)]
+    struct Checksum(u32);
+    let chain = || ("path".to_string(), Generation(1), Checksum(42));
+
+    let bytes = ArcBytes::<VecLayout>::from_buffer_with_metadata_chain(vec![0, 1], chain());
+    assert_eq!(bytes.metadata::<String>().unwrap(), "path");
+    assert_eq!(bytes.metadata::<Generation>(), Some(&Generation(1)));
+    assert_eq!(bytes.clone().metadata::<Checksum>(), Some(&Checksum(42)));
+    assert!(bytes.metadata::<u32>().is_none());
+    assert!(bytes.metadata::<()>().is_none());
+    let (path, _, checksum) = bytes.metadata::<(String, Generation, Checksum)>().unwrap();
+    assert_eq!((path.as_str(), checksum), ("path", &Checksum(42)));
+    let (buffer, (_, generation, _)) = bytes
+        .try_into_buffer_with_metadata::<Vec<u8>, (String, Generation, Checksum)>()
+        .unwrap();
+    assert_eq!((buffer, generation), (vec![0, 1], Generation(1)));
+
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer_with_metadata_chain(
+        vec![0, 1],
+        (Checksum(42), Generation(1)),
+    );
+    bytes.extend_from_slice(&[2]);
+    assert_eq!(bytes.metadata::<Generation>(), Some(&Generation(1)));
+    assert!(bytes.metadata::<String>().is_none());
+    let bytes = bytes.freeze::<ArcLayout<true>>();
+    assert_eq!(bytes.metadata::<Checksum>(), Some(&Checksum(42)));
+}
+
+// the buffer is dropped with the last strong reference, even if weak ones remain
+#[test]
+fn weak() {
+    struct DropTracked(Vec<u8>, Arc<AtomicBool>);
+    impl Buffer<[u8]> for DropTracked {
+        fn as_slice(&self) -> &[u8] {
+            &self.0
+        }
+    }
+    impl Drop for DropTracked {
+        fn drop(&mut self) {
+            self.1.store(true, Ordering::Relaxed);
+        }
+    }
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let buffer = DropTracked(b"hello world".to_vec(), dropped.clone());
+    let bytes = ArcBytes::<ArcLayout<true>>::from_buffer(buffer);
+    let weak = bytes.subslice(6..).downgrade();
+    let weak2 = weak.clone();
+    assert!(!bytes.is_unique());
+    let bytes = bytes.try_into_mut::<ArcLayout<true>>().unwrap_err();
+    let upgraded = weak2.upgrade().unwrap();
+    assert_eq!(upgraded, b"world");
+    drop(weak2);
+    drop(bytes);
+    assert!(!dropped.load(Ordering::Relaxed));
+    drop(upgraded);
+    assert!(dropped.load(Ordering::Relaxed));
+    assert!(weak.upgrade().is_none());
+    assert!(weak.clone().upgrade().is_none());
+
+    let bytes = ArcBytes::<ArcLayout<true>>::from(b"hello world".to_vec());
+    let weak = bytes.downgrade();
+    drop(weak);
+    assert!(bytes.is_unique());
+    assert!(bytes.try_into_mut::<ArcLayout<true>>().is_ok());
+
+    let bytes = ArcBytes::<ArcLayout<true, true>>::from_static(b"hello world");
+    assert!(bytes.downgrade().upgrade().is_none());
+    let bytes = ArcBytes::<VecLayout>::from(b"hello world".to_vec());
+    let weak = bytes.downgrade();
+    assert!(bytes.is_unique());
+    assert!(weak.upgrade().is_none());
+}
+
+// raw parts round trip exactly, the ownership being transferred back and forth
+#[test]
+fn raw_parts() {
+    fn round_trip<L: Layout>(bytes: ArcBytes<L>) {
+        let (ptr, len, repr) = (bytes.as_ptr(), bytes.len(), bytes.repr());
+        let parts = bytes.into_raw_parts();
+        assert_eq!(
+            (parts.start.as_ptr().cast_const(), parts.length),
+            (ptr, len)
+        );
+        let bytes = unsafe { ArcBytes::<L>::from_raw_parts(parts) };
+        assert_eq!(
+            (bytes.as_ptr(), bytes.len(), bytes.repr()),
+            (ptr, len, repr)
+        );
+        assert_eq!(bytes, b"hello world"[11 - len..]);
+    }
+    round_trip(ArcBytes::<ArcLayout<true>>::from(b"hello world".to_vec()));
+    round_trip(ArcBytes::<ArcLayout<true, true>>::from_static(
+        b"hello world",
+    ));
+    round_trip(ArcBytes::<VecLayout>::from(b"hello world".to_vec()).subslice(6..));
+    round_trip(ArcBytes::<BoxedSliceLayout>::from(b"hello world".to_vec()));
+
+    let item = Arc::new(());
+    let slice = ArcSlice::<[Arc<()>], ArcLayout<true>>::from(vec![item.clone(), item.clone()]);
+    let clone = slice.clone();
+    let parts = slice.into_raw_parts();
+    drop(clone);
+    assert_eq!(Arc::strong_count(&item), 3);
+    drop(unsafe { ArcSlice::from_raw_parts(parts) });
+    assert_eq!(Arc::strong_count(&item), 1);
+}
+
+// metadata can be attached to unique slices, the vec buffer being reused, other ones copied
+#[test]
+fn with_metadata() {
+    fn check<L: AnyBufferLayout>(mut bytes: ArcBytes<L>) {
+        let clone = bytes.clone();
+        bytes = bytes.with_metadata("path".to_string()).unwrap_err().0;
+        drop(clone);
+        bytes.advance(6);
+        let bytes = bytes.with_metadata("path".to_string()).unwrap();
+        assert_eq!(bytes, b"world");
+        assert_eq!(bytes.metadata::<String>().unwrap(), "path");
+        let bytes = bytes.with_metadata("other".to_string()).unwrap();
+        let (buffer, metadata) = bytes
+            .try_into_buffer_with_metadata::<Vec<u8>, String>()
+            .unwrap();
+        assert_eq!((&buffer[..], &metadata[..]), (&b"world"[..], "other"));
+    }
+    check(ArcBytes::<ArcLayout<true>>::from(b"hello world".to_vec()));
+    check(ArcBytes::<VecLayout>::from(b"hello world".to_vec()));
+    check(ArcBytes::<BoxedSliceLayout>::from(b"hello world".to_vec()));
+    check(ArcBytes::<ArcLayout<true>>::from(b"hello world"));
+    struct MyBuffer(Vec<u8>);
+    impl Buffer<[u8]> for MyBuffer {
+        fn as_slice(&self) -> &[u8] {
+            &self.0
+        }
+    }
+    let buffer = MyBuffer(b"hello world".to_vec());
+    check(ArcBytes::<ArcLayout<true>>::from_buffer(buffer));
+    // items needing drop can only be moved out of a vector buffer
+    let strings = ArcSlice::<[String], ArcLayout<true>>::from_array(["hello".to_string()]);
+    let (strings, metadata) = strings.with_metadata("path").unwrap_err();
+    assert_eq!(
+        (&strings[..], metadata),
+        (&["hello".to_string()][..], "path")
+    );
+}
+
+// subslices, even empty ones, point into the parent slice at the range start
+#[test]
+fn subslice_anchored() {
+    fn check<L: Layout>(bytes: ArcBytes<L>) {
+        let ptr = bytes.as_ptr();
+        for (start, end) in [(0, 0), (2, 2), (6, 11), (11, 11)] {
+            let subslice = bytes.subslice(start..end);
+            assert_eq!(subslice.as_ptr(), ptr.wrapping_add(start));
+            let subslice = bytes.subslice_from_ref(&bytes[start..end]);
+            assert_eq!(subslice.as_ptr(), ptr.wrapping_add(start));
+        }
+        let mut bytes2 = bytes.clone();
+        assert_eq!(bytes2.split_off(11).as_ptr(), ptr.wrapping_add(11));
+        assert_eq!(bytes2.split_to(0).as_ptr(), ptr);
+        bytes2.advance(11);
+        assert_eq!(bytes2.as_ptr(), ptr.wrapping_add(11));
+        assert_eq!(
+            bytes.borrow(11..).clone_arc().as_ptr(),
+            ptr.wrapping_add(11)
+        );
+        let s = ArcSlice::<str, L>::from("hello world");
+        assert_eq!(s.subslice(11..).as_ptr(), s[11..].as_ptr());
+    }
+    fn check_vec<L: AnyBufferLayout>() {
+        check(ArcBytes::<L>::from(b"hello world".to_vec()));
+        check(ArcBytes::<L>::from_slice(b"hello world"));
+        let erased = ErasedArcBytes::from(ArcBytes::<L>::from(b"hello world".to_vec()));
+        assert_eq!(erased.subslice(11..).as_ptr(), erased[11..].as_ptr());
+    }
+    check(ArcBytes::<ArcLayout<false, false>>::from_slice(
+        b"hello world",
+    ));
+    check_vec::<ArcLayout<true, false>>();
+    check_vec::<ArcLayout<true, true>>();
+    check_vec::<BoxedSliceLayout>();
+    check_vec::<VecLayout>();
+    #[cfg(feature = "inlined")]
+    {
+        use arc_slice::inlined::SmallArcSlice;
+        let bytes = SmallArcSlice::<[u8]>::from_slice(&[0; 64]);
+        assert_eq!(bytes.subslice(64..).as_ptr(), bytes[64..].as_ptr());
+        let small = SmallArcSlice::<[u8]>::from_slice(b"hello");
+        assert_eq!(small.subslice(5..).len(), 0);
+    }
+}
+
+// the buffer capacity is known for crate-allocated and mutable buffers, whether the slice is
+// shared or not
+#[test]
+fn buffer_capacity() {
+    struct Frozen(Vec<u8>);
+    impl Buffer<[u8]> for Frozen {
+        fn as_slice(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    fn check_vec<L: AnyBufferLayout>() {
+        let mut vec = Vec::with_capacity(64);
+        vec.extend_from_slice(b"hello world");
+        let bytes = ArcBytes::<L>::from(vec);
+        assert_eq!(bytes.buffer_capacity(), Some(64));
+        let hello = bytes.subslice(..5);
+        assert_eq!(hello.buffer_capacity(), Some(64));
+        drop(bytes);
+        assert_eq!(hello.buffer_capacity(), Some(64));
+        let bytes = ArcBytes::<L>::from_buffer(b"hello world".to_vec());
+        assert_eq!(bytes.clone().buffer_capacity(), Some(11));
+        let bytes = ArcBytes::<L>::from_buffer(Frozen(b"hello world".to_vec()));
+        assert_eq!(bytes.buffer_capacity(), None);
+        assert_eq!(ArcBytes::<L>::from_slice(b"").buffer_capacity(), None);
+    }
+
+    let bytes = ArcBytes::<ArcLayout<false, false>>::from_slice(b"hello world");
+    assert_eq!(bytes.buffer_capacity(), Some(11));
+    assert_eq!(bytes.clone().subslice(6..).buffer_capacity(), Some(11));
+    let mut bytes_mut: ArcBytesMut<ArcLayout<false, false>> = bytes.try_into_mut().unwrap();
+    bytes_mut.reserve(100);
+    let capacity = bytes_mut.capacity();
+    let bytes = bytes_mut.freeze::<ArcLayout<false, false>>();
+    assert_eq!(bytes.buffer_capacity(), Some(capacity));
+    assert_eq!(
+        ArcBytes::<ArcLayout<false, false>>::from_slice(b"").buffer_capacity(),
+        None
+    );
+    let items = ArcSlice::<[u32], ArcLayout<false, false>>::from_slice(&[0; 8]);
+    assert_eq!(items.pinned_bytes(), Some(32));
+    let mut strings = Vec::with_capacity(8);
+    strings.extend(["hello".to_string(), "world".to_string()]);
+    let strings = ArcSlice::<[String], ArcLayout<true>>::from(strings);
+    assert_eq!(strings.buffer_capacity(), Some(8));
+    assert_eq!(strings.subslice(1..).buffer_capacity(), Some(8));
+
+    check_vec::<ArcLayout<true, false>>();
+    check_vec::<ArcLayout<true, true>>();
+    check_vec::<BoxedSliceLayout>();
+    check_vec::<VecLayout>();
+    #[cfg(feature = "raw-buffer")]
+    check_vec::<arc_slice::layout::RawLayout>();
+}
+
+// searching works on any item type, and borrowed matches point into the searched slice
+#[test]
+fn find_subslice() {
+    let bytes = <ArcBytes>::from(b"level=info msg=started\nlevel=error msg=failed\n");
+    assert_eq!(bytes.find_subslice(b"level=error"), Some(23));
+    assert_eq!(bytes.find_subslice(b""), Some(0));
+    assert_eq!(bytes.find_subslice(b"\n\n"), None);
+    assert_eq!(
+        bytes.find(b"msg=failed"),
+        bytes.find_subslice(b"msg=failed")
+    );
+    let borrow = bytes.find_and_borrow(b"error").unwrap();
+    assert_eq!(borrow.as_ptr(), bytes[29..].as_ptr());
+    let error = borrow.clone_arc();
+    drop(bytes);
+    assert_eq!(error, b"error");
+    assert!(error.find_and_borrow(b"errors").is_none());
+
+    let items = ArcSlice::<[u32]>::from(&[1, 2, 3, 1, 2, 4]);
+    assert_eq!(items.find_subslice(&[1, 2, 4]), Some(3));
+    assert_eq!(items.find_and_borrow(&[2, 3]).unwrap().len(), 2);
+
+    let s = <ArcStr>::from("h\u{e9}llo");
+    assert_eq!(&*s.find_and_borrow(b"llo").unwrap(), "llo");
+    let res = panic::catch_unwind(|| s.find_and_borrow(b"\xa9").map(|b| b.len()));
+    assert!(res.is_err());
+}
+
+// case conversions clone the slice when there is nothing to convert, and copy it otherwise
+#[test]
+fn ascii_case_arc() {
+    fn check<L: Layout>() {
+        let lower = ArcStr::<L>::from("content-type: é");
+        let upper = ArcStr::<L>::from("CONTENT-TYPE: é");
+        let mixed = ArcStr::<L>::from("Content-Type: é");
+        assert_eq!(lower.to_ascii_lowercase_arc().as_ptr(), lower.as_ptr());
+        assert_eq!(upper.to_ascii_uppercase_arc().as_ptr(), upper.as_ptr());
+        for s in [&lower, &upper, &mixed] {
+            if s != &lower {
+                let converted = s.to_ascii_lowercase_arc();
+                assert_ne!(converted.as_ptr(), s.as_ptr());
+                assert_eq!(converted, lower);
+            }
+            if s != &upper {
+                let converted = s.to_ascii_uppercase_arc();
+                assert_ne!(converted.as_ptr(), s.as_ptr());
+                assert_eq!(converted, upper);
+            }
+            let bytes = ArcBytes::<L>::from(s.as_bytes());
+            assert_eq!(bytes.to_ascii_lowercase_arc(), lower.as_bytes());
+            assert_eq!(bytes.to_ascii_uppercase_arc(), upper.as_bytes());
+        }
+        let bytes = ArcBytes::<L>::from(b"\xffkey");
+        assert_eq!(bytes.to_ascii_lowercase_arc().as_ptr(), bytes.as_ptr());
+        assert_eq!(bytes.to_ascii_uppercase_arc(), b"\xffKEY");
+        let empty = ArcBytes::<L>::new();
+        assert_eq!(empty.to_ascii_uppercase_arc(), b"");
+        let doubled = bytes.map_or_clone(|s| Some([s, s].concat()));
+        assert_eq!(doubled, b"\xffkey\xffkey");
+        assert_eq!(bytes.map_or_clone(|_| None).as_ptr(), bytes.as_ptr());
+    }
+    check::<ArcLayout<false>>();
+    check::<ArcLayout<true>>();
+    check::<BoxedSliceLayout>();
+    check::<VecLayout>();
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "capi")]
+
+use core::{
+    ffi::c_void,
+    ptr, slice,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use arc_slice::capi::*;
+
+unsafe fn as_slice<'a>(bytes: *const arc_bytes_t) -> &'a [u8] {
+    unsafe { slice::from_raw_parts(arc_bytes_data(bytes), arc_bytes_len(bytes)) }
+}
+
+// copied slices are shared by clones and subslices
+#[test]
+fn from_copy() {
+    let data = b"hello world".to_vec();
+    unsafe {
+        let bytes = arc_bytes_from_copy(data.as_ptr(), data.len());
+        drop(data);
+        assert_eq!(as_slice(bytes), b"hello world");
+        let clone = arc_bytes_clone(bytes);
+        assert_eq!(arc_bytes_data(clone), arc_bytes_data(bytes));
+        let world = arc_bytes_subslice(clone, 6, 5);
+        arc_bytes_drop(bytes);
+        arc_bytes_drop(clone);
+        assert_eq!(as_slice(world), b"world");
+        let empty = arc_bytes_subslice(world, 5, 0);
+        assert_eq!(as_slice(empty), b"");
+        arc_bytes_drop(empty);
+        arc_bytes_drop(world);
+    }
+}
+
+// invalid inputs return null instead of panicking, and null handles are accepted
+#[test]
+fn null_and_empty() {
+    unsafe {
+        let empty = arc_bytes_from_copy(ptr::null(), 0);
+        assert!(!empty.is_null());
+        assert!(!arc_bytes_data(empty).is_null());
+        assert_eq!(arc_bytes_len(empty), 0);
+        assert!(arc_bytes_subslice(empty, 0, 1).is_null());
+        assert!(arc_bytes_subslice(empty, 1, 0).is_null());
+        arc_bytes_drop(empty);
+        assert!(arc_bytes_from_copy(ptr::null(), 1).is_null());
+        let bytes = arc_bytes_from_copy(b"hello".as_ptr(), 5);
+        assert!(arc_bytes_subslice(bytes, 1, usize::MAX).is_null());
+        assert!(arc_bytes_subslice(bytes, 3, 3).is_null());
+        arc_bytes_drop(bytes);
+        assert!(arc_bytes_clone(ptr::null()).is_null());
+        assert!(arc_bytes_subslice(ptr::null(), 0, 0).is_null());
+        assert!(arc_bytes_data(ptr::null()).is_null());
+        assert_eq!(arc_bytes_len(ptr::null()), 0);
+        arc_bytes_drop(ptr::null_mut());
+    }
+}
+
+static DATA: [u8; 11] = *b"hello world";
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C" fn count_drop(ctx: *mut c_void) {
+    assert_eq!(ctx as usize, 42);
+    DROPPED.fetch_add(1, Ordering::Relaxed);
+}
+
+// foreign memory is not copied, and its destructor is called once with the last handle
+#[test]
+fn from_foreign() {
+    let ctx = 42 as *mut c_void;
+    unsafe {
+        assert!(arc_bytes_from_foreign(ptr::null(), 1, ctx, Some(count_drop)).is_null());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+        let bytes = arc_bytes_from_foreign(DATA.as_ptr(), DATA.len(), ctx, Some(count_drop));
+        assert_eq!(arc_bytes_data(bytes), DATA.as_ptr());
+        let hello = arc_bytes_subslice(bytes, 0, 5);
+        let thread_bytes = arc_bytes_clone(bytes) as usize;
+        std::thread::spawn(move || arc_bytes_drop(thread_bytes as *mut arc_bytes_t))
+            .join()
+            .unwrap();
+        arc_bytes_drop(bytes);
+        assert_eq!(as_slice(hello), b"hello");
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+        arc_bytes_drop(hello);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+        let empty = arc_bytes_from_foreign(ptr::null(), 0, ctx, Some(count_drop));
+        assert_eq!(arc_bytes_len(empty), 0);
+        arc_bytes_drop(empty);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+        arc_bytes_drop(arc_bytes_from_foreign(DATA.as_ptr(), 5, ctx, None));
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "codec")]
+
+use arc_slice::{
+    codec::Base64Alphabet,
+    error::{Base64Error, HexError},
+    layout::{ArcLayout, VecLayout},
+    ArcBytes, ArcBytesMut,
+};
+
+// xorshift generator, to produce deterministic pseudo-random inputs
+fn random_bytes(seed: &mut u64, len: usize) -> Vec<u8> {
+    (0..len)
+        .map(|_| {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed as u8
+        })
+        .collect()
+}
+
+#[test]
+fn hex_round_trip() {
+    let mut seed = 42;
+    for len in 0..if cfg!(miri) { 8 } else { 256 } {
+        let bytes = ArcBytes::<ArcLayout<true>>::from(random_bytes(&mut seed, len));
+        let hex = bytes.encode_hex();
+        assert_eq!(hex.len(), 2 * len);
+        assert_eq!(hex, format!("{bytes:x}"));
+        assert_eq!(ArcBytesMut::<VecLayout>::from_hex(&hex).unwrap(), *bytes);
+        let upper = hex.to_uppercase();
+        assert_eq!(<ArcBytesMut>::from_hex(&upper).unwrap(), *bytes);
+    }
+}
+
+#[test]
+fn base64_round_trip() {
+    let mut seed = 42;
+    for len in 0..if cfg!(miri) { 8 } else { 256 } {
+        let bytes = ArcBytes::<ArcLayout<true>>::from(random_bytes(&mut seed, len));
+        for alphabet in [Base64Alphabet::Standard, Base64Alphabet::UrlSafe] {
+            let encoded = bytes.encode_base64(alphabet);
+            let padded = alphabet == Base64Alphabet::Standard;
+            assert_eq!(encoded.len() % 4 == 0, padded || len % 3 == 0);
+            let decoded = ArcBytesMut::<VecLayout>::from_base64(&encoded, alphabet).unwrap();
+            assert_eq!(decoded, *bytes);
+            // padding is optional when decoding
+            let unpadded = encoded.trim_end_matches('=');
+            assert_eq!(
+                <ArcBytesMut>::from_base64(unpadded, alphabet).unwrap(),
+                *bytes
+            );
+        }
+    }
+}
+
+#[test]
+fn base64_rfc4648() {
+    let vectors = [
+        ("", ""),
+        ("f", "Zg=="),
+        ("fo", "Zm8="),
+        ("foo", "Zm9v"),
+        ("foob", "Zm9vYg=="),
+        ("fooba", "Zm9vYmE="),
+        ("foobar", "Zm9vYmFy"),
+    ];
+    for (bytes, encoded) in vectors {
+        let bytes = <ArcBytes>::from(bytes.as_bytes());
+        assert_eq!(bytes.encode_base64(Base64Alphabet::Standard), encoded);
+    }
+    let bytes = <ArcBytes>::from(&[0xfb, 0xff, 0xbf]);
+    assert_eq!(bytes.encode_base64(Base64Alphabet::Standard), "+/+/");
+    assert_eq!(bytes.encode_base64(Base64Alphabet::UrlSafe), "-_-_");
+}
+
+#[test]
+fn hex_error_position() {
+    let from_hex = <ArcBytesMut>::from_hex;
+    assert_eq!(from_hex("0"), Err(HexError::OddLength { index: 0 }));
+    assert_eq!(from_hex("00f"), Err(HexError::OddLength { index: 2 }));
+    assert_eq!(from_hex("x0"), Err(HexError::InvalidDigit { index: 0 }));
+    assert_eq!(from_hex("00ff0 "), Err(HexError::InvalidDigit { index: 5 }));
+    assert_eq!(from_hex("00é"), Err(HexError::InvalidDigit { index: 2 }));
+}
+
+#[test]
+fn base64_error_position() {
+    let from_base64 = |s| <ArcBytesMut>::from_base64(s, Base64Alphabet::Standard);
+    let invalid_length = |index| Err(Base64Error::InvalidLength { index });
+    let invalid_character = |index| Err(Base64Error::InvalidCharacter { index });
+    let invalid_padding = |index| Err(Base64Error::InvalidPadding { index });
+    assert_eq!(from_base64("Z"), invalid_length(0));
+    assert_eq!(from_base64("Zm9vY"), invalid_length(4));
+    assert_eq!(from_base64("Zm9vY==="), invalid_character(5));
+    assert_eq!(from_base64("Zm9v-mFy"), invalid_character(4));
+    assert_eq!(from_base64("Zm9vYmF_"), invalid_character(7));
+    // non-zero trailing bits
+    assert_eq!(from_base64("Zh=="), invalid_character(1));
+    assert_eq!(from_base64("Zm9="), invalid_character(2));
+    assert_eq!(from_base64("Zg="), invalid_padding(2));
+    assert_eq!(from_base64("Zm9vYmE=="), invalid_padding(7));
+}
@@ -8,8 +8,9 @@ use std::{
 };
 
 use arc_slice::{
-    layout::{DefaultLayout, VecLayout},
-    ArcBytes, ArcSlice,
+    error::TryReserveError,
+    layout::{ArcLayout, DefaultLayout, DefaultLayoutMut, VecLayout},
+    ArcBytes, ArcBytesMut, ArcSlice, ArcSliceMut,
 };
 
 #[test]
@@ -73,3 +74,75 @@ fn arc_slice_drop_with_unique_hint() {
     drop(bytes);
     thread.join().unwrap();
 }
+
+// slices are shareable between threads, as their items must be `Send + Sync`
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ArcSlice<[Arc<u8>]>>();
+    assert_send_sync::<ArcSlice<[std::sync::atomic::AtomicU8], VecLayout>>();
+    assert_send_sync::<arc_slice::ArcSliceMut<[u8], VecLayout>>();
+}
+
+// split parts of a shared slice can be appended to concurrently, each one being limited to its
+// own capacity as long as the others are alive
+#[test]
+fn split_shared_concurrent_append() {
+    fn fill(
+        mut part: ArcSliceMut<[u8], DefaultLayoutMut, false>,
+        byte: u8,
+    ) -> ArcSliceMut<[u8], DefaultLayoutMut, false> {
+        while part.try_extend_from_slice(&[byte]).is_ok() {}
+        assert_eq!(part.try_reserve(1), Err(TryReserveError::NotUnique));
+        part
+    }
+    for _ in 0..if cfg!(miri) { 4 } else { 1000 } {
+        let mut left = <ArcBytesMut>::with_capacity(64);
+        left.extend_from_slice(b"ab");
+        let mut left = left.into_shared();
+        let right = left.split_off(32);
+        assert!(left.capacity() == 32 && right.capacity() >= 32);
+        let right = thread::spawn(move || fill(right, b'r'));
+        let mut left = fill(left, b'l');
+        let right = right.join().unwrap();
+        assert_eq!(left[..2], *b"ab");
+        assert!(left[2..].iter().all(|&b| b == b'l'));
+        assert!(right.iter().all(|&b| b == b'r'));
+        assert_eq!(left.len() + right.len(), 32 + right.capacity());
+        drop(right);
+        left.try_reserve(1).unwrap();
+        assert_eq!(left.len(), 32);
+    }
+}
+
+// a weak reference can only be upgraded while a strong one is alive
+#[test]
+fn weak_concurrent_upgrade() {
+    for _ in 0..if cfg!(miri) { 4 } else { 1000 } {
+        let bytes = ArcBytes::<ArcLayout<true>>::from(b"hello world".to_vec());
+        let weak = bytes.downgrade();
+        let thread = thread::spawn(move || weak.upgrade());
+        drop(bytes);
+        if let Some(upgraded) = thread.join().unwrap() {
+            assert_eq!(upgraded, b"hello world");
+            assert!(upgraded.is_unique());
+        }
+    }
+}
+
+// raw parts can be sent to another thread to be reconstructed there
+#[test]
+fn raw_parts_send() {
+    let bytes = ArcBytes::<ArcLayout<true>>::from(b"hello world".to_vec());
+    let mut bytes_mut = <ArcBytesMut>::with_capacity(32);
+    bytes_mut.extend_from_slice(b"hello world");
+    let (parts, parts_mut) = (bytes.clone().into_raw_parts(), bytes_mut.into_raw_parts());
+    let thread = thread::spawn(move || unsafe {
+        let mut bytes_mut = ArcSliceMut::from_raw_parts(parts_mut);
+        bytes_mut.extend_from_slice(b"!");
+        (ArcSlice::from_raw_parts(parts), bytes_mut)
+    });
+    let (clone, bytes_mut) = thread.join().unwrap();
+    assert_eq!(clone, bytes);
+    assert_eq!(bytes_mut, b"hello world!");
+}
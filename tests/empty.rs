@@ -0,0 +1,151 @@
+use std::{
+    alloc::{GlobalAlloc, Layout as AllocLayout, System},
+    cell::Cell,
+};
+
+#[cfg(feature = "raw-buffer")]
+use arc_slice::layout::RawLayout;
+use arc_slice::{
+    layout::{ArcLayout, BoxedSliceLayout, Layout, LayoutMut, VecLayout},
+    ArcSlice, ArcSliceMut,
+};
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: AllocLayout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: AllocLayout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn empty_no_alloc<L: Layout, LM: LayoutMut>() {
+    let hello = ArcSlice::<[u8], L>::from_slice(b"hello");
+    let before = allocations();
+    let frozen = ArcSliceMut::<[u8], LM>::new().try_freeze::<L>().unwrap();
+    let from_slice = ArcSlice::<[u8], L>::from_slice(b"");
+    let subslice = hello.subslice(2..2);
+    let clone = frozen.clone();
+    drop(hello);
+    assert_eq!(allocations(), before);
+    for empty in [&frozen, &from_slice, &subslice, &clone] {
+        assert_eq!(empty, b"");
+    }
+}
+
+// empty slices of every layout are frozen, created and cloned without allocation
+#[test]
+fn empty() {
+    macro_rules! empty_no_alloc {
+        ($($layout:ty),*) => {$(
+            empty_no_alloc::<$layout, ArcLayout<false, false>>();
+            empty_no_alloc::<$layout, ArcLayout<true, true>>();
+            empty_no_alloc::<$layout, VecLayout>();
+        )*};
+    }
+    empty_no_alloc!(
+        ArcLayout<false, false>,
+        ArcLayout<false, true>,
+        ArcLayout<true, false>,
+        ArcLayout<true, true>,
+        BoxedSliceLayout,
+        VecLayout
+    );
+    #[cfg(feature = "raw-buffer")]
+    empty_no_alloc!(RawLayout);
+
+    let before = allocations();
+    let empty = ArcSlice::<[u8], ArcLayout<false, false>>::from_slice(b"");
+    let mut mutable = empty.try_into_mut::<ArcLayout<false, false>>().unwrap();
+    assert_eq!(allocations(), before);
+    mutable.extend_from_slice(b"hello");
+    assert_eq!(mutable, b"hello");
+}
+
+fn new_and_default<L: Layout>() {
+    const COUNT: usize = if cfg!(miri) { 1000 } else { 1_000_000 };
+    let before = allocations();
+    let new = ArcSlice::<[u8], L>::new();
+    let default = ArcSlice::<str, L>::default();
+    let mut clones = Vec::with_capacity(COUNT);
+    let before_clones = allocations();
+    clones.extend((0..COUNT).map(|_| new.clone()));
+    assert_eq!(allocations(), before_clones);
+    assert!(clones.iter().all(|clone| clone.is_static()));
+    drop(clones);
+    let mut split = new.subslice(..);
+    let other = split.split_off(0);
+    let (left, right) = default.clone().split_into(0);
+    assert_eq!(allocations(), before + 1);
+    assert!([&new, &split, &other]
+        .iter()
+        .all(|s| s.is_empty() && s.is_static()));
+    assert!([&default, &left, &right].iter().all(|s| s.is_empty()));
+    let mut mutable = new.try_into_mut::<ArcLayout<false, false>>().unwrap();
+    mutable.extend_from_slice(b"hello");
+    assert_eq!(mutable, b"hello");
+}
+
+// empty slices of every layout can be created in const context, and are not refcounted
+#[test]
+fn new_default() {
+    const EMPTY: ArcSlice<[u8], ArcLayout<false, false>> = ArcSlice::new();
+    assert_eq!(EMPTY, b"");
+    new_and_default::<ArcLayout<false, false>>();
+    new_and_default::<ArcLayout<false, true>>();
+    new_and_default::<ArcLayout<true, false>>();
+    new_and_default::<ArcLayout<true, true>>();
+    new_and_default::<BoxedSliceLayout>();
+    new_and_default::<VecLayout>();
+    #[cfg(feature = "raw-buffer")]
+    new_and_default::<RawLayout>();
+}
+
+// buffers backed by static data are referenced directly, without allocation
+#[test]
+fn static_buffer() {
+    use arc_slice::buffer::Buffer;
+
+    struct Generated(usize);
+    static TABLE: [&[u8]; 3] = [b"", b"hello", b"world"];
+    impl Buffer<[u8]> for Generated {
+        fn as_slice(&self) -> &[u8] {
+            TABLE[self.0]
+        }
+        fn as_static(&self) -> Option<&'static [u8]> {
+            Some(TABLE[self.0])
+        }
+    }
+
+    let before = allocations();
+    for (i, &slice) in TABLE.iter().enumerate() {
+        let bytes = ArcSlice::<[u8], ArcLayout<true, true>>::from_buffer(Generated(i));
+        assert_eq!(bytes, slice);
+        assert!(bytes.is_static());
+        let bytes =
+            ArcSlice::<[u8], ArcLayout<true, true>>::from_buffer_with_metadata(Generated(i), ());
+        assert_eq!(bytes.clone(), slice);
+        let bytes = ArcSlice::<[u8], VecLayout>::try_from_buffer(Generated(i))
+            .ok()
+            .unwrap();
+        assert_eq!(bytes.subslice(..), slice);
+    }
+    assert_eq!(allocations(), before);
+    let bytes = ArcSlice::<[u8], ArcLayout<true, false>>::from_buffer(Generated(1));
+    assert!(bytes.try_into_buffer::<Generated>().is_err());
+}
@@ -0,0 +1,150 @@
+use arc_slice::{
+    error::FrameError,
+    frame::{Endian, LengthPrefix},
+    layout::{ArcLayout, VecLayout},
+    ArcBytes, ArcBytesMut,
+};
+
+const PREFIXES: [LengthPrefix; 7] = [
+    LengthPrefix::U16(Endian::Big),
+    LengthPrefix::U16(Endian::Little),
+    LengthPrefix::U32(Endian::Big),
+    LengthPrefix::U32(Endian::Little),
+    LengthPrefix::U64(Endian::Big),
+    LengthPrefix::U64(Endian::Little),
+    LengthPrefix::Varint,
+];
+
+fn payloads() -> Vec<Vec<u8>> {
+    [0, 1, 127, 128, 300, 16384, 65535]
+        .into_iter()
+        .map(|len| (0..len).map(|i| i as u8).collect())
+        .collect()
+}
+
+// frames written with both `write_frame` and `begin_frame` are split back zero-copy
+#[test]
+fn frame_round_trip() {
+    for prefix in PREFIXES {
+        let mut buf = ArcBytesMut::<VecLayout>::from(b"header".to_vec());
+        buf.advance(6);
+        for (i, payload) in payloads().iter().enumerate() {
+            if i % 2 == 0 {
+                buf.write_frame(prefix, payload).unwrap();
+            } else {
+                let mut frame = buf.begin_frame(prefix);
+                frame.extend_from_slice(&payload[..payload.len() / 2]);
+                frame.extend_from_slice(&payload[payload.len() / 2..]);
+                assert_eq!(frame.payload_len(), payload.len());
+                frame.finish().unwrap();
+            }
+        }
+        let mut bytes = buf.freeze::<ArcLayout<true>>();
+        let start = bytes.as_ptr();
+        for payload in payloads() {
+            let frame = bytes.split_frame(prefix).unwrap().unwrap();
+            assert_eq!(frame, payload);
+            assert!(start <= frame.as_ptr() && frame.as_ptr() <= bytes.as_ptr());
+        }
+        assert!(bytes.is_empty());
+        assert_eq!(bytes.split_frame(prefix), Ok(None));
+    }
+}
+
+// the guard only appends to the payload, which excludes the reserved prefix
+#[test]
+fn frame_guard_payload() {
+    for prefix in PREFIXES {
+        let mut buf = ArcBytesMut::<ArcLayout<true>>::from(b"previous");
+        let mut frame = buf.begin_frame(prefix);
+        frame.push(b'h');
+        frame.extend_from_slice(b"ello");
+        frame.as_mut()[0] = b'H';
+        assert_eq!(frame.as_mut(), b"Hello");
+        frame.finish().unwrap();
+        let mut bytes = buf.freeze::<ArcLayout<true>>().subslice(8..);
+        assert_eq!(bytes.split_frame(prefix).unwrap().unwrap(), b"Hello");
+    }
+}
+
+// incomplete frames, including incomplete prefixes, leave the slice untouched
+#[test]
+fn frame_partial_input() {
+    for prefix in PREFIXES {
+        let mut buf = ArcBytesMut::<ArcLayout<true>>::new();
+        buf.write_frame(prefix, &[42; 200]).unwrap();
+        let encoded = buf.freeze::<ArcLayout<true>>();
+        for cut in 0..encoded.len() {
+            let mut partial = encoded.subslice(..cut);
+            assert_eq!(partial.split_frame(prefix), Ok(None));
+            assert_eq!(partial, encoded[..cut]);
+        }
+        let mut complete = encoded.clone();
+        assert_eq!(complete.split_frame(prefix).unwrap().unwrap(), [42; 200]);
+    }
+}
+
+// lengths exceeding the prefix or `isize::MAX`, and malformed prefixes, are distinct errors
+#[test]
+fn frame_errors() {
+    let mut buf = ArcBytesMut::<ArcLayout<true>>::from(b"previous");
+    let prefix = LengthPrefix::U16(Endian::Big);
+    assert_eq!(
+        buf.write_frame(prefix, &[0; 1 << 16]),
+        Err(FrameError::Overflow)
+    );
+    let mut frame = buf.begin_frame(prefix);
+    frame.extend_from_slice(&[0; 1 << 16]);
+    assert_eq!(frame.finish(), Err(FrameError::Overflow));
+    assert_eq!(buf, b"previous");
+    // an unfinished frame is discarded
+    buf.begin_frame(prefix).extend_from_slice(b"payload");
+    assert_eq!(buf, b"previous");
+
+    let mut bytes = <ArcBytes>::from(&u64::MAX.to_be_bytes());
+    assert_eq!(
+        bytes.split_frame(LengthPrefix::U64(Endian::Big)),
+        Err(FrameError::Overflow)
+    );
+    let mut bytes = <ArcBytes>::from(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+    assert_eq!(
+        bytes.split_frame(LengthPrefix::Varint),
+        Err(FrameError::Overflow)
+    );
+    let mut bytes = <ArcBytes>::from(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02]);
+    assert_eq!(
+        bytes.split_frame(LengthPrefix::Varint),
+        Err(FrameError::Malformed)
+    );
+    assert_eq!(bytes.len(), 10);
+}
+
+// shorthand helpers split common prefixes, and never consume incomplete frames
+#[test]
+fn split_prefixed() {
+    let mut bytes = <ArcBytes>::from(b"\x02hi\0\x02hi\0\0\0\x02hi\x02hi");
+    let start = bytes.as_ptr();
+    let frames = [
+        bytes.split_prefixed_u8().unwrap(),
+        bytes.split_prefixed_u16_be().unwrap(),
+        bytes.split_prefixed_u32_be().unwrap(),
+        bytes.split_prefixed_varint().unwrap().unwrap(),
+    ];
+    for (frame, offset) in frames.iter().zip([1, 5, 11, 14]) {
+        assert_eq!(*frame, b"hi");
+        assert_eq!(frame.as_ptr(), start.wrapping_add(offset));
+    }
+    assert!(bytes.is_empty());
+    let mut bytes = <ArcBytes>::from(b"\0\0\x02\x00");
+    assert_eq!(bytes.split_prefixed_u8(), Some(ArcBytes::new()));
+    assert_eq!(bytes.split_prefixed_u16_be(), None);
+    assert_eq!(bytes.split_prefixed_u32_be(), None);
+    assert_eq!(bytes, b"\0\x02\x00");
+    let mut buf = ArcBytesMut::<ArcLayout<true>>::new();
+    assert_eq!(
+        buf.write_frame(LengthPrefix::U8, &[0; 256]),
+        Err(FrameError::Overflow)
+    );
+    buf.write_frame(LengthPrefix::U8, b"hello").unwrap();
+    assert_eq!(buf, b"\x05hello");
+}
@@ -0,0 +1,58 @@
+#![cfg(all(feature = "std", feature = "oom-handling", not(miri)))]
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use arc_slice::layout::ArcLayout;
+
+fn temp_file(name: &str, content: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("arc-slice-{}-{name}", std::process::id()));
+    fs::File::create(&path).unwrap().write_all(content).unwrap();
+    path
+}
+
+// file content is read with the path attached as metadata
+#[test]
+fn read() {
+    let path = temp_file("read", b"hello world");
+    let bytes = arc_slice::fs::read::<ArcLayout<true>>(&path).unwrap();
+    assert_eq!(bytes, b"hello world");
+    assert_eq!(bytes.metadata::<PathBuf>().unwrap(), &path);
+    assert_eq!(bytes.try_into_buffer::<Vec<u8>>().unwrap(), b"hello world");
+    fs::remove_file(&path).unwrap();
+
+    let path = temp_file("read-empty", b"");
+    let bytes = arc_slice::fs::read::<ArcLayout<true>>(&path).unwrap();
+    assert_eq!(bytes, []);
+    assert_eq!(bytes.metadata::<PathBuf>().unwrap(), &path);
+    fs::remove_file(&path).unwrap();
+
+    let err = arc_slice::fs::read::<ArcLayout<true>>(Path::new("does/not/exist")).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
+
+// mapped file is kept alive by the slice
+#[cfg(feature = "memmap2")]
+#[test]
+fn read_with_mmap() {
+    let path = temp_file("mmap", b"hello world");
+    let bytes = unsafe { arc_slice::fs::read_with_mmap(&path) }.unwrap();
+    let hello = bytes.subslice(..5);
+    drop(bytes);
+    assert_eq!(hello, b"hello");
+    assert_eq!(hello.metadata::<PathBuf>().unwrap(), &path);
+    drop(hello);
+    fs::remove_file(&path).unwrap();
+
+    let path = temp_file("mmap-empty", b"");
+    let bytes = unsafe { arc_slice::fs::read_with_mmap(&path) }.unwrap();
+    assert_eq!(bytes, []);
+    assert_eq!(bytes.metadata::<PathBuf>().unwrap(), &path);
+    fs::remove_file(&path).unwrap();
+
+    let err = unsafe { arc_slice::fs::read_with_mmap("does/not/exist") }.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "loom")]
+//! Model checking of the refcount orderings, run with `cargo test --features loom --test loom`.
+//!
+//! Items write to their cell when dropped, so loom reports any read not synchronized with the
+//! final drop, e.g. because of a too weak ordering.
+use arc_slice::{
+    layout::{AnyBufferLayout, ArcLayout, Layout, LayoutMut, VecLayout},
+    ArcSlice, ArcSliceMut,
+};
+use loom::{cell::UnsafeCell, thread};
+
+struct Item(UnsafeCell<usize>);
+
+unsafe impl Sync for Item {}
+
+impl Item {
+    fn new(value: usize) -> Self {
+        Self(UnsafeCell::new(value))
+    }
+
+    fn get(&self) -> usize {
+        self.0.with(|ptr| unsafe { *ptr })
+    }
+}
+
+impl Drop for Item {
+    fn drop(&mut self) {
+        self.0.with_mut(|ptr| unsafe { *ptr = usize::MAX });
+    }
+}
+
+fn items() -> [Item; 2] {
+    [Item::new(0), Item::new(1)]
+}
+
+fn from_array<L: Layout>() -> ArcSlice<[Item], L> {
+    ArcSlice::from_array(items())
+}
+
+fn from_vec<L: AnyBufferLayout>() -> ArcSlice<[Item], L> {
+    ArcSlice::from(Vec::from(items()))
+}
+
+#[test]
+fn clone_drop() {
+    fn check(new: fn() -> ArcSlice<[Item], ArcLayout<true>>) {
+        loom::model(move || {
+            let s = new();
+            let s2 = s.clone();
+            let thread = thread::spawn(move || assert_eq!(s2[1].get(), 1));
+            assert_eq!(s[0].get(), 0);
+            drop(s);
+            thread.join().unwrap();
+        });
+    }
+    check(from_array);
+    check(from_vec);
+}
+
+#[test]
+fn drop_with_unique_hint() {
+    fn check(new: fn() -> ArcSlice<[Item], ArcLayout<true>>) {
+        loom::model(move || {
+            let s = new();
+            let s2 = s.clone();
+            let thread = thread::spawn(move || {
+                let s3 = s2.clone();
+                drop(s2);
+                assert_eq!(s3[1].get(), 1);
+            });
+            assert_eq!(s[0].get(), 0);
+            s.drop_with_unique_hint();
+            thread.join().unwrap();
+        });
+    }
+    check(from_array);
+    check(from_vec);
+}
+
+#[test]
+fn concurrent_promotion() {
+    loom::model(|| {
+        let s = loom::sync::Arc::new(from_vec::<VecLayout>());
+        let threads = [0, 1].map(|i| {
+            let s = s.clone();
+            thread::spawn(move || {
+                let clone = (*s).clone();
+                assert_eq!(clone[i].get(), i);
+            })
+        });
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(s[0].get(), 0);
+    });
+}
+
+#[test]
+fn try_into_unique_drop() {
+    fn check<L: LayoutMut>(new: fn() -> ArcSliceMut<[Item], L>) {
+        loom::model(move || {
+            let mut s = new().into_shared();
+            let s2 = s.split_off(1);
+            let thread = thread::spawn(move || assert_eq!(s2[0].get(), 1));
+            match s.try_into_unique() {
+                Ok(s) => s[0].0.with_mut(|ptr| unsafe { *ptr = 2 }),
+                Err(s) => assert_eq!(s[0].get(), 0),
+            }
+            thread.join().unwrap();
+        });
+    }
+    check::<ArcLayout<false>>(|| ArcSliceMut::from_array(items()));
+    check::<ArcLayout<true>>(|| ArcSliceMut::from(Vec::from(items())));
+    check::<VecLayout>(|| ArcSliceMut::from(Vec::from(items())));
+}
@@ -0,0 +1,46 @@
+#![cfg(all(feature = "std", feature = "oom-handling", unix))]
+
+use std::{
+    ffi::{OsStr, OsString},
+    os::unix::ffi::{OsStrExt, OsStringExt},
+    path::{Path, PathBuf},
+};
+
+use arc_slice::{layout::ArcLayout, ArcSlice};
+
+// paths are raw bytes on Unix, so non UTF-8 bytes are preserved
+#[test]
+fn path_non_utf8() {
+    let bytes = b"/tmp/\xff\xfe.txt";
+    let path = PathBuf::from(OsString::from_vec(bytes.to_vec()));
+    let s = ArcSlice::<Path, ArcLayout<true>>::from_buffer(path.clone());
+    assert_eq!(&*s, path.as_path());
+    assert_eq!(s.file_name().unwrap().as_bytes(), b"\xff\xfe.txt");
+    assert_eq!(s.try_into_buffer::<PathBuf>().unwrap(), path);
+
+    let s = ArcSlice::<Path>::from_slice(&path);
+    assert_eq!(s.as_os_str().as_bytes(), bytes);
+    assert_eq!(ArcSlice::<Path>::from_slice(Path::new("")).as_os_str(), "");
+}
+
+#[test]
+fn path_metadata() {
+    let s =
+        ArcSlice::<Path, ArcLayout<true>>::from_buffer_with_metadata(PathBuf::from("a/b"), 42usize);
+    assert_eq!(&*s, Path::new("a/b"));
+    assert_eq!(s.metadata::<usize>(), Some(&42));
+    let (path, metadata) = s.try_into_buffer_with_metadata::<PathBuf, usize>().unwrap();
+    assert_eq!(path, Path::new("a/b"));
+    assert_eq!(metadata, 42);
+}
+
+#[test]
+fn os_str_non_utf8() {
+    let os_string = OsString::from_vec(b"\x80abc".to_vec());
+    let s = ArcSlice::<OsStr, ArcLayout<true>>::from_buffer(os_string.clone());
+    assert_eq!(&*s, os_string.as_os_str());
+    assert_eq!(s.to_str(), None);
+    assert_eq!(s.try_into_buffer::<OsString>().unwrap(), os_string);
+    let s = ArcSlice::<OsStr>::from_slice(&os_string);
+    assert_eq!(s.as_bytes(), b"\x80abc");
+}
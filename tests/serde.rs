@@ -0,0 +1,114 @@
+#![cfg(feature = "serde")]
+
+use arc_slice::{
+    layout::{ArcLayout, VecLayout},
+    ArcBytes, ArcBytesMut, ArcStr,
+};
+use serde::{
+    de::{Deserializer, Visitor},
+    forward_to_deserialize_any,
+};
+
+// deserializer yielding an owned byte buffer, like io-based binary formats do
+struct ByteBufDeserializer(Vec<u8>);
+
+impl<'de> Deserializer<'de> for ByteBufDeserializer {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_byte_buf(self.0)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+// binary formats round trip
+#[test]
+fn postcard_round_trip() {
+    let bytes = <ArcBytes>::from(b"hello world");
+    let encoded = postcard::to_allocvec(&bytes).unwrap();
+    assert_eq!(postcard::from_bytes::<ArcBytes>(&encoded).unwrap(), bytes);
+    let bytes = <ArcBytes<VecLayout>>::from(b"hello world");
+    let encoded = postcard::to_allocvec(&bytes).unwrap();
+    let decoded: ArcBytes<VecLayout> = postcard::from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, bytes);
+    let string = <ArcStr>::from("hello world");
+    let encoded = postcard::to_allocvec(&string).unwrap();
+    assert_eq!(postcard::from_bytes::<ArcStr>(&encoded).unwrap(), string);
+}
+
+// owned byte buffers are reused without copy when the layout supports it
+#[test]
+fn byte_buf_no_copy() {
+    let vec = b"hello world".to_vec();
+    let ptr = vec.as_ptr();
+    let bytes: ArcBytes<VecLayout> =
+        serde::Deserialize::deserialize(ByteBufDeserializer(vec)).unwrap();
+    assert_eq!((bytes.as_ptr(), &bytes[..]), (ptr, &b"hello world"[..]));
+    let vec = b"hello world".to_vec();
+    let ptr = vec.as_ptr();
+    let bytes: ArcBytes<ArcLayout<true>> =
+        serde::Deserialize::deserialize(ByteBufDeserializer(vec)).unwrap();
+    assert_eq!(bytes.as_ptr(), ptr);
+    let vec = b"hello world".to_vec();
+    let ptr = vec.as_ptr();
+    let bytes: ArcBytesMut<VecLayout> =
+        serde::Deserialize::deserialize(ByteBufDeserializer(vec)).unwrap();
+    assert_eq!(bytes.as_ptr(), ptr);
+    // the buffer is copied otherwise
+    let bytes: ArcBytes<ArcLayout<false>> =
+        serde::Deserialize::deserialize(ByteBufDeserializer(b"hello world".to_vec())).unwrap();
+    assert_eq!(bytes, b"hello world");
+}
+
+// human-readable formats round trip, bytes being a sequence of numbers by default
+#[test]
+fn json_round_trip() {
+    let bytes = <ArcBytes>::from(b"\x00\xff");
+    let json = serde_json::to_string(&bytes).unwrap();
+    assert_eq!(json, "[0,255]");
+    assert_eq!(serde_json::from_str::<ArcBytes>(&json).unwrap(), bytes);
+    let string = <ArcStr>::from("hello \"world\"");
+    let json = serde_json::to_string(&string).unwrap();
+    assert_eq!(json, r#""hello \"world\"""#);
+    assert_eq!(serde_json::from_str::<ArcStr>(&json).unwrap(), string);
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Hex {
+    #[serde(with = "arc_slice::serde::hex")]
+    bytes: ArcBytes,
+    #[serde(with = "arc_slice::serde::hex")]
+    bytes_mut: ArcBytesMut<VecLayout>,
+}
+
+// hex is used for human-readable formats only, and invalid digits are rejected
+#[test]
+fn hex_round_trip() {
+    let hex = Hex {
+        bytes: <ArcBytes>::from(b"\x01\x23\xab"),
+        bytes_mut: ArcBytesMut::from(b"\xcd\xef".to_vec()),
+    };
+    let json = serde_json::to_string(&hex).unwrap();
+    assert_eq!(json, r#"{"bytes":"0123ab","bytes_mut":"cdef"}"#);
+    assert_eq!(serde_json::from_str::<Hex>(&json).unwrap(), hex);
+    let upper = r#"{"bytes":"0123AB","bytes_mut":""}"#;
+    assert_eq!(
+        serde_json::from_str::<Hex>(upper).unwrap().bytes,
+        b"\x01\x23\xab"
+    );
+    assert!(serde_json::from_str::<Hex>(r#"{"bytes":"012","bytes_mut":""}"#).is_err());
+    assert!(serde_json::from_str::<Hex>(r#"{"bytes":"0g","bytes_mut":""}"#).is_err());
+
+    let encoded = postcard::to_allocvec(&hex).unwrap();
+    assert_eq!(encoded, b"\x03\x01\x23\xab\x02\xcd\xef");
+    assert_eq!(postcard::from_bytes::<Hex>(&encoded).unwrap(), hex);
+}
@@ -1,4 +1,11 @@
-use arc_slice::{layout::VecLayout, ArcBytesMut};
+use arc_slice::{
+    buffer::AsMutBuffer,
+    error::TryReserveError,
+    layout::{
+        AnyBufferLayout, ArcLayout, BoxedSliceLayout, FromLayout, Layout, LayoutMut, VecLayout,
+    },
+    ArcBytesMut, ArcSliceMut, ArcStrMut,
+};
 
 #[test]
 fn reclaim_vec() {
@@ -9,3 +16,1185 @@ fn reclaim_vec() {
     bytes.reserve(1000);
     assert_eq!(bytes.as_ptr(), ptr);
 }
+
+// buffer can only be accessed mutably when the slice is unique
+#[test]
+fn buffer_mut() {
+    let buffer = unsafe { AsMutBuffer::new([0u8, 1, 2, 3]) };
+    let mut bytes = ArcBytesMut::<VecLayout>::from_buffer(buffer).into_shared();
+    let mut other = bytes.split_off(2);
+    assert!(unsafe { bytes.buffer_mut::<AsMutBuffer<[u8; 4]>>() }.is_none());
+    assert!(unsafe { other.buffer_mut::<AsMutBuffer<[u8; 4]>>() }.is_none());
+    drop(other);
+    let buffer = unsafe { bytes.buffer_mut::<AsMutBuffer<[u8; 4]>>() }.unwrap();
+    assert_eq!(**buffer, [0, 1, 2, 3]);
+    assert_eq!(bytes, [0, 1]);
+}
+
+// unique slices grow when written through `BufMut`, like `BytesMut`
+#[cfg(feature = "bytes")]
+#[test]
+fn buf_mut_grow() {
+    use bytes::BufMut;
+    fn put(mut buf: impl BufMut, slice: &[u8]) {
+        buf.put_slice(slice);
+    }
+    let mut bytes = ArcBytesMut::<VecLayout>::with_capacity(4);
+    put(&mut bytes, &[42; 100]);
+    bytes.put_u8(0);
+    assert_eq!(bytes.len(), 101);
+    assert!(bytes.capacity() >= 101);
+}
+
+// shared slices are bounded by their capacity
+#[cfg(feature = "bytes")]
+#[test]
+#[should_panic(expected = "advance out of bounds")]
+fn buf_mut_shared_bounded() {
+    use bytes::BufMut;
+    let mut bytes = ArcBytesMut::<VecLayout>::with_capacity(4).into_shared();
+    bytes.put_slice(&[42; 100]);
+}
+
+// reservation is capped by the spare capacity when the buffer can't grow
+#[test]
+fn try_reserve_at_most() {
+    let buffer = unsafe { AsMutBuffer::new([0u8; 8]) };
+    let mut bytes = ArcBytesMut::<VecLayout>::from_buffer(buffer);
+    bytes.truncate(2);
+    assert_eq!(bytes.try_reserve_at_most(4), 4);
+    assert_eq!(bytes.try_reserve_at_most(10), 6);
+    assert_eq!(bytes.capacity(), 8);
+
+    let mut bytes = ArcBytesMut::<VecLayout>::with_capacity(8).into_shared();
+    let mut other = bytes.split_off(4);
+    assert_eq!(bytes.try_reserve_at_most(10), 4);
+    assert_eq!(other.try_reserve_at_most(10), 4);
+    bytes.try_extend_from_slice(&[0; 4]).unwrap();
+    assert_eq!(bytes.try_reserve_at_most(10), 0);
+}
+
+// reservation falls back to the largest amount the allocator accepts
+#[cfg(feature = "allocator-api")]
+#[test]
+fn try_reserve_at_most_alloc_error() {
+    use std::{alloc::Layout, ptr::NonNull};
+
+    use allocator_api2::alloc::{AllocError, Allocator, Global};
+    use arc_slice::layout::ArcLayout;
+
+    struct Bounded;
+    unsafe impl Allocator for Bounded {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            if layout.size() > 1024 {
+                return Err(AllocError);
+            }
+            Global.allocate(layout)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe { Global.deallocate(ptr, layout) };
+        }
+    }
+
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::with_capacity_in(4, Bounded);
+    bytes.extend_from_slice(b"hello");
+    let reserved = bytes.try_reserve_at_most(4096);
+    assert!((512..4096).contains(&reserved));
+    assert!(bytes.capacity() >= bytes.len() + reserved && bytes.capacity() <= 1024);
+    assert_eq!(bytes.try_reserve_at_most(usize::MAX), bytes.capacity() - 5);
+}
+
+// every allocation goes through the custom allocator, and is released through it
+#[cfg(feature = "allocator-api")]
+#[test]
+fn with_capacity_in() {
+    use std::{
+        alloc::Layout,
+        ptr::NonNull,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use allocator_api2::alloc::{AllocError, Allocator, Global};
+    use arc_slice::{layout::ArcLayout, ArcBytes};
+
+    struct Counting {
+        allocations: AtomicUsize,
+        live: AtomicUsize,
+    }
+    unsafe impl Allocator for Counting {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.allocations.fetch_add(1, Ordering::Relaxed);
+            self.live.fetch_add(1, Ordering::Relaxed);
+            Global.allocate(layout)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.live.fetch_sub(1, Ordering::Relaxed);
+            unsafe { Global.deallocate(ptr, layout) };
+        }
+    }
+    static COUNTING: Counting = Counting {
+        allocations: AtomicUsize::new(0),
+        live: AtomicUsize::new(0),
+    };
+
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::with_capacity_in(4, &COUNTING);
+    assert_eq!(COUNTING.allocations.load(Ordering::Relaxed), 2);
+    bytes.extend_from_slice(b"hello world");
+    assert!(COUNTING.allocations.load(Ordering::Relaxed) > 2);
+    assert_eq!(COUNTING.live.load(Ordering::Relaxed), 2);
+    let frozen = bytes.freeze::<ArcLayout<true>>();
+    let hello = frozen.subslice(..5);
+    drop(frozen);
+    assert_eq!(hello, b"hello");
+    assert_eq!(COUNTING.live.load(Ordering::Relaxed), 2);
+    drop(hello);
+    assert_eq!(COUNTING.live.load(Ordering::Relaxed), 0);
+
+    let bytes = ArcBytes::<ArcLayout<true>>::from_slice_in(b"hello world", &COUNTING);
+    assert_eq!(bytes, b"hello world");
+    assert_eq!(COUNTING.live.load(Ordering::Relaxed), 2);
+    drop(bytes);
+    assert_eq!(COUNTING.live.load(Ordering::Relaxed), 0);
+}
+
+// concatenation allocates the exact total length, skipping empty parts
+#[test]
+fn concat() {
+    use arc_slice::{ArcSlice, ArcSliceMut};
+
+    let bytes = <ArcBytesMut>::concat(&[b"hello", b"", b" ", b"", b"world"]);
+    assert_eq!(bytes, b"hello world");
+    assert_eq!(bytes.capacity(), 11);
+    let s = ArcSliceMut::<str>::concat(&["", "hello", "", "world", ""]);
+    assert_eq!(s, "helloworld");
+    assert_eq!(<ArcBytesMut>::concat(&[b"", b""]), []);
+    assert_eq!(<ArcBytesMut>::concat(&[]).capacity(), 0);
+
+    let s = ArcSlice::<str>::from_slices(&["hello", "", " world"]);
+    assert_eq!(s, "hello world");
+    assert_eq!(ArcSlice::<[u8]>::from_slices(&[]), []);
+}
+
+// write_at patches a sub-region, and panics if it overflows the slice
+#[test]
+fn write_at() {
+    let mut bytes = <ArcBytesMut>::from(b"hello world");
+    bytes.write_at(0, b"H");
+    bytes.write_at(6, b"World");
+    bytes.write_at(11, b"");
+    assert_eq!(bytes, b"Hello World");
+    let mut bytes = bytes.into_shared();
+    let mut tail = bytes.split_off(6);
+    tail.write_at(0, b"there");
+    tail.fill(b'!');
+    assert_eq!(tail, b"!!!!!");
+    assert_eq!(bytes, b"Hello ");
+    let res = std::panic::catch_unwind(move || bytes.write_at(5, b"!!"));
+    assert!(res.is_err());
+}
+
+// removed items are moved out, and dropped exactly once
+#[test]
+fn remove() {
+    use std::sync::Arc;
+
+    use arc_slice::{layout::ArcLayout, ArcSliceMut};
+
+    let items: Vec<Arc<usize>> = (0..6).map(Arc::new).collect();
+    let array = <[_; 6]>::try_from(items.clone()).unwrap();
+    let mut slice = ArcSliceMut::<[Arc<usize>], ArcLayout<true>>::from_array(array);
+    assert_eq!(*slice.swap_remove(1), 1);
+    assert_eq!(*slice.remove(0), 0);
+    assert_eq!(slice.pop().as_deref(), Some(&4));
+    assert_eq!(slice.iter().map(|i| **i).collect::<Vec<_>>(), [5, 2, 3]);
+    slice.push(Arc::new(42));
+    assert_eq!(slice.len(), 4);
+    let counts = || items.iter().map(Arc::strong_count).collect::<Vec<_>>();
+    assert_eq!(counts(), [1, 1, 2, 2, 1, 2]);
+    drop(slice);
+    assert_eq!(counts(), [1; 6]);
+
+    let mut empty = ArcSliceMut::<[String]>::new();
+    assert!(empty.pop().is_none());
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| empty.remove(0)));
+    assert!(res.is_err());
+}
+
+// removed items are not dropped again by the buffer, which must support setting its length
+#[test]
+fn remove_buffer() {
+    use std::sync::Arc;
+
+    use arc_slice::{
+        buffer::{Buffer, BufferMut},
+        error::TryReserveError,
+    };
+
+    struct VecBuffer(Vec<Arc<()>>);
+    impl Buffer<[Arc<()>]> for VecBuffer {
+        fn as_slice(&self) -> &[Arc<()>] {
+            &self.0
+        }
+    }
+    unsafe impl BufferMut<[Arc<()>]> for VecBuffer {
+        fn as_mut_slice(&mut self) -> &mut [Arc<()>] {
+            &mut self.0
+        }
+        fn capacity(&self) -> usize {
+            self.0.capacity()
+        }
+        unsafe fn set_len(&mut self, len: usize) -> bool {
+            unsafe { self.0.set_len(len) };
+            true
+        }
+        fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+            BufferMut::try_reserve(&mut self.0, additional)
+        }
+    }
+
+    let item = Arc::new(());
+    let count = || Arc::strong_count(&item);
+    let check = |mut s: ArcSliceMut<[Arc<()>], ArcLayout<true>>| {
+        assert_eq!(count(), 5);
+        drop(s.swap_remove(0));
+        drop(s.remove(1));
+        drop(s.pop());
+        assert_eq!((s.len(), count()), (1, 2));
+        s.push(item.clone());
+        assert_eq!(count(), 3);
+        s.reserve(16);
+        assert!(s.capacity() >= 18);
+        assert_eq!(count(), 3);
+        drop(s.pop());
+        let frozen = s.freeze::<ArcLayout<true>>();
+        assert_eq!(count(), 2);
+        drop(frozen);
+        assert_eq!(count(), 1);
+    };
+    check(ArcSliceMut::from(vec![item.clone(); 4]));
+    check(ArcSliceMut::from_buffer(VecBuffer(vec![item.clone(); 4])));
+
+    let buffer = unsafe { AsMutBuffer::new([item.clone(), item.clone()]) };
+    let mut s = ArcSliceMut::<[Arc<()>], ArcLayout<true>>::from_buffer(buffer);
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| s.pop()));
+    assert!(res.is_err());
+    assert_eq!(s.len(), 2);
+    drop(s);
+    assert_eq!(count(), 1);
+}
+
+// a fixed arena serves both the buffer and the inner Arc, until it is exhausted
+#[test]
+fn buffer_source() {
+    use std::{
+        alloc::Layout,
+        cell::UnsafeCell,
+        ptr::NonNull,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use arc_slice::{
+        buffer::BufferSource,
+        error::{AllocError, TryReserveError},
+        layout::ArcLayout,
+        ArcBytes, ArcSliceMut,
+    };
+
+    #[repr(align(64))]
+    struct Arena {
+        memory: UnsafeCell<[u8; 256]>,
+        offset: AtomicUsize,
+        live: AtomicUsize,
+    }
+    unsafe impl Sync for Arena {}
+    unsafe impl BufferSource for &'static Arena {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+            let mut offset = self.offset.load(Ordering::Relaxed);
+            loop {
+                let start = (offset + layout.align() - 1) & !(layout.align() - 1);
+                let end = start + layout.size();
+                if end > 256 {
+                    return Err(AllocError);
+                }
+                match self.offset.compare_exchange_weak(
+                    offset,
+                    end,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        self.live.fetch_add(1, Ordering::Relaxed);
+                        let ptr = unsafe { self.memory.get().cast::<u8>().add(start) };
+                        return Ok(NonNull::new(ptr).unwrap());
+                    }
+                    Err(cur) => offset = cur,
+                }
+            }
+        }
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+            self.live.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+    static ARENA: Arena = Arena {
+        memory: UnsafeCell::new([0; 256]),
+        offset: AtomicUsize::new(0),
+        live: AtomicUsize::new(0),
+    };
+
+    let mut bytes = ArcSliceMut::<[u8], ArcLayout<true>>::with_capacity_in(16, &ARENA);
+    assert_eq!(ARENA.live.load(Ordering::Relaxed), 2);
+    bytes.extend_from_slice(b"hello world, bye");
+    assert_eq!(bytes.try_reserve(1), Err(TryReserveError::Unsupported));
+    let mut frozen = bytes.freeze::<ArcLayout<true>>();
+    let hello = frozen.split_to(5);
+    let world = frozen.subslice(1..6);
+    drop(frozen);
+    assert_eq!((&hello[..], &world[..]), (&b"hello"[..], &b"world"[..]));
+    assert_eq!(ARENA.live.load(Ordering::Relaxed), 2);
+    drop((hello, world));
+    assert_eq!(ARENA.live.load(Ordering::Relaxed), 0);
+
+    let err = ArcSliceMut::<[u8], ArcLayout<true>>::try_with_capacity_in(256, &ARENA);
+    assert_eq!(err.unwrap_err(), AllocError);
+    let bytes = ArcBytes::<ArcLayout<true>>::try_from_slice_in(b"hello", &ARENA).unwrap();
+    assert_eq!(bytes, b"hello");
+}
+
+// async writes grow the buffer, and are only short when reservation is unsupported
+#[cfg(feature = "tokio")]
+#[test]
+fn async_write() {
+    use arc_slice::{buffer::AsMutBuffer, layout::ArcLayout, ArcBytesMut};
+    use futures::executor::block_on;
+    use tokio::io::AsyncWriteExt;
+
+    let mut bytes = <ArcBytesMut>::new();
+    block_on(bytes.write_all(b"hello ")).unwrap();
+    block_on(bytes.write_all(b"world")).unwrap();
+    block_on(bytes.flush()).unwrap();
+    assert_eq!(bytes, b"hello world");
+
+    let buffer = unsafe { AsMutBuffer::new([0u8; 4]) };
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer(buffer);
+    bytes.truncate(0);
+    assert_eq!(block_on(bytes.write(b"hello")).unwrap(), 4);
+    assert_eq!(block_on(bytes.write(b"o")).unwrap(), 0);
+    block_on(bytes.shutdown()).unwrap();
+    assert_eq!(bytes, b"hell");
+}
+
+// only the appended items are zeroed, even when the spare capacity holds stale data
+#[test]
+fn extend_zeroed() {
+    let mut bytes = <ArcBytesMut>::with_capacity(8);
+    bytes.extend_from_slice(b"hello");
+    bytes.truncate(2);
+    bytes.extend_zeroed(3);
+    assert_eq!(bytes, b"he\0\0\0");
+    bytes.extend_zeroed(8);
+    assert_eq!(bytes, b"he\0\0\0\0\0\0\0\0\0\0\0");
+
+    let buffer = unsafe { AsMutBuffer::new([1u8; 4]) };
+    let mut bytes = ArcBytesMut::<VecLayout>::from_buffer(buffer);
+    bytes.truncate(2);
+    bytes.try_extend_zeroed(2).unwrap();
+    assert_eq!(bytes, [1, 1, 0, 0]);
+    assert_eq!(
+        bytes.try_extend_zeroed(1),
+        Err(arc_slice::error::TryReserveError::Unsupported)
+    );
+}
+
+// duplicates are independent copies keeping the capacity, for every kind of item
+#[test]
+fn duplicate() {
+    let mut bytes = <ArcBytesMut>::with_capacity(16);
+    bytes.extend_from_slice(b"hello");
+    let mut copy = bytes.duplicate();
+    assert_ne!(copy.as_ptr(), bytes.as_ptr());
+    assert_eq!(copy.capacity(), 16);
+    copy.extend_from_slice(b" world");
+    bytes[0] = b'j';
+    assert_eq!(bytes, b"jello");
+    assert_eq!(copy, b"hello world");
+
+    let s = <ArcStrMut>::from("héllo");
+    let copy = s.try_duplicate().unwrap();
+    assert_ne!(copy.as_ptr(), s.as_ptr());
+    assert_eq!(copy, "héllo");
+
+    let mut strings = ArcSliceMut::<[String]>::with_capacity(4);
+    strings.push("hello".to_string());
+    strings.push("world".to_string());
+    let mut copy = strings.duplicate();
+    copy[0].push('!');
+    drop(strings);
+    assert_eq!(copy, ["hello!".to_string(), "world".to_string()]);
+    assert_eq!(copy.capacity(), 4);
+
+    assert_eq!(<ArcBytesMut>::new().duplicate(), b"");
+}
+
+// integer writers append with the requested byte order and width
+#[test]
+fn put_int() {
+    let mut bytes = <ArcBytesMut>::new();
+    bytes.put_u8(1);
+    bytes.put_u16(0x0203);
+    bytes.put_u32_le(0x0706_0504);
+    bytes.put_i16(-1);
+    bytes.put_uint(0x0a0b0c, 3);
+    bytes.put_int_le(-2, 2);
+    bytes.put_uint_le(0, 0);
+    assert_eq!(
+        bytes,
+        [1, 2, 3, 4, 5, 6, 7, 0xff, 0xff, 0x0a, 0x0b, 0x0c, 0xfe, 0xff]
+    );
+    bytes.put_f64_ne(1.5);
+    assert_eq!(bytes[14..], 1.5f64.to_ne_bytes());
+    assert!(std::panic::catch_unwind(move || bytes.put_uint(0, 9)).is_err());
+}
+
+fn freeze_capacity_round_trip<L: Layout + AnyBufferLayout, LM: LayoutMut + AnyBufferLayout>() {
+    let mut bytes = ArcBytesMut::<LM>::with_capacity(4096);
+    bytes.extend_from_slice(&[0; 100]);
+    let frozen = bytes.try_freeze::<L>().unwrap();
+    assert_eq!(frozen.try_into_mut::<LM>().unwrap().capacity(), 4096);
+
+    let mut vec = Vec::with_capacity(4096);
+    vec.extend_from_slice(&[0; 100]);
+    let frozen = ArcBytesMut::<LM>::from(vec).try_freeze::<L>().unwrap();
+    assert_eq!(frozen.try_into_mut::<LM>().unwrap().capacity(), 4096);
+
+    let mut vec = Vec::with_capacity(4096);
+    vec.extend_from_slice(&[0; 100]);
+    let mut frozen = ArcBytesMut::<LM>::from(vec).try_freeze::<L>().unwrap();
+    let clone = frozen.clone();
+    frozen.advance(10);
+    drop(clone);
+    let bytes = frozen.try_into_mut::<LM>().unwrap();
+    assert_eq!(bytes.len(), 90);
+    assert_eq!(bytes.capacity(), 4086);
+}
+
+// converting a frozen slice back into a mutable one recovers the spare capacity
+#[test]
+fn freeze_capacity() {
+    freeze_capacity_round_trip::<ArcLayout<true>, ArcLayout<true>>();
+    freeze_capacity_round_trip::<ArcLayout<true>, VecLayout>();
+    freeze_capacity_round_trip::<VecLayout, ArcLayout<true>>();
+    freeze_capacity_round_trip::<VecLayout, VecLayout>();
+    freeze_capacity_round_trip::<BoxedSliceLayout, VecLayout>();
+
+    let mut bytes = <ArcBytesMut<ArcLayout<false>>>::with_capacity(4096);
+    bytes.extend_from_slice(&[0; 100]);
+    let mut frozen = bytes.freeze::<ArcLayout<false>>();
+    frozen.advance(10);
+    let bytes = frozen.try_into_mut::<ArcLayout<false>>().unwrap();
+    assert_eq!(bytes.capacity(), 4086);
+}
+
+// parts are unsplit in both orders, with empty parts, and only when they share the buffer
+#[test]
+fn unsplit() {
+    let mut a = ArcBytesMut::<VecLayout>::from(b"hello world".to_vec()).into_shared();
+    let mut b = a.split_off(5);
+    let c = b.split_off(1);
+    b.try_unsplit(c).unwrap();
+    b.try_unsplit(a).unwrap();
+    assert_eq!(b, b"hello world");
+
+    // spare capacity of the first part is only skipped if the second part is empty
+    let mut a = <ArcBytesMut>::with_capacity(16);
+    a.extend_from_slice(b"hello");
+    let mut a = a.into_shared();
+    let b = a.split_off(8);
+    let mut c = a.split_off(4);
+    assert_eq!((a.len(), c.len()), (4, 1));
+    c.try_unsplit(b).unwrap();
+    assert_eq!(c.capacity(), 12);
+    let d = a.split_off(2);
+    let a = c.try_unsplit(a).unwrap_err();
+    c.try_unsplit(d).unwrap();
+    c.try_unsplit(a).unwrap();
+    assert_eq!((&c[..], c.capacity()), (&b"hello"[..], 16));
+    let d = c.split_off(4);
+    c.truncate(2);
+    assert!(c.try_unsplit(d).is_err());
+
+    // empty parts without capacity
+    let mut empty = ArcBytesMut::<VecLayout>::new().into_shared();
+    empty.try_unsplit(ArcBytesMut::new().into_shared()).unwrap();
+    empty
+        .try_unsplit(ArcBytesMut::from(b"hello".to_vec()).into_shared())
+        .unwrap();
+    empty.try_unsplit(ArcBytesMut::new().into_shared()).unwrap();
+    assert_eq!(empty, b"hello");
+
+    // foreign buffers
+    let buffer = unsafe { AsMutBuffer::new(*b"hello world") };
+    let mut a = ArcBytesMut::<ArcLayout<true>>::from_buffer(buffer).into_shared();
+    let mut b = a.split_off(6);
+    let other = ArcBytesMut::from_buffer(unsafe { AsMutBuffer::new(*b"world") }).into_shared();
+    let other = a.try_unsplit(other).unwrap_err();
+    assert!(b.try_unsplit(other).is_err());
+    b.try_unsplit(a).unwrap();
+    assert_eq!(b, b"hello world");
+}
+
+// parts converted to another layout are unsplit by comparing their underlying buffer
+#[test]
+fn unsplit_any() {
+    let mut a = ArcBytesMut::<VecLayout>::from(b"hello world".to_vec()).into_shared();
+    let b = a.split_off(5);
+    let mut b = b.try_with_layout::<ArcLayout<true>>().unwrap();
+    let c = b.split_off(1);
+    b.try_unsplit_any(c).unwrap();
+    let other = ArcBytesMut::<VecLayout>::from(b"hello".to_vec()).into_shared();
+    let other = b.try_unsplit_any(other).unwrap_err();
+    assert_eq!(other, b"hello");
+    b.try_unsplit_any(a).unwrap();
+    assert_eq!(b, b"hello world");
+    let bytes = b.try_into_unique().unwrap();
+    drop(bytes.freeze::<VecLayout>());
+
+    let mut a = ArcBytesMut::<ArcLayout<true>>::from(b"hello world").into_shared();
+    let b = a.split_off(5);
+    let b = b.try_with_layout::<ArcLayout<false>>().unwrap();
+    a.try_unsplit_any(b).unwrap();
+    assert_eq!(a, b"hello world");
+    let mut empty = ArcBytesMut::<ArcLayout<false>>::new().into_shared();
+    empty.try_unsplit_any(a).unwrap();
+    assert_eq!(empty, b"hello world");
+    let vec = ArcBytesMut::<VecLayout>::from(b"vec".to_vec()).into_shared();
+    assert!(empty.try_unsplit_any(vec).is_err());
+}
+
+fn freeze_metadata_preserved<LM: LayoutMut + AnyBufferLayout, L: Layout>() {
+    let bytes = ArcBytesMut::<LM>::from_buffer_with_metadata(vec![0, 1, 2], "metadata");
+    let frozen = bytes.try_freeze::<L>().unwrap();
+    assert_eq!(frozen.metadata::<&str>(), Some(&"metadata"));
+}
+
+// freezing keeps the buffer metadata, or fails if the target layout can't hold it
+#[test]
+fn freeze_metadata() {
+    freeze_metadata_preserved::<ArcLayout<true>, ArcLayout<true>>();
+    freeze_metadata_preserved::<ArcLayout<true>, BoxedSliceLayout>();
+    freeze_metadata_preserved::<ArcLayout<true>, VecLayout>();
+    freeze_metadata_preserved::<VecLayout, ArcLayout<true>>();
+    freeze_metadata_preserved::<VecLayout, VecLayout>();
+    #[cfg(feature = "raw-buffer")]
+    freeze_metadata_preserved::<ArcLayout<true>, arc_slice::layout::RawLayout>();
+
+    let bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], 42);
+    let frozen = bytes.freeze::<ArcLayout<true>>();
+    assert_eq!(frozen.metadata::<i32>(), Some(&42));
+    let bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer_with_metadata(vec![0, 1, 2], 42);
+    let bytes = bytes.try_freeze::<ArcLayout<false>>().unwrap_err();
+    assert_eq!(bytes.metadata::<i32>(), Some(&42));
+}
+
+// formatting reserves the whole length at once, and surfaces reservation errors
+#[test]
+fn format() {
+    use std::{cell::Cell, fmt, fmt::Write};
+
+    // formatting happens only once when the spare capacity is enough
+    struct Counted<'a>(&'a Cell<usize>);
+    impl fmt::Display for Counted<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            f.write_str("counted")
+        }
+    }
+    let count = Cell::new(0);
+    let mut s = ArcStrMut::<ArcLayout<false>>::with_capacity(16);
+    s.format(format_args!("{}!", Counted(&count))).unwrap();
+    assert_eq!((&s[..], count.get()), ("counted!", 1));
+    write!(s, "{}{}", Counted(&count), 42).unwrap();
+    assert_eq!((&s[..], count.get()), ("counted!counted42", 3));
+
+    let mut s = ArcStrMut::<ArcLayout<false>>::new();
+    s.format(format_args!("{:>1000}", 42)).unwrap();
+    assert_eq!((s.len(), s.capacity()), (1000, 1000));
+    assert!(s.ends_with(" 42"));
+    write!(s, "{}", "a".repeat(100)).unwrap();
+    assert_eq!(s.len(), 1100);
+
+    let mut s = ArcStrMut::<ArcLayout<false>>::with_capacity(16).into_shared();
+    let _other = s.split_off(16);
+    s.format(format_args!("{:>16}", 42)).unwrap();
+    let err = s.format(format_args!("{}", 42)).unwrap_err();
+    assert_eq!(err, TryReserveError::NotUnique);
+    assert!(write!(s, "{}", 42).is_err());
+    assert_eq!(s.len(), 16);
+
+    let buffer = unsafe { AsMutBuffer::new([0u8; 8]) };
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer(buffer);
+    bytes.truncate(0);
+    bytes.format(format_args!("{}", 4242)).unwrap();
+    let err = bytes.format(format_args!("{:08}", 42)).unwrap_err();
+    assert_eq!(err, TryReserveError::Unsupported);
+    assert_eq!(bytes, b"4242");
+    write!(bytes, "{}", 4242).unwrap();
+    assert_eq!(bytes, b"42424242");
+}
+
+// formatted strings are allocated once with their exact length, and can be frozen
+#[test]
+fn from_fmt() {
+    let s = ArcStrMut::<ArcLayout<false>>::from_fmt(format_args!("{:>100}", 42));
+    assert_eq!((s.len(), s.capacity()), (100, 100));
+    assert_eq!(s.freeze::<ArcLayout<false>>().trim_start(), "42");
+    let s = ArcStrMut::<VecLayout>::try_from_fmt(format_args!("static")).unwrap();
+    assert_eq!(s, "static");
+    let s = arc_slice::arc_format!(ArcStrMut<VecLayout>; "{}-{x}", 4, x = 2);
+    assert_eq!(s, "4-2");
+}
+
+// zeroizing a split shared slice leaves the other part untouched
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize() {
+    use zeroize::Zeroize;
+
+    let mut bytes = ArcBytesMut::<ArcLayout<false>>::from(b"hello world").into_shared();
+    let mut world = bytes.split_off(5);
+    world.zeroize();
+    assert_eq!(world, [0; 6]);
+    assert_eq!(bytes, b"hello");
+    drop(world);
+    let mut bytes = bytes.try_into_unique().unwrap();
+    bytes.zeroize();
+    assert_eq!(bytes, [0; 5]);
+}
+
+// once the other parts of a split shared slice are dropped, reservation reuses the buffer
+#[test]
+fn reserve_shared_after_drop() {
+    fn check<L: LayoutMut>() {
+        let mut bytes = ArcBytesMut::<L>::with_capacity(64);
+        bytes.extend_from_slice(b"hello world");
+        let mut bytes = bytes.into_shared();
+        let world = bytes.split_off(5);
+        assert_eq!(bytes.try_reserve(1), Err(TryReserveError::NotUnique));
+        drop(world);
+        let ptr = bytes.as_ptr();
+        bytes.try_reserve(32).unwrap();
+        assert_eq!(bytes.as_ptr(), ptr);
+        assert_eq!(bytes.capacity(), 64);
+        bytes.try_extend_from_slice(b" again").unwrap();
+        let hello = bytes.split_to(6);
+        drop(hello);
+        bytes.try_reserve(50).unwrap();
+        assert_eq!(bytes.as_ptr(), ptr.wrapping_add(6));
+        assert_eq!(bytes, b"again");
+    }
+    check::<ArcLayout<false>>();
+    check::<ArcLayout<true>>();
+    check::<VecLayout>();
+}
+
+// only the lower bound of the size hint is reserved, so a huge upper bound doesn't overallocate
+#[test]
+fn extend_lower_size_hint() {
+    let items = (0..usize::MAX).map(|i| i as u8).take_while(|&i| i < 100);
+    let mut bytes = ArcBytesMut::<ArcLayout<false>>::new();
+    bytes.extend(items);
+    assert_eq!(bytes.len(), 100);
+    assert!(bytes.capacity() < 1000);
+    bytes
+        .try_reserve_with_hint(bytes.spare_capacity() + 1, 5000)
+        .unwrap();
+    assert!(bytes.capacity() >= 5000);
+}
+
+// spare capacity chunks are only available for crate-allocated buffers and vectors
+#[test]
+fn spare_capacity_chunks_mut() {
+    let mut bytes = ArcBytesMut::<VecLayout>::from(Vec::with_capacity(10));
+    let mut chunks = bytes.spare_capacity_chunks_mut(4).unwrap();
+    let mut lens = Vec::new();
+    while let Some(chunk) = chunks.next_chunk() {
+        lens.push(chunk.len());
+        chunk[0].write(lens.len() as u8);
+        unsafe { chunks.commit(1) };
+    }
+    assert_eq!(
+        lens,
+        [4; 7].into_iter().chain([3, 2, 1]).collect::<Vec<_>>()
+    );
+    assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let mut bytes = ArcBytesMut::<ArcLayout<false>>::new();
+    assert!(bytes
+        .spare_capacity_chunks_mut(4)
+        .unwrap()
+        .next_chunk()
+        .is_none());
+    let buffer = unsafe { AsMutBuffer::new([0u8; 4]) };
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer(buffer);
+    assert!(bytes.spare_capacity_chunks_mut(4).is_none());
+}
+
+// replacing a range shifts the tail both ways, and only reserves when the slice grows
+#[test]
+fn replace_range() {
+    let mut bytes = ArcBytesMut::<VecLayout>::from(b"hello world".to_vec());
+    bytes.replace_range(5..5, b",");
+    assert_eq!(bytes, b"hello, world");
+    bytes.replace_range(..=4, b"hi");
+    assert_eq!(bytes, b"hi, world");
+    bytes.replace_range(4.., b"there");
+    assert_eq!(bytes, b"hi, there");
+    bytes.replace_range(.., b"");
+    assert!(bytes.is_empty());
+
+    let buffer = unsafe { AsMutBuffer::new([0u8, 1, 2, 3]) };
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer(buffer).into_shared();
+    bytes.try_replace_range(1..3, &[4]).unwrap();
+    assert_eq!(bytes, [0, 4, 3]);
+    bytes.try_replace_range(1..2, &[5, 6]).unwrap();
+    assert_eq!(bytes, [0, 5, 6, 3]);
+    assert_eq!(
+        bytes.try_replace_range(..0, &[7]),
+        Err(TryReserveError::Unsupported)
+    );
+    assert_eq!(bytes, [0, 5, 6, 3]);
+}
+
+// insertion shifts the tail to the right, growing the buffer when needed, and moves droppable
+// items without duplicating them
+#[test]
+fn insert() {
+    fn check<L: LayoutMut>() {
+        let mut bytes = ArcBytesMut::<L>::from(b"world");
+        bytes.insert_from_slice(0, b"hello");
+        assert_eq!(bytes, b"helloworld");
+        bytes.insert(5, b' ');
+        assert_eq!(bytes, b"hello world");
+        let len = bytes.len();
+        bytes.insert_from_slice(len, b"!");
+        bytes.try_insert(len, b'?').unwrap();
+        assert_eq!(bytes, b"hello world?!");
+        bytes.insert_from_slice(6, &[b'.'; 1000]);
+        assert!(bytes.capacity() >= 1013);
+        assert_eq!(&bytes[..6], b"hello ");
+        assert_eq!(&bytes[6..1006], [b'.'; 1000]);
+        assert_eq!(&bytes[1006..], b"world?!");
+        bytes.try_insert_from_slice(0, b"").unwrap();
+        assert_eq!(bytes.len(), 1013);
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| bytes.insert(1014, 0)));
+        assert!(res.is_err());
+    }
+    check::<ArcLayout<false>>();
+    check::<ArcLayout<true>>();
+    check::<VecLayout>();
+
+    let items: Vec<std::sync::Arc<usize>> = (0..4).map(std::sync::Arc::new).collect();
+    let mut slice = ArcSliceMut::<[std::sync::Arc<usize>]>::new();
+    slice.insert(0, items[3].clone());
+    slice.insert(0, items[0].clone());
+    slice.insert(1, items[2].clone());
+    slice.try_insert(1, items[1].clone()).unwrap();
+    assert_eq!(slice.iter().map(|i| **i).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert!(items.iter().all(|i| std::sync::Arc::strong_count(i) == 2));
+    drop(slice);
+    assert!(items.iter().all(|i| std::sync::Arc::strong_count(i) == 1));
+}
+
+// string insertion copies the bytes at a char boundary, growing the buffer when needed
+#[test]
+fn insert_str() {
+    fn check<L: LayoutMut>() {
+        let mut s = ArcStrMut::<L>::from("wörld");
+        s.insert_from_slice(0, "héllo");
+        assert_eq!(s, "héllowörld");
+        s.try_insert_from_slice(6, " ").unwrap();
+        assert_eq!(s, "héllo wörld");
+        let len = s.len();
+        s.insert_from_slice(len, &".".repeat(1000));
+        assert!(s.capacity() >= 1013);
+        assert_eq!(&s[..len], "héllo wörld");
+    }
+    check::<ArcLayout<false>>();
+    check::<ArcLayout<true>>();
+    check::<VecLayout>();
+}
+
+// inserting inside a multi-byte char is rejected
+#[test]
+#[should_panic(expected = "byte index 2 is not a char boundary")]
+fn insert_str_not_char_boundary() {
+    let mut s = <ArcStrMut>::from("héllo");
+    s.insert_from_slice(2, "!");
+}
+
+// frozen frames never change while the tail keeps being written, and the buffer is reused in
+// place once they are dropped
+#[test]
+fn freeze_to() {
+    fn check<LM: LayoutMut + AnyBufferLayout>() {
+        let mut bytes = ArcBytesMut::<LM>::from(Vec::with_capacity(64)).into_shared();
+        let start = bytes.as_ptr();
+        let mut frames = Vec::new();
+        for i in 0..4u8 {
+            bytes.try_extend_from_slice(&[i; 8]).unwrap();
+            let frame = bytes.freeze_to::<ArcLayout<true>>(8);
+            assert_eq!(frame.as_ptr(), unsafe { start.add(8 * i as usize) });
+            frames.push(frame);
+            assert!(bytes.is_empty());
+        }
+        bytes.try_extend_from_slice(b"tail").unwrap();
+        let frame = bytes.try_freeze_to::<ArcLayout<true>>(2).unwrap();
+        assert_eq!(frame, b"ta");
+        assert_eq!(bytes, b"il");
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(frame, &[i as u8; 8]);
+        }
+        drop((frames, frame));
+        let mut bytes = bytes.try_into_unique().unwrap();
+        assert!(bytes.try_reclaim(40));
+        assert_eq!(bytes.as_ptr(), start);
+        assert_eq!(bytes, b"il");
+    }
+    check::<ArcLayout<true>>();
+    check::<VecLayout>();
+
+    let mut text = ArcStrMut::<ArcLayout<true>>::from("héllo").into_shared();
+    let frozen = text.freeze_to::<ArcLayout<true>>(3);
+    assert_eq!(frozen, "hé");
+    assert_eq!(text, "llo");
+}
+
+// short slices are inlined, releasing the buffer, and a failed freeze returns the original slice
+#[cfg(feature = "inlined")]
+#[test]
+fn try_freeze_small() {
+    use arc_slice::inlined::SmallArcSlice;
+
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer_with_metadata(vec![0; 64], "meta");
+    bytes.truncate(4);
+    let small: SmallArcSlice<[u8], ArcLayout<false>> = bytes.try_freeze_small().unwrap();
+    assert!(small.is_inlined());
+    assert_eq!(small, [0; 4]);
+
+    let bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer_with_metadata(vec![1; 64], "meta");
+    let bytes = bytes
+        .try_freeze_small::<ArcLayout<false>>()
+        .map(drop)
+        .unwrap_err();
+    assert_eq!(bytes, [1; 64]);
+    assert_eq!(bytes.metadata::<&str>(), Some(&"meta"));
+    let small = bytes.try_freeze_small::<ArcLayout<true>>().unwrap();
+    assert!(!small.is_inlined());
+    assert_eq!(small, [1; 64]);
+}
+
+// back-references copy items one by one, so overlapping ranges repeat the pattern
+#[test]
+fn extend_from_within() {
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from(b"abcd".to_vec());
+    // range touching the current end
+    bytes.extend_from_within(2..4);
+    assert_eq!(bytes, b"abcdcd");
+    bytes.extend_from_within(..=1);
+    assert_eq!(bytes, b"abcdcdab");
+    // distance = 1 (RLE)
+    bytes.extend_from_within(7..12);
+    assert_eq!(bytes, b"abcdcdabbbbbb");
+    // distance < length
+    let mut bytes = <ArcBytesMut>::from(b"xyz");
+    bytes.try_extend_from_within(1..9).unwrap();
+    assert_eq!(bytes, b"xyzyzyzyzyz");
+    bytes.extend_from_within(5..5);
+    bytes.extend_from_within(11..11);
+    assert_eq!(bytes.len(), 11);
+
+    let mut bytes = <ArcBytesMut>::from(b"abc");
+    assert!(std::panic::catch_unwind(move || bytes.extend_from_within(3..4)).is_err());
+    let mut bytes = <ArcBytesMut>::from(b"abc");
+    assert!(std::panic::catch_unwind(move || bytes.extend_from_within(4..4)).is_err());
+
+    let buffer = unsafe { AsMutBuffer::new(*b"ab\0\0") };
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer(buffer);
+    bytes.truncate(2);
+    bytes.try_extend_from_within(..2).unwrap();
+    assert_eq!(
+        bytes.try_extend_from_within(..1),
+        Err(TryReserveError::Unsupported)
+    );
+    assert_eq!(bytes, b"abab");
+}
+
+// raw parts round trip exactly, the ownership being transferred back and forth
+#[test]
+fn raw_parts() {
+    fn round_trip<L: LayoutMut, const UNIQUE: bool>(bytes: ArcSliceMut<[u8], L, UNIQUE>) {
+        let (ptr, len, cap) = (bytes.as_ptr(), bytes.len(), bytes.capacity());
+        let parts = bytes.into_raw_parts();
+        assert_eq!(
+            (
+                parts.start.as_ptr().cast_const(),
+                parts.length,
+                parts.capacity
+            ),
+            (ptr, len, cap)
+        );
+        let mut bytes = unsafe { ArcSliceMut::<[u8], L, UNIQUE>::from_raw_parts(parts) };
+        assert_eq!(
+            (bytes.as_ptr(), bytes.len(), bytes.capacity()),
+            (ptr, len, cap)
+        );
+        assert_eq!(bytes, b"hello world"[..len]);
+        bytes.try_extend_from_slice(b"!").unwrap();
+    }
+    let mut bytes = ArcBytesMut::<ArcLayout>::with_capacity(32);
+    bytes.extend_from_slice(b"hello world");
+    round_trip(bytes);
+    round_trip(ArcBytesMut::<VecLayout>::from(b"hello world".to_vec()));
+    round_trip(ArcBytesMut::<VecLayout>::new());
+    let mut bytes = ArcBytesMut::<ArcLayout>::with_capacity(32).into_shared();
+    bytes.try_extend_from_slice(b"hello world").unwrap();
+    round_trip(bytes);
+}
+
+// reads are written in the spare capacity, only for buffers supporting uninit writes
+#[cfg(read_buf)]
+#[test]
+fn read_buf_from() {
+    let mut bytes = ArcBytesMut::<VecLayout>::from(Vec::with_capacity(8));
+    let mut reader = &b"hello world"[..];
+    assert_eq!(bytes.read_buf_from(&mut reader, 0).unwrap(), 0);
+    assert_eq!(bytes.read_buf_from(&mut reader, 5).unwrap(), 5);
+    assert_eq!(bytes.read_buf_from(&mut reader, 16).unwrap(), 3);
+    assert_eq!(bytes.read_buf_from(&mut reader, 16).unwrap(), 0);
+    assert_eq!(bytes, b"hello wo");
+    bytes.reserve(3);
+    assert_eq!(bytes.read_buf_from(&mut reader, 16).unwrap(), 3);
+    assert_eq!(bytes, b"hello world");
+
+    let buffer = unsafe { AsMutBuffer::new(vec![0; 8]) };
+    let mut bytes = ArcBytesMut::<ArcLayout<true>>::from_buffer(buffer);
+    bytes.truncate(0);
+    let err = bytes.read_buf_from(&mut &b"hello"[..], 5).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+}
+
+// boxed slices are adopted and recovered without reallocation if the slice spans the buffer
+#[test]
+fn boxed_slice_round_trip() {
+    fn check<L: AnyBufferLayout + LayoutMut>() {
+        let boxed: Box<[u8]> = b"hello world".to_vec().into_boxed_slice();
+        let ptr = boxed.as_ptr();
+        let mut bytes = ArcBytesMut::<L>::from(boxed);
+        assert_eq!(bytes.as_ptr(), ptr);
+        bytes[0] = b'H';
+        let boxed = bytes.try_into_buffer::<Box<[u8]>>().unwrap();
+        assert_eq!((boxed.as_ptr(), &*boxed), (ptr, &b"Hello world"[..]));
+
+        let mut bytes = ArcBytesMut::<L>::from(boxed);
+        bytes.truncate(5);
+        let mut bytes = bytes.try_into_buffer::<Box<[u8]>>().unwrap_err();
+        bytes.advance(1);
+        assert!(bytes.try_into_buffer::<Box<[u8]>>().is_err());
+
+        let boxed: Box<str> = "hello world".into();
+        let ptr = boxed.as_ptr();
+        let s = ArcSliceMut::<str, L>::from(boxed);
+        let boxed = s.try_into_buffer::<Box<str>>().unwrap();
+        assert_eq!((boxed.as_ptr(), &*boxed), (ptr, "hello world"));
+    }
+    check::<ArcLayout<true>>();
+    check::<VecLayout>();
+}
+
+// initialized items reuse the buffer, whatever the layout
+#[test]
+fn assume_init() {
+    fn check<L: LayoutMut>() {
+        let mut s = ArcSliceMut::<[u32], L>::new_uninit(4);
+        let ptr = s.as_ptr();
+        s.iter_mut().for_each(|item| _ = item.write(42));
+        let s = unsafe { s.assume_init() };
+        assert_eq!((s.as_ptr(), &s[..]), (ptr.cast(), &[42; 4][..]));
+    }
+    check::<ArcLayout<false>>();
+    check::<ArcLayout<true>>();
+    check::<VecLayout>();
+}
+
+// buffers with items needing drop track their length themselves, whatever the drop order
+#[test]
+fn buffer_needs_drop() {
+    let item = std::sync::Arc::new(());
+    let s = ArcSliceMut::<[_], ArcLayout<true>>::from(vec![item.clone(), item.clone()]);
+    drop(s);
+    assert_eq!(std::sync::Arc::strong_count(&item), 1);
+    let mut s =
+        ArcSliceMut::<[_], ArcLayout<true>>::from(vec![item.clone(), item.clone()]).into_shared();
+    let s2 = s.split_off(1);
+    drop(s2);
+    drop(s);
+    assert_eq!(std::sync::Arc::strong_count(&item), 1);
+}
+
+// items pushed into a buffer needing drop are dropped with it, and truncated ones right away
+#[test]
+fn buffer_needs_drop_length() {
+    let item = std::sync::Arc::new(());
+    let count = || std::sync::Arc::strong_count(&item);
+    let mut vec = Vec::with_capacity(8);
+    vec.push(item.clone());
+    let mut s = ArcSliceMut::<[_], ArcLayout<true>>::from(vec);
+    s.push(item.clone());
+    s.insert(0, item.clone());
+    drop(s);
+    assert_eq!(count(), 1);
+    let mut s = ArcSliceMut::<[_], ArcLayout<true>>::from(vec![item.clone(); 3]);
+    s.truncate(1);
+    assert_eq!(count(), 2);
+    s.extend([item.clone(), item.clone()]);
+    let frozen = s.freeze::<ArcLayout<true>>();
+    assert_eq!(count(), 4);
+    drop(frozen);
+    assert_eq!(count(), 1);
+}
+
+// items needing drop can be pushed beyond the capacity, the buffer moving them
+#[test]
+fn buffer_needs_drop_grow() {
+    fn check<L: LayoutMut>(mut s: ArcSliceMut<[std::sync::Arc<()>], L>) {
+        let item = s[0].clone();
+        for _ in 0..16 {
+            s.push(item.clone());
+        }
+        assert_eq!(std::sync::Arc::strong_count(&item), 18);
+        drop(s);
+        assert_eq!(std::sync::Arc::strong_count(&item), 1);
+    }
+    fn with_capacity<L: LayoutMut>() -> ArcSliceMut<[std::sync::Arc<()>], L> {
+        let mut s = ArcSliceMut::<[_], L>::with_capacity(1);
+        s.push(std::sync::Arc::new(()));
+        s
+    }
+    check(with_capacity::<ArcLayout<false>>());
+    check(with_capacity::<VecLayout>());
+    check(ArcSliceMut::<[_], ArcLayout<true>>::from(vec![
+        std::sync::Arc::new(()),
+    ]));
+}
+
+// built slices take the initialized count as length, which cannot exceed the capacity
+#[test]
+fn build() {
+    let s = unsafe {
+        ArcSliceMut::<[String]>::build(3, |uninit| {
+            uninit[0].write("hello".into());
+            1
+        })
+    };
+    assert_eq!((&s[..], s.capacity()), (&["hello".to_string()][..], 3));
+    let s = unsafe { ArcBytesMut::<VecLayout>::try_build(0, |_| 0) }.unwrap();
+    assert_eq!((&s[..], s.capacity()), (&[][..], 0));
+    let res = std::panic::catch_unwind(|| unsafe { <ArcBytesMut>::build(2, |_| 3) });
+    assert!(res.is_err());
+}
+
+// deep clones copy the initialized items into an independent buffer with the same capacity
+#[test]
+fn deep_clone() {
+    fn check<L: LayoutMut>(mut s: ArcBytesMut<L>) {
+        s.advance(1);
+        let mut clone = s.deep_clone();
+        assert_eq!((&clone, clone.capacity()), (&s, s.capacity()));
+        assert_ne!(clone.as_ptr(), s.as_ptr());
+        clone[0] = b'E';
+        clone.extend_from_slice(b"!");
+        assert_eq!(clone, b"Ello!");
+        assert_eq!(s, b"ello");
+        s.truncate(0);
+        assert_eq!(clone, b"Ello!");
+    }
+    let mut vec = Vec::with_capacity(16);
+    vec.extend_from_slice(b"hello");
+    check(ArcBytesMut::<VecLayout>::from(vec.clone()));
+    check(ArcBytesMut::<ArcLayout<false>>::from_slice(b"hello"));
+    check(ArcBytesMut::<ArcLayout<true>>::from(vec));
+    let empty = ArcStrMut::<ArcLayout<false>>::new();
+    assert_eq!(empty.try_deep_clone().unwrap().capacity(), 0);
+}
+
+// parts are frozen at once, and the buffer is released once all of them are dropped
+#[test]
+fn freeze_parts() {
+    fn check<L: LayoutMut, L2: FromLayout<L>>(s: ArcBytesMut<L>) {
+        let ptr = s.as_ptr();
+        let parts = s.freeze_parts::<L2>(&[0, 5, 5, 6, 11]);
+        assert_eq!(parts, [&b""[..], b"hello", b"", b" ", b"world", b""]);
+        assert_eq!(parts[1].as_ptr(), ptr);
+        assert_eq!(parts[4].as_ptr(), ptr.wrapping_add(6));
+        let mut parts = parts.into_iter();
+        let hello = parts.nth(1).unwrap();
+        drop(parts);
+        assert!(hello.try_into_mut::<ArcLayout<true>>().is_ok());
+    }
+    fn bytes<L: LayoutMut + AnyBufferLayout>() -> ArcBytesMut<L> {
+        let mut vec = Vec::with_capacity(16);
+        vec.extend_from_slice(b"hello world");
+        ArcBytesMut::from(vec)
+    }
+    check::<VecLayout, VecLayout>(bytes());
+    check::<VecLayout, BoxedSliceLayout>(bytes());
+    check::<VecLayout, ArcLayout<true>>(bytes());
+    check::<ArcLayout<true>, ArcLayout<true>>(bytes());
+    check::<ArcLayout, ArcLayout>(ArcBytesMut::from_slice(b"hello world"));
+
+    let s = ArcStrMut::<ArcLayout>::from("hello");
+    let parts = s.try_freeze_parts::<ArcLayout>(&[]).unwrap();
+    assert_eq!(parts, ["hello"]);
+    let s = ArcStrMut::<ArcLayout>::from("été");
+    assert_eq!(s.freeze_parts::<ArcLayout>(&[2, 3]), ["é", "t", "é"]);
+    let empty = ArcBytesMut::<ArcLayout>::new().freeze_parts::<ArcLayout>(&[0]);
+    assert_eq!(empty, [b"", b""]);
+    let res = std::panic::catch_unwind(|| {
+        ArcBytesMut::<ArcLayout>::from_slice(b"hello").freeze_parts::<ArcLayout>(&[3, 2])
+    });
+    assert!(res.is_err());
+    let res = std::panic::catch_unwind(|| {
+        ArcBytesMut::<ArcLayout>::from_slice(b"hello").freeze_parts::<ArcLayout>(&[6])
+    });
+    assert!(res.is_err());
+    let res = std::panic::catch_unwind(|| {
+        ArcStrMut::<ArcLayout>::from("été").freeze_parts::<ArcLayout>(&[1])
+    });
+    assert!(res.is_err());
+}
+
+// buffers spanning the whole slice are reclaimed without copy, other ones are shrunk or copied
+#[test]
+fn into_boxed_slice() {
+    let boxed: Box<[u8]> = Box::new(*b"hello");
+    let ptr = boxed.as_ptr();
+    let s = ArcBytesMut::<VecLayout>::from(boxed.into_vec());
+    let boxed = s.try_into_boxed_slice().unwrap();
+    assert_eq!((&*boxed, boxed.as_ptr()), (&b"hello"[..], ptr));
+    let s = ArcBytesMut::<ArcLayout<true>>::from(boxed);
+    let boxed = s.try_into_boxed_slice().unwrap();
+    assert_eq!((&*boxed, boxed.as_ptr()), (&b"hello"[..], ptr));
+
+    let mut vec = Vec::with_capacity(16);
+    vec.extend_from_slice(b"hello world");
+    let mut s = ArcBytesMut::<VecLayout>::from(vec);
+    s.advance(6);
+    let s = s.try_into_boxed_slice().unwrap_err();
+    assert_eq!(*s.into_boxed_slice(), *b"world");
+
+    let s = ArcStrMut::<ArcLayout<false>>::from("hello");
+    assert_eq!(&*s.into_boxed_slice(), "hello");
+    let mut s = ArcBytesMut::<ArcLayout<true>>::from(vec![0, 1, 2, 3]).into_shared();
+    let s2 = s.split_off(2);
+    let s = s.try_into_boxed_slice().unwrap_err();
+    assert_eq!(*s.into_boxed_slice(), [0, 1]);
+    assert_eq!(*s2.into_boxed_slice(), [2, 3]);
+}